@@ -1,11 +1,29 @@
 use crate::input::{Action, normalize_hotkey_spec};
 use crate::model::{
-    AlertSnapshot, ContextCatalogRow, CustomResourceDef, NamespaceScope, OverviewMetrics,
-    PodContainerInfo, ResourceTab, RowData, TableData,
+    AlertSnapshot, ArgoNamespaceLive, ContextCatalogRow, CustomResourceDef, DeletePropagation,
+    LogWindow, NamespaceCounts, NamespaceScope, OverviewMetrics, PodContainerInfo, ResourceTab,
+    RowData, SecretEntry, TableData, TableSort, UsageSort, VOLUME_SNAPSHOT_GROUP, WatchActivity,
+    filter_column_key, format_window_duration,
 };
 use chrono::Local;
-use std::collections::{HashMap, HashSet};
+use regex::{Regex, RegexBuilder};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::path::Path;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_LOG_TAIL: usize = 500;
+pub const DEFAULT_LOG_WINDOW_SECS: u64 = 900;
+const DEFAULT_ROLLOUT_WATCH_SECS: u64 = 300;
+const DEFAULT_DEBUG_IMAGE: &str = "busybox";
+const DEFAULT_EXPORT_DIR: &str = ".manifests/exports";
+const MIN_REFRESH_TICK_MS: u64 = 500;
+const DEFAULT_TOP_LIMIT: usize = 10;
+const DEFAULT_TERRAFORM_PLAN_TIMEOUT_SECS: u64 = 600;
+const TAB_REFRESH_SLACK: Duration = Duration::from_millis(100);
+const WATCH_ACTIVITY_LIMIT: usize = 200;
+const REAUTH_RETRY: Duration = Duration::from_secs(30);
+const NAMESPACE_COUNTS_TTL: Duration = Duration::from_secs(30);
+const DEFAULT_REFRESH_MS: u64 = 1_500;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum InputMode {
@@ -13,6 +31,7 @@ pub enum InputMode {
     Command,
     Filter,
     Jump,
+    Edit,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -33,18 +52,95 @@ pub enum TableOverlayKind {
     PodLogs,
     RelatedLogs,
     Shell,
+    Secret,
+    PortForwards,
+    HelmRelease,
+    Diff,
+    Activity,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ArgoResourcePanelSection {
     Events,
     Manifest,
+    Diff,
+}
+
+impl ArgoResourcePanelSection {
+    const ALL: [ArgoResourcePanelSection; 3] = [Self::Events, Self::Manifest, Self::Diff];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Events => "events",
+            Self::Manifest => "manifest",
+            Self::Diff => "diff",
+        }
+    }
+
+    fn step(self, forward: bool) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|section| *section == self)
+            .unwrap_or(0);
+        let len = Self::ALL.len();
+        let next = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        Self::ALL[next]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HelmReleaseSection {
+    Status,
+    Values,
+    Manifest,
+}
+
+impl HelmReleaseSection {
+    const ALL: [HelmReleaseSection; 3] = [Self::Status, Self::Values, Self::Manifest];
+
+    fn from_token(token: &str) -> Option<Self> {
+        match token.trim().to_ascii_lowercase().as_str() {
+            "status" | "s" => Some(Self::Status),
+            "values" | "v" => Some(Self::Values),
+            "manifest" | "m" => Some(Self::Manifest),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Status => "status",
+            Self::Values => "values",
+            Self::Manifest => "manifest",
+        }
+    }
+
+    fn step(self, forward: bool) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|section| *section == self)
+            .unwrap_or(0);
+        let len = Self::ALL.len();
+        let next = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        Self::ALL[next]
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OpsInspectTarget {
     ArgoCdSync {
         name: String,
+        prune: bool,
+        dry_run: bool,
+        resource: Option<String>,
     },
     ArgoCdRefresh {
         name: String,
@@ -62,17 +158,51 @@ pub enum OpsInspectTarget {
     ArgoCdDelete {
         name: String,
     },
+    ArgoCdCreate {
+        name: String,
+        repo: String,
+        path: String,
+        dest_namespace: String,
+        project: String,
+    },
     HelmReleases,
     HelmRelease {
         name: String,
+        section: HelmReleaseSection,
+    },
+    HelmHistory {
+        name: String,
+    },
+    HelmRollback {
+        name: String,
+        revision: String,
+    },
+    HelmDiff {
+        release: String,
+        chart: String,
+        values_file: Option<String>,
     },
     TerraformOverview,
+    TerraformPlan {
+        dir: String,
+        timeout_secs: u64,
+    },
+    TerraformStateShow {
+        dir: String,
+        address: String,
+    },
     AnsibleOverview,
     DockerOverview,
     OpenShiftProjects,
     KustomizeBuild {
         path: String,
     },
+    KustomizeDiff {
+        path: String,
+    },
+    KustomizeApply {
+        path: String,
+    },
     RbacMatrix {
         subject: Option<String>,
     },
@@ -85,6 +215,15 @@ pub enum OpsInspectTarget {
     GitFetch {
         repo: String,
         reference: Option<String>,
+        full: bool,
+    },
+    GitRefs {
+        repo: String,
+    },
+    GitDiff {
+        repo: String,
+        from: String,
+        to: String,
     },
     GitFiles {
         repo: String,
@@ -114,6 +253,241 @@ pub struct PluginCommandDef {
     pub mutating: bool,
     pub timeout_secs: u64,
     pub retries: u8,
+    pub stream: bool,
+    pub confirm: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DangerousCommandRule {
+    pub action: String,
+    pub resource: Option<String>,
+    pub context: Option<String>,
+    pub labels: BTreeMap<String, String>,
+    pub replicas: Option<i32>,
+}
+
+impl DangerousCommandRule {
+    fn describe(&self) -> String {
+        let mut parts = vec![self.action.clone()];
+        if let Some(resource) = &self.resource {
+            parts.push(resource.clone());
+        }
+        if let Some(replicas) = self.replicas {
+            parts.push(format!("to {replicas}"));
+        }
+        if !self.labels.is_empty() {
+            let labels = self
+                .labels
+                .iter()
+                .map(|(key, value)| format!("{key}={value}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            parts.push(format!("-l {labels}"));
+        }
+        if let Some(context) = &self.context {
+            parts.push(format!("in {context}"));
+        }
+        parts.join(" ")
+    }
+
+    fn matches(
+        &self,
+        action: &str,
+        target: &CommandTarget<'_>,
+        context: &str,
+        labels: Option<&BTreeMap<String, String>>,
+    ) -> bool {
+        if !self.action.trim().eq_ignore_ascii_case(action) {
+            return false;
+        }
+        if let Some(pattern) = &self.context
+            && !wildcard_match(pattern, context)
+        {
+            return false;
+        }
+        if let Some(resource) = &self.resource {
+            let matched = match (ResourceTab::from_token(resource), target.tab) {
+                (Some(rule_tab), Some(tab)) => rule_tab == tab,
+                _ => target
+                    .kinds
+                    .iter()
+                    .any(|kind| kind.eq_ignore_ascii_case(resource)),
+            };
+            if !matched {
+                return false;
+            }
+        }
+        if let Some(replicas) = self.replicas
+            && target.replicas != Some(replicas)
+        {
+            return false;
+        }
+        self.labels
+            .iter()
+            .all(|(key, value)| labels.and_then(|labels| labels.get(key)) == Some(value))
+    }
+}
+
+struct CachedArgoLive {
+    live: ArgoNamespaceLive,
+    with_endpoints: bool,
+    fetched_at: Instant,
+}
+
+struct DangerousMatch {
+    rule: String,
+    rows: Vec<(Option<String>, String)>,
+}
+
+impl DangerousMatch {
+    fn row_lines(&self) -> Vec<String> {
+        let mut lines = vec![
+            String::new(),
+            format!("Dangerous ({}), {} matching:", self.rule, self.rows.len()),
+        ];
+        lines.extend(bulk_target_lines(&self.rows));
+        lines
+    }
+}
+
+#[derive(Debug, Default)]
+struct CommandTarget<'a> {
+    tab: Option<ResourceTab>,
+    kinds: Vec<&'a str>,
+    namespace: Option<&'a str>,
+    name: Option<&'a str>,
+    replicas: Option<i32>,
+}
+
+impl<'a> CommandTarget<'a> {
+    fn of(command: &'a AppCommand) -> Self {
+        match command {
+            AppCommand::DeleteSelected {
+                tab,
+                namespace,
+                name,
+                ..
+            } => Self::resource(*tab, namespace.as_deref(), name),
+            AppCommand::RestartWorkload {
+                tab,
+                namespace,
+                name,
+            }
+            | AppCommand::RolloutUndo {
+                tab,
+                namespace,
+                name,
+                ..
+            } => Self::resource(*tab, Some(namespace), name),
+            AppCommand::ScaleWorkload {
+                tab,
+                namespace,
+                name,
+                replicas,
+            } => Self {
+                replicas: Some(*replicas),
+                ..Self::resource(*tab, Some(namespace), name)
+            },
+            AppCommand::DeleteCustomResource {
+                custom,
+                namespace,
+                name,
+                ..
+            } => Self::custom(custom, namespace.as_deref(), name, None),
+            AppCommand::ScaleCustomResource {
+                custom,
+                namespace,
+                name,
+                replicas,
+            } => Self::custom(custom, namespace.as_deref(), name, Some(*replicas)),
+            AppCommand::EditSelected {
+                resource,
+                namespace,
+                name,
+            } => Self {
+                tab: ResourceTab::from_token(resource),
+                kinds: vec![resource.as_str()],
+                namespace: namespace.as_deref(),
+                name: Some(name),
+                replicas: None,
+            },
+            AppCommand::ApplyYaml {
+                tab,
+                namespace,
+                name,
+                ..
+            } => Self::resource(*tab, namespace.as_deref(), name),
+            AppCommand::ExecInPod {
+                namespace,
+                pod_name,
+                ..
+            }
+            | AppCommand::OpenPodShell {
+                namespace,
+                pod_name,
+                ..
+            }
+            | AppCommand::DebugPod {
+                namespace,
+                pod_name,
+                ..
+            }
+            | AppCommand::ForceDeletePod {
+                namespace,
+                name: pod_name,
+            } => Self::resource(ResourceTab::Pods, Some(namespace), pod_name),
+            AppCommand::RerunJob { namespace, name } => {
+                Self::resource(ResourceTab::Jobs, Some(namespace), name)
+            }
+            AppCommand::CreateJobFromCronJob { namespace, cronjob } => {
+                Self::resource(ResourceTab::CronJobs, Some(namespace), cronjob)
+            }
+            AppCommand::ToggleCronJobSuspend {
+                namespace, name, ..
+            } => Self::resource(ResourceTab::CronJobs, Some(namespace), name),
+            AppCommand::BulkDelete {
+                custom: Some(custom),
+                ..
+            } => Self {
+                name: None,
+                ..Self::custom(custom, None, "", None)
+            },
+            AppCommand::BulkDelete { tab, .. } | AppCommand::BulkRestart { tab, .. } => Self {
+                tab: Some(*tab),
+                ..Self::default()
+            },
+            _ => Self::default(),
+        }
+    }
+
+    fn resource(tab: ResourceTab, namespace: Option<&'a str>, name: &'a str) -> Self {
+        Self {
+            tab: Some(tab),
+            kinds: Vec::new(),
+            namespace,
+            name: Some(name),
+            replicas: None,
+        }
+    }
+
+    fn custom(
+        custom: &'a CustomResourceDef,
+        namespace: Option<&'a str>,
+        name: &'a str,
+        replicas: Option<i32>,
+    ) -> Self {
+        Self {
+            tab: None,
+            kinds: vec![
+                custom.kind.as_str(),
+                custom.plural.as_str(),
+                custom.name.as_str(),
+            ],
+            namespace,
+            name: Some(name),
+            replicas,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -124,6 +498,8 @@ pub struct PluginRun {
     pub mutating: bool,
     pub timeout_secs: u64,
     pub retries: u8,
+    pub stream: bool,
+    pub env: Vec<(String, String)>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -140,17 +516,24 @@ pub enum AppCommand {
     RefreshActive,
     RefreshAll,
     RefreshCustomResourceCatalog,
+    RefreshTab {
+        tab: ResourceTab,
+    },
     LoadPodLogs {
         namespace: String,
         pod_name: String,
         container: Option<String>,
         previous: bool,
+        tail: usize,
+        window: Option<LogWindow>,
     },
     LoadResourceLogs {
         tab: ResourceTab,
         namespace: Option<String>,
         name: String,
         previous: bool,
+        tail: usize,
+        window: Option<LogWindow>,
     },
     LoadPodContainers {
         namespace: String,
@@ -171,22 +554,59 @@ pub enum AppCommand {
         tab: ResourceTab,
         namespace: Option<String>,
         name: String,
+        propagation: DeletePropagation,
+    },
+    DeleteCustomResource {
+        custom: CustomResourceDef,
+        namespace: Option<String>,
+        name: String,
+        propagation: DeletePropagation,
+    },
+    ForceDeletePod {
+        namespace: String,
+        name: String,
     },
     RestartWorkload {
         tab: ResourceTab,
         namespace: String,
         name: String,
     },
+    RerunJob {
+        namespace: String,
+        name: String,
+    },
+    CreateJobFromCronJob {
+        namespace: String,
+        cronjob: String,
+    },
+    ToggleCronJobSuspend {
+        namespace: String,
+        name: String,
+        suspend: bool,
+    },
     ScaleWorkload {
         tab: ResourceTab,
         namespace: String,
         name: String,
         replicas: i32,
     },
+    ScaleCustomResource {
+        custom: CustomResourceDef,
+        namespace: Option<String>,
+        name: String,
+        replicas: i32,
+    },
     ExecInPod {
         namespace: String,
         pod_name: String,
         command: Vec<String>,
+        tty: bool,
+    },
+    DebugPod {
+        namespace: String,
+        pod_name: String,
+        image: String,
+        target_container: Option<String>,
     },
     OpenPodShell {
         namespace: String,
@@ -206,6 +626,12 @@ pub enum AppCommand {
         local_port: u16,
         remote_port: u16,
     },
+    StartNamedPortForward {
+        namespace: String,
+        name: String,
+        local_port: u16,
+        port_name: String,
+    },
     SwitchContext {
         context: String,
     },
@@ -226,49 +652,357 @@ pub enum AppCommand {
         namespace: Option<String>,
         name: String,
     },
+    LoadNamespaceSummary {
+        name: String,
+    },
+    DecodeSecret {
+        namespace: String,
+        name: String,
+    },
+    OpenArgoSource {
+        app: String,
+        repo: String,
+        path: String,
+        revision: Option<String>,
+    },
+    LoadNodeNeighbors {
+        node: String,
+        pod: String,
+    },
+    DescribeSelected {
+        tab: ResourceTab,
+        resource: String,
+        namespace: Option<String>,
+        name: String,
+    },
+    ShowResourceEvents {
+        kind: String,
+        namespace: Option<String>,
+        name: String,
+    },
+    ShowTopPods {
+        sort: UsageSort,
+        limit: usize,
+    },
+    LoadTerraformState {
+        dir: String,
+    },
+    BulkDelete {
+        tab: ResourceTab,
+        custom: Option<CustomResourceDef>,
+        targets: Vec<(Option<String>, String)>,
+        propagation: DeletePropagation,
+    },
+    BulkRestart {
+        tab: ResourceTab,
+        targets: Vec<(String, String)>,
+    },
+    RestartWatchers,
+    ScanDeprecatedApis,
+    LoadNodeUsage,
+    ExportNamespace {
+        namespace: String,
+        dir: String,
+        include_secrets: bool,
+    },
+    InspectImages {
+        tab: ResourceTab,
+        namespace: String,
+        name: String,
+    },
+    WatchRollout {
+        tab: ResourceTab,
+        namespace: String,
+        name: String,
+        timeout_secs: u64,
+    },
+    RolloutStatus {
+        tab: ResourceTab,
+        namespace: String,
+        name: String,
+    },
+    RolloutHistory {
+        tab: ResourceTab,
+        namespace: String,
+        name: String,
+    },
+    RolloutUndo {
+        tab: ResourceTab,
+        namespace: String,
+        name: String,
+        to_revision: Option<u64>,
+    },
+    StopPortForward {
+        pid: u32,
+    },
+    ExportSelectedYaml {
+        tab: ResourceTab,
+        namespace: Option<String>,
+        name: String,
+        path: String,
+    },
+    OpenScratchYaml {
+        file_stem: String,
+        manifest: String,
+    },
+    ApplyYaml {
+        tab: ResourceTab,
+        kind: String,
+        namespace: Option<String>,
+        name: String,
+        yaml: String,
+        resource_version: Option<String>,
+    },
     RunPlugin {
         run: PluginRun,
     },
+    CopyToClipboard {
+        label: String,
+        text: String,
+    },
 }
 
-#[derive(Debug, Clone)]
-struct PendingConfirmation {
-    prompt: String,
-    command: AppCommand,
-}
-
-#[derive(Debug, Clone)]
-struct ContainerPickerState {
-    namespace: String,
-    pod_name: String,
-    containers: Vec<ContainerPickerEntry>,
-    selected: usize,
+impl AppCommand {
+    pub fn mutating_action(&self) -> Option<String> {
+        let action = match self {
+            AppCommand::DeleteSelected { .. }
+            | AppCommand::DeleteCustomResource { .. }
+            | AppCommand::BulkDelete { .. } => "delete",
+            AppCommand::ForceDeletePod { .. } => "force delete",
+            AppCommand::RestartWorkload { .. } | AppCommand::BulkRestart { .. } => "restart",
+            AppCommand::RolloutUndo { .. } => "rollout undo",
+            AppCommand::RerunJob { .. } => "rerun job",
+            AppCommand::CreateJobFromCronJob { .. } => "create job",
+            AppCommand::ToggleCronJobSuspend { suspend: true, .. } => "suspend cronjob",
+            AppCommand::ToggleCronJobSuspend { suspend: false, .. } => "resume cronjob",
+            AppCommand::ScaleWorkload { .. } | AppCommand::ScaleCustomResource { .. } => "scale",
+            AppCommand::ExecInPod { .. } => "exec",
+            AppCommand::OpenPodShell { .. } => "shell",
+            AppCommand::DebugPod { .. } => "debug",
+            AppCommand::EditSelected { .. } | AppCommand::ApplyYaml { .. } => "edit",
+            AppCommand::StartPortForward { .. } | AppCommand::StartNamedPortForward { .. } => {
+                "port-forward"
+            }
+            AppCommand::InspectOps { target } => match target {
+                OpsInspectTarget::ArgoCdSync { dry_run: false, .. } => "argocd sync",
+                OpsInspectTarget::ArgoCdRollback { .. } => "argocd rollback",
+                OpsInspectTarget::ArgoCdDelete { .. } => "argocd delete",
+                OpsInspectTarget::ArgoCdCreate { .. } => "argocd create",
+                OpsInspectTarget::HelmRollback { .. } => "helm rollback",
+                OpsInspectTarget::GitExport { .. } => "git export",
+                OpsInspectTarget::GitApply { .. } => "git apply",
+                OpsInspectTarget::KustomizeApply { .. } => "kustomize apply",
+                _ => return None,
+            },
+            AppCommand::RunPlugin { run } if run.mutating => {
+                return Some(format!("plugin {}", run.name));
+            }
+            _ => return None,
+        };
+        Some(action.to_string())
+    }
 }
 
 #[derive(Debug, Clone)]
-struct ArgoResourceTarget {
+struct YamlEditor {
+    tab: ResourceTab,
     kind: String,
     namespace: Option<String>,
     name: String,
+    resource_version: Option<String>,
+    lines: Vec<String>,
+    row: usize,
+    col: usize,
+    scroll: usize,
+    error: Option<String>,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
-pub struct ContainerPickerEntry {
-    pub idx: usize,
-    pub pf: String,
-    pub name: String,
-    pub image: String,
-    pub ready: String,
-    pub state: String,
-    pub restarts: String,
-    pub age: String,
-}
+impl YamlEditor {
+    fn line_len(&self, row: usize) -> usize {
+        self.lines.get(row).map_or(0, |line| line.chars().count())
+    }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
-struct FlowState {
-    active_tab_index: usize,
+    fn byte_offset(&self) -> usize {
+        let line = &self.lines[self.row];
+        line.char_indices()
+            .nth(self.col)
+            .map_or(line.len(), |(offset, _)| offset)
+    }
+
+    fn insert(&mut self, text: &str) {
+        let offset = self.byte_offset();
+        self.lines[self.row].insert_str(offset, text);
+        self.col += text.chars().count();
+    }
+
+    fn newline(&mut self) {
+        let offset = self.byte_offset();
+        let rest = self.lines[self.row].split_off(offset);
+        let indent = self.lines[self.row]
+            .chars()
+            .take_while(|c| *c == ' ')
+            .collect::<String>();
+        self.row += 1;
+        self.col = indent.chars().count();
+        self.lines.insert(self.row, format!("{indent}{rest}"));
+    }
+
+    fn backspace(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+            let offset = self.byte_offset();
+            self.lines[self.row].remove(offset);
+        } else if self.row > 0 {
+            let line = self.lines.remove(self.row);
+            self.row -= 1;
+            self.col = self.line_len(self.row);
+            self.lines[self.row].push_str(&line);
+        }
+    }
+
+    fn delete(&mut self) {
+        if self.col < self.line_len(self.row) {
+            let offset = self.byte_offset();
+            self.lines[self.row].remove(offset);
+        } else if self.row + 1 < self.lines.len() {
+            let next = self.lines.remove(self.row + 1);
+            self.lines[self.row].push_str(&next);
+        }
+    }
+
+    fn move_vertical(&mut self, delta: isize) {
+        let last = self.lines.len().saturating_sub(1) as isize;
+        self.row = (self.row as isize + delta).clamp(0, last) as usize;
+        self.col = self.col.min(self.line_len(self.row));
+    }
+
+    fn move_horizontal(&mut self, delta: isize) {
+        if delta < 0 && self.col == 0 && self.row > 0 {
+            self.row -= 1;
+            self.col = self.line_len(self.row);
+        } else if delta > 0 && self.col == self.line_len(self.row) {
+            if self.row + 1 < self.lines.len() {
+                self.row += 1;
+                self.col = 0;
+            }
+        } else {
+            self.col =
+                (self.col as isize + delta).clamp(0, self.line_len(self.row) as isize) as usize;
+        }
+    }
+
+    fn keep_cursor_visible(&mut self, height: usize) {
+        let height = height.max(1);
+        if self.row < self.scroll {
+            self.scroll = self.row;
+        } else if self.row >= self.scroll + height {
+            self.scroll = self.row + 1 - height;
+        }
+    }
+
+    fn text(&self) -> String {
+        let mut text = self.lines.join("\n");
+        text.push('\n');
+        text
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TerraformStatePicker {
+    dir: String,
+    addresses: Vec<String>,
+    selected: usize,
+    open: bool,
+}
+
+#[derive(Debug, Clone)]
+struct RowMarks {
+    context: String,
+    tab: ResourceTab,
+    rows: BTreeSet<(Option<String>, String)>,
+}
+
+#[derive(Debug, Clone)]
+struct PendingConfirmation {
+    prompt: String,
+    command: AppCommand,
+    typed_name: Option<String>,
+    typed: String,
+    details: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct ContainerPickerState {
+    namespace: String,
+    pod_name: String,
+    containers: Vec<ContainerPickerEntry>,
+    selected: usize,
+}
+
+#[derive(Debug, Clone)]
+struct ArgoResourceTarget {
+    kind: String,
+    namespace: Option<String>,
+    name: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReferenceMenuEntry {
+    pub label: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum SelectorKind {
+    Label,
+    Field,
+}
+
+impl SelectorKind {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Label => "label",
+            Self::Field => "field",
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::Label => "Label",
+            Self::Field => "Field",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ReferenceMenu {
+    title: String,
+    entries: Vec<ReferenceMenuEntry>,
+    selected: usize,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ContainerPickerEntry {
+    pub idx: usize,
+    pub pf: String,
+    pub name: String,
+    pub image: String,
+    pub ready: String,
+    pub state: String,
+    pub restarts: String,
+    pub last_exit: String,
+    pub cpu: String,
+    pub memory: String,
+    pub age: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct FlowState {
+    active_tab_index: usize,
     namespace_scope: NamespaceScope,
     filter: String,
+    label_selectors: HashMap<ResourceTab, String>,
+    field_selectors: HashMap<ResourceTab, String>,
     selected_crd: Option<String>,
     selected_indices: HashMap<ResourceTab, usize>,
 }
@@ -296,6 +1030,10 @@ struct ViewState {
     table_overlay_title: Option<String>,
     table_overlay_kind: TableOverlayKind,
     table_overlay_return_picker: Option<ContainerPickerState>,
+    log_filter: String,
+    log_filter_case_sensitive: bool,
+    secret_entries: Vec<SecretEntry>,
+    secret_revealed: bool,
     table_scroll: u16,
     detail_overlay: Option<String>,
     detail_overlay_title: Option<String>,
@@ -321,10 +1059,28 @@ pub struct App {
     input: String,
     status: String,
     read_only: bool,
+    log_tail: usize,
+    log_window_secs: u64,
+    pending_log_request: Option<AppCommand>,
+    log_request: Option<AppCommand>,
+    last_input_at: Instant,
+    idle_after: Option<Duration>,
+    idle_refresh_ms: u64,
+    tab_refresh_ms: HashMap<ResourceTab, u64>,
+    tab_refreshed_at: HashMap<ResourceTab, Instant>,
     show_help: bool,
     pending_g: bool,
     completion_index: usize,
     pending_confirmation: Option<PendingConfirmation>,
+    reference_menu: Option<ReferenceMenu>,
+    namespace_picker: Option<NamespaceScope>,
+    confirm_skip: HashSet<String>,
+    dangerous_commands: Vec<DangerousCommandRule>,
+    typed_confirmed: Option<AppCommand>,
+    adopt_context_namespace: bool,
+    export_dir: Option<String>,
+    strip_name_prefixes: Vec<String>,
+    server_version: Option<String>,
     cluster: String,
     context: String,
     user: String,
@@ -332,6 +1088,10 @@ pub struct App {
     table_overlay_title: Option<String>,
     table_overlay_kind: TableOverlayKind,
     table_overlay_return_picker: Option<ContainerPickerState>,
+    log_filter: String,
+    log_filter_case_sensitive: bool,
+    secret_entries: Vec<SecretEntry>,
+    secret_revealed: bool,
     show_table_overview: bool,
     table_scroll: u16,
     detail_overlay: Option<String>,
@@ -345,6 +1105,9 @@ pub struct App {
     detail_view_height: u16,
     discovered_crds: Vec<CustomResourceDef>,
     selected_crd: Option<String>,
+    pending_row_focus: Option<(ResourceTab, Option<String>, String)>,
+    last_named_namespace: Option<String>,
+    yaml_editor: Option<YamlEditor>,
     context_catalog: Vec<ContextCatalogRow>,
     available_contexts: Vec<String>,
     available_clusters: Vec<String>,
@@ -359,7 +1122,30 @@ pub struct App {
     hotkey_commands: Vec<HotkeyCommandDef>,
     config_source: Option<String>,
     active_port_forwards: Vec<PortForwardSession>,
+    port_forward_cursor: usize,
     overview_metrics: OverviewMetrics,
+    metrics_unavailable: bool,
+    pod_pages: Option<(String, NamespaceScope, usize)>,
+    label_selectors: HashMap<ResourceTab, String>,
+    field_selectors: HashMap<ResourceTab, String>,
+    degraded_watches: HashMap<ResourceTab, String>,
+    watch_disabled: bool,
+    auth_expired: bool,
+    last_reauth_attempt: Option<Instant>,
+    namespace_counts: HashMap<String, (NamespaceCounts, Instant)>,
+    default_refresh_ms: u64,
+    argocd_live: HashMap<(String, String), CachedArgoLive>,
+    watch_activity: VecDeque<WatchActivity>,
+    hidden_tabs: HashSet<ResourceTab>,
+    show_hidden_tabs: bool,
+    absolute_ages: bool,
+    top_sort: UsageSort,
+    top_limit: usize,
+    row_marks: Option<RowMarks>,
+    helm_release_view: Option<(String, HelmReleaseSection)>,
+    argo_panel_view: Option<(ArgoResourceTarget, ArgoResourcePanelSection)>,
+    terraform_plan_timeout_secs: u64,
+    terraform_state: Option<TerraformStatePicker>,
     alert_snapshot: AlertSnapshot,
     flow_stack: Vec<FlowState>,
     active_view_slot: usize,
@@ -397,6 +1183,10 @@ impl App {
             table_overlay_title: None,
             table_overlay_kind: TableOverlayKind::Generic,
             table_overlay_return_picker: None,
+            log_filter: String::new(),
+            log_filter_case_sensitive: false,
+            secret_entries: Vec::new(),
+            secret_revealed: false,
             table_scroll: 0,
             detail_overlay: None,
             detail_overlay_title: None,
@@ -422,10 +1212,28 @@ impl App {
             input: String::new(),
             status: "Ready".to_string(),
             read_only: false,
+            log_tail: DEFAULT_LOG_TAIL,
+            log_window_secs: DEFAULT_LOG_WINDOW_SECS,
+            pending_log_request: None,
+            log_request: None,
+            last_input_at: Instant::now(),
+            idle_after: None,
+            idle_refresh_ms: 15_000,
+            tab_refresh_ms: HashMap::new(),
+            tab_refreshed_at: HashMap::new(),
             show_help: false,
             pending_g: false,
             completion_index: 0,
             pending_confirmation: None,
+            reference_menu: None,
+            namespace_picker: None,
+            confirm_skip: HashSet::new(),
+            dangerous_commands: Vec::new(),
+            typed_confirmed: None,
+            adopt_context_namespace: false,
+            export_dir: None,
+            strip_name_prefixes: Vec::new(),
+            server_version: None,
             cluster,
             context,
             user: "-".to_string(),
@@ -433,6 +1241,10 @@ impl App {
             table_overlay_title: None,
             table_overlay_kind: TableOverlayKind::Generic,
             table_overlay_return_picker: None,
+            log_filter: String::new(),
+            log_filter_case_sensitive: false,
+            secret_entries: Vec::new(),
+            secret_revealed: false,
             show_table_overview: false,
             table_scroll: 0,
             detail_overlay: None,
@@ -446,6 +1258,9 @@ impl App {
             detail_view_height: 20,
             discovered_crds: Vec::new(),
             selected_crd: None,
+            pending_row_focus: None,
+            last_named_namespace: None,
+            yaml_editor: None,
             context_catalog: Vec::new(),
             available_contexts: Vec::new(),
             available_clusters: Vec::new(),
@@ -460,7 +1275,30 @@ impl App {
             hotkey_commands: Vec::new(),
             config_source: None,
             active_port_forwards: Vec::new(),
+            port_forward_cursor: 0,
             overview_metrics: OverviewMetrics::default(),
+            metrics_unavailable: false,
+            pod_pages: None,
+            label_selectors: HashMap::new(),
+            field_selectors: HashMap::new(),
+            degraded_watches: HashMap::new(),
+            watch_disabled: false,
+            auth_expired: false,
+            last_reauth_attempt: None,
+            namespace_counts: HashMap::new(),
+            default_refresh_ms: DEFAULT_REFRESH_MS,
+            argocd_live: HashMap::new(),
+            watch_activity: VecDeque::new(),
+            hidden_tabs: HashSet::from([ResourceTab::VolumeSnapshots]),
+            show_hidden_tabs: false,
+            absolute_ages: false,
+            top_sort: UsageSort::Cpu,
+            top_limit: DEFAULT_TOP_LIMIT,
+            row_marks: None,
+            helm_release_view: None,
+            argo_panel_view: None,
+            terraform_plan_timeout_secs: DEFAULT_TERRAFORM_PLAN_TIMEOUT_SECS,
+            terraform_state: None,
             alert_snapshot: AlertSnapshot::default(),
             flow_stack: Vec::new(),
             active_view_slot: initial_slot,
@@ -480,14 +1318,83 @@ impl App {
         self.detail_mode
     }
 
-    pub fn tabs(&self) -> &[ResourceTab] {
-        &self.tabs
-    }
-
     pub fn active_tab(&self) -> ResourceTab {
         self.tabs[self.active_tab_index]
     }
 
+    pub fn visible_tabs(&self) -> Vec<ResourceTab> {
+        self.tabs
+            .iter()
+            .copied()
+            .filter(|tab| !self.tab_hidden(*tab))
+            .collect()
+    }
+
+    fn tab_hidden(&self, tab: ResourceTab) -> bool {
+        !self.show_hidden_tabs && self.hidden_tabs.contains(&tab)
+    }
+
+    fn set_tab_hidden(&mut self, tab: ResourceTab, hidden: bool) {
+        if !hidden {
+            self.hidden_tabs.remove(&tab);
+            return;
+        }
+        self.hidden_tabs.insert(tab);
+        self.leave_hidden_tab();
+    }
+
+    fn leave_hidden_tab(&mut self) {
+        if self.tab_hidden(self.active_tab())
+            && let Some(index) = self.tabs.iter().position(|tab| *tab == ResourceTab::Orca)
+        {
+            self.active_tab_index = index;
+        }
+    }
+
+    pub fn set_unserved_tabs(&mut self, unserved: HashSet<ResourceTab>) {
+        for tab in ResourceTab::ALL {
+            if tab.api_resource().is_some() {
+                self.set_tab_hidden(tab, unserved.contains(&tab));
+            }
+        }
+    }
+
+    fn tabs_command(&mut self, mode: Option<&str>) -> AppCommand {
+        match mode {
+            Some("all") => self.show_hidden_tabs = true,
+            Some("served") => {
+                self.show_hidden_tabs = false;
+                self.leave_hidden_tab();
+            }
+            Some(other) => {
+                self.status = format!("Unknown tabs mode '{other}' (all|served)");
+                return AppCommand::None;
+            }
+            None => {}
+        }
+        let mut hidden = self
+            .tabs
+            .iter()
+            .filter(|tab| self.hidden_tabs.contains(tab))
+            .map(|tab| tab.title())
+            .collect::<Vec<_>>();
+        if hidden.is_empty() {
+            hidden.push("none");
+        }
+        self.status = if self.show_hidden_tabs {
+            format!(
+                "Showing all tabs; not served by cluster: {}",
+                hidden.join(", ")
+            )
+        } else {
+            format!(
+                "Hidden tabs (API not served): {}; :tabs all shows them",
+                hidden.join(", ")
+            )
+        };
+        AppCommand::None
+    }
+
     pub fn cluster(&self) -> &str {
         &self.cluster
     }
@@ -567,9 +1474,26 @@ impl App {
         default_namespace: String,
         preserve_all_namespaces: bool,
     ) {
+        if self.context != context {
+            self.metrics_unavailable = false;
+        }
+        if self.context != context || self.cluster != cluster {
+            self.argocd_live.clear();
+        }
         self.cluster = cluster;
         self.context = context;
         self.user = user;
+        if self.adopt_context_namespace
+            && let Some(namespace) = self
+                .context_catalog
+                .iter()
+                .find(|row| row.context == self.context)
+                .map(|row| row.namespace.trim())
+                .filter(|namespace| !namespace.is_empty() && *namespace != "-")
+        {
+            self.namespace_scope = NamespaceScope::Named(namespace.to_string());
+            return;
+        }
         if preserve_all_namespaces && matches!(self.namespace_scope, NamespaceScope::All) {
             return;
         }
@@ -652,6 +1576,81 @@ impl App {
         self.config_source = source;
     }
 
+    pub fn set_adopt_context_namespace(&mut self, enabled: bool) {
+        self.adopt_context_namespace = enabled;
+    }
+
+    pub fn set_export_dir(&mut self, dir: Option<String>) {
+        self.export_dir = dir;
+    }
+
+    pub fn set_strip_name_prefixes(&mut self, prefixes: Vec<String>) {
+        self.strip_name_prefixes = prefixes;
+    }
+
+    pub fn display_name(&self, row: &RowData) -> String {
+        for prefix in &self.strip_name_prefixes {
+            let prefix = match row.namespace.as_deref() {
+                Some(namespace) => prefix.replace("{namespace}", namespace),
+                None if prefix.contains("{namespace}") => continue,
+                None => prefix.clone(),
+            };
+            if let Some(rest) = row.name.strip_prefix(&prefix)
+                && !rest.is_empty()
+            {
+                return format!("…{rest}");
+            }
+        }
+        row.name.clone()
+    }
+
+    pub fn display_age(&self, row: &RowData) -> Option<String> {
+        if !self.absolute_ages {
+            return None;
+        }
+        row.created
+            .map(|created| created.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+    }
+
+    pub fn row_detail(
+        &self,
+        tab: ResourceTab,
+        namespace: Option<&str>,
+        name: &str,
+    ) -> Option<String> {
+        self.tables
+            .get(&tab)?
+            .rows
+            .iter()
+            .find(|row| row.name == name && row.namespace.as_deref() == namespace)
+            .map(|row| row.detail.clone())
+    }
+
+    pub fn set_confirm_skip(&mut self, actions: Vec<String>) {
+        self.confirm_skip = actions
+            .into_iter()
+            .map(|action| action.trim().to_ascii_lowercase())
+            .filter(|action| {
+                !action.is_empty() && !ALWAYS_CONFIRM_ACTIONS.contains(&action.as_str())
+            })
+            .collect();
+    }
+
+    pub fn set_dangerous_commands(&mut self, rules: Vec<DangerousCommandRule>) {
+        self.dangerous_commands = rules
+            .into_iter()
+            .filter(|rule| !rule.action.trim().is_empty())
+            .collect();
+    }
+
+    pub fn set_server_version(&mut self, version: Option<String>) {
+        self.server_version = version;
+    }
+
+    pub fn server_version(&self) -> Option<&str> {
+        self.server_version.as_deref()
+    }
+
     pub fn set_user(&mut self, user: String) {
         self.user = user;
     }
@@ -697,6 +1696,171 @@ impl App {
         };
     }
 
+    pub fn set_log_tail(&mut self, tail: usize) {
+        self.log_tail = tail;
+    }
+
+    pub fn set_idle_refresh(&mut self, idle_after: Option<Duration>, idle_refresh_ms: u64) {
+        self.idle_after = idle_after;
+        self.idle_refresh_ms = idle_refresh_ms;
+    }
+
+    pub fn mark_input(&mut self) {
+        self.last_input_at = Instant::now();
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.idle_after
+            .is_some_and(|idle_after| self.last_input_at.elapsed() >= idle_after)
+    }
+
+    pub fn refresh_interval_ms(&self, active_ms: u64) -> u64 {
+        if self.is_idle() {
+            self.idle_refresh_ms.max(active_ms)
+        } else {
+            active_ms
+        }
+    }
+
+    pub fn set_terraform_plan_timeout(&mut self, timeout_secs: Option<u64>) {
+        self.terraform_plan_timeout_secs = timeout_secs
+            .filter(|secs| *secs > 0)
+            .unwrap_or(DEFAULT_TERRAFORM_PLAN_TIMEOUT_SECS);
+    }
+
+    pub fn set_tab_refresh_intervals(&mut self, intervals: HashMap<ResourceTab, u64>) {
+        self.tab_refresh_ms = intervals.into_iter().filter(|(_, ms)| *ms > 0).collect();
+    }
+
+    pub fn tab_refresh_ms(&self, tab: ResourceTab, default_ms: u64) -> u64 {
+        self.tab_refresh_ms.get(&tab).copied().unwrap_or(default_ms)
+    }
+
+    pub fn refresh_tick_ms(&self, default_ms: u64) -> u64 {
+        let fastest = self
+            .tab_refresh_ms
+            .values()
+            .map(|ms| (*ms).max(MIN_REFRESH_TICK_MS))
+            .fold(default_ms, u64::min);
+        self.refresh_interval_ms(fastest)
+    }
+
+    pub fn set_default_refresh_ms(&mut self, refresh_ms: u64) {
+        self.default_refresh_ms = refresh_ms;
+    }
+
+    /// Live objects fetched for an Argo CD app namespace during the current refresh
+    /// interval of the Argo resources tab, so refreshes triggered in between (watch
+    /// events, tab switches, `r`) skip kubectl.
+    pub fn cached_argocd_live(
+        &self,
+        namespace: &str,
+        want_endpoints: bool,
+        now: Instant,
+    ) -> Option<ArgoNamespaceLive> {
+        self.argocd_live
+            .get(&(self.context.clone(), namespace.to_string()))
+            .filter(|cached| cached.with_endpoints || !want_endpoints)
+            .filter(|cached| {
+                now.saturating_duration_since(cached.fetched_at) < self.argocd_live_ttl()
+            })
+            .map(|cached| cached.live.clone())
+    }
+
+    /// Stores a complete fetch and drops expired entries; context switches clear the rest.
+    pub fn cache_argocd_live(
+        &mut self,
+        namespace: String,
+        live: ArgoNamespaceLive,
+        with_endpoints: bool,
+        now: Instant,
+    ) {
+        let ttl = self.argocd_live_ttl();
+        self.argocd_live
+            .retain(|_, cached| now.saturating_duration_since(cached.fetched_at) < ttl);
+        self.argocd_live.insert(
+            (self.context.clone(), namespace),
+            CachedArgoLive {
+                live,
+                with_endpoints,
+                fetched_at: now,
+            },
+        );
+    }
+
+    fn argocd_live_ttl(&self) -> Duration {
+        let interval = Duration::from_millis(self.refresh_interval_ms(
+            self.tab_refresh_ms(ResourceTab::ArgoCdResources, self.default_refresh_ms),
+        ));
+        interval.saturating_sub(TAB_REFRESH_SLACK)
+    }
+
+    pub fn tab_refresh_due(&self, tab: ResourceTab, default_ms: u64, now: Instant) -> bool {
+        let Some(last) = self.tab_refreshed_at.get(&tab) else {
+            return true;
+        };
+        let interval = Duration::from_millis(self.tab_refresh_ms(tab, default_ms));
+        now.saturating_duration_since(*last) + TAB_REFRESH_SLACK >= interval
+    }
+
+    fn active_marks(&self) -> Option<&BTreeSet<(Option<String>, String)>> {
+        self.row_marks
+            .as_ref()
+            .filter(|marks| {
+                marks.context == self.context
+                    && marks.tab == self.active_tab()
+                    && !marks.rows.is_empty()
+            })
+            .map(|marks| &marks.rows)
+    }
+
+    pub fn marked_count(&self) -> usize {
+        self.active_marks().map_or(0, BTreeSet::len)
+    }
+
+    pub fn row_marked(&self, row: &RowData) -> bool {
+        self.active_marks()
+            .is_some_and(|marks| marks.contains(&(row.namespace.clone(), row.name.clone())))
+    }
+
+    pub fn set_row_marks(&mut self, tab: ResourceTab, marks: Vec<(Option<String>, String)>) {
+        self.row_marks = if marks.is_empty() {
+            None
+        } else {
+            Some(RowMarks {
+                context: self.context.clone(),
+                tab,
+                rows: marks.into_iter().collect(),
+            })
+        };
+    }
+
+    fn toggle_row_mark(&mut self) {
+        let tab = self.active_tab();
+        let Some(identity) = self.selected_row_identity_for_tab(tab) else {
+            self.status = "No row to mark".to_string();
+            return;
+        };
+        if self.active_marks().is_none() {
+            self.row_marks = Some(RowMarks {
+                context: self.context.clone(),
+                tab,
+                rows: BTreeSet::new(),
+            });
+        }
+        if let Some(marks) = self.row_marks.as_mut()
+            && !marks.rows.remove(&identity)
+        {
+            marks.rows.insert(identity);
+        }
+        self.move_selection(1);
+        self.status = format!(
+            "{} marked in {} (:delete / :restart act on all, Esc clears)",
+            self.marked_count(),
+            tab.title()
+        );
+    }
+
     pub fn execute_hotkey_signature(&mut self, signature: &str) -> Option<AppCommand> {
         let binding = self
             .hotkey_commands
@@ -730,6 +1894,37 @@ impl App {
             .map(|pending| pending.prompt.as_str())
     }
 
+    pub fn pending_confirmation_text(&self) -> Option<String> {
+        let pending = self.pending_confirmation.as_ref()?;
+        Some(match &pending.typed_name {
+            Some(expected) => format!(
+                "{}: type '{expected}' and press Enter (Esc cancels) > {}",
+                pending.prompt, pending.typed
+            ),
+            None => format!("{}? (y/n)", pending.prompt),
+        })
+    }
+
+    pub fn pending_confirmation_details(&self) -> Option<&[String]> {
+        self.pending_confirmation
+            .as_ref()
+            .filter(|pending| !pending.details.is_empty())
+            .map(|pending| pending.details.as_slice())
+    }
+
+    pub fn pending_confirmation_typed(&self) -> Option<(&str, &str)> {
+        let pending = self.pending_confirmation.as_ref()?;
+        let expected = pending.typed_name.as_deref()?;
+        Some((expected, pending.typed.as_str()))
+    }
+
+    pub fn key_mode(&self) -> InputMode {
+        match &self.pending_confirmation {
+            Some(pending) if pending.typed_name.is_some() => InputMode::Command,
+            _ => self.mode,
+        }
+    }
+
     pub fn detail_scroll(&self) -> u16 {
         self.detail_scroll
     }
@@ -764,6 +1959,11 @@ impl App {
                 TableOverlayKind::PodLogs => "log",
                 TableOverlayKind::RelatedLogs => "LOG",
                 TableOverlayKind::Shell => "sh",
+                TableOverlayKind::Secret => "sec",
+                TableOverlayKind::PortForwards => "pf",
+                TableOverlayKind::HelmRelease => "helm",
+                TableOverlayKind::Diff => "diff",
+                TableOverlayKind::Activity => "feed",
                 TableOverlayKind::Generic => "out",
             };
         }
@@ -791,21 +1991,73 @@ impl App {
         self.table_overlay.as_deref()
     }
 
-    pub fn container_picker_title(&self) -> Option<String> {
-        self.container_picker
-            .as_ref()
-            .map(|picker| format!("Containers {}/{}", picker.namespace, picker.pod_name))
+    pub fn log_overlay_active(&self) -> bool {
+        self.table_overlay_active()
+            && matches!(
+                self.table_overlay_kind,
+                TableOverlayKind::PodLogs | TableOverlayKind::RelatedLogs
+            )
     }
 
-    pub fn container_picker_headers(&self) -> Vec<String> {
-        vec![
-            "Idx".to_string(),
-            "Name".to_string(),
+    pub fn log_filter_regex(&self) -> Option<Regex> {
+        if !self.log_overlay_active() || self.log_filter.is_empty() {
+            return None;
+        }
+        let ignore_case = !self.log_filter_case_sensitive;
+        RegexBuilder::new(&self.log_filter)
+            .case_insensitive(ignore_case)
+            .build()
+            .or_else(|_| {
+                RegexBuilder::new(&regex::escape(&self.log_filter))
+                    .case_insensitive(ignore_case)
+                    .build()
+            })
+            .ok()
+    }
+
+    pub fn table_overlay_lines(&self) -> Vec<&str> {
+        let text = self.table_overlay.as_deref().unwrap_or("");
+        match self.log_filter_regex() {
+            Some(pattern) => text.lines().filter(|line| pattern.is_match(line)).collect(),
+            None => text.lines().collect(),
+        }
+    }
+
+    pub fn log_filter_summary(&self) -> Option<String> {
+        if !self.log_overlay_active() || self.log_filter.is_empty() {
+            return None;
+        }
+        let total = self.table_overlay.as_deref().unwrap_or("").lines().count();
+        let matches = self.table_overlay_lines().len();
+        Some(format!(
+            "/{} {matches}/{total} {}",
+            self.log_filter,
+            if self.log_filter_case_sensitive {
+                "case"
+            } else {
+                "nocase"
+            }
+        ))
+    }
+
+    pub fn container_picker_title(&self) -> Option<String> {
+        self.container_picker
+            .as_ref()
+            .map(|picker| format!("Containers {}/{}", picker.namespace, picker.pod_name))
+    }
+
+    pub fn container_picker_headers(&self) -> Vec<String> {
+        vec![
+            "Idx".to_string(),
+            "Name".to_string(),
             "Container".to_string(),
             "Image".to_string(),
             "Ready".to_string(),
             "State".to_string(),
             "Restart".to_string(),
+            "Last Exit".to_string(),
+            "CPU".to_string(),
+            "Mem".to_string(),
             "Age".to_string(),
             "PF".to_string(),
         ]
@@ -862,6 +2114,21 @@ impl App {
                     container.state
                 },
                 restarts: container.restarts.to_string(),
+                last_exit: if container.last_exit.trim().is_empty() {
+                    "-".to_string()
+                } else {
+                    container.last_exit
+                },
+                cpu: if container.cpu.is_empty() {
+                    "-".to_string()
+                } else {
+                    container.cpu
+                },
+                memory: if container.memory.is_empty() {
+                    "-".to_string()
+                } else {
+                    container.memory
+                },
                 age: if container.age.trim().is_empty() {
                     "-".to_string()
                 } else {
@@ -870,7 +2137,7 @@ impl App {
             })
             .collect::<Vec<_>>();
 
-        entries.sort_by(|left, right| left.idx.cmp(&right.idx));
+        entries.sort_by_key(|entry| entry.idx);
         if entries.is_empty() {
             self.container_picker = None;
             self.status = "No containers found for selected pod".to_string();
@@ -936,7 +2203,7 @@ impl App {
 
     pub fn completion_candidates(&self) -> Vec<String> {
         match self.mode {
-            InputMode::Normal | InputMode::Filter => Vec::new(),
+            InputMode::Normal | InputMode::Filter | InputMode::Edit => Vec::new(),
             InputMode::Command => self.command_completions(),
             InputMode::Jump => self.jump_completions(),
         }
@@ -988,6 +2255,12 @@ impl App {
             .unwrap_or_default()
     }
 
+    pub fn active_sort(&self) -> Option<TableSort> {
+        self.tables
+            .get(&self.active_tab())
+            .and_then(|table| table.sort)
+    }
+
     pub fn active_visible_rows(&self) -> Vec<&RowData> {
         self.visible_rows_for(self.active_tab())
     }
@@ -1018,7 +2291,7 @@ impl App {
         let visible_rows = table
             .rows
             .iter()
-            .filter(|row| row.matches_filter(&self.filter))
+            .filter(|row| table.row_matches_filter(row, self.row_filter()))
             .collect::<Vec<_>>();
         if visible_rows.is_empty() {
             return None;
@@ -1034,6 +2307,133 @@ impl App {
             .unwrap_or(0)
     }
 
+    pub fn reference_menu_active(&self) -> bool {
+        self.reference_menu.is_some()
+    }
+
+    pub fn reference_menu_title(&self) -> Option<&str> {
+        self.reference_menu.as_ref().map(|menu| menu.title.as_str())
+    }
+
+    pub fn reference_menu_entries(&self) -> &[ReferenceMenuEntry] {
+        self.reference_menu
+            .as_ref()
+            .map(|menu| menu.entries.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn reference_menu_selected(&self) -> usize {
+        self.reference_menu
+            .as_ref()
+            .map(|menu| menu.selected)
+            .unwrap_or(0)
+    }
+
+    pub fn terraform_state_picker_active(&self) -> bool {
+        self.terraform_state
+            .as_ref()
+            .is_some_and(|picker| picker.open)
+    }
+
+    pub fn terraform_state_picker(&self) -> Option<(&str, &[String], usize)> {
+        self.terraform_state
+            .as_ref()
+            .filter(|picker| picker.open)
+            .map(|picker| {
+                (
+                    picker.dir.as_str(),
+                    picker.addresses.as_slice(),
+                    picker.selected,
+                )
+            })
+    }
+
+    pub fn open_terraform_state_picker(&mut self, dir: String, addresses: Vec<String>) {
+        let selected = self
+            .terraform_state
+            .as_ref()
+            .filter(|picker| picker.dir == dir)
+            .map_or(0, |picker| picker.selected)
+            .min(addresses.len().saturating_sub(1));
+        self.status = if addresses.is_empty() {
+            format!("Terraform state in {dir} has no resources")
+        } else {
+            format!(
+                "Terraform state in {dir}: {} resources; j/k move, Enter show, Esc close",
+                addresses.len()
+            )
+        };
+        self.terraform_state = Some(TerraformStatePicker {
+            open: !addresses.is_empty(),
+            dir,
+            addresses,
+            selected,
+        });
+    }
+
+    pub fn namespace_picker_active(&self) -> bool {
+        self.namespace_picker.is_some()
+    }
+
+    pub fn namespace_picker_selected(&self) -> usize {
+        let Some(picked) = &self.namespace_picker else {
+            return 0;
+        };
+        self.namespace_picker_entries()
+            .iter()
+            .position(|entry| entry == picked)
+            .unwrap_or(0)
+    }
+
+    pub fn namespace_picker_entries(&self) -> Vec<NamespaceScope> {
+        let mut names = self
+            .tables
+            .get(&ResourceTab::Namespaces)
+            .map(|table| {
+                table
+                    .rows
+                    .iter()
+                    .map(|row| row.name.clone())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if let NamespaceScope::Named(current) = &self.namespace_scope
+            && !names.contains(current)
+        {
+            names.push(current.clone());
+        }
+        names.sort();
+        names.dedup();
+        std::iter::once(NamespaceScope::All)
+            .chain(names.into_iter().map(NamespaceScope::Named))
+            .collect()
+    }
+
+    pub fn label_selector_for(&self, tab: ResourceTab) -> Option<&str> {
+        self.label_selectors.get(&tab).map(String::as_str)
+    }
+
+    pub fn field_selector_for(&self, tab: ResourceTab) -> Option<&str> {
+        self.field_selectors.get(&tab).map(String::as_str)
+    }
+
+    pub fn table_has_more_for(&self, tab: ResourceTab) -> bool {
+        self.tables.get(&tab).is_some_and(|table| table.has_more)
+    }
+
+    pub fn list_pages_for(&self, tab: ResourceTab) -> usize {
+        match &self.pod_pages {
+            Some((context, scope, pages))
+                if tab == ResourceTab::Pods
+                    && *context == self.context
+                    && *scope == self.namespace_scope =>
+            {
+                *pages
+            }
+            _ => 1,
+        }
+    }
+
     pub fn table_has_error_for(&self, tab: ResourceTab) -> bool {
         self.tables
             .get(&tab)
@@ -1088,6 +2488,53 @@ impl App {
         Some(self.active_port_forwards.remove(index))
     }
 
+    pub fn show_port_forward_overlay(&mut self) {
+        self.port_forward_cursor = 0;
+        self.set_table_overlay_with_kind(
+            "Port-forwards",
+            render_port_forwards(&self.active_port_forwards, 0),
+            TableOverlayKind::PortForwards,
+        );
+        self.status = format!(
+            "{} active port-forwards (j/k select, x stop)",
+            self.active_port_forwards.len()
+        );
+    }
+
+    pub fn refresh_port_forward_overlay(&mut self) {
+        if self.table_overlay_kind() != Some(TableOverlayKind::PortForwards) {
+            return;
+        }
+        self.port_forward_cursor = self
+            .port_forward_cursor
+            .min(self.active_port_forwards.len().saturating_sub(1));
+        self.table_overlay = Some(render_port_forwards(
+            &self.active_port_forwards,
+            self.port_forward_cursor,
+        ));
+    }
+
+    fn move_port_forward_cursor(&mut self, delta: isize) {
+        let max = self.active_port_forwards.len().saturating_sub(1);
+        self.port_forward_cursor = self
+            .port_forward_cursor
+            .saturating_add_signed(delta)
+            .min(max);
+        self.refresh_port_forward_overlay();
+    }
+
+    fn prepare_stop_port_forward(&mut self) -> AppCommand {
+        let Some(session) = self.active_port_forwards.get(self.port_forward_cursor) else {
+            self.status = "No port-forward selected".to_string();
+            return AppCommand::None;
+        };
+        self.status = format!(
+            "Stopping port-forward {}/{} {}:{} (pid {})",
+            session.namespace, session.name, session.local_port, session.remote_port, session.pid
+        );
+        AppCommand::StopPortForward { pid: session.pid }
+    }
+
     pub fn port_forward_badge(&self) -> Option<String> {
         let row = self.active_selected_row()?;
         let namespace = row.namespace.as_deref()?;
@@ -1182,10 +2629,12 @@ impl App {
 
     pub fn set_pod_logs_overlay(&mut self, title: impl Into<String>, detail: String) {
         self.set_table_overlay_with_kind(title, detail, TableOverlayKind::PodLogs);
+        self.log_request = self.pending_log_request.take();
     }
 
     pub fn set_related_logs_overlay(&mut self, title: impl Into<String>, detail: String) {
         self.set_table_overlay_with_kind(title, detail, TableOverlayKind::RelatedLogs);
+        self.log_request = self.pending_log_request.take();
     }
 
     pub fn set_shell_overlay(&mut self, title: impl Into<String>, detail: String) {
@@ -1193,11 +2642,87 @@ impl App {
         self.table_scroll = self.table_max_scroll();
     }
 
+    pub fn set_helm_release_overlay(
+        &mut self,
+        name: &str,
+        section: HelmReleaseSection,
+        detail: String,
+    ) {
+        let tabs = HelmReleaseSection::ALL
+            .iter()
+            .map(|candidate| {
+                if *candidate == section {
+                    format!("[{}]", candidate.label())
+                } else {
+                    candidate.label().to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.set_table_overlay_with_kind(
+            format!("Helm Release {name}  {tabs}  ([/] switch)"),
+            detail,
+            TableOverlayKind::HelmRelease,
+        );
+        self.helm_release_view = Some((name.to_string(), section));
+    }
+
+    pub fn set_argo_panel_section_overlay(
+        &mut self,
+        kind: &str,
+        namespace: Option<&str>,
+        name: &str,
+        section: ArgoResourcePanelSection,
+        detail: String,
+    ) {
+        let tabs = ArgoResourcePanelSection::ALL
+            .iter()
+            .map(|candidate| {
+                if *candidate == section {
+                    format!("[{}]", candidate.label())
+                } else {
+                    candidate.label().to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let title = match namespace {
+            Some(namespace) => format!("Argo {kind} {namespace}/{name}  {tabs}  ([/] switch)"),
+            None => format!("Argo {kind} {name}  {tabs}  ([/] switch)"),
+        };
+        let overlay_kind = match section {
+            ArgoResourcePanelSection::Diff => TableOverlayKind::Diff,
+            _ => TableOverlayKind::Generic,
+        };
+        self.set_table_overlay_with_kind(title, detail, overlay_kind);
+        self.argo_panel_view = Some((
+            ArgoResourceTarget {
+                kind: kind.to_string(),
+                namespace: namespace.map(str::to_string),
+                name: name.to_string(),
+            },
+            section,
+        ));
+    }
+
+    pub fn set_diff_overlay(&mut self, title: impl Into<String>, detail: String) {
+        self.set_table_overlay_with_kind(title, detail, TableOverlayKind::Diff);
+    }
+
     pub fn set_output_overlay(&mut self, title: impl Into<String>, detail: String) {
         self.set_table_overlay_with_kind(title, detail, TableOverlayKind::Generic);
         self.table_scroll = 0;
     }
 
+    pub fn set_secret_overlay(&mut self, title: impl Into<String>, entries: Vec<SecretEntry>) {
+        self.set_table_overlay_with_kind(
+            title,
+            render_secret_entries(&entries, false),
+            TableOverlayKind::Secret,
+        );
+        self.secret_entries = entries;
+    }
+
     pub fn replace_shell_output(&mut self, snapshot: String) {
         if !self.shell_overlay_active() {
             return;
@@ -1211,6 +2736,20 @@ impl App {
         self.table_scroll = self.table_max_scroll();
     }
 
+    pub fn replace_stream_output(&mut self, title: &str, snapshot: String) -> bool {
+        if self.table_overlay_title.as_deref() != Some(title)
+            || self.table_overlay_kind != TableOverlayKind::Generic
+        {
+            return false;
+        }
+        let follow = self.table_scroll >= self.table_max_scroll();
+        self.table_overlay = Some(snapshot);
+        if follow {
+            self.table_scroll = self.table_max_scroll();
+        }
+        true
+    }
+
     fn set_table_overlay_with_kind(
         &mut self,
         title: impl Into<String>,
@@ -1221,7 +2760,11 @@ impl App {
         self.table_overlay = Some(detail);
         self.table_overlay_kind = kind;
         self.table_overlay_return_picker = self.container_picker.clone();
+        self.log_filter.clear();
+        self.secret_entries.clear();
+        self.secret_revealed = false;
         self.container_picker = None;
+        self.argo_panel_view = None;
         self.show_table_overview = false;
         self.table_scroll = 0;
         self.focus = FocusPane::Table;
@@ -1233,6 +2776,104 @@ impl App {
         self.overview_metrics = metrics;
     }
 
+    pub fn metrics_unavailable(&self) -> bool {
+        self.metrics_unavailable
+    }
+
+    pub fn mark_watch_degraded(&mut self, tab: ResourceTab, error: String, retry_secs: u64) {
+        let first_failure = self.degraded_watches.insert(tab, error.clone()).is_none();
+        if first_failure && tab == self.active_tab() {
+            self.status = format!(
+                "Watch degraded for {}: {} (retrying in {retry_secs}s)",
+                tab.title(),
+                summarize_error_line(&error)
+            );
+        }
+    }
+
+    pub fn mark_watch_healthy(&mut self, tab: ResourceTab) {
+        if self.degraded_watches.remove(&tab).is_some() && tab == self.active_tab() {
+            self.status = format!("Watch recovered for {}", tab.title());
+        }
+    }
+
+    pub fn clear_degraded_watches(&mut self) {
+        self.degraded_watches.clear();
+    }
+
+    pub fn record_watch_activity(&mut self, activity: WatchActivity) {
+        self.watch_activity.push_back(activity);
+        while self.watch_activity.len() > WATCH_ACTIVITY_LIMIT {
+            self.watch_activity.pop_front();
+        }
+        if self.table_overlay_kind() == Some(TableOverlayKind::Activity) {
+            self.table_overlay = Some(render_watch_activity(&self.watch_activity));
+        }
+    }
+
+    pub fn show_activity_overlay(&mut self) {
+        self.set_table_overlay_with_kind(
+            "Watch activity",
+            render_watch_activity(&self.watch_activity),
+            TableOverlayKind::Activity,
+        );
+        self.status = format!(
+            "{} recent watch events (newest first, updates live)",
+            self.watch_activity.len()
+        );
+    }
+
+    pub fn auth_expired(&self) -> bool {
+        self.auth_expired
+    }
+
+    pub fn mark_auth_expired(&mut self) {
+        if !self.auth_expired {
+            self.auth_expired = true;
+            self.status = "Authentication expired — refreshing credentials…".to_string();
+        }
+    }
+
+    pub fn mark_auth_recovered(&mut self) {
+        if self.auth_expired {
+            self.auth_expired = false;
+            self.last_reauth_attempt = None;
+            self.status = "Authentication recovered with refreshed credentials".to_string();
+        }
+    }
+
+    pub fn reauth_due(&self, now: Instant) -> bool {
+        self.auth_expired
+            && self
+                .last_reauth_attempt
+                .is_none_or(|last| now.saturating_duration_since(last) >= REAUTH_RETRY)
+    }
+
+    pub fn note_reauth_attempt(&mut self, now: Instant) {
+        self.last_reauth_attempt = Some(now);
+    }
+
+    pub fn watch_disabled(&self) -> bool {
+        self.watch_disabled
+    }
+
+    pub fn set_watch_disabled(&mut self, disabled: bool) {
+        self.watch_disabled = disabled;
+    }
+
+    pub fn watch_degraded(&self, tab: ResourceTab) -> bool {
+        self.degraded_watches.contains_key(&tab)
+    }
+
+    pub fn mark_metrics_unavailable(&mut self) {
+        if self.metrics_unavailable {
+            return;
+        }
+        self.metrics_unavailable = true;
+        self.overview_metrics = OverviewMetrics::default();
+        self.status = "metrics-server not installed — CPU/MEM unavailable".to_string();
+    }
+
     pub fn set_alert_snapshot(&mut self, snapshot: AlertSnapshot) {
         self.alert_snapshot = snapshot;
     }
@@ -1254,12 +2895,35 @@ impl App {
     }
 
     pub fn apply_action(&mut self, action: Action) -> AppCommand {
+        let command = self.dispatch_action(action);
+        if matches!(
+            command,
+            AppCommand::LoadPodLogs { .. } | AppCommand::LoadResourceLogs { .. }
+        ) {
+            self.pending_log_request = Some(command.clone());
+        }
+        command
+    }
+
+    fn dispatch_action(&mut self, action: Action) -> AppCommand {
         if let Some(pending) = self.pending_confirmation.take() {
+            if pending.typed_name.is_some() {
+                return self.apply_typed_confirmation_action(pending, action);
+            }
             match action {
-                Action::ConfirmYes | Action::EnterResource => {
+                Action::ConfirmYes => {
                     self.status = format!("Confirmed: {}", pending.prompt);
                     return pending.command;
                 }
+                Action::EnterResource if pending.details.is_empty() => {
+                    self.status = format!("Confirmed: {}", pending.prompt);
+                    return pending.command;
+                }
+                Action::ToggleFocus => {
+                    self.pending_confirmation = Some(pending);
+                    self.cycle_delete_propagation();
+                    return AppCommand::None;
+                }
                 Action::ConfirmNo | Action::CancelInput | Action::ClearDetailOverlay => {
                     self.status = "Action cancelled".to_string();
                     return AppCommand::None;
@@ -1273,6 +2937,22 @@ impl App {
             }
         }
 
+        if self.mode == InputMode::Edit {
+            return self.apply_yaml_editor_action(action);
+        }
+
+        if self.reference_menu.is_some() {
+            return self.apply_reference_menu_action(action);
+        }
+
+        if self.namespace_picker.is_some() {
+            return self.apply_namespace_picker_action(action);
+        }
+
+        if self.terraform_state_picker_active() {
+            return self.apply_terraform_state_action(action);
+        }
+
         if !matches!(action, Action::GPrefix) {
             self.pending_g = false;
         }
@@ -1292,6 +2972,8 @@ impl App {
             Action::Down => {
                 if self.container_picker_active() {
                     self.move_container_selection(1);
+                } else if self.table_overlay_kind() == Some(TableOverlayKind::PortForwards) {
+                    self.move_port_forward_cursor(1);
                 } else if self.focus == FocusPane::Detail {
                     self.scroll_detail(1);
                 } else if self.table_overlay_active() {
@@ -1304,6 +2986,8 @@ impl App {
             Action::Up => {
                 if self.container_picker_active() {
                     self.move_container_selection(-1);
+                } else if self.table_overlay_kind() == Some(TableOverlayKind::PortForwards) {
+                    self.move_port_forward_cursor(-1);
                 } else if self.focus == FocusPane::Detail {
                     self.scroll_detail(-1);
                 } else if self.table_overlay_active() {
@@ -1401,10 +3085,18 @@ impl App {
                 AppCommand::None
             }
             Action::StartFilter => {
+                if self.log_overlay_active() {
+                    self.mode = InputMode::Filter;
+                    self.input = self.log_filter.clone();
+                    self.completion_index = 0;
+                    self.status = "Log filter mode (substring or regex)".to_string();
+                    return AppCommand::None;
+                }
                 self.mode = InputMode::Filter;
                 self.input = self.filter.clone();
                 self.completion_index = 0;
-                self.status = "Filter mode".to_string();
+                self.status =
+                    "Filter mode (fuzzy name match, live; Tab scopes to a column)".to_string();
                 AppCommand::None
             }
             Action::Refresh => {
@@ -1416,6 +3108,18 @@ impl App {
                 AppCommand::RefreshActive
             }
             Action::LoadPodLogs => self.create_logs_command(false),
+            Action::OlderLogWindow | Action::NewerLogWindow
+                if self.table_overlay_kind() == Some(TableOverlayKind::HelmRelease) =>
+            {
+                self.step_helm_release_section(matches!(action, Action::NewerLogWindow))
+            }
+            Action::OlderLogWindow | Action::NewerLogWindow
+                if self.argo_panel_view.is_some() && self.table_overlay_active() =>
+            {
+                self.step_argo_panel_section(matches!(action, Action::NewerLogWindow))
+            }
+            Action::OlderLogWindow => self.step_log_window(true),
+            Action::NewerLogWindow => self.step_log_window(false),
             Action::LoadResourceLogs => self.create_related_logs_command(true),
             Action::OpenPodShell => self.prepare_shell_command(None, "auto".to_string()),
             Action::EditResource => {
@@ -1425,6 +3129,62 @@ impl App {
                     self.prepare_edit_command()
                 }
             }
+            Action::DescribeResource => self.prepare_describe_command(),
+            Action::ForceDeletePod => self.prepare_force_delete_pod(),
+            Action::ScratchYaml => self.prepare_scratch_yaml_command(true),
+            Action::EditYamlInline => self.open_yaml_editor(),
+            Action::CopyReference => self.open_reference_menu(None),
+            Action::CopySelection => self.copy_selection(),
+            Action::ShowResourceEvents => self.prepare_resource_events(),
+            Action::ExportSelectedYaml => self.prepare_export_yaml_command(None),
+            Action::FocusRowNamespace => self.focus_selected_row_namespace(),
+            Action::ShowNodeNeighbors => self.prepare_node_neighbors_command(),
+            Action::OpenArgoSource => self.prepare_argo_source_command(),
+            Action::DecodeSecret => self.decode_secret_or_toggle_reveal(),
+            Action::CycleSortColumn => {
+                self.update_active_sort(|sort, columns| match sort {
+                    None if columns > 0 => Some(TableSort {
+                        column: 0,
+                        descending: false,
+                    }),
+                    Some(sort) if sort.column + 1 < columns => Some(TableSort {
+                        column: sort.column + 1,
+                        descending: sort.descending,
+                    }),
+                    _ => None,
+                });
+                AppCommand::None
+            }
+            Action::ToggleRowMark => {
+                if !self.table_overlay_active() && self.focus == FocusPane::Table {
+                    self.toggle_row_mark();
+                }
+                AppCommand::None
+            }
+            Action::TriggerCronJob => self.prepare_trigger_cronjob(),
+            Action::ToggleCronJobSuspend => self.prepare_cronjob_suspend_toggle(None),
+            Action::ToggleAgeFormat => {
+                self.absolute_ages = !self.absolute_ages;
+                self.status = if self.absolute_ages {
+                    "Age columns show creation timestamps (UTC)".to_string()
+                } else {
+                    "Age columns show relative ages".to_string()
+                };
+                AppCommand::None
+            }
+            Action::ToggleSortDirection => {
+                self.update_active_sort(|sort, _| {
+                    sort.map(|sort| TableSort {
+                        column: sort.column,
+                        descending: !sort.descending,
+                    })
+                });
+                AppCommand::None
+            }
+            Action::RestoreAllNamespaces => self.restore_all_namespaces(),
+            Action::ToggleNamespaceScope => self.toggle_namespace_scope(),
+            Action::NextNamespace => self.cycle_namespace(true),
+            Action::PrevNamespace => self.cycle_namespace(false),
             Action::ShowManifest => {
                 if self.active_tab() == ResourceTab::ArgoCdResources {
                     self.prepare_argocd_resource_section(ArgoResourcePanelSection::Manifest)
@@ -1440,6 +3200,20 @@ impl App {
                 self.status = "Port-forward mode (:port-forward <local>:<remote>)".to_string();
                 AppCommand::None
             }
+            Action::ToggleLogFilterCase => {
+                if !self.log_overlay_active() {
+                    self.status = "Case toggle applies to the log filter".to_string();
+                    return AppCommand::None;
+                }
+                self.log_filter_case_sensitive = !self.log_filter_case_sensitive;
+                self.table_scroll = 0;
+                self.status = if self.log_filter_case_sensitive {
+                    "Log filter is case-sensitive".to_string()
+                } else {
+                    "Log filter ignores case".to_string()
+                };
+                AppCommand::None
+            }
             Action::ToggleOverview => {
                 self.show_table_overview = !self.show_table_overview;
                 if self.show_table_overview {
@@ -1463,6 +3237,10 @@ impl App {
                     } else {
                         self.status = "Closed container list".to_string();
                     }
+                } else if self.log_overlay_active() && !self.log_filter.is_empty() {
+                    self.log_filter.clear();
+                    self.table_scroll = 0;
+                    self.status = "Log filter cleared".to_string();
                 } else if self.table_overlay_active() {
                     if let Some(previous_picker) = self.table_overlay_return_picker.clone() {
                         self.clear_table_overlay();
@@ -1485,8 +3263,15 @@ impl App {
                 {
                     self.dismiss_detail_view();
                     self.status = "Closed details".to_string();
+                } else if self.marked_count() > 0 {
+                    self.row_marks = None;
+                    self.status = "Cleared marked rows".to_string();
                 } else if self.pop_flow_state() {
                     self.status = "Back to previous flow step".to_string();
+                } else if !self.filter.is_empty() {
+                    self.filter.clear();
+                    self.clamp_all_selections();
+                    self.status = "Filter cleared".to_string();
                 } else {
                     self.status = "At flow root".to_string();
                 }
@@ -1509,7 +3294,11 @@ impl App {
             }
             Action::SubmitInput => self.submit_input(),
             Action::CompleteInput => {
-                self.apply_completion();
+                if self.mode == InputMode::Filter && !self.log_overlay_active() {
+                    self.cycle_filter_column();
+                } else {
+                    self.apply_completion();
+                }
                 AppCommand::None
             }
             Action::NextSuggestion => {
@@ -1521,15 +3310,23 @@ impl App {
                 AppCommand::None
             }
             Action::CancelInput => {
+                let table_filter = self.mode == InputMode::Filter && !self.log_overlay_active();
                 self.mode = InputMode::Normal;
                 self.input.clear();
                 self.completion_index = 0;
-                self.status = "Input cancelled".to_string();
+                if table_filter {
+                    self.filter.clear();
+                    self.clamp_all_selections();
+                    self.status = "Filter cleared".to_string();
+                } else {
+                    self.status = "Input cancelled".to_string();
+                }
                 AppCommand::None
             }
             Action::Backspace => {
                 self.input.pop();
                 self.completion_index = 0;
+                self.clamp_active_selection();
                 AppCommand::None
             }
             Action::Delete => {
@@ -1545,28 +3342,68 @@ impl App {
             Action::InputChar(c) => {
                 self.input.push(c);
                 self.completion_index = 0;
+                self.clamp_active_selection();
                 AppCommand::None
             }
             Action::ConfirmYes | Action::ConfirmNo => {
                 self.status = "No pending confirmation".to_string();
                 AppCommand::None
             }
+            Action::Left | Action::Right | Action::InsertNewline => AppCommand::None,
             Action::SwitchView(slot) => self.switch_view_slot(slot as usize),
             Action::DeleteView(slot) => self.delete_view_slot(slot as usize),
         }
     }
 
     pub fn set_active_table_data(&mut self, tab: ResourceTab, mut table: TableData) {
-        let selected_identity = self.selected_row_identity_for_tab(tab);
+        self.tab_refreshed_at.insert(tab, Instant::now());
+        if let Some(marks) = self.row_marks.as_mut()
+            && marks.tab == tab
+        {
+            marks.rows.retain(|(namespace, name)| {
+                table
+                    .rows
+                    .iter()
+                    .any(|row| row.name == *name && row.namespace == *namespace)
+            });
+        }
+        let selected_identity = match self
+            .pending_row_focus
+            .take_if(|(pending_tab, _, _)| *pending_tab == tab)
+        {
+            Some((_, namespace, name)) => Some((namespace, name)),
+            None => self.selected_row_identity_for_tab(tab),
+        };
         let previous_selected = self.selected_index_for_tab(tab);
         table.selected = table.selected.min(table.rows.len().saturating_sub(1));
-        self.tables.insert(tab, table);
-        if let Some((namespace, name)) = selected_identity {
+        if let Some(sort) = self.tables.get(&tab).and_then(|previous| previous.sort) {
+            table.apply_sort(Some(sort));
+        }
+        let changes = match self.tables.get_mut(&tab) {
+            Some(previous) if previous.error.is_none() && previous.last_refreshed.is_some() => {
+                Some(previous.merge_rows(table))
+            }
+            _ => {
+                self.tables.insert(tab, table);
+                None
+            }
+        };
+        if let Some((namespace, name)) = selected_identity {
             self.select_row_by_identity_with_fallback(tab, namespace, &name, previous_selected);
         } else {
             self.set_selected_index_for_tab(tab, previous_selected);
         }
-        self.status = format!("{} updated", tab.title());
+        self.status = match changes {
+            Some(changes) if changes.is_empty() => format!("{} up to date", tab.title()),
+            Some(changes) => format!(
+                "{} updated (+{} ~{} -{})",
+                tab.title(),
+                changes.added,
+                changes.changed,
+                changes.removed
+            ),
+            None => format!("{} updated", tab.title()),
+        };
     }
 
     pub fn set_active_tab_error(&mut self, tab: ResourceTab, error: impl Into<String>) {
@@ -1589,12 +3426,108 @@ impl App {
         self.detail_scroll = 0;
     }
 
+    /// Resource counts for the namespace detail, taken from the loaded Pods, Deployments
+    /// and Services tables when they cover `name`, else from a recent fetch.
+    pub fn namespace_counts(&self, name: &str, now: Instant) -> Option<NamespaceCounts> {
+        self.namespace_counts_from_tables(name).or_else(|| {
+            self.namespace_counts
+                .get(name)
+                .filter(|(_, fetched)| {
+                    now.saturating_duration_since(*fetched) < NAMESPACE_COUNTS_TTL
+                })
+                .map(|(counts, _)| *counts)
+        })
+    }
+
+    pub fn cache_namespace_counts(&mut self, name: &str, counts: NamespaceCounts, now: Instant) {
+        self.namespace_counts
+            .insert(name.to_string(), (counts, now));
+    }
+
+    fn namespace_counts_from_tables(&self, name: &str) -> Option<NamespaceCounts> {
+        if let NamespaceScope::Named(scope) = &self.namespace_scope
+            && scope != name
+        {
+            return None;
+        }
+        let rows = |tab: ResourceTab| {
+            let table = self.tables.get(&tab)?;
+            let complete = table.last_refreshed.is_some()
+                && table.error.is_none()
+                && !table.has_more
+                && !self.label_selectors.contains_key(&tab)
+                && !self.field_selectors.contains_key(&tab);
+            complete.then(|| {
+                table
+                    .rows
+                    .iter()
+                    .filter(|row| row.namespace.as_deref() == Some(name))
+                    .collect::<Vec<_>>()
+            })
+        };
+        let column = |tab: ResourceTab, header: &str| {
+            self.tables
+                .get(&tab)
+                .and_then(|table| table.headers.iter().position(|column| column == header))
+        };
+
+        let pods = rows(ResourceTab::Pods)?;
+        let deployments = rows(ResourceTab::Deployments)?;
+        let services = rows(ResourceTab::Services)?;
+        let ready = column(ResourceTab::Deployments, "Ready")?;
+        // Same definition as `KubeGateway::namespace_counts`: the phase, not the Status
+        // column, so CrashLoopBackOff pods still count as running.
+        let running_pods = pods
+            .iter()
+            .filter(|row| row.phase.as_deref() == Some("Running"))
+            .count();
+        let ready_deployments = deployments
+            .iter()
+            .filter(|row| {
+                row.columns
+                    .get(ready)
+                    .and_then(|value| value.split_once('/'))
+                    .and_then(|(ready, desired)| {
+                        Some(ready.parse::<i64>().ok()? >= desired.parse::<i64>().ok()?)
+                    })
+                    .unwrap_or(false)
+            })
+            .count();
+        Some(NamespaceCounts {
+            pods: pods.len(),
+            running_pods,
+            deployments: deployments.len(),
+            ready_deployments,
+            services: services.len(),
+        })
+    }
+
+    pub fn show_namespace_summary(&mut self, name: &str, summary: String) {
+        if self.active_tab() != ResourceTab::Namespaces
+            || self.detail_mode != DetailPaneMode::Details
+        {
+            return;
+        }
+        let Some(row) = self.active_selected_row() else {
+            return;
+        };
+        if row.name != name {
+            return;
+        }
+        let detail = format!("{summary}\n\n{}", row.detail);
+        self.set_detail_overlay(format!("Namespace {name}"), detail);
+    }
+
     pub fn set_status(&mut self, status: impl Into<String>) {
         self.status = normalize_status_text(status.into());
     }
 
     pub fn set_custom_resources(&mut self, mut crds: Vec<CustomResourceDef>) {
         crds.sort_by(|left, right| left.name.cmp(&right.name));
+        let snapshots_served = crds
+            .iter()
+            .any(|crd| crd.group == VOLUME_SNAPSHOT_GROUP && crd.kind == "VolumeSnapshot");
+        self.set_tab_hidden(ResourceTab::VolumeSnapshots, !snapshots_served);
         self.discovered_crds = crds;
 
         if self.discovered_crds.is_empty() {
@@ -1630,7 +3563,7 @@ impl App {
         table
             .rows
             .iter()
-            .filter(|row| row.matches_filter(&self.filter))
+            .filter(|row| table.row_matches_filter(row, self.row_filter()))
             .collect()
     }
 
@@ -1696,29 +3629,37 @@ impl App {
         }
     }
 
+    fn row_filter(&self) -> &str {
+        if self.mode == InputMode::Filter && !self.log_overlay_active() {
+            &self.input
+        } else {
+            &self.filter
+        }
+    }
+
     fn clamp_active_selection(&mut self) {
         self.clamp_selection_for_tab(self.active_tab());
     }
 
     fn clamp_selection_for_tab(&mut self, tab: ResourceTab) {
-        let filter = self.filter.clone();
+        let filter = self.row_filter().to_string();
         if let Some(table) = self.tables.get_mut(&tab) {
             let visible_len = table
                 .rows
                 .iter()
-                .filter(|row| row.matches_filter(&filter))
+                .filter(|row| table.row_matches_filter(row, &filter))
                 .count();
             table.selected = table.selected.min(visible_len.saturating_sub(1));
         }
     }
 
     fn clamp_all_selections(&mut self) {
-        let filter = self.filter.clone();
+        let filter = self.row_filter().to_string();
         for table in self.tables.values_mut() {
             let visible_len = table
                 .rows
                 .iter()
-                .filter(|row| row.matches_filter(&filter))
+                .filter(|row| table.row_matches_filter(row, &filter))
                 .count();
             table.selected = table.selected.min(visible_len.saturating_sub(1));
         }
@@ -1734,6 +3675,8 @@ impl App {
             active_tab_index: self.active_tab_index,
             namespace_scope: self.namespace_scope.clone(),
             filter: self.filter.clone(),
+            label_selectors: self.label_selectors.clone(),
+            field_selectors: self.field_selectors.clone(),
             selected_crd: self.selected_crd.clone(),
             selected_indices,
         }
@@ -1745,6 +3688,8 @@ impl App {
             .min(self.tabs.len().saturating_sub(1));
         self.namespace_scope = state.namespace_scope.clone();
         self.filter = state.filter.clone();
+        self.label_selectors = state.label_selectors.clone();
+        self.field_selectors = state.field_selectors.clone();
         self.selected_crd = state.selected_crd.clone();
 
         let tabs = self.tabs.clone();
@@ -1768,6 +3713,17 @@ impl App {
         self.flow_stack.clear();
     }
 
+    pub fn focus_row_in_tab(&mut self, tab: ResourceTab, namespace: Option<String>, name: String) {
+        self.push_flow_state();
+        self.filter.clear();
+        self.clear_table_overlay();
+        self.clear_detail_overlay();
+        self.focus = FocusPane::Table;
+        let _ = self.switch_to_tab(tab);
+        self.select_row_by_identity(tab, namespace.clone(), &name);
+        self.pending_row_focus = Some((tab, namespace, name));
+    }
+
     fn push_flow_state(&mut self) {
         let snapshot = self.capture_flow_state();
         let should_push = self
@@ -1794,8 +3750,13 @@ impl App {
         }
 
         let len = self.tabs.len() as isize;
-        let current = self.active_tab_index as isize;
-        let next = (current + delta).rem_euclid(len) as usize;
+        let mut next = self.active_tab_index;
+        for _ in 0..len {
+            next = (next as isize + delta).rem_euclid(len) as usize;
+            if !self.tab_hidden(self.tabs[next]) {
+                break;
+            }
+        }
         self.active_tab_index = next;
         self.on_tab_changed()
     }
@@ -1818,6 +3779,10 @@ impl App {
             table_overlay_title: self.table_overlay_title.clone(),
             table_overlay_kind: self.table_overlay_kind,
             table_overlay_return_picker: self.table_overlay_return_picker.clone(),
+            log_filter: self.log_filter.clone(),
+            log_filter_case_sensitive: self.log_filter_case_sensitive,
+            secret_entries: self.secret_entries.clone(),
+            secret_revealed: self.secret_revealed,
             table_scroll: self.table_scroll,
             detail_overlay: self.detail_overlay.clone(),
             detail_overlay_title: self.detail_overlay_title.clone(),
@@ -1845,6 +3810,10 @@ impl App {
         self.table_overlay_title = state.table_overlay_title.clone();
         self.table_overlay_kind = state.table_overlay_kind;
         self.table_overlay_return_picker = state.table_overlay_return_picker.clone();
+        self.log_filter = state.log_filter.clone();
+        self.log_filter_case_sensitive = state.log_filter_case_sensitive;
+        self.secret_entries = state.secret_entries.clone();
+        self.secret_revealed = state.secret_revealed;
         self.table_scroll = state.table_scroll;
         self.detail_overlay = state.detail_overlay.clone();
         self.detail_overlay_title = state.detail_overlay_title.clone();
@@ -1890,6 +3859,9 @@ impl App {
             state.table_overlay_title = None;
             state.table_overlay_kind = TableOverlayKind::Generic;
             state.table_overlay_return_picker = None;
+            state.log_filter.clear();
+            state.secret_entries.clear();
+            state.secret_revealed = false;
             state.table_scroll = 0;
             state.detail_overlay = None;
             state.detail_overlay_title = None;
@@ -1964,6 +3936,13 @@ impl App {
     }
 
     fn switch_to_tab(&mut self, target: ResourceTab) -> AppCommand {
+        if self.tab_hidden(target) {
+            self.status = format!(
+                "Tab '{}' is not served by this cluster (:tabs all shows it)",
+                target.title()
+            );
+            return AppCommand::None;
+        }
         if let Some(index) = self.tabs.iter().position(|tab| *tab == target) {
             self.active_tab_index = index;
             return self.on_tab_changed();
@@ -1978,14 +3957,14 @@ impl App {
         self.clear_table_overlay();
         self.clear_container_picker();
         self.detail_scroll = 0;
-        if self.active_tab() == ResourceTab::ArgoCdResources && self.argocd_selected_app.is_none() {
-            if let Some(row) = self
+        if self.active_tab() == ResourceTab::ArgoCdResources
+            && self.argocd_selected_app.is_none()
+            && let Some(row) = self
                 .tables
                 .get(&ResourceTab::ArgoCdApps)
                 .and_then(|table| table.rows.get(table.selected))
-            {
-                self.argocd_selected_app = Some(row.name.clone());
-            }
+        {
+            self.argocd_selected_app = Some(row.name.clone());
         }
         self.status = format!("Switched to {}", self.active_tab().title());
         if self
@@ -2011,6 +3990,36 @@ impl App {
             .rem_euclid(completion_len as isize) as usize;
     }
 
+    fn cycle_filter_column(&mut self) {
+        let Some(table) = self.tables.get(&self.active_tab()) else {
+            return;
+        };
+        let headers = table.headers.clone();
+        if headers.is_empty() {
+            return;
+        }
+        let (current, value) = match table.column_filter(&self.input) {
+            Some((index, value)) => (Some(index), value.to_string()),
+            None => (None, self.input.trim().to_string()),
+        };
+        let next = match current {
+            None => Some(0),
+            Some(index) if index + 1 < headers.len() => Some(index + 1),
+            Some(_) => None,
+        };
+        match next {
+            Some(index) => {
+                self.input = format!("{}:{value}", filter_column_key(&headers[index]));
+                self.status = format!("Filter scope: {} column", headers[index]);
+            }
+            None => {
+                self.input = value;
+                self.status = "Filter scope: all columns".to_string();
+            }
+        }
+        self.clamp_active_selection();
+    }
+
     fn apply_completion(&mut self) {
         let completions = self.completion_candidates();
         if completions.is_empty() {
@@ -2033,12 +4042,41 @@ impl App {
             "readonly".to_string(),
             "readonly on".to_string(),
             "readonly off".to_string(),
+            "tail ".to_string(),
+            "log-window ".to_string(),
+            "scratch".to_string(),
+            "slices".to_string(),
+            "rewatch".to_string(),
+            "deprecations".to_string(),
+            "rollout watch".to_string(),
+            "rollout status".to_string(),
+            "rollout history".to_string(),
+            "rollout undo".to_string(),
+            "images".to_string(),
+            "more".to_string(),
+            "ref".to_string(),
+            "selector".to_string(),
+            "fields".to_string(),
+            "node-top".to_string(),
+            "export ns ".to_string(),
+            "save".to_string(),
+            "scratch raw".to_string(),
+            "has label ".to_string(),
+            "has annotation ".to_string(),
             "config".to_string(),
             "ops".to_string(),
             "tools".to_string(),
             "alerts".to_string(),
             "pulses".to_string(),
+            "activity".to_string(),
+            "rerun".to_string(),
+            "run-now".to_string(),
+            "suspend".to_string(),
+            "resume".to_string(),
             "xray".to_string(),
+            "describe".to_string(),
+            "neighbors".to_string(),
+            "source".to_string(),
             "argocd".to_string(),
             "argo".to_string(),
             "argocd ".to_string(),
@@ -2052,11 +4090,13 @@ impl App {
             "argocd certs".to_string(),
             "argocd gpg".to_string(),
             "argocd sync ".to_string(),
+            "argocd sync --dry-run ".to_string(),
             "argocd refresh ".to_string(),
             "argocd diff ".to_string(),
             "argocd history ".to_string(),
             "argocd rollback ".to_string(),
             "argocd delete ".to_string(),
+            "argocd create ".to_string(),
             "k8s".to_string(),
             "kube".to_string(),
             "kubernetes".to_string(),
@@ -2065,6 +4105,11 @@ impl App {
             "kubernetes ".to_string(),
             "helm".to_string(),
             "helm ".to_string(),
+            "helm history ".to_string(),
+            "helm diff ".to_string(),
+            "tf plan ".to_string(),
+            "tf state".to_string(),
+            "helm rollback ".to_string(),
             "tf".to_string(),
             "terraform".to_string(),
             "ansible".to_string(),
@@ -2077,11 +4122,15 @@ impl App {
             "openshift".to_string(),
             "kustomize".to_string(),
             "kustomize .".to_string(),
+            "kustomize diff ".to_string(),
+            "kustomize apply ".to_string(),
             "plugin".to_string(),
             "plugin ".to_string(),
             "git".to_string(),
             "git ".to_string(),
             "git fetch ".to_string(),
+            "git refs ".to_string(),
+            "git diff ".to_string(),
             "git files ".to_string(),
             "git show ".to_string(),
             "git export ".to_string(),
@@ -2107,14 +4156,22 @@ impl App {
             "logs".to_string(),
             "edit".to_string(),
             "delete".to_string(),
+            "force-delete".to_string(),
+            "resource-events".to_string(),
+            "top cpu".to_string(),
+            "top mem".to_string(),
+            "tabs all".to_string(),
+            "tabs served".to_string(),
             "restart".to_string(),
             "scale ".to_string(),
             "exec ".to_string(),
+            "exec -t ".to_string(),
             "shell".to_string(),
             "shell auto".to_string(),
             "shell /bin/sh".to_string(),
             "shell /bin/bash".to_string(),
             "bash".to_string(),
+            "debug ".to_string(),
             "ssh".to_string(),
             "pf ".to_string(),
             "port-forward ".to_string(),
@@ -2123,7 +4180,7 @@ impl App {
             "q".to_string(),
         ];
 
-        for tab in &self.tabs {
+        for tab in self.visible_tabs() {
             candidates.push(tab.short_token().to_string());
             candidates.push(tab.title().to_ascii_lowercase());
         }
@@ -2191,6 +4248,7 @@ impl App {
             "tools".to_string(),
             "alerts".to_string(),
             "pulses".to_string(),
+            "activity".to_string(),
             "xray".to_string(),
             "argocd".to_string(),
             "argo".to_string(),
@@ -2226,7 +4284,7 @@ impl App {
             "clusters".to_string(),
             "users".to_string(),
         ];
-        for tab in &self.tabs {
+        for tab in self.visible_tabs() {
             candidates.push(tab.short_token().to_string());
             candidates.push(tab.title().to_ascii_lowercase());
         }
@@ -2288,7 +4346,7 @@ impl App {
         let visible_rows = table
             .rows
             .iter()
-            .filter(|row| row.matches_filter(&self.filter))
+            .filter(|row| table.row_matches_filter(row, self.row_filter()))
             .collect::<Vec<_>>();
         if visible_rows.is_empty() {
             return None;
@@ -2310,7 +4368,7 @@ impl App {
         name: &str,
         fallback_selected: usize,
     ) {
-        let filter = self.filter.clone();
+        let filter = self.row_filter().to_string();
         let Some(table) = self.tables.get_mut(&tab) else {
             return;
         };
@@ -2319,7 +4377,7 @@ impl App {
         let mut matched_indices = Vec::new();
 
         for row in &table.rows {
-            if !row.matches_filter(&filter) {
+            if !table.row_matches_filter(row, &filter) {
                 continue;
             }
 
@@ -2340,7 +4398,7 @@ impl App {
             let visible_len = table
                 .rows
                 .iter()
-                .filter(|row| row.matches_filter(&filter))
+                .filter(|row| table.row_matches_filter(row, &filter))
                 .count();
             table.selected = fallback_selected.min(visible_len.saturating_sub(1));
         }
@@ -2354,12 +4412,12 @@ impl App {
     }
 
     fn set_selected_index_for_tab(&mut self, tab: ResourceTab, selected: usize) {
-        let filter = self.filter.clone();
+        let filter = self.row_filter().to_string();
         if let Some(table) = self.tables.get_mut(&tab) {
             let visible_len = table
                 .rows
                 .iter()
-                .filter(|row| row.matches_filter(&filter))
+                .filter(|row| table.row_matches_filter(row, &filter))
                 .count();
             table.selected = selected.min(visible_len.saturating_sub(1));
         }
@@ -2435,6 +4493,7 @@ impl App {
                 self.push_flow_state();
                 self.drill_into_pods(row_namespace, &row_name, false)
             }
+            ResourceTab::Nodes => self.drill_into_node_pods(&row_name),
             ResourceTab::ArgoCdApps => {
                 let app_name = row_name;
                 self.push_flow_state();
@@ -2448,6 +4507,7 @@ impl App {
                 }
             }
             ResourceTab::ArgoCdResources => self.prepare_argocd_resource_panel(),
+            ResourceTab::Events => self.jump_to_event_object(),
             ResourceTab::ArgoCdProjects
             | ResourceTab::ArgoCdRepos
             | ResourceTab::ArgoCdClusters
@@ -2539,10 +4599,7 @@ impl App {
             return AppCommand::None;
         };
 
-        let section_label = match section {
-            ArgoResourcePanelSection::Events => "events",
-            ArgoResourcePanelSection::Manifest => "manifest",
-        };
+        let section_label = section.label();
         self.status = match target.namespace.as_deref() {
             Some(namespace) => format!(
                 "Loading Argo {section_label} for {} {namespace}/{}",
@@ -2576,6 +4633,9 @@ impl App {
         self.detail_scroll = 0;
         self.focus = FocusPane::Detail;
         self.status = format!("Opened details for {name}");
+        if self.active_tab() == ResourceTab::Namespaces {
+            return AppCommand::LoadNamespaceSummary { name };
+        }
         AppCommand::None
     }
 
@@ -2610,9 +4670,121 @@ impl App {
         }
     }
 
+    fn jump_to_event_object(&mut self) -> AppCommand {
+        if self.active_tab() != ResourceTab::Events {
+            self.status = "Object jump is available from Events".to_string();
+            return AppCommand::None;
+        }
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No event selected".to_string();
+            return AppCommand::None;
+        };
+        let kind = row.columns.get(1).cloned().unwrap_or_default();
+        let name = row.columns.get(2).cloned().unwrap_or_default();
+        let event_namespace = row.namespace.clone();
+        if kind.is_empty() || kind == "-" || name.is_empty() || name == "-" {
+            self.status = "Selected event has no involved object".to_string();
+            return AppCommand::None;
+        }
+
+        let target = ResourceTab::from_token(&kind)
+            .filter(|tab| self.tabs.contains(tab) && !matches!(tab, ResourceTab::Events))
+            .map(|tab| (tab, None))
+            .or_else(|| {
+                self.discovered_crds
+                    .iter()
+                    .find(|crd| crd.kind.eq_ignore_ascii_case(&kind))
+                    .map(|crd| (ResourceTab::CustomResources, Some(crd.name.clone())))
+            });
+        let Some((tab, crd)) = target else {
+            self.status = format!("No tab for {kind}; describing {name}");
+            return AppCommand::DescribeSelected {
+                tab: ResourceTab::Events,
+                resource: kind.to_ascii_lowercase(),
+                namespace: event_namespace,
+                name,
+            };
+        };
+
+        self.push_flow_state();
+        if crd.is_some() {
+            self.selected_crd = crd;
+        }
+        let namespaced = self
+            .kubectl_resource_for_tab(tab)
+            .is_none_or(|(_, namespaced)| namespaced);
+        let namespace = if namespaced { event_namespace } else { None };
+        if let Some(namespace) = namespace.clone()
+            && self.namespace_scope != NamespaceScope::All
+        {
+            self.namespace_scope = NamespaceScope::Named(namespace);
+        }
+        self.filter.clear();
+        self.clear_table_overlay();
+        self.clear_detail_overlay();
+        self.focus = FocusPane::Table;
+        self.clamp_all_selections();
+
+        let switched = self.switch_to_tab(tab);
+        self.select_row_by_identity(tab, namespace.clone(), &name);
+        self.pending_row_focus = Some((tab, namespace, name.clone()));
+        self.status = format!("Jumped to {} {name}", tab.title());
+        if switched == AppCommand::None || tab == ResourceTab::CustomResources {
+            AppCommand::RefreshActive
+        } else {
+            switched
+        }
+    }
+
+    fn jump_to_service_endpoint_slices(&mut self) -> AppCommand {
+        if self.active_tab() != ResourceTab::Services {
+            self.status = "Endpoint slice jump is available from Services".to_string();
+            return AppCommand::None;
+        }
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No service selected".to_string();
+            return AppCommand::None;
+        };
+        let service = row.name.clone();
+        let namespace = row.namespace.clone();
+        self.push_flow_state();
+        if let Some(namespace) = namespace {
+            self.namespace_scope = NamespaceScope::Named(namespace);
+        }
+        self.filter.clear();
+        self.label_selectors.insert(
+            ResourceTab::EndpointSlices,
+            format!("kubernetes.io/service-name={service}"),
+        );
+        self.clear_table_overlay();
+        self.clear_detail_overlay();
+        self.focus = FocusPane::Table;
+        self.clamp_all_selections();
+
+        let switched = self.switch_to_tab(ResourceTab::EndpointSlices);
+        self.status = format!("Endpoint slices for service {service}");
+        if switched == AppCommand::None {
+            AppCommand::RefreshActive
+        } else {
+            switched
+        }
+    }
+
     fn submit_input(&mut self) -> AppCommand {
         match self.mode {
-            InputMode::Normal => AppCommand::None,
+            InputMode::Normal | InputMode::Edit => AppCommand::None,
+            InputMode::Filter if self.log_overlay_active() => {
+                self.log_filter = self.input.trim().to_string();
+                self.mode = InputMode::Normal;
+                self.input.clear();
+                self.completion_index = 0;
+                self.table_scroll = 0;
+                self.status = match self.log_filter_summary() {
+                    Some(summary) => format!("Log filter: {summary}"),
+                    None => "Log filter cleared".to_string(),
+                };
+                AppCommand::None
+            }
             InputMode::Filter => {
                 self.filter = self.input.trim().to_string();
                 self.mode = InputMode::Normal;
@@ -2700,6 +4872,14 @@ impl App {
                 self.handle_read_only_command(parts.next());
                 AppCommand::None
             }
+            "tail" => {
+                self.handle_log_tail_command(parts.next());
+                AppCommand::None
+            }
+            "log-window" | "logwin" => {
+                self.handle_log_window_command(parts.next());
+                AppCommand::None
+            }
             "config" => {
                 self.show_runtime_config_overlay();
                 AppCommand::None
@@ -2708,7 +4888,68 @@ impl App {
             "tools" => AppCommand::InspectTooling,
             "alerts" | "alert" => AppCommand::InspectAlerts,
             "pulses" | "pulse" => AppCommand::InspectPulses,
+            "activity" | "feed" => {
+                self.show_activity_overlay();
+                AppCommand::None
+            }
             "xray" | "xr" | "x" => self.prepare_xray_command(parts.next()),
+            "describe" | "desc" => self.prepare_describe_command(),
+            "slices" => self.jump_to_service_endpoint_slices(),
+            "deprecations" | "deprecated" => {
+                self.status = "Scanning for deprecated API versions".to_string();
+                AppCommand::ScanDeprecatedApis
+            }
+            "images" | "img" => self.prepare_image_summary_command(),
+            "node-top" | "ntop" => {
+                self.status = "Loading per-node CPU/memory usage".to_string();
+                AppCommand::LoadNodeUsage
+            }
+            "export" => {
+                let args = parts.collect::<Vec<_>>();
+                self.prepare_namespace_export_command(&args)
+            }
+            "save" | "write" => self.prepare_export_yaml_command(parts.next()),
+            "rollout" => match parts.next() {
+                Some("watch") | Some("w") => self.prepare_rollout_watch_command(parts.next()),
+                Some("status") | Some("s") => self.selected_rollout_target("status").map_or(
+                    AppCommand::None,
+                    |(tab, namespace, name)| AppCommand::RolloutStatus {
+                        tab,
+                        namespace,
+                        name,
+                    },
+                ),
+                Some("history") | Some("hist") | Some("h") => self
+                    .selected_rollout_target("history")
+                    .map_or(AppCommand::None, |(tab, namespace, name)| {
+                        AppCommand::RolloutHistory {
+                            tab,
+                            namespace,
+                            name,
+                        }
+                    }),
+                Some("undo") | Some("rollback") => self.prepare_rollout_undo_command(parts.next()),
+                _ => {
+                    self.status =
+                        "Usage: :rollout watch [timeout-secs] | status | history | undo [revision]"
+                            .to_string();
+                    AppCommand::None
+                }
+            },
+            "rewatch" => {
+                self.status = "Restarting resource watchers".to_string();
+                AppCommand::RestartWatchers
+            }
+            "scratch" => match parts.next() {
+                None | Some("clean") => self.prepare_scratch_yaml_command(true),
+                Some("raw") => self.prepare_scratch_yaml_command(false),
+                Some(_) => {
+                    self.status = "Usage: :scratch [clean|raw]".to_string();
+                    AppCommand::None
+                }
+            },
+            "neighbors" | "nb" => self.prepare_node_neighbors_command(),
+            "source" | "src" => self.prepare_argo_source_command(),
             "orca" => self.switch_to_tab(ResourceTab::Orca),
             "argocd" | "argo" => {
                 let args = parts.map(str::to_string).collect::<Vec<_>>();
@@ -2718,22 +4959,8 @@ impl App {
                 let args = parts.map(str::to_string).collect::<Vec<_>>();
                 self.open_kubernetes_command(args)
             }
-            "helm" => {
-                if let Some(name) = parts.next() {
-                    AppCommand::InspectOps {
-                        target: OpsInspectTarget::HelmRelease {
-                            name: name.to_string(),
-                        },
-                    }
-                } else {
-                    AppCommand::InspectOps {
-                        target: OpsInspectTarget::HelmReleases,
-                    }
-                }
-            }
-            "tf" | "terraform" => AppCommand::InspectOps {
-                target: OpsInspectTarget::TerraformOverview,
-            },
+            "helm" => self.helm_command(parts.collect()),
+            "tf" | "terraform" => self.terraform_command(parts.collect()),
             "ansible" | "ans" => AppCommand::InspectOps {
                 target: OpsInspectTarget::AnsibleOverview,
             },
@@ -2765,12 +4992,7 @@ impl App {
             "oc" | "openshift" => AppCommand::InspectOps {
                 target: OpsInspectTarget::OpenShiftProjects,
             },
-            "kustomize" | "kustom" => {
-                let path = parts.next().unwrap_or(".").to_string();
-                AppCommand::InspectOps {
-                    target: OpsInspectTarget::KustomizeBuild { path },
-                }
-            }
+            "kustomize" | "kustom" => self.kustomize_command(parts.collect()),
             "git" | "repo" => {
                 let args = parts.map(str::to_string).collect::<Vec<_>>();
                 self.prepare_git_command(args)
@@ -2824,7 +5046,7 @@ impl App {
                 AppCommand::None
             }
             "all-ns" | "allns" | "all" | "all-namespaces" => {
-                self.namespace_scope = NamespaceScope::All;
+                self.set_all_namespaces();
                 self.status = "Namespace scope set to all".to_string();
                 AppCommand::RefreshAll
             }
@@ -2833,10 +5055,13 @@ impl App {
                     self.namespace_scope = NamespaceScope::Named(namespace.to_string());
                     self.status = format!("Namespace scope set to '{namespace}'");
                     AppCommand::RefreshAll
-                } else {
+                } else if command == "namespaces" {
                     self.switch_to_tab(ResourceTab::Namespaces)
+                } else {
+                    self.open_namespace_picker()
                 }
             }
+            "tabs" => self.tabs_command(parts.next()),
             "tab" => {
                 let Some(raw_tab) = parts.next() else {
                     self.status = "Usage: :tab <pods|deployments|services|...>".to_string();
@@ -2852,6 +5077,12 @@ impl App {
                 let remainder = parts.collect::<Vec<_>>().join(" ");
                 self.handle_tab_shortcut(target_tab, &remainder)
             }
+            "has" => {
+                let kind = parts.next();
+                let expr = parts.collect::<Vec<_>>().join(" ");
+                self.handle_metadata_filter_command(kind, &expr);
+                AppCommand::None
+            }
             "filter" => {
                 self.filter = parts.collect::<Vec<_>>().join(" ");
                 self.clamp_all_selections();
@@ -2880,8 +5111,39 @@ impl App {
                     self.prepare_edit_command()
                 }
             }
-            "delete" | "del" => self.prepare_delete_confirmation(),
+            "force-delete" | "kill" => self.prepare_force_delete_pod(),
+            "resource-events" | "rev" => self.prepare_resource_events(),
+            "top" => self.top_command(parts.collect()),
+            "delete" | "del" => {
+                let propagation = match parts.next() {
+                    None => DeletePropagation::default(),
+                    Some(raw) => match DeletePropagation::from_token(raw) {
+                        Some(propagation) => propagation,
+                        None => {
+                            self.status = format!(
+                                "Unknown propagation policy '{raw}' (background|foreground|orphan)"
+                            );
+                            return AppCommand::None;
+                        }
+                    },
+                };
+                self.prepare_delete_confirmation(propagation)
+            }
+            "more" | "load-more" => self.prepare_load_more_command(),
+            "ref" | "copy-ref" => self.open_reference_menu(parts.next()),
+            "selector" | "sel" => {
+                let selector = parts.collect::<Vec<_>>().join(" ");
+                self.set_selector_command(SelectorKind::Label, &selector)
+            }
+            "fields" | "fsel" => {
+                let selector = parts.collect::<Vec<_>>().join(" ");
+                self.set_selector_command(SelectorKind::Field, &selector)
+            }
             "restart" => self.prepare_restart_confirmation(),
+            "rerun" => self.prepare_rerun_confirmation(),
+            "run-now" | "trigger" => self.prepare_trigger_cronjob(),
+            "suspend" => self.prepare_cronjob_suspend_toggle(Some(true)),
+            "resume" => self.prepare_cronjob_suspend_toggle(Some(false)),
             "scale" => {
                 let Some(raw_replicas) = parts.next() else {
                     self.status = "Usage: :scale <replicas>".to_string();
@@ -2903,16 +5165,39 @@ impl App {
                 self.prepare_shell_command(container, shell)
             }
             "bash" => self.prepare_shell_command(None, "/bin/bash".to_string()),
+            "debug" | "dbg" => {
+                let image = parts.next().map(str::to_string);
+                let target = parts.next().map(str::to_string);
+                self.prepare_debug_command(image, target)
+            }
             "pf" | "port-forward" => {
                 let Some(mapping) = parts.next() else {
-                    self.status = "Usage: :port-forward <local>:<remote>".to_string();
+                    self.show_port_forward_overlay();
                     return AppCommand::None;
                 };
-                let Some((local_port, remote_port)) = parse_port_mapping(mapping) else {
+                if mapping == "kill" || mapping == "stop" {
+                    let Some(pid) = parts.next().and_then(|raw| raw.parse::<u32>().ok()) else {
+                        self.status = "Usage: :pf kill <pid>".to_string();
+                        return AppCommand::None;
+                    };
+                    if !self
+                        .active_port_forwards
+                        .iter()
+                        .any(|session| session.pid == pid)
+                    {
+                        self.status = format!("No active port-forward with pid {pid}");
+                        return AppCommand::None;
+                    }
+                    return AppCommand::StopPortForward { pid };
+                }
+                if let Some((local_port, remote_port)) = parse_port_mapping(mapping) {
+                    return self.prepare_port_forward(local_port, remote_port);
+                }
+                let Some((local_port, port_name)) = parse_named_port_mapping(mapping) else {
                     self.status = format!("Invalid port mapping '{mapping}'");
                     return AppCommand::None;
                 };
-                self.prepare_port_forward(local_port, remote_port)
+                self.prepare_named_port_forward(local_port, port_name)
             }
             "crd" | "custom" => self.select_custom_resource(parts.next()),
             "crd-refresh" => AppCommand::RefreshCustomResourceCatalog,
@@ -2969,6 +5254,11 @@ impl App {
             return AppCommand::InspectPulses;
         }
 
+        if matches!(first.as_str(), "activity" | "feed") {
+            self.show_activity_overlay();
+            return AppCommand::None;
+        }
+
         if matches!(first.as_str(), "xray" | "xr" | "x") {
             return self.prepare_xray_command(parts.next());
         }
@@ -2986,23 +5276,11 @@ impl App {
         }
 
         if first == "helm" {
-            return if let Some(name) = parts.next() {
-                AppCommand::InspectOps {
-                    target: OpsInspectTarget::HelmRelease {
-                        name: name.to_string(),
-                    },
-                }
-            } else {
-                AppCommand::InspectOps {
-                    target: OpsInspectTarget::HelmReleases,
-                }
-            };
+            return self.helm_command(parts.collect());
         }
 
         if matches!(first.as_str(), "tf" | "terraform") {
-            return AppCommand::InspectOps {
-                target: OpsInspectTarget::TerraformOverview,
-            };
+            return self.terraform_command(parts.collect());
         }
 
         if matches!(first.as_str(), "ansible" | "ans") {
@@ -3050,10 +5328,7 @@ impl App {
         }
 
         if matches!(first.as_str(), "kustomize" | "kustom") {
-            let path = parts.next().unwrap_or(".").to_string();
-            return AppCommand::InspectOps {
-                target: OpsInspectTarget::KustomizeBuild { path },
-            };
+            return self.kustomize_command(parts.collect());
         }
 
         if matches!(first.as_str(), "git" | "repo") {
@@ -3335,17 +5610,65 @@ impl App {
                     table_cell(&plugin.description, 72)
                 };
                 lines.push(format!(
-                    "- {} [{}] {} timeout:{}s retries:{} ({})",
+                    "- {} [{}] {} timeout:{}s retries:{}{}{} ({})",
                     plugin.name,
                     mutate,
                     plugin.command,
                     plugin.timeout_secs,
                     plugin.retries,
+                    if plugin.stream { " stream" } else { "" },
+                    if plugin.confirm.unwrap_or(plugin.mutating) {
+                        " confirm"
+                    } else {
+                        ""
+                    },
                     description
                 ));
             }
         }
 
+        lines.push(String::new());
+        lines.push("skip_confirm".to_string());
+        if self.confirm_skip.is_empty() {
+            lines.push("-".to_string());
+        } else {
+            let mut actions = self.confirm_skip.iter().cloned().collect::<Vec<_>>();
+            actions.sort();
+            lines.push(format!("- {}", actions.join(", ")));
+        }
+
+        lines.push(String::new());
+        lines.push("dangerous_commands".to_string());
+        if self.dangerous_commands.is_empty() {
+            lines.push("-".to_string());
+        } else {
+            for rule in &self.dangerous_commands {
+                lines.push(format!("- {}", rule.describe()));
+            }
+        }
+
+        lines.push(String::new());
+        lines.push(format!(
+            "adopt_context_namespace: {}",
+            if self.adopt_context_namespace {
+                "on"
+            } else {
+                "off"
+            }
+        ));
+        lines.push(format!(
+            "export_dir: {}",
+            self.export_dir.as_deref().unwrap_or(DEFAULT_EXPORT_DIR)
+        ));
+        lines.push(format!(
+            "strip_name_prefixes: {}",
+            if self.strip_name_prefixes.is_empty() {
+                "(none)".to_string()
+            } else {
+                self.strip_name_prefixes.join(", ")
+            }
+        ));
+
         lines.push(String::new());
         lines.push("hotkeys".to_string());
         if self.hotkey_commands.is_empty() {
@@ -3405,15 +5728,51 @@ impl App {
             args.extend(extra.clone());
         }
 
-        self.status = format!("Running plugin '{}'", plugin.name);
-        AppCommand::RunPlugin {
-            run: PluginRun {
-                name: plugin.name,
-                program: plugin.command,
-                args,
-                mutating: plugin.mutating,
-                timeout_secs: plugin.timeout_secs,
-                retries: plugin.retries,
+        let run = PluginRun {
+            name: plugin.name,
+            program: self.interpolate_plugin_template(&plugin.command, &extra),
+            args,
+            mutating: plugin.mutating,
+            timeout_secs: plugin.timeout_secs,
+            retries: plugin.retries,
+            stream: plugin.stream,
+            env: self.plugin_env(),
+        };
+        if plugin.confirm.unwrap_or(plugin.mutating) {
+            let prompt = if run.args.is_empty() {
+                format!("Run plugin '{}' ({})", run.name, run.program)
+            } else {
+                format!(
+                    "Run plugin '{}' ({} {})",
+                    run.name,
+                    run.program,
+                    run.args.join(" ")
+                )
+            };
+            return self.request_confirmation("plugin", prompt, AppCommand::RunPlugin { run });
+        }
+        self.status = format!("Running plugin '{}'", run.name);
+        AppCommand::RunPlugin { run }
+    }
+
+    fn git_fetch_command(&mut self, args: &[String]) -> AppCommand {
+        let full = args.iter().any(|arg| arg == "--full");
+        let mut positional = args.iter().filter(|arg| *arg != "--full").cloned();
+        let Some(repo) = positional.next() else {
+            self.status = "Usage: :git fetch [--full] <url-or-repo> [ref]".to_string();
+            return AppCommand::None;
+        };
+        let reference = positional.next();
+        self.status = if full {
+            format!("Syncing repository '{}' with full history", repo)
+        } else {
+            format!("Syncing repository '{}'", repo)
+        };
+        AppCommand::InspectOps {
+            target: OpsInspectTarget::GitFetch {
+                repo,
+                reference,
+                full,
             },
         }
     }
@@ -3427,29 +5786,33 @@ impl App {
 
         let first = args[0].trim().to_string();
         if looks_like_repo_locator(&first) {
-            let reference = args.get(1).cloned();
-            self.status = format!("Syncing repository '{}'", first);
-            return AppCommand::InspectOps {
-                target: OpsInspectTarget::GitFetch {
-                    repo: first,
-                    reference,
-                },
-            };
+            return self.git_fetch_command(&args);
         }
 
         match resolve_command_token(&first).as_str() {
             "help" | "?" | "ls" | "list" => AppCommand::InspectOps {
                 target: OpsInspectTarget::GitCatalog,
             },
-            "fetch" | "clone" | "pull" => {
+            "fetch" | "clone" | "pull" => self.git_fetch_command(&args[1..]),
+            "refs" | "branches" | "tags" => {
                 let Some(repo) = args.get(1).cloned() else {
-                    self.status = "Usage: :git fetch <url-or-repo> [ref]".to_string();
+                    self.status = "Usage: :git refs <url-or-repo>".to_string();
+                    return AppCommand::None;
+                };
+                self.status = format!("Listing branches and tags for '{repo}'");
+                AppCommand::InspectOps {
+                    target: OpsInspectTarget::GitRefs { repo },
+                }
+            }
+            "diff" => {
+                let (Some(repo), Some(from)) = (args.get(1).cloned(), args.get(2).cloned()) else {
+                    self.status = "Usage: :git diff <url-or-repo> <from> [to]".to_string();
                     return AppCommand::None;
                 };
-                let reference = args.get(2).cloned();
-                self.status = format!("Syncing repository '{}'", repo);
+                let to = args.get(3).cloned().unwrap_or_else(|| "HEAD".to_string());
+                self.status = format!("Diffing {repo} {from}..{to}");
                 AppCommand::InspectOps {
-                    target: OpsInspectTarget::GitFetch { repo, reference },
+                    target: OpsInspectTarget::GitDiff { repo, from, to },
                 }
             }
             "files" => {
@@ -3540,6 +5903,34 @@ impl App {
         }
     }
 
+    fn plugin_env(&self) -> Vec<(String, String)> {
+        let mut env = vec![("ORCA_CONTEXT".to_string(), self.context().to_string())];
+        let Some(row) = self.active_selected_row() else {
+            return env;
+        };
+        let tab = self.active_tab();
+        let (kind, namespace, name) = if let Some(target) = self.selected_argocd_resource_target() {
+            (Some(target.kind), target.namespace, target.name)
+        } else {
+            let kind = match tab {
+                ResourceTab::CustomResources => {
+                    self.selected_custom_resource().map(|crd| crd.kind.clone())
+                }
+                _ => tab.kind().map(str::to_string),
+            };
+            (kind, row.namespace.clone(), row.name.clone())
+        };
+        if let Some(kind) = kind {
+            env.push(("ORCA_SELECTED_KIND".to_string(), kind));
+        }
+        env.push((
+            "ORCA_SELECTED_NAMESPACE".to_string(),
+            namespace.unwrap_or_default(),
+        ));
+        env.push(("ORCA_SELECTED_NAME".to_string(), name));
+        env
+    }
+
     fn interpolate_plugin_template(&self, template: &str, extra: &[String]) -> String {
         let selected = self.active_selected_row();
         let selected_name = selected
@@ -3577,7 +5968,213 @@ impl App {
             .replace("{args}", &joined_extra)
     }
 
-    fn open_kubernetes_command(&mut self, args: Vec<String>) -> AppCommand {
+    fn helm_command(&mut self, args: Vec<&str>) -> AppCommand {
+        match args.as_slice() {
+            [] => AppCommand::InspectOps {
+                target: OpsInspectTarget::HelmReleases,
+            },
+            ["history" | "hist", name] => AppCommand::InspectOps {
+                target: OpsInspectTarget::HelmHistory {
+                    name: name.to_string(),
+                },
+            },
+            ["rollback" | "rb", name, revision] if revision.parse::<u32>().is_ok() => {
+                self.prepare_helm_rollback(name, revision)
+            }
+            ["diff", release, chart, rest @ ..] if rest.len() <= 1 => AppCommand::InspectOps {
+                target: OpsInspectTarget::HelmDiff {
+                    release: release.to_string(),
+                    chart: chart.to_string(),
+                    values_file: rest.first().map(|path| path.to_string()),
+                },
+            },
+            ["diff", ..] => {
+                self.status = "Usage: :helm diff <release> <chart> [values-file]".to_string();
+                AppCommand::None
+            }
+            ["history" | "hist", ..] => {
+                self.status = "Usage: :helm history <release>".to_string();
+                AppCommand::None
+            }
+            ["rollback" | "rb", ..] => {
+                self.status = "Usage: :helm rollback <release> <revision>".to_string();
+                AppCommand::None
+            }
+            [name, section] => self.helm_release_command(name, Some(section)),
+            [name] => self.helm_release_command(name, None),
+            _ => {
+                self.status = "Usage: :helm [release] [status|values|manifest]".to_string();
+                AppCommand::None
+            }
+        }
+    }
+
+    fn terraform_command(&mut self, args: Vec<&str>) -> AppCommand {
+        match args.as_slice() {
+            [] => AppCommand::InspectOps {
+                target: OpsInspectTarget::TerraformOverview,
+            },
+            ["state", "refresh", rest @ ..] if rest.len() <= 1 => {
+                let dir = rest.first().copied().unwrap_or(".").to_string();
+                self.status = format!("Loading terraform state list in {dir}...");
+                AppCommand::LoadTerraformState { dir }
+            }
+            ["state", rest @ ..] if rest.len() <= 1 => {
+                let dir = rest.first().copied().unwrap_or(".").to_string();
+                match self.terraform_state.as_mut() {
+                    Some(picker) if picker.dir == dir && !picker.addresses.is_empty() => {
+                        picker.open = true;
+                        self.status = format!(
+                            "Terraform state in {dir}: {} resources (cached; :tf state refresh {dir} reloads)",
+                            picker.addresses.len()
+                        );
+                        AppCommand::None
+                    }
+                    _ => {
+                        self.status = format!("Loading terraform state list in {dir}...");
+                        AppCommand::LoadTerraformState { dir }
+                    }
+                }
+            }
+            ["plan", rest @ ..] if rest.len() <= 2 => {
+                let mut dir = ".".to_string();
+                let mut timeout_secs = self.terraform_plan_timeout_secs;
+                for arg in rest {
+                    match arg.parse::<u64>() {
+                        Ok(secs) if secs > 0 => timeout_secs = secs,
+                        _ => dir = arg.to_string(),
+                    }
+                }
+                self.status =
+                    format!("Running terraform plan in {dir} (timeout {timeout_secs}s)...");
+                AppCommand::InspectOps {
+                    target: OpsInspectTarget::TerraformPlan { dir, timeout_secs },
+                }
+            }
+            _ => {
+                self.status =
+                    "Usage: :tf [plan [dir] [timeout-secs] | state [refresh] [dir]]".to_string();
+                AppCommand::None
+            }
+        }
+    }
+
+    fn kustomize_command(&mut self, args: Vec<&str>) -> AppCommand {
+        match args.as_slice() {
+            ["diff" | "dry-run", rest @ ..] if rest.len() <= 1 => {
+                let path = rest.first().copied().unwrap_or(".").to_string();
+                self.status = format!("Running server-side dry-run apply for {path}...");
+                AppCommand::InspectOps {
+                    target: OpsInspectTarget::KustomizeDiff { path },
+                }
+            }
+            ["apply", rest @ ..] if rest.len() <= 1 => {
+                if !self.ensure_write_allowed("kustomize apply") {
+                    return AppCommand::None;
+                }
+                let path = rest.first().copied().unwrap_or(".").to_string();
+                let prompt = match &self.namespace_scope {
+                    NamespaceScope::Named(namespace) => {
+                        format!("Apply kustomize build of {path} to namespace {namespace}")
+                    }
+                    NamespaceScope::All => format!("Apply kustomize build of {path}"),
+                };
+                self.request_confirmation(
+                    "kustomize apply",
+                    prompt,
+                    AppCommand::InspectOps {
+                        target: OpsInspectTarget::KustomizeApply { path },
+                    },
+                )
+            }
+            [] | [_] => AppCommand::InspectOps {
+                target: OpsInspectTarget::KustomizeBuild {
+                    path: args.first().copied().unwrap_or(".").to_string(),
+                },
+            },
+            _ => {
+                self.status = "Usage: :kustomize [diff|apply] [path]".to_string();
+                AppCommand::None
+            }
+        }
+    }
+
+    fn prepare_helm_rollback(&mut self, name: &str, revision: &str) -> AppCommand {
+        if !self.ensure_write_allowed("helm rollback") {
+            return AppCommand::None;
+        }
+        let prompt = match &self.namespace_scope {
+            NamespaceScope::Named(namespace) => {
+                format!("Roll back helm release {namespace}/{name} to revision {revision}")
+            }
+            NamespaceScope::All => {
+                format!("Roll back helm release {name} to revision {revision}")
+            }
+        };
+        self.request_confirmation(
+            "helm rollback",
+            prompt,
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::HelmRollback {
+                    name: name.to_string(),
+                    revision: revision.to_string(),
+                },
+            },
+        )
+    }
+
+    fn helm_release_command(&mut self, name: &str, section: Option<&str>) -> AppCommand {
+        let section = match section {
+            None => HelmReleaseSection::Status,
+            Some(raw) => match HelmReleaseSection::from_token(raw) {
+                Some(section) => section,
+                None => {
+                    self.status = format!(
+                        "Unknown helm section '{raw}'. Use: :helm <release> [status|values|manifest]"
+                    );
+                    return AppCommand::None;
+                }
+            },
+        };
+        AppCommand::InspectOps {
+            target: OpsInspectTarget::HelmRelease {
+                name: name.to_string(),
+                section,
+            },
+        }
+    }
+
+    fn step_argo_panel_section(&mut self, forward: bool) -> AppCommand {
+        let Some((target, section)) = self.argo_panel_view.clone() else {
+            return AppCommand::None;
+        };
+        let section = section.step(forward);
+        self.status = format!(
+            "Loading Argo {} for {} {}",
+            section.label(),
+            target.kind,
+            target.name
+        );
+        AppCommand::LoadArgoResourcePanelSection {
+            kind: target.kind,
+            namespace: target.namespace,
+            name: target.name,
+            section,
+        }
+    }
+
+    fn step_helm_release_section(&mut self, forward: bool) -> AppCommand {
+        let Some((name, section)) = self.helm_release_view.clone() else {
+            return AppCommand::None;
+        };
+        let section = section.step(forward);
+        self.status = format!("Loading helm {} for {name}", section.label());
+        AppCommand::InspectOps {
+            target: OpsInspectTarget::HelmRelease { name, section },
+        }
+    }
+
+    fn open_kubernetes_command(&mut self, args: Vec<String>) -> AppCommand {
         if args.is_empty() {
             let command = self.switch_to_tab(ResourceTab::Pods);
             self.status = "Kubernetes workspace".to_string();
@@ -3673,12 +6270,7 @@ impl App {
             "gpg" | "gpgkeys" | "gpg-keys" => {
                 self.switch_and_refresh_argocd_tab(ResourceTab::ArgoCdGpgKeys, "Argo CD GPG keys")
             }
-            "sync" => self.prepare_argocd_action(
-                args.get(1).map(String::as_str),
-                "sync",
-                |name| OpsInspectTarget::ArgoCdSync { name },
-                true,
-            ),
+            "sync" => self.prepare_argocd_sync(&args[1..]),
             "refresh" => self.prepare_argocd_action(
                 args.get(1).map(String::as_str),
                 "refresh",
@@ -3736,6 +6328,7 @@ impl App {
                     },
                 }
             }
+            "create" | "new" => self.prepare_argocd_create(&args[1..]),
             "delete" | "del" => {
                 if !self.ensure_write_allowed("argocd delete") {
                     return AppCommand::None;
@@ -3790,6 +6383,125 @@ impl App {
             .or_else(|| self.selected_row_name_for(ResourceTab::ArgoCdApps))
     }
 
+    fn prepare_argocd_create(&mut self, args: &[String]) -> AppCommand {
+        let [name, repo, path, rest @ ..] = args else {
+            self.status =
+                "Usage: :argocd create <name> <url-or-repo> <path> [dest-namespace] [project]"
+                    .to_string();
+            return AppCommand::None;
+        };
+        if rest.len() > 2 {
+            self.status =
+                "Usage: :argocd create <name> <url-or-repo> <path> [dest-namespace] [project]"
+                    .to_string();
+            return AppCommand::None;
+        }
+        if !self.ensure_write_allowed("argocd create") {
+            return AppCommand::None;
+        }
+        let dest_namespace = rest
+            .first()
+            .cloned()
+            .unwrap_or_else(|| match &self.namespace_scope {
+                NamespaceScope::Named(namespace) => namespace.clone(),
+                NamespaceScope::All => "default".to_string(),
+            });
+        let project = rest
+            .get(1)
+            .cloned()
+            .unwrap_or_else(|| "default".to_string());
+        self.request_confirmation(
+            "argocd create",
+            format!(
+                "Create Argo CD app {name} from {repo} path {path} -> namespace {dest_namespace} (project {project})"
+            ),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::ArgoCdCreate {
+                    name: name.clone(),
+                    repo: repo.clone(),
+                    path: path.clone(),
+                    dest_namespace,
+                    project,
+                },
+            },
+        )
+    }
+
+    fn prepare_argocd_sync(&mut self, args: &[String]) -> AppCommand {
+        let mut explicit_app = None;
+        let mut prune = false;
+        let mut dry_run = false;
+        let mut resource = None;
+        let mut iter = args.iter().map(String::as_str);
+        while let Some(arg) = iter.next() {
+            match arg {
+                "--prune" => prune = true,
+                "--dry-run" => dry_run = true,
+                "--resource" => match iter.next() {
+                    Some(value) => resource = Some(value.to_string()),
+                    None => {
+                        self.status =
+                            "Usage: :argocd sync [app] [--prune] [--dry-run] [--resource GROUP:KIND:NAME]"
+                                .to_string();
+                        return AppCommand::None;
+                    }
+                },
+                _ if arg.starts_with("--resource=") => {
+                    resource = Some(arg.trim_start_matches("--resource=").to_string());
+                }
+                _ if explicit_app.is_none() && !arg.starts_with("--") => explicit_app = Some(arg),
+                _ => {
+                    self.status = format!(
+                        "Unknown argocd sync option '{arg}'. Use --prune, --dry-run or --resource GROUP:KIND:NAME"
+                    );
+                    return AppCommand::None;
+                }
+            }
+        }
+
+        if !dry_run && !self.ensure_write_allowed("argocd sync") {
+            return AppCommand::None;
+        }
+        let Some(app_name) = self.resolve_argocd_app_target(explicit_app) else {
+            self.status = "No Argo CD app selected for sync".to_string();
+            return AppCommand::None;
+        };
+        self.argocd_selected_app = Some(app_name.clone());
+
+        let mut options = Vec::new();
+        if let Some(resource) = resource.as_deref() {
+            options.push(format!("resource {resource}"));
+        }
+        if prune {
+            options.push("prune".to_string());
+        }
+        if dry_run {
+            options.push("dry-run".to_string());
+        }
+        let summary = if options.is_empty() {
+            app_name.clone()
+        } else {
+            format!("{app_name} ({})", options.join(", "))
+        };
+        let command = AppCommand::InspectOps {
+            target: OpsInspectTarget::ArgoCdSync {
+                name: app_name,
+                prune,
+                dry_run,
+                resource,
+            },
+        };
+        if prune && !dry_run {
+            return self.request_confirmation(
+                "argocd sync",
+                format!("Sync Argo CD app {summary}; pruning deletes resources missing from git"),
+                command,
+            );
+        }
+        self.status = format!("Argo CD sync {summary}");
+        command
+    }
+
     fn prepare_argocd_action<F>(
         &mut self,
         explicit_app: Option<&str>,
@@ -3909,6 +6621,113 @@ impl App {
         self.switch_to_tab(ResourceTab::CustomResources)
     }
 
+    fn step_log_window(&mut self, older: bool) -> AppCommand {
+        let Some(mut command) = self
+            .log_request
+            .clone()
+            .filter(|_| self.log_overlay_active())
+        else {
+            self.status = "Log windows are available while viewing logs".to_string();
+            return AppCommand::None;
+        };
+        let (AppCommand::LoadPodLogs { window, .. } | AppCommand::LoadResourceLogs { window, .. }) =
+            &mut command
+        else {
+            return AppCommand::None;
+        };
+
+        let next = match (older, *window) {
+            (true, None) => Some(LogWindow {
+                width_secs: self.log_window_secs,
+                offset: 0,
+            }),
+            (true, Some(current)) => Some(LogWindow {
+                offset: current.offset.saturating_add(1),
+                ..current
+            }),
+            (false, None) => {
+                self.status = "Already showing the latest logs".to_string();
+                return AppCommand::None;
+            }
+            (false, Some(current)) if current.offset == 0 => None,
+            (false, Some(current)) => Some(LogWindow {
+                offset: current.offset - 1,
+                ..current
+            }),
+        };
+        *window = next;
+        self.status = match next {
+            Some(next) => format!("Fetching logs from {}", next.label()),
+            None => format!(
+                "Fetching latest logs (tail {})",
+                log_tail_label(self.log_tail)
+            ),
+        };
+        command
+    }
+
+    fn handle_log_window_command(&mut self, value: Option<&str>) {
+        match value.map(str::trim).filter(|value| !value.is_empty()) {
+            None => {
+                self.status = format!(
+                    "Log window is {} ([ older, ] newer in the logs view)",
+                    format_window_duration(self.log_window_secs)
+                );
+            }
+            Some(raw) => match parse_window_secs(raw) {
+                Some(seconds) => {
+                    self.log_window_secs = seconds;
+                    self.status = format!("Log window set to {}", format_window_duration(seconds));
+                }
+                None => {
+                    self.status = "Usage: :log-window <duration> (e.g. 90s, 15m, 2h)".to_string();
+                }
+            },
+        }
+    }
+
+    fn handle_log_tail_command(&mut self, value: Option<&str>) {
+        match value.map(str::trim).filter(|value| !value.is_empty()) {
+            None => {
+                self.status = format!(
+                    "Log tail is {} (use :tail <lines>, 0 = all)",
+                    log_tail_label(self.log_tail)
+                );
+            }
+            Some(raw) => match raw.parse::<usize>() {
+                Ok(tail) => {
+                    self.log_tail = tail;
+                    self.status = format!("Log tail set to {}", log_tail_label(tail));
+                }
+                Err(_) => {
+                    self.status = "Usage: :tail <lines> (0 = all)".to_string();
+                }
+            },
+        }
+    }
+
+    fn handle_metadata_filter_command(&mut self, kind: Option<&str>, expr: &str) {
+        let prefix = match kind.map(str::to_ascii_lowercase).as_deref() {
+            Some("label" | "labels" | "l") => "label",
+            Some("annotation" | "annotations" | "anno" | "a") => "annotation",
+            _ => {
+                self.status = "Usage: :has label|annotation <key>[=<value>]".to_string();
+                return;
+            }
+        };
+        let expr = expr.trim();
+        if expr.is_empty() || expr.starts_with('=') {
+            self.status = format!("Usage: :has {prefix} <key>[=<value>]");
+            return;
+        }
+
+        self.filter = format!("{prefix}:{expr}");
+        self.clamp_all_selections();
+        self.clear_detail_overlay();
+        self.clear_table_overlay();
+        self.status = format!("Filter: '{}'", self.filter);
+    }
+
     fn handle_read_only_command(&mut self, value: Option<&str>) {
         match value.map(str::trim).filter(|value| !value.is_empty()) {
             None => {
@@ -3941,2689 +6760,8465 @@ impl App {
         }
     }
 
-    fn ensure_write_allowed(&mut self, action: &str) -> bool {
-        if self.read_only {
-            self.status = format!("Read-only mode ON: '{action}' is blocked");
-            false
-        } else {
-            true
+    pub fn allow_command(&mut self, command: &AppCommand) -> bool {
+        let Some(action) = command.mutating_action() else {
+            return true;
+        };
+        if !self.ensure_write_allowed(&action) {
+            return false;
+        }
+        if self
+            .typed_confirmed
+            .take()
+            .is_some_and(|confirmed| &confirmed == command)
+        {
+            return true;
+        }
+        match self.dangerous_rule_for(command) {
+            Some(rule) => {
+                let prompt = format!("Run {action}");
+                self.request_typed_confirmation(
+                    prompt,
+                    command.clone(),
+                    format!("Dangerous command ({rule})"),
+                    Vec::new(),
+                );
+                false
+            }
+            None => true,
         }
     }
 
-    fn prepare_delete_confirmation(&mut self) -> AppCommand {
-        if !self.ensure_write_allowed("delete") {
-            return AppCommand::None;
-        }
+    fn dangerous_rule_for(&self, command: &AppCommand) -> Option<String> {
+        self.dangerous_match_for(command)
+            .map(|matched| matched.rule)
+    }
 
-        let tab = self.active_tab();
-        if matches!(
-            tab,
-            ResourceTab::Events
-                | ResourceTab::CustomResources
-                | ResourceTab::ArgoCdApps
-                | ResourceTab::ArgoCdResources
-                | ResourceTab::ArgoCdProjects
-                | ResourceTab::ArgoCdRepos
-                | ResourceTab::ArgoCdClusters
-                | ResourceTab::ArgoCdAccounts
-                | ResourceTab::ArgoCdCerts
-                | ResourceTab::ArgoCdGpgKeys
-        ) {
-            self.status = format!("Delete is not supported for {}", tab.title());
-            return AppCommand::None;
+    /// Finds the first dangerous rule the command trips. Bulk commands carry no single
+    /// name, so each marked row is checked on its own and the matching rows are returned.
+    fn dangerous_match_for(&self, command: &AppCommand) -> Option<DangerousMatch> {
+        let action = command.mutating_action()?;
+        let target = CommandTarget::of(command);
+        let rows = match command {
+            AppCommand::BulkDelete { targets, .. } => targets.clone(),
+            AppCommand::BulkRestart { targets, .. } => targets
+                .iter()
+                .map(|(namespace, name)| (Some(namespace.clone()), name.clone()))
+                .collect(),
+            _ => {
+                let labels = self.command_target_labels(&target);
+                return self
+                    .dangerous_commands
+                    .iter()
+                    .find(|rule| rule.matches(&action, &target, &self.context, labels))
+                    .map(|rule| DangerousMatch {
+                        rule: rule.describe(),
+                        rows: Vec::new(),
+                    });
+            }
+        };
+
+        let mut matched_rule = None;
+        let mut matched_rows = Vec::new();
+        for (namespace, name) in &rows {
+            let row_target = CommandTarget {
+                tab: target.tab,
+                kinds: target.kinds.clone(),
+                namespace: namespace.as_deref(),
+                name: Some(name),
+                replicas: target.replicas,
+            };
+            let labels = self.command_target_labels(&row_target);
+            if let Some(rule) = self
+                .dangerous_commands
+                .iter()
+                .find(|rule| rule.matches(&action, &row_target, &self.context, labels))
+            {
+                matched_rule.get_or_insert_with(|| rule.describe());
+                matched_rows.push((namespace.clone(), name.clone()));
+            }
         }
+        matched_rule.map(|rule| DangerousMatch {
+            rule,
+            rows: matched_rows,
+        })
+    }
 
-        let Some(row) = self.active_selected_row() else {
-            self.status = "No selected resource to delete".to_string();
-            return AppCommand::None;
+    fn command_target_labels(
+        &self,
+        target: &CommandTarget<'_>,
+    ) -> Option<&BTreeMap<String, String>> {
+        let tab = if target.kinds.is_empty() {
+            target.tab?
+        } else {
+            ResourceTab::CustomResources
         };
+        let name = target.name?;
+        self.tables
+            .get(&tab)?
+            .rows
+            .iter()
+            .find(|row| row.name == name && row.namespace.as_deref() == target.namespace)
+            .map(|row| &row.labels)
+    }
 
-        let namespace = match tab {
-            ResourceTab::Nodes
-            | ResourceTab::Namespaces
-            | ResourceTab::IngressClasses
-            | ResourceTab::StorageClasses
-            | ResourceTab::PersistentVolumes
-            | ResourceTab::ClusterRoles
-            | ResourceTab::ClusterRoleBindings => None,
-            _ => row.namespace.clone(),
-        };
-        let name = row.name.clone();
-        let prompt = match &namespace {
-            Some(ns) => format!("Delete {} {}/{}", tab.title(), ns, name),
-            None => format!("Delete {} {}", tab.title(), name),
+    fn request_typed_confirmation(
+        &mut self,
+        prompt: String,
+        command: AppCommand,
+        reason: String,
+        details: Vec<String>,
+    ) {
+        let expected = CommandTarget::of(&command)
+            .name
+            .map(str::to_string)
+            .or_else(|| command.mutating_action())
+            .unwrap_or_default();
+        self.status = format!("{reason}: type '{expected}' to confirm");
+        self.pending_confirmation = Some(PendingConfirmation {
+            prompt,
+            command,
+            typed_name: Some(expected),
+            typed: String::new(),
+            details,
+        });
+    }
+
+    fn cycle_delete_propagation(&mut self) {
+        let Some(pending) = self.pending_confirmation.as_mut() else {
+            return;
+        };
+        let (AppCommand::DeleteSelected { propagation, .. }
+        | AppCommand::DeleteCustomResource { propagation, .. }
+        | AppCommand::BulkDelete { propagation, .. }) = &mut pending.command
+        else {
+            return;
         };
+        *propagation = propagation.next();
+        let label = propagation.label();
+        let line = delete_propagation_line(&pending.command);
+        if let Some(existing) = pending
+            .details
+            .iter_mut()
+            .find(|detail| detail.starts_with("Cascade:"))
+        {
+            *existing = line;
+        }
+        self.status = format!("Delete propagation policy: {label}");
+    }
 
-        self.pending_confirmation = Some(PendingConfirmation {
-            prompt: prompt.clone(),
-            command: AppCommand::DeleteSelected {
-                tab,
-                namespace,
-                name,
+    fn request_delete_confirmation(&mut self, kind: &str, command: AppCommand) -> AppCommand {
+        let target = CommandTarget::of(&command);
+        let namespace = target.namespace.map(str::to_string);
+        let namespace = namespace.as_deref();
+        let name = target.name.unwrap_or_default().to_string();
+        let force = matches!(command, AppCommand::ForceDeletePod { .. });
+        let verb = if force { "Force delete" } else { "Delete" };
+        let prompt = match namespace {
+            Some(ns) => format!("{verb} {kind} {ns}/{name}"),
+            None => format!("{verb} {kind} {name}"),
+        };
+        let mut details = vec![
+            format!("Kind:      {kind}"),
+            match namespace {
+                Some(ns) => format!("Namespace: {ns}"),
+                None => "Scope:     cluster-wide".to_string(),
             },
-        });
-        self.status = format!("{prompt}? (y/n)");
+            format!("Name:      {name}"),
+            format!("Context:   {}", self.context),
+            delete_propagation_line(&command),
+        ];
+        if matches!(
+            command,
+            AppCommand::DeleteSelected {
+                tab: ResourceTab::Namespaces,
+                ..
+            }
+        ) {
+            details.push(String::new());
+            details.push(format!(
+                "WARNING: deleting namespace '{name}' deletes every resource inside it."
+            ));
+        }
+        if force {
+            details.push(String::new());
+            details.push(
+                "WARNING: force delete skips graceful termination; the pod is removed from the API \
+                 even if its containers are still running on the node."
+                    .to_string(),
+            );
+        }
+
+        if let Some(rule) = self.dangerous_rule_for(&command) {
+            self.request_typed_confirmation(
+                prompt,
+                command,
+                format!("Dangerous command ({rule})"),
+                details,
+            );
+        } else if namespace.is_none() {
+            self.request_typed_confirmation(
+                prompt,
+                command,
+                "Cluster-scoped delete".to_string(),
+                details,
+            );
+        } else {
+            self.status = format!("{prompt}? (y/n)");
+            self.pending_confirmation = Some(PendingConfirmation {
+                prompt,
+                command,
+                typed_name: None,
+                typed: String::new(),
+                details,
+            });
+        }
         AppCommand::None
     }
 
-    fn prepare_restart_confirmation(&mut self) -> AppCommand {
-        if !self.ensure_write_allowed("restart") {
+    fn apply_typed_confirmation_action(
+        &mut self,
+        mut pending: PendingConfirmation,
+        action: Action,
+    ) -> AppCommand {
+        let expected = pending.typed_name.clone().unwrap_or_default();
+        match action {
+            Action::InputChar(c) => pending.typed.push(c),
+            Action::Backspace => {
+                pending.typed.pop();
+            }
+            Action::SubmitInput if pending.typed.trim() == expected => {
+                self.status = format!("Confirmed: {}", pending.prompt);
+                self.typed_confirmed = Some(pending.command.clone());
+                return pending.command;
+            }
+            Action::SubmitInput => {
+                self.status = format!("Typed name does not match '{expected}'");
+                pending.typed.clear();
+            }
+            Action::CompleteInput => {
+                self.pending_confirmation = Some(pending);
+                self.cycle_delete_propagation();
+                return AppCommand::None;
+            }
+            Action::CancelInput | Action::ClearDetailOverlay => {
+                self.status = "Action cancelled".to_string();
+                return AppCommand::None;
+            }
+            _ => {}
+        }
+        self.pending_confirmation = Some(pending);
+        AppCommand::None
+    }
+
+    fn ensure_write_allowed(&mut self, action: &str) -> bool {
+        if self.read_only {
+            self.status = format!("Read-only mode ON: '{action}' is blocked");
+            false
+        } else {
+            true
+        }
+    }
+
+    fn prepare_delete_confirmation(&mut self, propagation: DeletePropagation) -> AppCommand {
+        if !self.ensure_write_allowed("delete") {
             return AppCommand::None;
         }
 
         let tab = self.active_tab();
-        if !matches!(tab, ResourceTab::Deployments | ResourceTab::StatefulSets) {
-            self.status = "Restart is available only for Deployments and StatefulSets".to_string();
+        if matches!(
+            tab,
+            ResourceTab::Events
+                | ResourceTab::ArgoCdApps
+                | ResourceTab::ArgoCdResources
+                | ResourceTab::ArgoCdProjects
+                | ResourceTab::ArgoCdRepos
+                | ResourceTab::ArgoCdClusters
+                | ResourceTab::ArgoCdAccounts
+                | ResourceTab::ArgoCdCerts
+                | ResourceTab::ArgoCdGpgKeys
+        ) {
+            self.status = format!("Delete is not supported for {}", tab.title());
             return AppCommand::None;
         }
+        if self.marked_count() > 0 {
+            return self.prepare_bulk_delete(tab, propagation);
+        }
+        if tab == ResourceTab::CustomResources {
+            return self.prepare_custom_resource_delete(propagation);
+        }
 
         let Some(row) = self.active_selected_row() else {
-            self.status = "No selected workload".to_string();
+            self.status = "No selected resource to delete".to_string();
             return AppCommand::None;
         };
 
-        let Some(namespace) = row.namespace.clone() else {
-            self.status = "Selected workload has no namespace".to_string();
-            return AppCommand::None;
+        let namespace = match tab {
+            ResourceTab::Nodes
+            | ResourceTab::Namespaces
+            | ResourceTab::IngressClasses
+            | ResourceTab::StorageClasses
+            | ResourceTab::PersistentVolumes
+            | ResourceTab::ClusterRoles
+            | ResourceTab::ClusterRoleBindings
+            | ResourceTab::PriorityClasses
+            | ResourceTab::MutatingWebhooks
+            | ResourceTab::ValidatingWebhooks => None,
+            _ => row.namespace.clone(),
         };
         let name = row.name.clone();
-        let prompt = format!("Restart {} {}/{}", tab.title(), namespace, name);
-        self.pending_confirmation = Some(PendingConfirmation {
-            prompt: prompt.clone(),
-            command: AppCommand::RestartWorkload {
+        self.request_delete_confirmation(
+            tab.title(),
+            AppCommand::DeleteSelected {
                 tab,
                 namespace,
                 name,
+                propagation,
             },
-        });
-        self.status = format!("{prompt}? (y/n)");
+        )
+    }
+
+    fn prepare_bulk_delete(
+        &mut self,
+        tab: ResourceTab,
+        propagation: DeletePropagation,
+    ) -> AppCommand {
+        let custom = if tab == ResourceTab::CustomResources {
+            let Some(custom) = self.selected_custom_resource().cloned() else {
+                self.status = "No CRD selected (:crd <name>)".to_string();
+                return AppCommand::None;
+            };
+            Some(custom)
+        } else {
+            None
+        };
+        let kind = custom
+            .as_ref()
+            .map_or_else(|| tab.title().to_string(), |custom| custom.kind.clone());
+        let targets = self
+            .active_marks()
+            .map(|marks| marks.iter().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+        let cluster_scoped = targets.iter().all(|(namespace, _)| namespace.is_none());
+        let prompt = format!("Delete {} {kind}", targets.len());
+        let mut details = vec![
+            format!("Kind:      {kind}"),
+            format!("Count:     {}", targets.len()),
+            format!("Context:   {}", self.context),
+            String::new(),
+        ];
+        details.extend(bulk_target_lines(&targets));
+        let command = AppCommand::BulkDelete {
+            tab,
+            custom,
+            targets,
+            propagation,
+        };
+        details.insert(3, delete_propagation_line(&command));
+        if tab == ResourceTab::Namespaces {
+            details.push(String::new());
+            details.push(
+                "WARNING: deleting these namespaces deletes every resource inside them."
+                    .to_string(),
+            );
+        }
+
+        if let Some(matched) = self.dangerous_match_for(&command) {
+            details.extend(matched.row_lines());
+            self.request_typed_confirmation(
+                prompt,
+                command,
+                format!("Dangerous command ({})", matched.rule),
+                details,
+            );
+        } else if cluster_scoped {
+            self.request_typed_confirmation(
+                prompt,
+                command,
+                "Cluster-scoped bulk delete".to_string(),
+                details,
+            );
+        } else {
+            self.status = format!("{prompt}? (y/n)");
+            self.pending_confirmation = Some(PendingConfirmation {
+                prompt,
+                command,
+                typed_name: None,
+                typed: String::new(),
+                details,
+            });
+        }
         AppCommand::None
     }
 
-    fn prepare_scale_command(&mut self, replicas: i32) -> AppCommand {
-        if !self.ensure_write_allowed("scale") {
-            return AppCommand::None;
+    fn prepare_bulk_restart(&mut self, tab: ResourceTab) -> AppCommand {
+        let targets = self
+            .active_marks()
+            .map(|marks| {
+                marks
+                    .iter()
+                    .filter_map(|(namespace, name)| Some((namespace.clone()?, name.clone())))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let prompt = format!("Restart {} {}", targets.len(), tab.title());
+        let mut details = vec![
+            format!("Kind:      {}", tab.title()),
+            format!("Count:     {}", targets.len()),
+            format!("Context:   {}", self.context),
+            String::new(),
+        ];
+        details.extend(bulk_target_lines(
+            &targets
+                .iter()
+                .map(|(namespace, name)| (Some(namespace.clone()), name.clone()))
+                .collect::<Vec<_>>(),
+        ));
+        let command = AppCommand::BulkRestart { tab, targets };
+        if let Some(matched) = self.dangerous_match_for(&command) {
+            details.extend(matched.row_lines());
         }
+        let command = self.request_confirmation("restart", prompt, command);
+        if let Some(pending) = self.pending_confirmation.as_mut()
+            && pending.details.is_empty()
+        {
+            pending.details = details;
+        }
+        command
+    }
 
-        if replicas < 0 {
-            self.status = "Replicas must be >= 0".to_string();
+    fn prepare_force_delete_pod(&mut self) -> AppCommand {
+        if !self.ensure_write_allowed("force delete") {
             return AppCommand::None;
         }
-
-        let tab = self.active_tab();
-        if !matches!(tab, ResourceTab::Deployments | ResourceTab::StatefulSets) {
-            self.status = "Scale is available only for Deployments and StatefulSets".to_string();
+        if self.active_tab() != ResourceTab::Pods {
+            self.status = "Force delete is available only on the Pods tab".to_string();
             return AppCommand::None;
         }
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected pod to force delete".to_string();
+            return AppCommand::None;
+        };
+        let Some(namespace) = row.namespace.clone() else {
+            self.status = "Selected pod has no namespace".to_string();
+            return AppCommand::None;
+        };
+        let name = row.name.clone();
+        self.request_delete_confirmation(
+            ResourceTab::Pods.title(),
+            AppCommand::ForceDeletePod { namespace, name },
+        )
+    }
 
+    fn prepare_custom_resource_delete(&mut self, propagation: DeletePropagation) -> AppCommand {
+        let Some(custom) = self.selected_custom_resource().cloned() else {
+            self.status = "No CRD selected (:crd <name>)".to_string();
+            return AppCommand::None;
+        };
         let Some(row) = self.active_selected_row() else {
-            self.status = "No selected workload".to_string();
+            self.status = "No selected resource to delete".to_string();
             return AppCommand::None;
         };
 
-        let Some(namespace) = row.namespace.clone() else {
-            self.status = "Selected workload has no namespace".to_string();
+        let namespace = if custom.namespaced {
+            row.namespace.clone()
+        } else {
+            None
+        };
+        let name = row.name.clone();
+        let kind = custom.kind.clone();
+        self.request_delete_confirmation(
+            &kind,
+            AppCommand::DeleteCustomResource {
+                custom,
+                namespace,
+                name,
+                propagation,
+            },
+        )
+    }
+
+    fn prepare_resource_events(&mut self) -> AppCommand {
+        let tab = self.active_tab();
+        let kind = match tab {
+            ResourceTab::CustomResources => {
+                self.selected_custom_resource().map(|crd| crd.kind.clone())
+            }
+            ResourceTab::Events => None,
+            _ => tab.kind().map(str::to_string),
+        };
+        let Some(kind) = kind else {
+            self.status = format!("Object events are not available for {}", tab.title());
+            return AppCommand::None;
+        };
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected resource for events".to_string();
             return AppCommand::None;
         };
+        let namespace = row.namespace.clone();
         let name = row.name.clone();
-        self.status = format!(
-            "Scaling {} {}/{} to {} replicas",
-            tab.title(),
+        self.status = format!("Loading events for {kind} {name}...");
+        AppCommand::ShowResourceEvents {
+            kind,
             namespace,
             name,
-            replicas
-        );
-        AppCommand::ScaleWorkload {
-            tab,
-            namespace,
-            name,
-            replicas,
         }
     }
 
-    fn prepare_exec_command(&mut self, command: Vec<String>) -> AppCommand {
-        if !self.ensure_write_allowed("exec") {
-            return AppCommand::None;
+    fn top_command(&mut self, args: Vec<&str>) -> AppCommand {
+        for arg in args {
+            if let Some(sort) = UsageSort::from_token(arg) {
+                self.top_sort = sort;
+            } else if arg == "flip" {
+                self.top_sort = self.top_sort.flip();
+            } else if let Ok(limit) = arg.parse::<usize>()
+                && limit > 0
+            {
+                self.top_limit = limit;
+            } else {
+                self.status = "Usage: :top [cpu|mem|flip] [N]".to_string();
+                return AppCommand::None;
+            }
         }
-
-        if self.active_tab() != ResourceTab::Pods {
-            self.status = "Exec is only available in the Pods tab".to_string();
+        if self.metrics_unavailable {
+            self.status = "Pod usage unavailable: metrics API is not served".to_string();
             return AppCommand::None;
         }
-
-        if command.is_empty() {
-            self.status = "Usage: :exec <command...>".to_string();
+        if self.overview_metrics.pod_usage.is_empty() {
+            self.status = "No pod usage sampled yet; wait for the next metrics refresh".to_string();
             return AppCommand::None;
         }
+        AppCommand::ShowTopPods {
+            sort: self.top_sort,
+            limit: self.top_limit,
+        }
+    }
 
+    fn copy_selection(&mut self) -> AppCommand {
+        if let Some(text) = self.detail_overlay.clone() {
+            let label = self
+                .detail_overlay_title
+                .clone()
+                .unwrap_or_else(|| "detail".to_string());
+            return AppCommand::CopyToClipboard { label, text };
+        }
         let Some(row) = self.active_selected_row() else {
-            self.status = "No selected pod".to_string();
-            return AppCommand::None;
-        };
-        let Some(namespace) = row.namespace.clone() else {
-            self.status = "Selected pod has no namespace".to_string();
+            self.status = "No selected resource to copy".to_string();
             return AppCommand::None;
         };
-        let pod_name = row.name.clone();
-        self.status = format!("Executing in {namespace}/{pod_name}: {}", command.join(" "));
-        AppCommand::ExecInPod {
-            namespace,
-            pod_name,
-            command,
+        if self.detail_mode == DetailPaneMode::Details && !row.detail.is_empty() {
+            return AppCommand::CopyToClipboard {
+                label: format!("{} YAML", row.name),
+                text: row.detail.clone(),
+            };
+        }
+        AppCommand::CopyToClipboard {
+            label: "name".to_string(),
+            text: row.name.clone(),
         }
     }
 
-    fn prepare_shell_command(&mut self, container: Option<String>, shell: String) -> AppCommand {
-        if !self.ensure_write_allowed("shell") {
+    fn open_reference_menu(&mut self, format: Option<&str>) -> AppCommand {
+        let tab = self.active_tab();
+        let Some(row) = self.active_selected_row().cloned() else {
+            self.status = "No selected resource to reference".to_string();
+            return AppCommand::None;
+        };
+        let kubectl_resource = self.kubectl_resource_for_tab(tab);
+        let entries = resource_reference_entries(&row, kubectl_resource);
+        if entries.is_empty() {
+            self.status = format!("No reference formats for {}", tab.title());
             return AppCommand::None;
         }
 
-        let (namespace, pod_name) = if self.active_tab() == ResourceTab::Pods {
-            let Some(row) = self.active_selected_row() else {
-                self.status = "No selected pod".to_string();
+        if let Some(format) = format {
+            let Some(entry) = entries.into_iter().find(|entry| entry.label == format) else {
+                self.status =
+                    format!("Unknown reference format '{format}' (kubectl|selector|argo)");
                 return AppCommand::None;
             };
-            let Some(namespace) = row.namespace.clone() else {
-                self.status = "Selected pod has no namespace".to_string();
-                return AppCommand::None;
+            return AppCommand::CopyToClipboard {
+                label: entry.label,
+                text: entry.value,
             };
-            (namespace, row.name.clone())
-        } else if let Some((namespace, pod_name)) = self.selected_argocd_pod_target() {
-            (namespace, pod_name)
-        } else {
-            self.status = "Shell access is available from Pods or Argo Pod nodes".to_string();
-            return AppCommand::None;
-        };
-        self.status = match container.as_deref() {
-            Some(container) => format!(
-                "Opening shell in {namespace}/{pod_name} (container: {container}, shell: {shell})"
-            ),
-            None => format!("Opening shell in {namespace}/{pod_name} ({shell})"),
-        };
-        AppCommand::OpenPodShell {
-            namespace,
-            pod_name,
-            container,
-            shell,
         }
+
+        self.status = "Copy reference: j/k select, Enter or 1-9 copy, Esc cancel".to_string();
+        self.reference_menu = Some(ReferenceMenu {
+            title: format!("Copy reference: {}", row.name),
+            entries,
+            selected: 0,
+        });
+        AppCommand::None
     }
 
-    fn selected_argocd_pod_target(&self) -> Option<(String, String)> {
-        let target = self.selected_argocd_resource_target()?;
-        if !target.kind.eq_ignore_ascii_case("pod") {
-            return None;
+    fn open_namespace_picker(&mut self) -> AppCommand {
+        self.namespace_picker = Some(self.namespace_scope.clone());
+        self.status = "Pick namespace: j/k move, Enter apply, Esc cancel".to_string();
+        let loaded = self
+            .tables
+            .get(&ResourceTab::Namespaces)
+            .is_some_and(|table| table.last_refreshed.is_some());
+        if loaded {
+            AppCommand::None
+        } else {
+            AppCommand::RefreshTab {
+                tab: ResourceTab::Namespaces,
+            }
         }
-        let namespace = target.namespace?;
-        Some((namespace, target.name))
     }
 
-    fn selected_argocd_resource_target(&self) -> Option<ArgoResourceTarget> {
-        if self.active_tab() != ResourceTab::ArgoCdResources {
-            return None;
-        }
-        let row = self.active_selected_row()?;
-        let (kind, fallback_name) = row.name.split_once('/')?;
-        let kind = kind.trim();
-        if kind.is_empty() {
-            return None;
-        }
-        let name = row
-            .columns
-            .get(2)
-            .map(|value| value.trim().to_string())
-            .filter(|value| !value.is_empty() && value != "-")
-            .unwrap_or_else(|| fallback_name.trim().to_string());
-        if name.is_empty() {
-            return None;
-        }
-        let namespace = row
-            .namespace
-            .clone()
-            .or_else(|| row.columns.get(1).cloned())
-            .map(|value| value.trim().to_string())
-            .filter(|value| !value.is_empty() && value != "-");
-        Some(ArgoResourceTarget {
-            kind: kind.to_string(),
-            namespace,
-            name,
-        })
+    fn apply_terraform_state_action(&mut self, action: Action) -> AppCommand {
+        let Some(picker) = self.terraform_state.as_mut() else {
+            return AppCommand::None;
+        };
+        let last = picker.addresses.len().saturating_sub(1);
+        let page = 10;
+        picker.selected = match action {
+            Action::Up => picker.selected.saturating_sub(1),
+            Action::Down => (picker.selected + 1).min(last),
+            Action::PageUp => picker.selected.saturating_sub(page),
+            Action::PageDown => (picker.selected + page).min(last),
+            Action::Top => 0,
+            Action::Bottom => last,
+            Action::EnterResource => {
+                picker.open = false;
+                let Some(address) = picker.addresses.get(picker.selected).cloned() else {
+                    return AppCommand::None;
+                };
+                let dir = picker.dir.clone();
+                self.status = format!("Loading terraform state show {address}");
+                return AppCommand::InspectOps {
+                    target: OpsInspectTarget::TerraformStateShow { dir, address },
+                };
+            }
+            Action::ClearDetailOverlay | Action::CancelInput => {
+                picker.open = false;
+                self.status = "Closed terraform state list".to_string();
+                return AppCommand::None;
+            }
+            _ => picker.selected,
+        };
+        AppCommand::None
     }
 
-    fn prepare_edit_command(&mut self) -> AppCommand {
-        if !self.ensure_write_allowed("edit") {
-            return AppCommand::None;
+    fn apply_namespace_picker_action(&mut self, action: Action) -> AppCommand {
+        let entries = self.namespace_picker_entries();
+        let last = entries.len().saturating_sub(1);
+        let selected = self.namespace_picker_selected();
+        let page = 10;
+        let next = match action {
+            Action::Up => selected.saturating_sub(1),
+            Action::Down => (selected + 1).min(last),
+            Action::PageUp => selected.saturating_sub(page),
+            Action::PageDown => (selected + page).min(last),
+            Action::Top => 0,
+            Action::Bottom => last,
+            Action::EnterResource | Action::ConfirmYes => {
+                self.namespace_picker = None;
+                return self.apply_picked_namespace(entries.into_iter().nth(selected));
+            }
+            Action::ClearDetailOverlay | Action::CancelInput | Action::ConfirmNo => {
+                self.namespace_picker = None;
+                self.status = "Namespace change cancelled".to_string();
+                return AppCommand::None;
+            }
+            _ => selected,
+        };
+        self.namespace_picker = entries.into_iter().nth(next);
+        AppCommand::None
+    }
+
+    fn apply_picked_namespace(&mut self, scope: Option<NamespaceScope>) -> AppCommand {
+        match scope {
+            Some(NamespaceScope::All) => {
+                self.set_all_namespaces();
+                self.status = "Namespace scope set to all".to_string();
+            }
+            Some(NamespaceScope::Named(namespace)) => {
+                self.status = format!("Namespace scope set to '{namespace}'");
+                self.namespace_scope = NamespaceScope::Named(namespace);
+            }
+            None => return AppCommand::None,
         }
+        self.clamp_all_selections();
+        AppCommand::RefreshAll
+    }
 
-        let tab = self.active_tab();
-        let Some((resource, namespaced)) = self.kubectl_resource_for_tab(tab) else {
-            self.status = format!("Edit is not supported for {}", tab.title());
+    fn apply_reference_menu_action(&mut self, action: Action) -> AppCommand {
+        let Some(menu) = self.reference_menu.as_mut() else {
             return AppCommand::None;
         };
+        let chosen = match action {
+            Action::Up => {
+                menu.selected = menu.selected.saturating_sub(1);
+                None
+            }
+            Action::Down => {
+                menu.selected = (menu.selected + 1).min(menu.entries.len().saturating_sub(1));
+                None
+            }
+            Action::EnterResource | Action::ConfirmYes => Some(menu.selected),
+            Action::SwitchView(slot) if slot > 0 && (slot as usize) <= menu.entries.len() => {
+                Some(slot as usize - 1)
+            }
+            Action::ClearDetailOverlay | Action::CancelInput | Action::ConfirmNo => {
+                self.reference_menu = None;
+                self.status = "Copy reference cancelled".to_string();
+                return AppCommand::None;
+            }
+            _ => None,
+        };
 
-        let Some(row) = self.active_selected_row() else {
-            self.status = "No selected resource".to_string();
+        let Some(index) = chosen else {
             return AppCommand::None;
         };
-
-        let name = row.name.clone();
-        let namespace = if namespaced {
-            row.namespace
-                .clone()
-                .or_else(|| match self.namespace_scope() {
-                    NamespaceScope::Named(namespace) => Some(namespace.clone()),
-                    NamespaceScope::All => None,
-                })
-        } else {
-            None
+        let Some(menu) = self.reference_menu.take() else {
+            return AppCommand::None;
         };
-
-        if namespaced && namespace.is_none() {
-            self.status = "Selected resource has no namespace".to_string();
+        let Some(entry) = menu.entries.into_iter().nth(index) else {
             return AppCommand::None;
-        }
-
-        self.status = match namespace.as_deref() {
-            Some(namespace) => format!("Editing {resource} {namespace}/{name}"),
-            None => format!("Editing {resource} {name}"),
         };
-
-        AppCommand::EditSelected {
-            resource,
-            namespace,
-            name,
+        AppCommand::CopyToClipboard {
+            label: entry.label,
+            text: entry.value,
         }
     }
 
-    fn prepare_xray_command(&mut self, raw_target: Option<&str>) -> AppCommand {
+    fn set_selector_command(&mut self, kind: SelectorKind, selector: &str) -> AppCommand {
         let tab = self.active_tab();
-        if !supports_xray(tab) {
-            self.status = format!("Xray is not supported for {}", tab.title());
+        if matches!(
+            tab,
+            ResourceTab::Orca
+                | ResourceTab::ArgoCdApps
+                | ResourceTab::ArgoCdResources
+                | ResourceTab::ArgoCdProjects
+                | ResourceTab::ArgoCdRepos
+                | ResourceTab::ArgoCdClusters
+                | ResourceTab::ArgoCdAccounts
+                | ResourceTab::ArgoCdCerts
+                | ResourceTab::ArgoCdGpgKeys
+        ) {
+            self.status = format!(
+                "{} selectors are not supported for {}",
+                kind.title(),
+                tab.title()
+            );
             return AppCommand::None;
         }
 
-        let (mut namespace, name) = if let Some(raw_target) = raw_target {
-            if let Some((target_namespace, target_name)) = parse_namespaced_target(raw_target) {
-                (Some(target_namespace.to_string()), target_name)
-            } else {
-                (None, raw_target.trim().to_string())
+        let selector = selector.trim();
+        if tab == ResourceTab::Pods {
+            self.pod_pages = None;
+        }
+        let selectors = match kind {
+            SelectorKind::Label => &mut self.label_selectors,
+            SelectorKind::Field => &mut self.field_selectors,
+        };
+        if selector.is_empty() || selector == "-" {
+            if selectors.remove(&tab).is_none() {
+                self.status = format!("No {} selector set for {}", kind.label(), tab.title());
+                return AppCommand::None;
             }
+            self.status = format!("Cleared {} selector for {}", kind.label(), tab.title());
         } else {
-            let Some(selected) = self.active_selected_row() else {
-                self.status = "No selected resource for xray".to_string();
-                return AppCommand::None;
-            };
-            (selected.namespace.clone(), selected.name.clone())
-        };
-
-        if name.is_empty() {
-            self.status = "Usage: :xray [namespace/name|name]".to_string();
-            return AppCommand::None;
+            selectors.insert(tab, selector.to_string());
+            self.status = format!(
+                "Listing {} with {} selector {selector}",
+                tab.title(),
+                kind.label()
+            );
         }
+        AppCommand::RefreshActive
+    }
 
-        let namespaced = self
-            .kubectl_resource_for_tab(tab)
-            .map(|(_, namespaced)| namespaced)
-            .unwrap_or(matches!(
-                tab,
-                ResourceTab::Pods
-                    | ResourceTab::CronJobs
-                    | ResourceTab::DaemonSets
-                    | ResourceTab::Deployments
-                    | ResourceTab::ReplicaSets
-                    | ResourceTab::ReplicationControllers
-                    | ResourceTab::StatefulSets
-                    | ResourceTab::Jobs
-                    | ResourceTab::Services
-                    | ResourceTab::Events
-            ));
-        if namespaced && namespace.is_none() {
-            namespace = match self.namespace_scope() {
-                NamespaceScope::Named(namespace) => Some(namespace.clone()),
-                NamespaceScope::All => None,
-            };
+    fn drill_into_node_pods(&mut self, node: &str) -> AppCommand {
+        self.push_flow_state();
+        self.set_all_namespaces();
+        self.pod_pages = None;
+        self.field_selectors
+            .insert(ResourceTab::Pods, format!("spec.nodeName={node}"));
+        let command = self.drill_into_pods(None, "", false);
+        self.status = format!("Pods on node {node}");
+        command
+    }
+
+    fn prepare_load_more_command(&mut self) -> AppCommand {
+        if self.active_tab() != ResourceTab::Pods {
+            self.status = "Load more is available only for Pods".to_string();
+            return AppCommand::None;
         }
-        if namespaced && namespace.is_none() {
-            self.status =
-                "Xray needs a namespace target (select a row or use :xray <ns>/<name>)".to_string();
+        if !self.table_has_more_for(ResourceTab::Pods) {
+            self.status = "All pods are loaded".to_string();
             return AppCommand::None;
         }
 
-        self.status = match namespace.as_deref() {
-            Some(namespace) => format!("Building xray for {} {namespace}/{name}", tab.title()),
-            None => format!("Building xray for {} {name}", tab.title()),
-        };
-        AppCommand::InspectXray {
-            tab,
-            namespace,
-            name,
-        }
+        let pages = self.list_pages_for(ResourceTab::Pods) + 1;
+        self.pod_pages = Some((self.context.clone(), self.namespace_scope.clone(), pages));
+        self.status = format!("Loading pods page {pages}");
+        AppCommand::RefreshActive
     }
 
-    fn kubectl_resource_for_tab(&self, tab: ResourceTab) -> Option<(String, bool)> {
-        match tab {
-            ResourceTab::Orca => None,
-            ResourceTab::ArgoCdApps => Some(("applications.argoproj.io".to_string(), true)),
-            ResourceTab::ArgoCdResources
-            | ResourceTab::ArgoCdProjects
-            | ResourceTab::ArgoCdRepos
-            | ResourceTab::ArgoCdClusters
-            | ResourceTab::ArgoCdAccounts
-            | ResourceTab::ArgoCdCerts
-            | ResourceTab::ArgoCdGpgKeys => None,
-            ResourceTab::Pods => Some(("pod".to_string(), true)),
-            ResourceTab::CronJobs => Some(("cronjob".to_string(), true)),
-            ResourceTab::DaemonSets => Some(("daemonset".to_string(), true)),
-            ResourceTab::Deployments => Some(("deployment".to_string(), true)),
-            ResourceTab::ReplicaSets => Some(("replicaset".to_string(), true)),
-            ResourceTab::ReplicationControllers => {
-                Some(("replicationcontroller".to_string(), true))
-            }
-            ResourceTab::StatefulSets => Some(("statefulset".to_string(), true)),
-            ResourceTab::Jobs => Some(("job".to_string(), true)),
-            ResourceTab::Services => Some(("service".to_string(), true)),
-            ResourceTab::Ingresses => Some(("ingress".to_string(), true)),
-            ResourceTab::IngressClasses => Some(("ingressclass".to_string(), false)),
-            ResourceTab::ConfigMaps => Some(("configmap".to_string(), true)),
-            ResourceTab::PersistentVolumeClaims => {
-                Some(("persistentvolumeclaim".to_string(), true))
-            }
-            ResourceTab::Secrets => Some(("secret".to_string(), true)),
-            ResourceTab::StorageClasses => Some(("storageclass".to_string(), false)),
-            ResourceTab::PersistentVolumes => Some(("persistentvolume".to_string(), false)),
-            ResourceTab::ServiceAccounts => Some(("serviceaccount".to_string(), true)),
-            ResourceTab::Roles => Some(("role".to_string(), true)),
-            ResourceTab::RoleBindings => Some(("rolebinding".to_string(), true)),
-            ResourceTab::ClusterRoles => Some(("clusterrole".to_string(), false)),
-            ResourceTab::ClusterRoleBindings => Some(("clusterrolebinding".to_string(), false)),
-            ResourceTab::NetworkPolicies => Some(("networkpolicy".to_string(), true)),
-            ResourceTab::Nodes => Some(("node".to_string(), false)),
-            ResourceTab::Namespaces => Some(("namespace".to_string(), false)),
-            ResourceTab::Events => None,
-            ResourceTab::CustomResources => {
-                let crd = self.selected_custom_resource()?;
-                let resource = if crd.group.is_empty() {
-                    crd.plural.clone()
-                } else {
-                    format!("{}.{}", crd.plural, crd.group)
-                };
-                Some((resource, crd.namespaced))
-            }
+    fn prepare_namespace_export_command(&mut self, args: &[&str]) -> AppCommand {
+        const USAGE: &str = "Usage: :export ns <dir> [--secrets]";
+        let include_secrets = args.contains(&"--secrets");
+        let positional = args
+            .iter()
+            .filter(|arg| !arg.starts_with("--"))
+            .copied()
+            .collect::<Vec<_>>();
+        let [scope, dir] = positional.as_slice() else {
+            self.status = USAGE.to_string();
+            return AppCommand::None;
+        };
+        if !matches!(*scope, "ns" | "namespace") {
+            self.status = USAGE.to_string();
+            return AppCommand::None;
         }
-    }
-
-    fn prepare_port_forward(&mut self, local_port: u16, remote_port: u16) -> AppCommand {
-        if !self.ensure_write_allowed("port-forward") {
+        let NamespaceScope::Named(namespace) = self.namespace_scope.clone() else {
+            self.status = "Export needs a single namespace scope (:ns <name>)".to_string();
             return AppCommand::None;
+        };
+
+        self.status = format!("Exporting namespace {namespace} to {dir}");
+        AppCommand::ExportNamespace {
+            namespace,
+            dir: (*dir).to_string(),
+            include_secrets,
         }
+    }
 
+    fn prepare_image_summary_command(&mut self) -> AppCommand {
         let tab = self.active_tab();
-        if !matches!(tab, ResourceTab::Pods | ResourceTab::Services) {
-            self.status = "Port-forward is available in Pods and Services tabs".to_string();
+        if !matches!(
+            tab,
+            ResourceTab::Pods
+                | ResourceTab::Deployments
+                | ResourceTab::StatefulSets
+                | ResourceTab::DaemonSets
+                | ResourceTab::ReplicaSets
+                | ResourceTab::Jobs
+                | ResourceTab::CronJobs
+        ) {
+            self.status = "Image summary is available for Pods and workloads".to_string();
             return AppCommand::None;
         }
 
         let Some(row) = self.active_selected_row() else {
-            self.status = "No selected target for port-forward".to_string();
+            self.status = "No selected resource".to_string();
             return AppCommand::None;
         };
         let Some(namespace) = row.namespace.clone() else {
-            self.status = "Selected target has no namespace".to_string();
+            self.status = "Selected resource has no namespace".to_string();
             return AppCommand::None;
         };
         let name = row.name.clone();
-        self.status = format!(
-            "Starting port-forward {} {}/{} {}:{}",
-            tab.title(),
+        self.status = format!("Inspecting images for {} {namespace}/{name}", tab.title());
+        AppCommand::InspectImages {
+            tab,
             namespace,
             name,
-            local_port,
-            remote_port
-        );
-        AppCommand::StartPortForward {
-            tab,
-            namespace,
-            name,
-            local_port,
-            remote_port,
         }
     }
 
-    fn create_logs_command(&mut self, previous: bool) -> AppCommand {
-        if self.container_picker_active() {
-            return self.load_selected_container_logs(previous);
+    fn selected_rollout_target(&mut self, what: &str) -> Option<(ResourceTab, String, String)> {
+        let tab = self.active_tab();
+        if !matches!(
+            tab,
+            ResourceTab::Deployments | ResourceTab::StatefulSets | ResourceTab::DaemonSets
+        ) {
+            self.status = format!(
+                "Rollout {what} is available only for Deployments, StatefulSets and DaemonSets"
+            );
+            return None;
         }
 
-        if self.active_tab() == ResourceTab::ArgoCdResources {
-            let Some(target) = self.selected_argocd_resource_target() else {
-                self.status = "No Argo CD resource selected".to_string();
-                return AppCommand::None;
-            };
-
-            if target.kind.eq_ignore_ascii_case("pod") {
-                let Some(namespace) = target.namespace else {
-                    self.status = "Selected Argo Pod has no namespace".to_string();
-                    return AppCommand::None;
-                };
-                let pod_name = target.name;
-                self.status = if previous {
-                    format!("Fetching previous logs for pod '{pod_name}' in '{namespace}'")
-                } else {
-                    format!("Fetching logs for pod '{pod_name}' in '{namespace}'")
-                };
-                return AppCommand::LoadPodLogs {
-                    namespace,
-                    pod_name,
-                    container: None,
-                    previous,
-                };
-            }
-            if let Some(tab) = argocd_logs_tab_for_kind(&target.kind) {
-                self.status = if previous {
-                    format!(
-                        "Resolving previous logs for {} '{}'",
-                        target.kind, target.name
-                    )
-                } else {
-                    format!("Resolving logs for {} '{}'", target.kind, target.name)
-                };
-                return AppCommand::LoadResourceLogs {
-                    tab,
-                    namespace: target.namespace,
-                    name: target.name,
-                    previous,
-                };
-            }
-            self.status = format!("Logs are not available for Argo kind '{}'", target.kind);
-            return AppCommand::None;
-        }
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected workload".to_string();
+            return None;
+        };
+        let Some(namespace) = row.namespace.clone() else {
+            self.status = "Selected workload has no namespace".to_string();
+            return None;
+        };
+        let name = row.name.clone();
+        self.status = format!(
+            "Loading rollout {what} for {} {namespace}/{name}",
+            tab.title()
+        );
+        Some((tab, namespace, name))
+    }
 
-        if self.active_tab() != ResourceTab::Pods {
-            self.status =
-                "Logs are available from Pods (or use Shift+L for workload logs)".to_string();
+    fn prepare_rollout_undo_command(&mut self, raw_revision: Option<&str>) -> AppCommand {
+        if !self.ensure_write_allowed("rollout undo") {
             return AppCommand::None;
         }
-
-        let Some(selected_row) = self.active_selected_row() else {
-            self.status = "No pod selected".to_string();
-            return AppCommand::None;
+        let to_revision = match raw_revision {
+            None => None,
+            Some(raw) => match raw.parse::<u64>() {
+                Ok(revision) if revision > 0 => Some(revision),
+                _ => {
+                    self.status = format!("Invalid revision '{raw}' (see :rollout history)");
+                    return AppCommand::None;
+                }
+            },
         };
-
-        let Some(namespace) =
-            selected_row
-                .namespace
-                .clone()
-                .or_else(|| match self.namespace_scope() {
-                    NamespaceScope::All => None,
-                    NamespaceScope::Named(ns) => Some(ns.clone()),
-                })
-        else {
-            self.status = "Pod namespace is unknown".to_string();
+        let Some((tab, namespace, name)) = self.selected_rollout_target("undo") else {
             return AppCommand::None;
         };
+        let prompt = match to_revision {
+            Some(revision) => format!(
+                "Roll back {} {namespace}/{name} to revision {revision}",
+                tab.title()
+            ),
+            None => format!(
+                "Roll back {} {namespace}/{name} to the previous revision",
+                tab.title()
+            ),
+        };
+        self.request_confirmation(
+            "rollout undo",
+            prompt,
+            AppCommand::RolloutUndo {
+                tab,
+                namespace,
+                name,
+                to_revision,
+            },
+        )
+    }
 
-        let pod_name = selected_row.name.clone();
-        self.status = if previous {
-            format!("Fetching previous logs for pod '{pod_name}' in '{namespace}'")
-        } else {
-            format!("Fetching logs for pod '{pod_name}' in '{namespace}'")
+    fn prepare_rollout_watch_command(&mut self, raw_timeout: Option<&str>) -> AppCommand {
+        let timeout_secs = match raw_timeout {
+            None => DEFAULT_ROLLOUT_WATCH_SECS,
+            Some(raw) => match raw.trim_end_matches('s').parse::<u64>() {
+                Ok(secs) if secs > 0 => secs,
+                _ => {
+                    self.status = format!("Invalid rollout timeout '{raw}'");
+                    return AppCommand::None;
+                }
+            },
         };
 
-        AppCommand::LoadPodLogs {
+        let Some((tab, namespace, name)) = self.selected_rollout_target("watch") else {
+            return AppCommand::None;
+        };
+        self.status = format!(
+            "Watching rollout of {} {}/{} (timeout {}s)",
+            tab.title(),
             namespace,
-            pod_name,
-            container: None,
-            previous,
+            name,
+            timeout_secs
+        );
+        AppCommand::WatchRollout {
+            tab,
+            namespace,
+            name,
+            timeout_secs,
         }
     }
 
-    fn create_related_logs_command(&mut self, previous: bool) -> AppCommand {
-        if self.container_picker_active() {
-            return self.load_selected_container_logs(previous);
+    fn prepare_restart_confirmation(&mut self) -> AppCommand {
+        if !self.ensure_write_allowed("restart") {
+            return AppCommand::None;
         }
 
         let tab = self.active_tab();
-        if tab == ResourceTab::ArgoCdResources {
-            return self.create_logs_command(previous);
+        if !matches!(
+            tab,
+            ResourceTab::Deployments | ResourceTab::StatefulSets | ResourceTab::DaemonSets
+        ) {
+            self.status = "Restart is available only for Deployments, StatefulSets and DaemonSets"
+                .to_string();
+            return AppCommand::None;
         }
-        if tab == ResourceTab::Pods {
-            return self.create_logs_command(previous);
+        if self.marked_count() > 0 {
+            return self.prepare_bulk_restart(tab);
         }
 
-        if !supports_related_logs(tab) {
-            self.status = format!("Shift+L logs are not supported for {}", tab.title());
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected workload".to_string();
             return AppCommand::None;
-        }
+        };
 
-        let Some(row) = self.active_selected_row() else {
-            self.status = "No selected resource".to_string();
+        let Some(namespace) = row.namespace.clone() else {
+            self.status = "Selected workload has no namespace".to_string();
             return AppCommand::None;
         };
         let name = row.name.clone();
-        let namespace = row.namespace.clone();
-        self.status = format!("Resolving related logs for {name}");
-        AppCommand::LoadResourceLogs {
-            tab,
-            namespace,
-            name,
-            previous,
+        let prompt = format!("Restart {} {}/{}", tab.title(), namespace, name);
+        self.request_confirmation(
+            "restart",
+            prompt,
+            AppCommand::RestartWorkload {
+                tab,
+                namespace,
+                name,
+            },
+        )
+    }
+
+    fn prepare_trigger_cronjob(&mut self) -> AppCommand {
+        if self.active_tab() != ResourceTab::CronJobs {
+            self.status = "Run now is available from CronJobs (t or :run-now)".to_string();
+            return AppCommand::None;
         }
+        self.prepare_rerun_confirmation()
     }
 
-    fn load_selected_container_logs(&mut self, previous: bool) -> AppCommand {
-        let Some(picker) = self.container_picker.as_ref() else {
-            self.status = "No container selected".to_string();
+    fn prepare_cronjob_suspend_toggle(&mut self, suspend: Option<bool>) -> AppCommand {
+        if self.active_tab() != ResourceTab::CronJobs {
+            self.status = "Suspend/resume is available from CronJobs (z)".to_string();
+            return AppCommand::None;
+        }
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected CronJobs".to_string();
             return AppCommand::None;
         };
-        if picker.containers.is_empty() {
-            self.status = "No containers available".to_string();
+        let Some(namespace) = row.namespace.clone() else {
+            self.status = "Selected CronJob has no namespace".to_string();
+            return AppCommand::None;
+        };
+        let name = row.name.clone();
+        let suspended = row.columns.get(3).is_some_and(|value| value == "Yes");
+        let suspend = suspend.unwrap_or(!suspended);
+        if suspend == suspended {
+            let state = if suspended { "suspended" } else { "active" };
+            self.status = format!("CronJob {namespace}/{name} is already {state}");
             return AppCommand::None;
         }
-        let selected = picker
-            .selected
-            .min(picker.containers.len().saturating_sub(1));
-        let container = picker.containers[selected].name.clone();
-        self.status = if previous {
-            format!(
-                "Fetching previous logs for {}/{} container '{}'",
-                picker.namespace, picker.pod_name, container
+        let (action, prompt) = if suspend {
+            (
+                "suspend cronjob",
+                format!("Suspend CronJob {namespace}/{name}: no new Jobs will be scheduled"),
             )
         } else {
-            format!(
-                "Fetching logs for {}/{} container '{}'",
-                picker.namespace, picker.pod_name, container
+            (
+                "resume cronjob",
+                format!("Resume CronJob {namespace}/{name}"),
             )
         };
-        AppCommand::LoadPodLogs {
-            namespace: picker.namespace.clone(),
-            pod_name: picker.pod_name.clone(),
-            container: Some(container),
-            previous,
+        if !self.ensure_write_allowed(action) {
+            return AppCommand::None;
         }
+        self.request_confirmation(
+            action,
+            prompt,
+            AppCommand::ToggleCronJobSuspend {
+                namespace,
+                name,
+                suspend,
+            },
+        )
     }
 
-    fn clear_detail_overlay(&mut self) {
-        self.detail_overlay_title = None;
-        self.detail_overlay = None;
+    fn prepare_rerun_confirmation(&mut self) -> AppCommand {
+        let tab = self.active_tab();
+        if !matches!(tab, ResourceTab::Jobs | ResourceTab::CronJobs) {
+            self.status = "Rerun is available only for Jobs and CronJobs".to_string();
+            return AppCommand::None;
+        }
+        let action = if tab == ResourceTab::Jobs {
+            "rerun job"
+        } else {
+            "create job"
+        };
+        if !self.ensure_write_allowed(action) {
+            return AppCommand::None;
+        }
+        let Some(row) = self.active_selected_row() else {
+            self.status = format!("No selected {}", tab.title());
+            return AppCommand::None;
+        };
+        let Some(namespace) = row.namespace.clone() else {
+            self.status = format!("Selected {} has no namespace", tab.title());
+            return AppCommand::None;
+        };
+        let name = row.name.clone();
+        if tab == ResourceTab::Jobs {
+            let prompt = format!(
+                "Rerun Job {namespace}/{name}: delete it with its pods and recreate it from its spec"
+            );
+            return self.request_confirmation(
+                action,
+                prompt,
+                AppCommand::RerunJob { namespace, name },
+            );
+        }
+        let prompt = format!("Create a Job from CronJob {namespace}/{name}");
+        self.request_confirmation(
+            action,
+            prompt,
+            AppCommand::CreateJobFromCronJob {
+                namespace,
+                cronjob: name,
+            },
+        )
     }
 
-    fn clear_table_overlay(&mut self) {
-        self.table_overlay_title = None;
-        self.table_overlay = None;
-        self.table_overlay_kind = TableOverlayKind::Generic;
-        self.table_overlay_return_picker = None;
-        self.table_scroll = 0;
+    fn request_confirmation(
+        &mut self,
+        action: &str,
+        prompt: String,
+        command: AppCommand,
+    ) -> AppCommand {
+        if let Some(rule) = self.dangerous_rule_for(&command) {
+            self.request_typed_confirmation(
+                prompt,
+                command,
+                format!("Dangerous command ({rule})"),
+                Vec::new(),
+            );
+            return AppCommand::None;
+        }
+        if self.confirm_skip.contains(action) && !ALWAYS_CONFIRM_ACTIONS.contains(&action) {
+            self.status = format!("{prompt} (confirmation skipped by config)");
+            return command;
+        }
+        self.status = format!("{prompt}? (y/n)");
+        self.pending_confirmation = Some(PendingConfirmation {
+            prompt,
+            command,
+            typed_name: None,
+            typed: String::new(),
+            details: Vec::new(),
+        });
+        AppCommand::None
     }
 
-    fn clear_container_picker(&mut self) {
-        self.container_picker = None;
-    }
+    fn prepare_scale_command(&mut self, replicas: i32) -> AppCommand {
+        if !self.ensure_write_allowed("scale") {
+            return AppCommand::None;
+        }
 
-    fn dismiss_detail_view(&mut self) {
-        self.clear_detail_overlay();
-        self.detail_mode = DetailPaneMode::Dashboard;
-        self.detail_scroll = 0;
-        self.focus = FocusPane::Table;
-    }
+        if replicas < 0 {
+            self.status = "Replicas must be >= 0".to_string();
+            return AppCommand::None;
+        }
 
-    fn table_page_step(&self) -> isize {
-        self.table_page_size.saturating_sub(1).max(1) as isize
-    }
+        let tab = self.active_tab();
+        if tab == ResourceTab::CustomResources {
+            return self.prepare_custom_resource_scale(replicas);
+        }
+        if !matches!(tab, ResourceTab::Deployments | ResourceTab::StatefulSets) {
+            self.status = "Scale is available only for Deployments, StatefulSets and scalable CRDs"
+                .to_string();
+            return AppCommand::None;
+        }
 
-    fn detail_page_step(&self) -> u16 {
-        self.detail_view_height.saturating_div(2).max(1)
-    }
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected workload".to_string();
+            return AppCommand::None;
+        };
 
-    fn scroll_detail(&mut self, delta: isize) {
-        let max = self.detail_max_scroll() as isize;
-        let current = self.detail_scroll as isize;
-        let next = (current + delta).clamp(0, max);
-        self.detail_scroll = next as u16;
+        let Some(namespace) = row.namespace.clone() else {
+            self.status = "Selected workload has no namespace".to_string();
+            return AppCommand::None;
+        };
+        let name = row.name.clone();
+        self.status = format!(
+            "Scaling {} {}/{} to {} replicas",
+            tab.title(),
+            namespace,
+            name,
+            replicas
+        );
+        AppCommand::ScaleWorkload {
+            tab,
+            namespace,
+            name,
+            replicas,
+        }
     }
 
-    fn scroll_table_overlay(&mut self, delta: isize) {
-        let max = self.table_max_scroll() as isize;
-        let current = self.table_scroll as isize;
-        let next = (current + delta).clamp(0, max);
-        self.table_scroll = next as u16;
-    }
+    fn prepare_custom_resource_scale(&mut self, replicas: i32) -> AppCommand {
+        let Some(custom) = self.selected_custom_resource().cloned() else {
+            self.status = "No CRD selected (:crd <name>)".to_string();
+            return AppCommand::None;
+        };
+        if !custom.scalable {
+            self.status = format!("{} does not expose a /scale subresource", custom.kind);
+            return AppCommand::None;
+        }
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected resource".to_string();
+            return AppCommand::None;
+        };
 
-    fn detail_max_scroll(&self) -> u16 {
-        let width = self.detail_view_width.max(1) as usize;
-        let height = self.detail_view_height.max(1) as usize;
-        let text = if let Some(overlay) = &self.detail_overlay {
-            overlay.as_str()
+        let namespace = if custom.namespaced {
+            row.namespace.clone()
         } else {
-            self.active_selected_row()
-                .map(|row| row.detail.as_str())
-                .unwrap_or("No resource selected")
+            None
         };
-
-        let visual_lines = visual_line_count(text, width);
-        visual_lines.saturating_sub(height) as u16
+        let name = row.name.clone();
+        self.status = format!("Scaling {} {} to {} replicas", custom.kind, name, replicas);
+        AppCommand::ScaleCustomResource {
+            custom,
+            namespace,
+            name,
+            replicas,
+        }
     }
 
-    fn table_max_scroll(&self) -> u16 {
-        let width = self.table_view_width.max(1) as usize;
-        let height = self.table_view_height.max(1) as usize;
-        let text = self.table_overlay.as_deref().unwrap_or("");
-        let visual_lines = visual_line_count(text, width);
-        visual_lines.saturating_sub(height) as u16
+    fn prepare_exec_command(&mut self, mut command: Vec<String>) -> AppCommand {
+        if !self.ensure_write_allowed("exec") {
+            return AppCommand::None;
+        }
+
+        if self.active_tab() != ResourceTab::Pods {
+            self.status = "Exec is only available in the Pods tab".to_string();
+            return AppCommand::None;
+        }
+
+        let tty = command
+            .first()
+            .is_some_and(|flag| matches!(flag.as_str(), "-t" | "-it" | "--tty"));
+        if tty {
+            command.remove(0);
+        }
+        if command.is_empty() {
+            self.status = "Usage: :exec [-t] <command...>".to_string();
+            return AppCommand::None;
+        }
+
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected pod".to_string();
+            return AppCommand::None;
+        };
+        let Some(namespace) = row.namespace.clone() else {
+            self.status = "Selected pod has no namespace".to_string();
+            return AppCommand::None;
+        };
+        let pod_name = row.name.clone();
+        self.status = format!(
+            "Executing in {namespace}/{pod_name}{}: {}",
+            if tty { " (tty)" } else { "" },
+            command.join(" ")
+        );
+        AppCommand::ExecInPod {
+            namespace,
+            pod_name,
+            command,
+            tty,
+        }
     }
-}
 
-fn visual_line_count(text: &str, width: usize) -> usize {
-    let width = width.max(1);
-    text.lines()
-        .map(|line| {
-            let chars = line.chars().count();
-            chars.div_ceil(width).max(1)
-        })
-        .sum::<usize>()
-        .max(1)
-}
+    fn prepare_shell_command(&mut self, container: Option<String>, shell: String) -> AppCommand {
+        if !self.ensure_write_allowed("shell") {
+            return AppCommand::None;
+        }
 
-fn parse_port_mapping(mapping: &str) -> Option<(u16, u16)> {
-    let mut parts = mapping.split(':');
-    let local = parts.next()?.parse::<u16>().ok()?;
-    let remote = parts.next()?.parse::<u16>().ok()?;
-    if parts.next().is_some() {
-        return None;
+        let (namespace, pod_name) = if self.active_tab() == ResourceTab::Pods {
+            let Some(row) = self.active_selected_row() else {
+                self.status = "No selected pod".to_string();
+                return AppCommand::None;
+            };
+            let Some(namespace) = row.namespace.clone() else {
+                self.status = "Selected pod has no namespace".to_string();
+                return AppCommand::None;
+            };
+            (namespace, row.name.clone())
+        } else if let Some((namespace, pod_name)) = self.selected_argocd_pod_target() {
+            (namespace, pod_name)
+        } else {
+            self.status = "Shell access is available from Pods or Argo Pod nodes".to_string();
+            return AppCommand::None;
+        };
+        self.status = match container.as_deref() {
+            Some(container) => format!(
+                "Opening shell in {namespace}/{pod_name} (container: {container}, shell: {shell})"
+            ),
+            None => format!("Opening shell in {namespace}/{pod_name} ({shell})"),
+        };
+        AppCommand::OpenPodShell {
+            namespace,
+            pod_name,
+            container,
+            shell,
+        }
     }
-    Some((local, remote))
-}
 
-fn resolve_command_token(raw: &str) -> String {
-    let lower = raw.to_ascii_lowercase();
-    let aliases = lower
-        .split(':')
-        .map(str::trim)
-        .filter(|alias| !alias.is_empty())
-        .collect::<Vec<_>>();
+    fn prepare_debug_command(
+        &mut self,
+        image: Option<String>,
+        target_container: Option<String>,
+    ) -> AppCommand {
+        if !self.ensure_write_allowed("debug") {
+            return AppCommand::None;
+        }
 
-    if aliases.is_empty() {
-        return String::new();
+        let (namespace, pod_name, picked_container) = if let Some(picker) =
+            self.container_picker.as_ref()
+        {
+            let picked = picker
+                .containers
+                .get(picker.selected)
+                .map(|entry| entry.name.clone());
+            (picker.namespace.clone(), picker.pod_name.clone(), picked)
+        } else if self.active_tab() == ResourceTab::Pods {
+            let Some(row) = self.active_selected_row() else {
+                self.status = "No selected pod".to_string();
+                return AppCommand::None;
+            };
+            let Some(namespace) = row.namespace.clone() else {
+                self.status = "Selected pod has no namespace".to_string();
+                return AppCommand::None;
+            };
+            (namespace, row.name.clone(), None)
+        } else if let Some((namespace, pod_name)) = self.selected_argocd_pod_target() {
+            (namespace, pod_name, None)
+        } else {
+            self.status = "Debug is available from Pods, containers or Argo Pod nodes".to_string();
+            return AppCommand::None;
+        };
+
+        let image = image.unwrap_or_else(|| DEFAULT_DEBUG_IMAGE.to_string());
+        let target_container = target_container.or(picked_container);
+        self.status = match target_container.as_deref() {
+            Some(container) => format!(
+                "Starting debug container ({image}) in {namespace}/{pod_name} targeting {container}"
+            ),
+            None => format!("Starting debug container ({image}) in {namespace}/{pod_name}"),
+        };
+        AppCommand::DebugPod {
+            namespace,
+            pod_name,
+            image,
+            target_container,
+        }
     }
 
-    for alias in &aliases {
-        if is_known_command_token(alias) {
-            return (*alias).to_string();
+    fn selected_argocd_pod_target(&self) -> Option<(String, String)> {
+        let target = self.selected_argocd_resource_target()?;
+        if !target.kind.eq_ignore_ascii_case("pod") {
+            return None;
         }
+        let namespace = target.namespace?;
+        Some((namespace, target.name))
     }
 
-    aliases[0].to_string()
-}
+    fn selected_argocd_resource_target(&self) -> Option<ArgoResourceTarget> {
+        if self.active_tab() != ResourceTab::ArgoCdResources {
+            return None;
+        }
+        let row = self.active_selected_row()?;
+        let (kind, fallback_name) = row.name.split_once('/')?;
+        let kind = kind.trim();
+        if kind.is_empty() {
+            return None;
+        }
+        let name = row
+            .columns
+            .get(2)
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty() && value != "-")
+            .unwrap_or_else(|| fallback_name.trim().to_string());
+        if name.is_empty() {
+            return None;
+        }
+        let namespace = row
+            .namespace
+            .clone()
+            .or_else(|| row.columns.get(1).cloned())
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty() && value != "-");
+        Some(ArgoResourceTarget {
+            kind: kind.to_string(),
+            namespace,
+            name,
+        })
+    }
 
-fn is_known_command_token(token: &str) -> bool {
-    matches!(
-        token,
-        "q" | "quit"
-            | "exit"
-            | "readonly"
-            | "ro"
-            | "config"
-            | "ops"
-            | "alerts"
-            | "alert"
-            | "pulses"
-            | "pulse"
-            | "xray"
-            | "xr"
-            | "x"
-            | "orca"
-            | "argocd"
-            | "argo"
-            | "k8s"
-            | "kube"
-            | "kubernetes"
-            | "helm"
-            | "tf"
-            | "terraform"
-            | "ansible"
-            | "ans"
-            | "docker"
-            | "rbac"
-            | "who-can"
-            | "whocan"
-            | "oc"
-            | "openshift"
-            | "kustomize"
-            | "kustom"
-            | "git"
-            | "repo"
-            | "plugin"
-            | "plug"
-            | "refresh"
-            | "reload"
-            | "r"
-            | "tools"
-            | "ctx"
-            | "context"
-            | "use-context"
-            | "cluster"
-            | "cl"
-            | "user"
-            | "usr"
-            | "contexts"
-            | "clusters"
-            | "users"
-            | "all-ns"
-            | "allns"
-            | "all"
-            | "all-namespaces"
-            | "ns"
-            | "namespace"
-            | "namespaces"
-            | "tab"
-            | "filter"
-            | "clear"
-            | "logs"
-            | "edit"
-            | "e"
-            | "delete"
-            | "del"
-            | "restart"
-            | "scale"
-            | "exec"
-            | "shell"
-            | "ssh"
-            | "bash"
-            | "pf"
-            | "port-forward"
-            | "crd"
-            | "custom"
-            | "crd-refresh"
-            | "help"
-    ) || ResourceTab::from_token(token).is_some()
-}
+    fn prepare_edit_command(&mut self) -> AppCommand {
+        if !self.ensure_write_allowed("edit") {
+            return AppCommand::None;
+        }
 
-fn supports_related_logs(tab: ResourceTab) -> bool {
-    matches!(
-        tab,
-        ResourceTab::Pods
-            | ResourceTab::Deployments
-            | ResourceTab::DaemonSets
-            | ResourceTab::StatefulSets
-            | ResourceTab::ReplicaSets
-            | ResourceTab::ReplicationControllers
-            | ResourceTab::Jobs
-            | ResourceTab::CronJobs
-            | ResourceTab::Services
-    )
-}
+        let tab = self.active_tab();
+        let Some((resource, namespaced)) = self.kubectl_resource_for_tab(tab) else {
+            self.status = format!("Edit is not supported for {}", tab.title());
+            return AppCommand::None;
+        };
 
-fn argocd_logs_tab_for_kind(kind: &str) -> Option<ResourceTab> {
-    match kind.to_ascii_lowercase().as_str() {
-        "deployment" => Some(ResourceTab::Deployments),
-        "daemonset" => Some(ResourceTab::DaemonSets),
-        "statefulset" => Some(ResourceTab::StatefulSets),
-        "replicaset" => Some(ResourceTab::ReplicaSets),
-        "replicationcontroller" => Some(ResourceTab::ReplicationControllers),
-        "job" => Some(ResourceTab::Jobs),
-        "cronjob" => Some(ResourceTab::CronJobs),
-        _ => None,
-    }
-}
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected resource".to_string();
+            return AppCommand::None;
+        };
 
-fn supports_xray(tab: ResourceTab) -> bool {
-    matches!(
-        tab,
-        ResourceTab::Pods
-            | ResourceTab::CronJobs
-            | ResourceTab::DaemonSets
-            | ResourceTab::Deployments
-            | ResourceTab::ReplicaSets
-            | ResourceTab::ReplicationControllers
-            | ResourceTab::StatefulSets
-            | ResourceTab::Jobs
-            | ResourceTab::Services
-            | ResourceTab::Nodes
-            | ResourceTab::Namespaces
-    )
-}
+        let name = row.name.clone();
+        let namespace = if namespaced {
+            row.namespace
+                .clone()
+                .or_else(|| match self.namespace_scope() {
+                    NamespaceScope::Named(namespace) => Some(namespace.clone()),
+                    NamespaceScope::All => None,
+                })
+        } else {
+            None
+        };
 
-fn parse_namespaced_target(input: &str) -> Option<(&str, String)> {
-    let (namespace, name) = input.split_once('/')?;
-    let namespace = namespace.trim();
-    let name = name.trim();
-    if namespace.is_empty() || name.is_empty() {
-        return None;
+        if namespaced && namespace.is_none() {
+            self.status = "Selected resource has no namespace".to_string();
+            return AppCommand::None;
+        }
+
+        self.status = match namespace.as_deref() {
+            Some(namespace) => format!("Editing {resource} {namespace}/{name}"),
+            None => format!("Editing {resource} {name}"),
+        };
+
+        AppCommand::EditSelected {
+            resource,
+            namespace,
+            name,
+        }
     }
-    Some((namespace, name.to_string()))
-}
 
-fn parse_namespace_target(input: &str) -> String {
-    if let Some((_, name)) = parse_namespaced_target(input) {
-        return name;
+    fn update_active_sort(
+        &mut self,
+        next: impl FnOnce(Option<TableSort>, usize) -> Option<TableSort>,
+    ) {
+        let tab = self.active_tab();
+        let identity = self.selected_row_identity_for_tab(tab);
+        let Some(table) = self.tables.get_mut(&tab) else {
+            self.status = "No table to sort".to_string();
+            return;
+        };
+        let current = table.sort;
+        let sort = next(current, table.headers.len());
+        if sort == current {
+            self.status = "Sort unchanged (S cycles column first)".to_string();
+            return;
+        }
+        table.apply_sort(sort);
+        if sort.is_none() {
+            table.rows.sort_by(|left, right| {
+                left.namespace
+                    .cmp(&right.namespace)
+                    .then_with(|| left.name.cmp(&right.name))
+            });
+        }
+        self.status = match sort {
+            Some(sort) => format!(
+                "Sorted by {} ({})",
+                table
+                    .headers
+                    .get(sort.column)
+                    .map(String::as_str)
+                    .unwrap_or("?"),
+                if sort.descending { "desc" } else { "asc" }
+            ),
+            None => "Sort reset to namespace/name".to_string(),
+        };
+        if let Some((namespace, name)) = identity {
+            self.select_row_by_identity(tab, namespace, &name);
+        }
     }
-    input.trim().to_string()
-}
 
-fn parse_shell_args(args: Vec<String>) -> (Option<String>, String) {
-    match args.as_slice() {
-        [] => (None, "auto".to_string()),
-        [single] => {
-            if is_shell_token(single) {
-                (None, normalize_shell_token(single))
+    fn decode_secret_or_toggle_reveal(&mut self) -> AppCommand {
+        if self.table_overlay_kind() == Some(TableOverlayKind::PortForwards) {
+            return self.prepare_stop_port_forward();
+        }
+        if self.table_overlay_kind() == Some(TableOverlayKind::Secret) {
+            self.secret_revealed = !self.secret_revealed;
+            self.table_overlay = Some(render_secret_entries(
+                &self.secret_entries,
+                self.secret_revealed,
+            ));
+            self.status = if self.secret_revealed {
+                "Secret values revealed (x to mask)".to_string()
             } else {
-                (Some(single.clone()), "auto".to_string())
-            }
+                "Secret values masked (x to reveal)".to_string()
+            };
+            return AppCommand::None;
         }
-        [container, shell, ..] => (Some(container.clone()), normalize_shell_token(shell)),
+
+        if self.active_tab() != ResourceTab::Secrets {
+            self.status = "Secret decoding is available from Secrets".to_string();
+            return AppCommand::None;
+        }
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No secret selected".to_string();
+            return AppCommand::None;
+        };
+        let name = row.name.clone();
+        let Some(namespace) = row
+            .namespace
+            .clone()
+            .or_else(|| match self.namespace_scope() {
+                NamespaceScope::Named(namespace) => Some(namespace.clone()),
+                NamespaceScope::All => None,
+            })
+        else {
+            self.status = "Secret namespace is unknown".to_string();
+            return AppCommand::None;
+        };
+        self.status = format!("Decoding secret {namespace}/{name}");
+        AppCommand::DecodeSecret { namespace, name }
     }
-}
 
-fn looks_like_repo_locator(input: &str) -> bool {
-    let trimmed = input.trim();
-    if trimmed.is_empty() {
-        return false;
+    fn prepare_argo_source_command(&mut self) -> AppCommand {
+        if self.active_tab() != ResourceTab::ArgoCdApps {
+            self.status = "Source editing is available from Argo CD apps".to_string();
+            return AppCommand::None;
+        }
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No Argo CD app selected".to_string();
+            return AppCommand::None;
+        };
+        let app = row.name.clone();
+        let Ok(payload) = serde_json::from_str::<serde_json::Value>(&row.detail) else {
+            self.status = format!("Argo CD app '{app}' detail is not JSON");
+            return AppCommand::None;
+        };
+        let source = payload
+            .pointer("/spec/source")
+            .or_else(|| payload.pointer("/spec/sources/0"));
+        let field = |key: &str| {
+            source
+                .and_then(|source| source.get(key))
+                .and_then(serde_json::Value::as_str)
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+        let Some(repo) = field("repoURL") else {
+            self.status = format!("Argo CD app '{app}' has no source repoURL");
+            return AppCommand::None;
+        };
+        let Some(path) = field("path") else {
+            self.status =
+                format!("Argo CD app '{app}' has no source path (chart sources are not editable)");
+            return AppCommand::None;
+        };
+        let revision = field("targetRevision").filter(|revision| revision != "HEAD");
+        self.status = format!("Opening {path} from {repo} in $EDITOR");
+        AppCommand::OpenArgoSource {
+            app,
+            repo,
+            path,
+            revision,
+        }
     }
-    trimmed.starts_with("http://")
-        || trimmed.starts_with("https://")
-        || trimmed.starts_with("ssh://")
-        || trimmed.starts_with("git@")
-        || trimmed.ends_with(".git")
-}
 
-fn is_shell_token(token: &str) -> bool {
-    matches!(token, "sh" | "bash" | "auto") || token.starts_with('/')
-}
+    fn prepare_node_neighbors_command(&mut self) -> AppCommand {
+        if self.active_tab() != ResourceTab::Pods {
+            self.status = "Node neighbors are available from Pods".to_string();
+            return AppCommand::None;
+        }
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No pod selected".to_string();
+            return AppCommand::None;
+        };
+        let pod = match row.namespace.as_deref() {
+            Some(namespace) => format!("{namespace}/{}", row.name),
+            None => row.name.clone(),
+        };
+        let Some(node) = row
+            .columns
+            .get(2)
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty() && value != "-")
+        else {
+            self.status = format!("Pod {pod} is not scheduled on a node");
+            return AppCommand::None;
+        };
+        self.status = format!("Loading pods on node {node}");
+        AppCommand::LoadNodeNeighbors { node, pod }
+    }
+
+    fn focus_selected_row_namespace(&mut self) -> AppCommand {
+        if let NamespaceScope::Named(namespace) = &self.namespace_scope {
+            self.status = format!("Already scoped to '{namespace}' (F restores all namespaces)");
+            return AppCommand::None;
+        }
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected resource".to_string();
+            return AppCommand::None;
+        };
+        let Some(namespace) = row.namespace.clone() else {
+            self.status = format!("{} is not namespaced", row.name);
+            return AppCommand::None;
+        };
+        self.namespace_scope = NamespaceScope::Named(namespace.clone());
+        self.status = format!("Namespace scope narrowed to '{namespace}'");
+        AppCommand::RefreshAll
+    }
+
+    fn restore_all_namespaces(&mut self) -> AppCommand {
+        if self.namespace_scope == NamespaceScope::All {
+            self.status = "Namespace scope is already all".to_string();
+            return AppCommand::None;
+        }
+        self.set_all_namespaces();
+        self.status = "Namespace scope set to all".to_string();
+        AppCommand::RefreshAll
+    }
+
+    fn toggle_namespace_scope(&mut self) -> AppCommand {
+        if let NamespaceScope::Named(namespace) = &self.namespace_scope {
+            let namespace = namespace.clone();
+            self.set_all_namespaces();
+            self.status = format!("Namespace scope set to all (a returns to '{namespace}')");
+            return AppCommand::RefreshAll;
+        }
+        let namespace = self
+            .last_named_namespace
+            .clone()
+            .or_else(|| {
+                self.active_selected_row()
+                    .and_then(|row| row.namespace.clone())
+            })
+            .unwrap_or_else(|| "default".to_string());
+        self.namespace_scope = NamespaceScope::Named(namespace.clone());
+        self.status = format!("Namespace scope set to '{namespace}'");
+        AppCommand::RefreshAll
+    }
+
+    fn cycle_namespace(&mut self, forward: bool) -> AppCommand {
+        let entries = self.namespace_picker_entries();
+        if entries.len() <= 1 {
+            self.status = "No namespaces loaded yet; open the Namespaces tab first".to_string();
+            return AppCommand::RefreshTab {
+                tab: ResourceTab::Namespaces,
+            };
+        }
+        let current = entries
+            .iter()
+            .position(|entry| entry == &self.namespace_scope)
+            .unwrap_or(0);
+        let next = if forward {
+            (current + 1) % entries.len()
+        } else {
+            (current + entries.len() - 1) % entries.len()
+        };
+        match entries[next].clone() {
+            NamespaceScope::All => {
+                self.set_all_namespaces();
+                self.status = "Namespace scope set to all".to_string();
+            }
+            NamespaceScope::Named(namespace) => {
+                self.namespace_scope = NamespaceScope::Named(namespace.clone());
+                self.status = format!("Namespace scope set to '{namespace}' ({{/}} cycle, F all)");
+            }
+        }
+        AppCommand::RefreshAll
+    }
+
+    fn set_all_namespaces(&mut self) {
+        if let NamespaceScope::Named(namespace) = &self.namespace_scope {
+            self.last_named_namespace = Some(namespace.clone());
+        }
+        self.namespace_scope = NamespaceScope::All;
+    }
+
+    fn prepare_describe_command(&mut self) -> AppCommand {
+        let tab = self.active_tab();
+        let Some((resource, namespaced)) = self.kubectl_resource_for_tab(tab) else {
+            self.status = format!("Describe is not supported for {}", tab.title());
+            return AppCommand::None;
+        };
+
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected resource".to_string();
+            return AppCommand::None;
+        };
+
+        let name = row.name.clone();
+        let namespace = if namespaced {
+            row.namespace
+                .clone()
+                .or_else(|| match self.namespace_scope() {
+                    NamespaceScope::Named(namespace) => Some(namespace.clone()),
+                    NamespaceScope::All => None,
+                })
+        } else {
+            None
+        };
+
+        if namespaced && namespace.is_none() {
+            self.status = "Selected resource has no namespace".to_string();
+            return AppCommand::None;
+        }
+
+        self.status = match namespace.as_deref() {
+            Some(namespace) => format!("Describing {resource} {namespace}/{name}"),
+            None => format!("Describing {resource} {name}"),
+        };
+
+        AppCommand::DescribeSelected {
+            tab,
+            resource,
+            namespace,
+            name,
+        }
+    }
+
+    fn open_yaml_editor(&mut self) -> AppCommand {
+        if !self.ensure_write_allowed("edit") {
+            return AppCommand::None;
+        }
+        let tab = self.active_tab();
+        if self.kubectl_resource_for_tab(tab).is_none() {
+            self.status = format!("Inline edit is not supported for {}", tab.title());
+            return AppCommand::None;
+        }
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected resource".to_string();
+            return AppCommand::None;
+        };
+        let original = serde_yaml::from_str::<serde_yaml::Value>(&row.detail).ok();
+        let resource_version = original.as_ref().and_then(|manifest| {
+            manifest
+                .get("metadata")?
+                .get("resourceVersion")?
+                .as_str()
+                .map(str::to_string)
+        });
+        let Some(kind) = original
+            .as_ref()
+            .and_then(|manifest| manifest.get("kind")?.as_str())
+            .map(str::to_string)
+        else {
+            self.status = format!("Could not load manifest for '{}': no kind", row.name);
+            return AppCommand::None;
+        };
+        let manifest = match clean_manifest_yaml(&row.detail) {
+            Ok(manifest) => manifest,
+            Err(error) => {
+                self.status = format!("Could not load manifest for '{}': {error}", row.name);
+                return AppCommand::None;
+            }
+        };
+
+        let name = row.name.clone();
+        self.yaml_editor = Some(YamlEditor {
+            tab,
+            kind,
+            namespace: row.namespace.clone(),
+            name: name.clone(),
+            resource_version,
+            lines: manifest.lines().map(str::to_string).collect(),
+            row: 0,
+            col: 0,
+            scroll: 0,
+            error: None,
+        });
+        self.mode = InputMode::Edit;
+        self.detail_mode = DetailPaneMode::Details;
+        self.focus = FocusPane::Detail;
+        self.status = format!(
+            "Editing {} '{name}' (Ctrl+S apply, Esc discard)",
+            tab.title()
+        );
+        AppCommand::None
+    }
+
+    fn apply_yaml_editor_action(&mut self, action: Action) -> AppCommand {
+        let page = self.detail_page_step().max(1) as isize;
+        let height = self.detail_view_height as usize;
+        let Some(editor) = self.yaml_editor.as_mut() else {
+            self.mode = InputMode::Normal;
+            return AppCommand::None;
+        };
+        match action {
+            Action::InputChar(c) => editor.insert(c.encode_utf8(&mut [0; 4])),
+            Action::CompleteInput => editor.insert("  "),
+            Action::InsertNewline => editor.newline(),
+            Action::Backspace => editor.backspace(),
+            Action::Delete => editor.delete(),
+            Action::Up => editor.move_vertical(-1),
+            Action::Down => editor.move_vertical(1),
+            Action::PageUp => editor.move_vertical(-page),
+            Action::PageDown => editor.move_vertical(page),
+            Action::Left => editor.move_horizontal(-1),
+            Action::Right => editor.move_horizontal(1),
+            Action::Top => editor.col = 0,
+            Action::Bottom => editor.col = editor.line_len(editor.row),
+            Action::CancelInput => {
+                self.close_yaml_editor();
+                self.status = "Discarded inline edit".to_string();
+                return AppCommand::None;
+            }
+            Action::SubmitInput => {
+                let yaml = editor.text();
+                if let Err(error) = validate_edited_manifest(
+                    &yaml,
+                    &editor.kind,
+                    editor.namespace.as_deref(),
+                    &editor.name,
+                ) {
+                    self.status = format!("Not applied: {error}");
+                    editor.error = Some(error);
+                    return AppCommand::None;
+                }
+                editor.error = None;
+                self.status = format!("Applying {} '{}'", editor.tab.title(), editor.name);
+                return AppCommand::ApplyYaml {
+                    tab: editor.tab,
+                    kind: editor.kind.clone(),
+                    namespace: editor.namespace.clone(),
+                    name: editor.name.clone(),
+                    yaml,
+                    resource_version: editor.resource_version.clone(),
+                };
+            }
+            _ => {}
+        }
+        editor.keep_cursor_visible(height);
+        AppCommand::None
+    }
+
+    pub fn finish_yaml_apply(&mut self, result: Result<(), String>) {
+        let Some(editor) = self.yaml_editor.as_mut() else {
+            return;
+        };
+        match result {
+            Ok(()) => {
+                self.status = format!("Applied {} '{}'", editor.tab.title(), editor.name);
+                self.close_yaml_editor();
+            }
+            Err(error) => {
+                self.status = format!("Apply failed for '{}'", editor.name);
+                editor.error = Some(error);
+            }
+        }
+    }
+
+    fn close_yaml_editor(&mut self) {
+        self.yaml_editor = None;
+        self.mode = InputMode::Normal;
+    }
+
+    pub fn yaml_editor_active(&self) -> bool {
+        self.yaml_editor.is_some()
+    }
+
+    pub fn yaml_editor_title(&self) -> Option<String> {
+        self.yaml_editor.as_ref().map(|editor| {
+            let target = match editor.namespace.as_deref() {
+                Some(namespace) => format!("{namespace}/{}", editor.name),
+                None => editor.name.clone(),
+            };
+            format!("Edit {} {target}", editor.tab.title())
+        })
+    }
+
+    pub fn yaml_editor_lines(&self) -> &[String] {
+        self.yaml_editor
+            .as_ref()
+            .map_or(&[], |editor| editor.lines.as_slice())
+    }
+
+    pub fn yaml_editor_cursor(&self) -> (usize, usize) {
+        self.yaml_editor
+            .as_ref()
+            .map_or((0, 0), |editor| (editor.row, editor.col))
+    }
+
+    pub fn yaml_editor_scroll(&self) -> usize {
+        self.yaml_editor.as_ref().map_or(0, |editor| editor.scroll)
+    }
+
+    pub fn yaml_editor_error(&self) -> Option<&str> {
+        self.yaml_editor
+            .as_ref()
+            .and_then(|editor| editor.error.as_deref())
+    }
+
+    fn prepare_scratch_yaml_command(&mut self, clean: bool) -> AppCommand {
+        let tab = self.active_tab();
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected resource".to_string();
+            return AppCommand::None;
+        };
+        if row.detail.trim().is_empty() {
+            self.status = format!("{} '{}' has no manifest to copy", tab.title(), row.name);
+            return AppCommand::None;
+        }
+
+        let manifest = if clean {
+            match clean_manifest_yaml(&row.detail) {
+                Ok(manifest) => manifest,
+                Err(error) => {
+                    self.status = format!("Could not clean manifest for '{}': {error}", row.name);
+                    return AppCommand::None;
+                }
+            }
+        } else {
+            row.detail.clone()
+        };
+
+        let file_stem = [
+            Some(tab.short_token()),
+            row.namespace.as_deref(),
+            Some(&row.name),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|part| {
+            part.chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("-");
+        self.status = format!(
+            "Opening scratch copy of {} '{}' in $EDITOR",
+            tab.title(),
+            row.name
+        );
+        AppCommand::OpenScratchYaml {
+            file_stem,
+            manifest,
+        }
+    }
+
+    fn prepare_export_yaml_command(&mut self, target: Option<&str>) -> AppCommand {
+        let tab = self.active_tab();
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected resource".to_string();
+            return AppCommand::None;
+        };
+        if row.detail.trim().is_empty() {
+            self.status = format!("{} '{}' has no manifest to save", tab.title(), row.name);
+            return AppCommand::None;
+        }
+
+        let namespace = row.namespace.clone();
+        let name = row.name.clone();
+        let file_name = match namespace.as_deref() {
+            Some(namespace) => format!("{namespace}-{name}.yaml"),
+            None => format!("{name}.yaml"),
+        };
+        let path = match target.map(str::trim).filter(|target| !target.is_empty()) {
+            Some(file) if file.ends_with(".yaml") || file.ends_with(".yml") => file.to_string(),
+            Some(dir) => Path::new(dir).join(&file_name).display().to_string(),
+            None => Path::new(self.export_dir.as_deref().unwrap_or(DEFAULT_EXPORT_DIR))
+                .join(&file_name)
+                .display()
+                .to_string(),
+        };
+        self.status = format!("Saving {} '{name}' YAML to {path}", tab.title());
+        AppCommand::ExportSelectedYaml {
+            tab,
+            namespace,
+            name,
+            path,
+        }
+    }
+
+    fn prepare_xray_command(&mut self, raw_target: Option<&str>) -> AppCommand {
+        let tab = self.active_tab();
+        if !supports_xray(tab) {
+            self.status = format!("Xray is not supported for {}", tab.title());
+            return AppCommand::None;
+        }
+
+        let (mut namespace, name) = if let Some(raw_target) = raw_target {
+            if let Some((target_namespace, target_name)) = parse_namespaced_target(raw_target) {
+                (Some(target_namespace.to_string()), target_name)
+            } else {
+                (None, raw_target.trim().to_string())
+            }
+        } else {
+            let Some(selected) = self.active_selected_row() else {
+                self.status = "No selected resource for xray".to_string();
+                return AppCommand::None;
+            };
+            (selected.namespace.clone(), selected.name.clone())
+        };
+
+        if name.is_empty() {
+            self.status = "Usage: :xray [namespace/name|name]".to_string();
+            return AppCommand::None;
+        }
+
+        let namespaced = self
+            .kubectl_resource_for_tab(tab)
+            .map(|(_, namespaced)| namespaced)
+            .unwrap_or(matches!(
+                tab,
+                ResourceTab::Pods
+                    | ResourceTab::CronJobs
+                    | ResourceTab::DaemonSets
+                    | ResourceTab::Deployments
+                    | ResourceTab::ReplicaSets
+                    | ResourceTab::ReplicationControllers
+                    | ResourceTab::StatefulSets
+                    | ResourceTab::Jobs
+                    | ResourceTab::Services
+                    | ResourceTab::Events
+            ));
+        if namespaced && namespace.is_none() {
+            namespace = match self.namespace_scope() {
+                NamespaceScope::Named(namespace) => Some(namespace.clone()),
+                NamespaceScope::All => None,
+            };
+        }
+        if namespaced && namespace.is_none() {
+            self.status =
+                "Xray needs a namespace target (select a row or use :xray <ns>/<name>)".to_string();
+            return AppCommand::None;
+        }
+
+        self.status = match namespace.as_deref() {
+            Some(namespace) => format!("Building xray for {} {namespace}/{name}", tab.title()),
+            None => format!("Building xray for {} {name}", tab.title()),
+        };
+        AppCommand::InspectXray {
+            tab,
+            namespace,
+            name,
+        }
+    }
+
+    fn kubectl_resource_for_tab(&self, tab: ResourceTab) -> Option<(String, bool)> {
+        match tab {
+            ResourceTab::Orca => None,
+            ResourceTab::ArgoCdApps => Some(("applications.argoproj.io".to_string(), true)),
+            ResourceTab::ArgoCdResources
+            | ResourceTab::ArgoCdProjects
+            | ResourceTab::ArgoCdRepos
+            | ResourceTab::ArgoCdClusters
+            | ResourceTab::ArgoCdAccounts
+            | ResourceTab::ArgoCdCerts
+            | ResourceTab::ArgoCdGpgKeys => None,
+            ResourceTab::Pods => Some(("pod".to_string(), true)),
+            ResourceTab::CronJobs => Some(("cronjob".to_string(), true)),
+            ResourceTab::DaemonSets => Some(("daemonset".to_string(), true)),
+            ResourceTab::Deployments => Some(("deployment".to_string(), true)),
+            ResourceTab::ReplicaSets => Some(("replicaset".to_string(), true)),
+            ResourceTab::ReplicationControllers => {
+                Some(("replicationcontroller".to_string(), true))
+            }
+            ResourceTab::StatefulSets => Some(("statefulset".to_string(), true)),
+            ResourceTab::Jobs => Some(("job".to_string(), true)),
+            ResourceTab::Services => Some(("service".to_string(), true)),
+            ResourceTab::EndpointSlices => Some(("endpointslice".to_string(), true)),
+            ResourceTab::Ingresses => Some(("ingress".to_string(), true)),
+            ResourceTab::IngressClasses => Some(("ingressclass".to_string(), false)),
+            ResourceTab::ConfigMaps => Some(("configmap".to_string(), true)),
+            ResourceTab::PersistentVolumeClaims => {
+                Some(("persistentvolumeclaim".to_string(), true))
+            }
+            ResourceTab::Secrets => Some(("secret".to_string(), true)),
+            ResourceTab::StorageClasses => Some(("storageclass".to_string(), false)),
+            ResourceTab::PersistentVolumes => Some(("persistentvolume".to_string(), false)),
+            ResourceTab::VolumeSnapshots => {
+                Some(("volumesnapshots.snapshot.storage.k8s.io".to_string(), true))
+            }
+            ResourceTab::ServiceAccounts => Some(("serviceaccount".to_string(), true)),
+            ResourceTab::Roles => Some(("role".to_string(), true)),
+            ResourceTab::RoleBindings => Some(("rolebinding".to_string(), true)),
+            ResourceTab::ClusterRoles => Some(("clusterrole".to_string(), false)),
+            ResourceTab::ClusterRoleBindings => Some(("clusterrolebinding".to_string(), false)),
+            ResourceTab::PriorityClasses => Some(("priorityclass".to_string(), false)),
+            ResourceTab::NetworkPolicies => Some(("networkpolicy".to_string(), true)),
+            ResourceTab::Leases => Some(("lease".to_string(), true)),
+            ResourceTab::MutatingWebhooks => {
+                Some(("mutatingwebhookconfiguration".to_string(), false))
+            }
+            ResourceTab::ValidatingWebhooks => {
+                Some(("validatingwebhookconfiguration".to_string(), false))
+            }
+            ResourceTab::Nodes => Some(("node".to_string(), false)),
+            ResourceTab::Namespaces => Some(("namespace".to_string(), false)),
+            ResourceTab::Events => None,
+            ResourceTab::CustomResources => {
+                let crd = self.selected_custom_resource()?;
+                let resource = if crd.group.is_empty() {
+                    crd.plural.clone()
+                } else {
+                    format!("{}.{}", crd.plural, crd.group)
+                };
+                Some((resource, crd.namespaced))
+            }
+        }
+    }
+
+    fn prepare_port_forward(&mut self, local_port: u16, remote_port: u16) -> AppCommand {
+        if !self.ensure_write_allowed("port-forward") {
+            return AppCommand::None;
+        }
+
+        let tab = self.active_tab();
+        if !matches!(tab, ResourceTab::Pods | ResourceTab::Services) {
+            self.status = "Port-forward is available in Pods and Services tabs".to_string();
+            return AppCommand::None;
+        }
+
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected target for port-forward".to_string();
+            return AppCommand::None;
+        };
+        let Some(namespace) = row.namespace.clone() else {
+            self.status = "Selected target has no namespace".to_string();
+            return AppCommand::None;
+        };
+        let name = row.name.clone();
+        self.status = format!(
+            "Starting port-forward {} {}/{} {}:{}",
+            tab.title(),
+            namespace,
+            name,
+            if local_port == 0 {
+                "auto".to_string()
+            } else {
+                local_port.to_string()
+            },
+            remote_port
+        );
+        AppCommand::StartPortForward {
+            tab,
+            namespace,
+            name,
+            local_port,
+            remote_port,
+        }
+    }
+
+    fn prepare_named_port_forward(&mut self, local_port: u16, port_name: String) -> AppCommand {
+        if !self.ensure_write_allowed("port-forward") {
+            return AppCommand::None;
+        }
+
+        if self.active_tab() != ResourceTab::Services {
+            self.status = "Named ports are available only in the Services tab".to_string();
+            return AppCommand::None;
+        }
+
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected target for port-forward".to_string();
+            return AppCommand::None;
+        };
+        let Some(namespace) = row.namespace.clone() else {
+            self.status = "Selected target has no namespace".to_string();
+            return AppCommand::None;
+        };
+        let name = row.name.clone();
+        self.status = format!("Resolving port '{port_name}' on service {namespace}/{name}");
+        AppCommand::StartNamedPortForward {
+            namespace,
+            name,
+            local_port,
+            port_name,
+        }
+    }
+
+    fn create_logs_command(&mut self, previous: bool) -> AppCommand {
+        if self.container_picker_active() {
+            return self.load_selected_container_logs(previous);
+        }
+
+        if self.active_tab() == ResourceTab::ArgoCdResources {
+            let Some(target) = self.selected_argocd_resource_target() else {
+                self.status = "No Argo CD resource selected".to_string();
+                return AppCommand::None;
+            };
+
+            if target.kind.eq_ignore_ascii_case("pod") {
+                let Some(namespace) = target.namespace else {
+                    self.status = "Selected Argo Pod has no namespace".to_string();
+                    return AppCommand::None;
+                };
+                let pod_name = target.name;
+                self.status = if previous {
+                    format!("Fetching previous logs for pod '{pod_name}' in '{namespace}'")
+                } else {
+                    format!("Fetching logs for pod '{pod_name}' in '{namespace}'")
+                };
+                return AppCommand::LoadPodLogs {
+                    namespace,
+                    pod_name,
+                    container: None,
+                    previous,
+                    tail: self.log_tail,
+                    window: None,
+                };
+            }
+            if let Some(tab) = argocd_logs_tab_for_kind(&target.kind) {
+                self.status = if previous {
+                    format!(
+                        "Resolving previous logs for {} '{}'",
+                        target.kind, target.name
+                    )
+                } else {
+                    format!("Resolving logs for {} '{}'", target.kind, target.name)
+                };
+                return AppCommand::LoadResourceLogs {
+                    tab,
+                    namespace: target.namespace,
+                    name: target.name,
+                    previous,
+                    tail: self.log_tail,
+                    window: None,
+                };
+            }
+            self.status = format!("Logs are not available for Argo kind '{}'", target.kind);
+            return AppCommand::None;
+        }
+
+        if self.active_tab() == ResourceTab::Jobs {
+            return self.create_related_logs_command(previous);
+        }
+
+        if self.active_tab() != ResourceTab::Pods {
+            self.status =
+                "Logs are available from Pods (or use Shift+L for workload logs)".to_string();
+            return AppCommand::None;
+        }
+
+        let Some(selected_row) = self.active_selected_row() else {
+            self.status = "No pod selected".to_string();
+            return AppCommand::None;
+        };
+
+        let Some(namespace) =
+            selected_row
+                .namespace
+                .clone()
+                .or_else(|| match self.namespace_scope() {
+                    NamespaceScope::All => None,
+                    NamespaceScope::Named(ns) => Some(ns.clone()),
+                })
+        else {
+            self.status = "Pod namespace is unknown".to_string();
+            return AppCommand::None;
+        };
+
+        let pod_name = selected_row.name.clone();
+        self.status = if previous {
+            format!("Fetching previous logs for pod '{pod_name}' in '{namespace}'")
+        } else {
+            format!("Fetching logs for pod '{pod_name}' in '{namespace}'")
+        };
+
+        AppCommand::LoadPodLogs {
+            namespace,
+            pod_name,
+            container: None,
+            previous,
+            tail: self.log_tail,
+            window: None,
+        }
+    }
+
+    fn create_related_logs_command(&mut self, previous: bool) -> AppCommand {
+        if self.container_picker_active() {
+            return self.load_selected_container_logs(previous);
+        }
+
+        let tab = self.active_tab();
+        if tab == ResourceTab::ArgoCdResources {
+            return self.create_logs_command(previous);
+        }
+        if tab == ResourceTab::Pods {
+            return self.create_logs_command(previous);
+        }
+
+        if !supports_related_logs(tab) {
+            self.status = format!("Shift+L logs are not supported for {}", tab.title());
+            return AppCommand::None;
+        }
+
+        let Some(row) = self.active_selected_row() else {
+            self.status = "No selected resource".to_string();
+            return AppCommand::None;
+        };
+        let name = row.name.clone();
+        let namespace = row.namespace.clone();
+        self.status = format!("Resolving related logs for {name}");
+        AppCommand::LoadResourceLogs {
+            tab,
+            namespace,
+            name,
+            previous,
+            tail: self.log_tail,
+            window: None,
+        }
+    }
+
+    fn load_selected_container_logs(&mut self, previous: bool) -> AppCommand {
+        let Some(picker) = self.container_picker.as_ref() else {
+            self.status = "No container selected".to_string();
+            return AppCommand::None;
+        };
+        if picker.containers.is_empty() {
+            self.status = "No containers available".to_string();
+            return AppCommand::None;
+        }
+        let selected = picker
+            .selected
+            .min(picker.containers.len().saturating_sub(1));
+        let container = picker.containers[selected].name.clone();
+        self.status = if previous {
+            format!(
+                "Fetching previous logs for {}/{} container '{}'",
+                picker.namespace, picker.pod_name, container
+            )
+        } else {
+            format!(
+                "Fetching logs for {}/{} container '{}'",
+                picker.namespace, picker.pod_name, container
+            )
+        };
+        AppCommand::LoadPodLogs {
+            namespace: picker.namespace.clone(),
+            pod_name: picker.pod_name.clone(),
+            container: Some(container),
+            previous,
+            tail: self.log_tail,
+            window: None,
+        }
+    }
+
+    fn clear_detail_overlay(&mut self) {
+        self.detail_overlay_title = None;
+        self.detail_overlay = None;
+    }
+
+    fn clear_table_overlay(&mut self) {
+        self.table_overlay_title = None;
+        self.table_overlay = None;
+        self.table_overlay_kind = TableOverlayKind::Generic;
+        self.table_overlay_return_picker = None;
+        self.helm_release_view = None;
+        self.argo_panel_view = None;
+        self.log_filter.clear();
+        self.log_request = None;
+        self.secret_entries.clear();
+        self.secret_revealed = false;
+        self.table_scroll = 0;
+    }
+
+    fn clear_container_picker(&mut self) {
+        self.container_picker = None;
+    }
+
+    fn dismiss_detail_view(&mut self) {
+        self.clear_detail_overlay();
+        self.detail_mode = DetailPaneMode::Dashboard;
+        self.detail_scroll = 0;
+        self.focus = FocusPane::Table;
+    }
+
+    fn table_page_step(&self) -> isize {
+        self.table_page_size.saturating_sub(1).max(1) as isize
+    }
+
+    fn detail_page_step(&self) -> u16 {
+        self.detail_view_height.saturating_div(2).max(1)
+    }
+
+    fn scroll_detail(&mut self, delta: isize) {
+        let max = self.detail_max_scroll() as isize;
+        let current = self.detail_scroll as isize;
+        let next = (current + delta).clamp(0, max);
+        self.detail_scroll = next as u16;
+    }
+
+    fn scroll_table_overlay(&mut self, delta: isize) {
+        let max = self.table_max_scroll() as isize;
+        let current = self.table_scroll as isize;
+        let next = (current + delta).clamp(0, max);
+        self.table_scroll = next as u16;
+    }
+
+    fn detail_max_scroll(&self) -> u16 {
+        let width = self.detail_view_width.max(1) as usize;
+        let height = self.detail_view_height.max(1) as usize;
+        let text = if let Some(overlay) = &self.detail_overlay {
+            overlay.as_str()
+        } else {
+            self.active_selected_row()
+                .map(|row| row.detail.as_str())
+                .unwrap_or("No resource selected")
+        };
+
+        let visual_lines = visual_line_count(text, width);
+        visual_lines.saturating_sub(height) as u16
+    }
+
+    fn table_max_scroll(&self) -> u16 {
+        let width = self.table_view_width.max(1) as usize;
+        let height = self.table_view_height.max(1) as usize;
+        let text = self.table_overlay_lines().join("\n");
+        let visual_lines = visual_line_count(&text, width);
+        visual_lines.saturating_sub(height) as u16
+    }
+}
+
+fn visual_line_count(text: &str, width: usize) -> usize {
+    let width = width.max(1);
+    text.lines()
+        .map(|line| {
+            let chars = line.chars().count();
+            chars.div_ceil(width).max(1)
+        })
+        .sum::<usize>()
+        .max(1)
+}
+
+fn render_secret_entries(entries: &[SecretEntry], revealed: bool) -> String {
+    if entries.is_empty() {
+        return "(secret has no data)".to_string();
+    }
+    let mut lines = Vec::new();
+    for entry in entries {
+        match (&entry.value, revealed) {
+            (None, _) => lines.push(format!("{}: <binary, {} bytes>", entry.key, entry.bytes)),
+            (Some(_), false) => {
+                lines.push(format!("{}: ******** ({} bytes)", entry.key, entry.bytes))
+            }
+            (Some(value), true) => {
+                let mut value_lines = value.lines();
+                lines.push(format!(
+                    "{}: {}",
+                    entry.key,
+                    value_lines.next().unwrap_or_default()
+                ));
+                lines.extend(value_lines.map(|line| format!("  {line}")));
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+fn parse_window_secs(raw: &str) -> Option<u64> {
+    let raw = raw.trim();
+    let (number, unit) = match raw.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        Some((index, _)) => raw.split_at(index),
+        None => (raw, "s"),
+    };
+    let value = number.parse::<u64>().ok().filter(|value| *value > 0)?;
+    let multiplier = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3_600,
+        "d" => 86_400,
+        _ => return None,
+    };
+    value.checked_mul(multiplier)
+}
+
+pub fn log_range_label(tail: usize, window: Option<LogWindow>) -> String {
+    match window {
+        Some(window) => window.label(),
+        None => format!("tail {}", log_tail_label(tail)),
+    }
+}
+
+pub fn log_tail_label(tail: usize) -> String {
+    if tail == 0 {
+        "all lines".to_string()
+    } else {
+        format!("{tail} lines")
+    }
+}
+
+fn parse_port_mapping(mapping: &str) -> Option<(u16, u16)> {
+    let mut parts = mapping.split(':');
+    let local = match parts.next()?.trim() {
+        "" => 0,
+        raw => raw.parse::<u16>().ok()?,
+    };
+    let remote = parts.next()?.parse::<u16>().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((local, remote))
+}
+
+fn parse_named_port_mapping(mapping: &str) -> Option<(u16, String)> {
+    let (left, right) = mapping.split_once(':')?;
+    let (left, right) = (left.trim(), right.trim());
+    if right.contains(':') {
+        return None;
+    }
+    let is_port_name = |raw: &str| {
+        !raw.is_empty()
+            && raw.parse::<u16>().is_err()
+            && raw
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+    };
+    let parse_local = |raw: &str| match raw {
+        "" => Some(0),
+        raw => raw.parse::<u16>().ok(),
+    };
+
+    if is_port_name(right) {
+        return Some((parse_local(left)?, right.to_string()));
+    }
+    if is_port_name(left) {
+        return Some((parse_local(right)?, left.to_string()));
+    }
+    None
+}
+
+fn resolve_command_token(raw: &str) -> String {
+    let lower = raw.to_ascii_lowercase();
+    let aliases = lower
+        .split(':')
+        .map(str::trim)
+        .filter(|alias| !alias.is_empty())
+        .collect::<Vec<_>>();
+
+    if aliases.is_empty() {
+        return String::new();
+    }
+
+    for alias in &aliases {
+        if is_known_command_token(alias) {
+            return (*alias).to_string();
+        }
+    }
+
+    aliases[0].to_string()
+}
+
+fn is_known_command_token(token: &str) -> bool {
+    matches!(
+        token,
+        "q" | "quit"
+            | "exit"
+            | "readonly"
+            | "ro"
+            | "tail"
+            | "log-window"
+            | "logwin"
+            | "scratch"
+            | "slices"
+            | "rewatch"
+            | "deprecations"
+            | "deprecated"
+            | "rollout"
+            | "force-delete"
+            | "kill"
+            | "tabs"
+            | "resource-events"
+            | "rev"
+            | "top"
+            | "images"
+            | "more"
+            | "load-more"
+            | "ref"
+            | "copy-ref"
+            | "selector"
+            | "sel"
+            | "fields"
+            | "fsel"
+            | "node-top"
+            | "ntop"
+            | "img"
+            | "export"
+            | "save"
+            | "write"
+            | "has"
+            | "describe"
+            | "desc"
+            | "neighbors"
+            | "nb"
+            | "source"
+            | "src"
+            | "config"
+            | "ops"
+            | "alerts"
+            | "alert"
+            | "pulses"
+            | "rerun"
+            | "run-now"
+            | "trigger"
+            | "suspend"
+            | "resume"
+            | "activity"
+            | "feed"
+            | "pulse"
+            | "xray"
+            | "xr"
+            | "x"
+            | "orca"
+            | "argocd"
+            | "argo"
+            | "k8s"
+            | "kube"
+            | "kubernetes"
+            | "helm"
+            | "tf"
+            | "terraform"
+            | "ansible"
+            | "ans"
+            | "docker"
+            | "rbac"
+            | "who-can"
+            | "whocan"
+            | "oc"
+            | "openshift"
+            | "kustomize"
+            | "kustom"
+            | "git"
+            | "repo"
+            | "plugin"
+            | "plug"
+            | "refresh"
+            | "reload"
+            | "r"
+            | "tools"
+            | "ctx"
+            | "context"
+            | "use-context"
+            | "cluster"
+            | "cl"
+            | "user"
+            | "usr"
+            | "contexts"
+            | "clusters"
+            | "users"
+            | "all-ns"
+            | "allns"
+            | "all"
+            | "all-namespaces"
+            | "ns"
+            | "namespace"
+            | "namespaces"
+            | "tab"
+            | "filter"
+            | "clear"
+            | "logs"
+            | "edit"
+            | "e"
+            | "delete"
+            | "del"
+            | "restart"
+            | "scale"
+            | "exec"
+            | "shell"
+            | "ssh"
+            | "bash"
+            | "debug"
+            | "dbg"
+            | "pf"
+            | "port-forward"
+            | "crd"
+            | "custom"
+            | "crd-refresh"
+            | "help"
+    ) || ResourceTab::from_token(token).is_some()
+}
+
+fn supports_related_logs(tab: ResourceTab) -> bool {
+    matches!(
+        tab,
+        ResourceTab::Pods
+            | ResourceTab::Deployments
+            | ResourceTab::DaemonSets
+            | ResourceTab::StatefulSets
+            | ResourceTab::ReplicaSets
+            | ResourceTab::ReplicationControllers
+            | ResourceTab::Jobs
+            | ResourceTab::CronJobs
+            | ResourceTab::Services
+    )
+}
+
+fn argocd_logs_tab_for_kind(kind: &str) -> Option<ResourceTab> {
+    match kind.to_ascii_lowercase().as_str() {
+        "deployment" => Some(ResourceTab::Deployments),
+        "daemonset" => Some(ResourceTab::DaemonSets),
+        "statefulset" => Some(ResourceTab::StatefulSets),
+        "replicaset" => Some(ResourceTab::ReplicaSets),
+        "replicationcontroller" => Some(ResourceTab::ReplicationControllers),
+        "job" => Some(ResourceTab::Jobs),
+        "cronjob" => Some(ResourceTab::CronJobs),
+        _ => None,
+    }
+}
+
+const SCRATCH_METADATA_NOISE: [&str; 7] = [
+    "managedFields",
+    "uid",
+    "resourceVersion",
+    "creationTimestamp",
+    "generation",
+    "selfLink",
+    "ownerReferences",
+];
+
+fn validate_edited_manifest(
+    yaml: &str,
+    kind: &str,
+    namespace: Option<&str>,
+    name: &str,
+) -> Result<(), String> {
+    let manifest: serde_yaml::Value =
+        serde_yaml::from_str(yaml).map_err(|error| format!("invalid YAML: {error}"))?;
+    let field = |path: &[&str]| {
+        path.iter()
+            .try_fold(&manifest, |value, key| value.get(*key))
+            .and_then(serde_yaml::Value::as_str)
+    };
+    if !manifest.is_mapping() {
+        return Err("manifest must be a YAML mapping".to_string());
+    }
+    for path in [&["apiVersion"][..], &["kind"][..]] {
+        if field(path).is_none_or(str::is_empty) {
+            return Err(format!("'{}' is required", path.join(".")));
+        }
+    }
+    if field(&["kind"]) != Some(kind) {
+        return Err(format!("kind must stay '{kind}'"));
+    }
+    if field(&["metadata", "name"]) != Some(name) {
+        return Err(format!("metadata.name must stay '{name}'"));
+    }
+    if let Some(edited) = field(&["metadata", "namespace"])
+        && Some(edited) != namespace
+    {
+        return Err(format!(
+            "metadata.namespace must stay '{}'",
+            namespace.unwrap_or("")
+        ));
+    }
+    Ok(())
+}
+
+pub fn clean_manifest_yaml(detail: &str) -> Result<String, serde_yaml::Error> {
+    let mut manifest: serde_yaml::Value = serde_yaml::from_str(detail)?;
+    if let Some(root) = manifest.as_mapping_mut() {
+        root.remove("status");
+        if let Some(metadata) = root
+            .get_mut("metadata")
+            .and_then(serde_yaml::Value::as_mapping_mut)
+        {
+            for key in SCRATCH_METADATA_NOISE {
+                metadata.remove(key);
+            }
+            let annotations_empty = metadata
+                .get_mut("annotations")
+                .and_then(serde_yaml::Value::as_mapping_mut)
+                .map(|annotations| {
+                    annotations.remove("kubectl.kubernetes.io/last-applied-configuration");
+                    annotations.is_empty()
+                })
+                .unwrap_or(false);
+            if annotations_empty {
+                metadata.remove("annotations");
+            }
+        }
+    }
+    serde_yaml::to_string(&manifest)
+}
+
+fn render_watch_activity(entries: &VecDeque<WatchActivity>) -> String {
+    if entries.is_empty() {
+        return "No watch events yet (objects created, updated or deleted while orca runs show up here)"
+            .to_string();
+    }
+    entries
+        .iter()
+        .rev()
+        .map(WatchActivity::line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_port_forwards(sessions: &[PortForwardSession], cursor: usize) -> String {
+    if sessions.is_empty() {
+        return "No active port-forwards (start one with :pf <local>:<remote>)".to_string();
+    }
+    let mut lines = vec![format!(
+        "  {:<10} {:<40} {:>13}  {}",
+        "KIND", "TARGET", "LOCAL:REMOTE", "PID"
+    )];
+    for (index, session) in sessions.iter().enumerate() {
+        lines.push(format!(
+            "{} {:<10} {:<40} {:>13}  {}",
+            if index == cursor { "▶" } else { " " },
+            session.tab.short_token(),
+            table_cell(&format!("{}/{}", session.namespace, session.name), 40),
+            format!("{}:{}", session.local_port, session.remote_port),
+            session.pid
+        ));
+    }
+    lines.push(String::new());
+    lines.push("j/k select  x stop selected  Esc close".to_string());
+    lines.join("\n")
+}
+
+const ALWAYS_CONFIRM_ACTIONS: [&str; 3] = ["delete", "drain", "rerun job"];
+const BULK_TARGET_PREVIEW: usize = 12;
+
+fn bulk_target_lines(targets: &[(Option<String>, String)]) -> Vec<String> {
+    let mut lines = targets
+        .iter()
+        .take(BULK_TARGET_PREVIEW)
+        .map(|(namespace, name)| match namespace {
+            Some(namespace) => format!("  {namespace}/{name}"),
+            None => format!("  {name}"),
+        })
+        .collect::<Vec<_>>();
+    if targets.len() > BULK_TARGET_PREVIEW {
+        lines.push(format!(
+            "  ... and {} more",
+            targets.len() - BULK_TARGET_PREVIEW
+        ));
+    }
+    lines
+}
+
+fn delete_propagation_line(command: &AppCommand) -> String {
+    if matches!(command, AppCommand::ForceDeletePod { .. }) {
+        return "Cascade:   foreground, grace period 0 (force delete)".to_string();
+    }
+    let propagation = match command {
+        AppCommand::DeleteSelected { propagation, .. }
+        | AppCommand::DeleteCustomResource { propagation, .. }
+        | AppCommand::BulkDelete { propagation, .. } => *propagation,
+        _ => DeletePropagation::default(),
+    };
+    format!("Cascade:   {}  (Tab cycles)", propagation.label())
+}
+
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.trim();
+    let Some((prefix, rest)) = pattern.split_once('*') else {
+        return pattern == text;
+    };
+    let Some(mut remaining) = text.strip_prefix(prefix) else {
+        return false;
+    };
+    let mut parts = rest.split('*').peekable();
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            return remaining.ends_with(part);
+        }
+        match remaining.find(part) {
+            Some(index) => remaining = &remaining[index + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+fn supports_xray(tab: ResourceTab) -> bool {
+    matches!(
+        tab,
+        ResourceTab::Pods
+            | ResourceTab::CronJobs
+            | ResourceTab::DaemonSets
+            | ResourceTab::Deployments
+            | ResourceTab::ReplicaSets
+            | ResourceTab::ReplicationControllers
+            | ResourceTab::StatefulSets
+            | ResourceTab::Jobs
+            | ResourceTab::Services
+            | ResourceTab::Nodes
+            | ResourceTab::Namespaces
+    )
+}
+
+fn parse_namespaced_target(input: &str) -> Option<(&str, String)> {
+    let (namespace, name) = input.split_once('/')?;
+    let namespace = namespace.trim();
+    let name = name.trim();
+    if namespace.is_empty() || name.is_empty() {
+        return None;
+    }
+    Some((namespace, name.to_string()))
+}
+
+fn parse_namespace_target(input: &str) -> String {
+    if let Some((_, name)) = parse_namespaced_target(input) {
+        return name;
+    }
+    input.trim().to_string()
+}
+
+fn parse_shell_args(args: Vec<String>) -> (Option<String>, String) {
+    match args.as_slice() {
+        [] => (None, "auto".to_string()),
+        [single] => {
+            if is_shell_token(single) {
+                (None, normalize_shell_token(single))
+            } else {
+                (Some(single.clone()), "auto".to_string())
+            }
+        }
+        [container, shell, ..] => (Some(container.clone()), normalize_shell_token(shell)),
+    }
+}
+
+fn looks_like_repo_locator(input: &str) -> bool {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return false;
+    }
+    trimmed.starts_with("http://")
+        || trimmed.starts_with("https://")
+        || trimmed.starts_with("ssh://")
+        || trimmed.starts_with("git@")
+        || trimmed.ends_with(".git")
+}
+
+fn is_shell_token(token: &str) -> bool {
+    matches!(token, "sh" | "bash" | "auto") || token.starts_with('/')
+}
+
+fn normalize_shell_token(token: &str) -> String {
+    match token {
+        "sh" => "/bin/sh".to_string(),
+        "bash" => "/bin/bash".to_string(),
+        "auto" => "auto".to_string(),
+        _ => token.to_string(),
+    }
+}
+
+fn filter_completions(mut candidates: Vec<String>, input: &str, limit: usize) -> Vec<String> {
+    candidates.sort();
+    candidates.dedup();
+
+    let query = normalize_mode_prefixed_input(input).to_ascii_lowercase();
+    if !query.is_empty() {
+        candidates = candidates
+            .into_iter()
+            .filter(|candidate| completion_matches(candidate, &query))
+            .collect::<Vec<_>>();
+    }
+
+    candidates.truncate(limit);
+    candidates
+}
+
+fn normalize_mode_prefixed_input(input: &str) -> String {
+    let mut query = input.trim();
+    while let Some(stripped) = query.strip_prefix(':').or_else(|| query.strip_prefix('>')) {
+        query = stripped.trim_start();
+    }
+    query.to_string()
+}
+
+fn resource_reference_entries(
+    row: &RowData,
+    kubectl_resource: Option<(String, bool)>,
+) -> Vec<ReferenceMenuEntry> {
+    let object = serde_yaml::from_str::<serde_yaml::Value>(&row.detail).ok();
+    let field = |key: &str| {
+        object
+            .as_ref()
+            .and_then(|value| value.get(key))
+            .and_then(serde_yaml::Value::as_str)
+            .map(str::to_string)
+    };
+    let mut entries = Vec::new();
+
+    if let Some((resource, namespaced)) = kubectl_resource {
+        let value = match row.namespace.as_deref() {
+            Some(namespace) if namespaced => {
+                format!("{resource}/{} -n {namespace}", row.name)
+            }
+            _ => format!("{resource}/{}", row.name),
+        };
+        entries.push(ReferenceMenuEntry {
+            label: "kubectl".to_string(),
+            value,
+        });
+    }
+
+    let selector = object
+        .as_ref()
+        .and_then(reference_selector_labels)
+        .unwrap_or_else(|| row.labels.clone());
+    if !selector.is_empty() {
+        let value = selector
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        entries.push(ReferenceMenuEntry {
+            label: "selector".to_string(),
+            value,
+        });
+    }
+
+    if let (Some(api_version), Some(kind)) = (field("apiVersion"), field("kind")) {
+        let group = api_version
+            .split_once('/')
+            .map(|(group, _)| group)
+            .unwrap_or("");
+        entries.push(ReferenceMenuEntry {
+            label: "argo".to_string(),
+            value: format!("{group}:{kind}:{}", row.name),
+        });
+    }
+
+    entries
+}
+
+fn reference_selector_labels(object: &serde_yaml::Value) -> Option<BTreeMap<String, String>> {
+    let selector = object.get("spec")?.get("selector")?;
+    let labels = selector.get("matchLabels").unwrap_or(selector);
+    let labels = labels
+        .as_mapping()?
+        .iter()
+        .filter_map(|(key, value)| Some((key.as_str()?.to_string(), value.as_str()?.to_string())))
+        .collect::<BTreeMap<_, _>>();
+    (!labels.is_empty()).then_some(labels)
+}
+
+pub fn render_table_text(table: &TableData) -> String {
+    let mut widths = table
+        .headers
+        .iter()
+        .map(|header| header.chars().count())
+        .collect::<Vec<_>>();
+    for row in &table.rows {
+        for (index, column) in row.columns.iter().enumerate().take(widths.len()) {
+            widths[index] = widths[index].max(column.chars().count());
+        }
+    }
+    let format_line = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![format_line(&table.headers)];
+    lines.extend(table.rows.iter().map(|row| format_line(&row.columns)));
+    if table.rows.is_empty() {
+        lines.push("(no rows)".to_string());
+    }
+    lines.join("\n")
+}
+
+fn table_cell(value: &str, width: usize) -> String {
+    let count = value.chars().count();
+    if count <= width {
+        return value.to_string();
+    }
+
+    if width <= 1 {
+        return "…".to_string();
+    }
+
+    let mut out = value
+        .chars()
+        .take(width.saturating_sub(1))
+        .collect::<String>();
+    out.push('…');
+    out
+}
+
+fn completion_matches(candidate: &str, query: &str) -> bool {
+    let lower = candidate.to_ascii_lowercase();
+    if lower.starts_with(query) {
+        return true;
+    }
+
+    let words = lower
+        .split(|ch: char| ch.is_ascii_whitespace() || matches!(ch, '/' | ':' | '-' | '.'))
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>();
+    query
+        .split_whitespace()
+        .all(|token| words.iter().any(|word| word.starts_with(token)))
+}
+
+fn summarize_error_line(error: &str) -> String {
+    error
+        .lines()
+        .find(|line| !line.trim().is_empty())
+        .map(|line| line.trim().to_string())
+        .unwrap_or_else(|| "unknown error".to_string())
+}
+
+fn normalize_status_text(status: String) -> String {
+    if status.contains("(y/n)") || status.contains("[y/n]") {
+        return status;
+    }
+    const MAX_STATUS_LEN: usize = 180;
+    if status.chars().count() <= MAX_STATUS_LEN {
+        return status;
+    }
+
+    let mut shortened = status
+        .chars()
+        .take(MAX_STATUS_LEN.saturating_sub(1))
+        .collect::<String>();
+    shortened.push('…');
+    shortened
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        App, AppCommand, ArgoResourcePanelSection, DEFAULT_LOG_TAIL, DEFAULT_LOG_WINDOW_SECS,
+        DangerousCommandRule, DetailPaneMode, HelmReleaseSection, HotkeyCommandDef, InputMode,
+        OpsInspectTarget, PluginCommandDef, PluginRun, TableOverlayKind, WATCH_ACTIVITY_LIMIT,
+        normalize_mode_prefixed_input, normalize_status_text, parse_named_port_mapping,
+        parse_port_mapping, render_table_text, resource_reference_entries, wildcard_match,
+    };
+    use crate::input::Action;
+    use crate::model::{
+        ArgoNamespaceLive, ContextCatalogRow, CustomResourceDef, DeletePropagation, LogWindow,
+        NamespaceCounts, NamespaceScope, OverviewMetrics, ResourceTab, RowData, SecretEntry,
+        TableData, UsageSort,
+    };
+    use chrono::Local;
+    use std::collections::{BTreeMap, HashMap, HashSet};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn filter_command_sets_filter() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "filter api".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::None);
+        assert_eq!(app.filter(), "api");
+    }
+
+    #[test]
+    fn tools_command_requests_tooling_inspection() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "tools".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::InspectTooling);
+    }
+
+    #[test]
+    fn git_command_without_args_opens_catalog() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "git".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::GitCatalog
+            }
+        );
+    }
+
+    #[test]
+    fn git_fetch_url_builds_target() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "git fetch https://github.com/example/app.git main".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::GitFetch {
+                    repo: "https://github.com/example/app.git".to_string(),
+                    reference: Some("main".to_string()),
+                    full: false,
+                }
+            }
+        );
+
+        assert_eq!(
+            run_command_line(&mut app, "git fetch --full app v1.2.0"),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::GitFetch {
+                    repo: "app".to_string(),
+                    reference: Some("v1.2.0".to_string()),
+                    full: true,
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn argocd_command_switches_to_argo_apps_tab() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "argocd".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::RefreshActive);
+        assert_eq!(app.active_tab(), ResourceTab::ArgoCdApps);
+    }
+
+    #[test]
+    fn app_starts_in_orca_tab() {
+        let app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        assert_eq!(app.active_tab(), ResourceTab::Orca);
+    }
+
+    #[test]
+    fn k8s_command_switches_to_pods_tab() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        app.apply_action(Action::StartCommand);
+        for c in "k8s".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::RefreshActive);
+        assert_eq!(app.active_tab(), ResourceTab::Pods);
+    }
+
+    #[test]
+    fn orca_command_switches_to_orca_tab() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+        app.apply_action(Action::StartCommand);
+        for c in "orca".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::RefreshActive);
+        assert_eq!(app.active_tab(), ResourceTab::Orca);
+    }
+
+    #[test]
+    fn argocd_with_app_name_switches_to_resource_tab() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "argocd guestbook".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::RefreshActive);
+        assert_eq!(app.active_tab(), ResourceTab::ArgoCdResources);
+        assert_eq!(app.argocd_selected_app(), Some("guestbook"));
+    }
+
+    #[test]
+    fn open_shell_from_argocd_pod_node_targets_selected_pod() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+        let mut resources = TableData::default();
+        resources.set_rows(
+            vec![
+                "Tree".to_string(),
+                "Namespace".to_string(),
+                "Name".to_string(),
+            ],
+            vec![RowData {
+                name: "Pod/guestbook-ui-6595f948db-abcde".to_string(),
+                namespace: Some("argocd-demo".to_string()),
+                columns: vec![
+                    "└── Pod".to_string(),
+                    "argocd-demo".to_string(),
+                    "guestbook-ui-6595f948db-abcde".to_string(),
+                ],
+                detail: "kind: Pod".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::ArgoCdResources, resources);
+        app.switch_to_tab(ResourceTab::ArgoCdResources);
+
+        let cmd = app.apply_action(Action::OpenPodShell);
+        assert_eq!(
+            cmd,
+            AppCommand::OpenPodShell {
+                namespace: "argocd-demo".to_string(),
+                pod_name: "guestbook-ui-6595f948db-abcde".to_string(),
+                container: None,
+                shell: "auto".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn load_logs_from_argocd_pod_node_targets_selected_pod() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+        let mut resources = TableData::default();
+        resources.set_rows(
+            vec![
+                "Tree".to_string(),
+                "Namespace".to_string(),
+                "Name".to_string(),
+            ],
+            vec![RowData {
+                name: "Pod/guestbook-ui-6595f948db-abcde".to_string(),
+                namespace: Some("argocd-demo".to_string()),
+                columns: vec![
+                    "└── Pod".to_string(),
+                    "argocd-demo".to_string(),
+                    "guestbook-ui-6595f948db-abcde".to_string(),
+                ],
+                detail: "kind: Pod".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::ArgoCdResources, resources);
+        app.switch_to_tab(ResourceTab::ArgoCdResources);
+
+        let cmd = app.apply_action(Action::LoadPodLogs);
+        assert_eq!(
+            cmd,
+            AppCommand::LoadPodLogs {
+                namespace: "argocd-demo".to_string(),
+                pod_name: "guestbook-ui-6595f948db-abcde".to_string(),
+                container: None,
+                previous: false,
+                tail: DEFAULT_LOG_TAIL,
+                window: None,
+            }
+        );
+    }
+
+    #[test]
+    fn load_logs_from_argocd_deployment_node_targets_workload_logs() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+        let mut resources = TableData::default();
+        resources.set_rows(
+            vec![
+                "Tree".to_string(),
+                "Namespace".to_string(),
+                "Name".to_string(),
+            ],
+            vec![RowData {
+                name: "Deployment/guestbook-ui".to_string(),
+                namespace: Some("argocd-demo".to_string()),
+                columns: vec![
+                    "󰹑 Deployment".to_string(),
+                    "argocd-demo".to_string(),
+                    "guestbook-ui".to_string(),
+                ],
+                detail: "kind: Deployment".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::ArgoCdResources, resources);
+        app.switch_to_tab(ResourceTab::ArgoCdResources);
+
+        let cmd = app.apply_action(Action::LoadPodLogs);
+        assert_eq!(
+            cmd,
+            AppCommand::LoadResourceLogs {
+                tab: ResourceTab::Deployments,
+                namespace: Some("argocd-demo".to_string()),
+                name: "guestbook-ui".to_string(),
+                previous: false,
+                tail: DEFAULT_LOG_TAIL,
+                window: None,
+            }
+        );
+    }
+
+    #[test]
+    fn edit_on_argocd_resource_opens_events_section() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+        let mut resources = TableData::default();
+        resources.set_rows(
+            vec![
+                "Tree".to_string(),
+                "Namespace".to_string(),
+                "Name".to_string(),
+            ],
+            vec![RowData {
+                name: "ReplicaSet/guestbook-ui-6595f948db".to_string(),
+                namespace: Some("argocd-demo".to_string()),
+                columns: vec![
+                    "└─󰹍 ReplicaSe".to_string(),
+                    "argocd-demo".to_string(),
+                    "guestbook-ui-6595f948db".to_string(),
+                ],
+                detail: "kind: ReplicaSet".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::ArgoCdResources, resources);
+        app.switch_to_tab(ResourceTab::ArgoCdResources);
+
+        let cmd = app.apply_action(Action::EditResource);
+        assert_eq!(
+            cmd,
+            AppCommand::LoadArgoResourcePanelSection {
+                kind: "ReplicaSet".to_string(),
+                namespace: Some("argocd-demo".to_string()),
+                name: "guestbook-ui-6595f948db".to_string(),
+                section: ArgoResourcePanelSection::Events,
+            }
+        );
+    }
+
+    #[test]
+    fn edit_on_argocd_apps_uses_kubectl_edit_flow() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+        let mut apps = TableData::default();
+        apps.set_rows(
+            vec![
+                "Name".to_string(),
+                "Project".to_string(),
+                "Namespace".to_string(),
+            ],
+            vec![RowData {
+                name: "guestbook".to_string(),
+                namespace: Some("argocd".to_string()),
+                columns: vec![
+                    "guestbook".to_string(),
+                    "default".to_string(),
+                    "argocd-demo".to_string(),
+                ],
+                detail: "kind: Application".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::ArgoCdApps, apps);
+        app.switch_to_tab(ResourceTab::ArgoCdApps);
+
+        let cmd = app.apply_action(Action::EditResource);
+        assert_eq!(
+            cmd,
+            AppCommand::EditSelected {
+                resource: "applications.argoproj.io".to_string(),
+                namespace: Some("argocd".to_string()),
+                name: "guestbook".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn m_on_argocd_resource_opens_manifest_section() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+        let mut resources = TableData::default();
+        resources.set_rows(
+            vec![
+                "Tree".to_string(),
+                "Namespace".to_string(),
+                "Name".to_string(),
+            ],
+            vec![RowData {
+                name: "Service/guestbook-ui".to_string(),
+                namespace: Some("argocd-demo".to_string()),
+                columns: vec![
+                    "󰒓 Service".to_string(),
+                    "argocd-demo".to_string(),
+                    "guestbook-ui".to_string(),
+                ],
+                detail: "kind: Service".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::ArgoCdResources, resources);
+        app.switch_to_tab(ResourceTab::ArgoCdResources);
+
+        let cmd = app.apply_action(Action::ShowManifest);
+        assert_eq!(
+            cmd,
+            AppCommand::LoadArgoResourcePanelSection {
+                kind: "Service".to_string(),
+                namespace: Some("argocd-demo".to_string()),
+                name: "guestbook-ui".to_string(),
+                section: ArgoResourcePanelSection::Manifest,
+            }
+        );
+    }
+
+    #[test]
+    fn argo_panel_sections_cycle_through_diff() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::All,
+        );
+        app.set_argo_panel_section_overlay(
+            "Service",
+            Some("argocd-demo"),
+            "guestbook-ui",
+            ArgoResourcePanelSection::Manifest,
+            "LIVE MANIFEST\nkind: Service".to_string(),
+        );
+        assert_eq!(
+            app.table_overlay_title(),
+            Some("Argo Service argocd-demo/guestbook-ui  events [manifest] diff  ([/] switch)")
+        );
+        let load = |section| AppCommand::LoadArgoResourcePanelSection {
+            kind: "Service".to_string(),
+            namespace: Some("argocd-demo".to_string()),
+            name: "guestbook-ui".to_string(),
+            section,
+        };
+        assert_eq!(
+            app.apply_action(Action::NewerLogWindow),
+            load(ArgoResourcePanelSection::Diff)
+        );
+        app.set_argo_panel_section_overlay(
+            "Service",
+            Some("argocd-demo"),
+            "guestbook-ui",
+            ArgoResourcePanelSection::Diff,
+            "DIFF guestbook".to_string(),
+        );
+        assert_eq!(app.table_overlay_kind(), Some(TableOverlayKind::Diff));
+        assert_eq!(
+            app.apply_action(Action::NewerLogWindow),
+            load(ArgoResourcePanelSection::Events)
+        );
+
+        app.set_output_overlay("Other", "text".to_string());
+        assert_ne!(
+            app.apply_action(Action::NewerLogWindow),
+            load(ArgoResourcePanelSection::Events)
+        );
+    }
+
+    #[test]
+    fn argocd_projects_switches_to_projects_tab() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "argocd projects".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::RefreshActive);
+        assert_eq!(app.active_tab(), ResourceTab::ArgoCdProjects);
+    }
+
+    #[test]
+    fn argocd_sync_builds_ops_target() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "argocd sync guestbook".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::ArgoCdSync {
+                    name: "guestbook".to_string(),
+                    prune: false,
+                    dry_run: false,
+                    resource: None,
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn argocd_create_confirms_with_scope_namespace_and_default_project() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("shop".to_string()),
+        );
+        assert_eq!(
+            run_command_line(&mut app, "argocd create web"),
+            AppCommand::None
+        );
+        assert!(app.status().starts_with("Usage: :argocd create"));
+
+        assert_eq!(
+            run_command_line(&mut app, "argocd create web platform-manifests apps/web"),
+            AppCommand::None
+        );
+        assert_eq!(
+            app.pending_confirmation_prompt(),
+            Some(
+                "Create Argo CD app web from platform-manifests path apps/web -> namespace shop (project default)"
+            )
+        );
+        assert_eq!(
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::ArgoCdCreate {
+                    name: "web".to_string(),
+                    repo: "platform-manifests".to_string(),
+                    path: "apps/web".to_string(),
+                    dest_namespace: "shop".to_string(),
+                    project: "default".to_string(),
+                }
+            }
+        );
+
+        app.set_read_only(true);
+        assert_eq!(
+            run_command_line(
+                &mut app,
+                "argocd create web platform-manifests apps/web web team"
+            ),
+            AppCommand::None
+        );
+        assert!(app.pending_confirmation_prompt().is_none());
+    }
+
+    #[test]
+    fn argocd_sync_options_gate_prune_and_skip_guard_for_dry_run() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        assert_eq!(
+            run_command_line(
+                &mut app,
+                "argocd sync guestbook --dry-run --resource apps:Deployment:guestbook-ui"
+            ),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::ArgoCdSync {
+                    name: "guestbook".to_string(),
+                    prune: false,
+                    dry_run: true,
+                    resource: Some("apps:Deployment:guestbook-ui".to_string()),
+                }
+            }
+        );
+
+        assert_eq!(
+            run_command_line(&mut app, "argocd sync guestbook --prune"),
+            AppCommand::None
+        );
+        assert_eq!(
+            app.pending_confirmation_prompt(),
+            Some("Sync Argo CD app guestbook (prune); pruning deletes resources missing from git")
+        );
+        assert_eq!(
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::ArgoCdSync {
+                    name: "guestbook".to_string(),
+                    prune: true,
+                    dry_run: false,
+                    resource: None,
+                }
+            }
+        );
+
+        assert_eq!(
+            run_command_line(&mut app, "argocd sync guestbook --force"),
+            AppCommand::None
+        );
+        assert!(
+            app.status()
+                .starts_with("Unknown argocd sync option '--force'")
+        );
+
+        app.set_read_only(true);
+        assert_eq!(
+            run_command_line(&mut app, "argocd sync guestbook"),
+            AppCommand::None
+        );
+        assert!(matches!(
+            run_command_line(&mut app, "argocd sync guestbook --prune --dry-run"),
+            AppCommand::InspectOps { .. }
+        ));
+    }
+
+    #[test]
+    fn argocd_rollback_accepts_id_and_app() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "argocd rollback 3 guestbook".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::ArgoCdRollback {
+                    name: "guestbook".to_string(),
+                    id: "3".to_string(),
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn helm_release_command_requests_release_overlay() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "helm my-release".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::HelmRelease {
+                    name: "my-release".to_string(),
+                    section: HelmReleaseSection::Status,
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn terraform_plan_takes_dir_and_timeout() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        assert_eq!(
+            run_command_line(&mut app, "tf plan"),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::TerraformPlan {
+                    dir: ".".to_string(),
+                    timeout_secs: 600,
+                }
+            }
+        );
+        app.set_terraform_plan_timeout(Some(1_200));
+        assert_eq!(
+            run_command_line(&mut app, "terraform plan infra/prod"),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::TerraformPlan {
+                    dir: "infra/prod".to_string(),
+                    timeout_secs: 1_200,
+                }
+            }
+        );
+        assert_eq!(
+            run_command_line(&mut app, "tf plan infra/prod 90"),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::TerraformPlan {
+                    dir: "infra/prod".to_string(),
+                    timeout_secs: 90,
+                }
+            }
+        );
+        assert_eq!(run_command_line(&mut app, "tf apply"), AppCommand::None);
+    }
+
+    #[test]
+    fn terraform_state_picker_shows_selected_address() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        assert_eq!(
+            run_command_line(&mut app, "tf state infra"),
+            AppCommand::LoadTerraformState {
+                dir: "infra".to_string()
+            }
+        );
+        app.open_terraform_state_picker(
+            "infra".to_string(),
+            vec![
+                "aws_s3_bucket.logs".to_string(),
+                "aws_iam_role.ci".to_string(),
+            ],
+        );
+        assert!(app.terraform_state_picker_active());
+        app.apply_action(Action::Down);
+        assert_eq!(
+            app.apply_action(Action::EnterResource),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::TerraformStateShow {
+                    dir: "infra".to_string(),
+                    address: "aws_iam_role.ci".to_string(),
+                }
+            }
+        );
+        assert!(!app.terraform_state_picker_active());
+
+        assert_eq!(
+            run_command_line(&mut app, "tf state infra"),
+            AppCommand::None
+        );
+        assert_eq!(
+            app.terraform_state_picker()
+                .map(|(_, _, selected)| selected),
+            Some(1)
+        );
+        app.apply_action(Action::ClearDetailOverlay);
+        assert_eq!(
+            run_command_line(&mut app, "tf state refresh infra"),
+            AppCommand::LoadTerraformState {
+                dir: "infra".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn kustomize_dry_run_is_direct_but_apply_needs_confirmation() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("apps".to_string()),
+        );
+        assert_eq!(
+            run_command_line(&mut app, "kustomize overlays/prod"),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::KustomizeBuild {
+                    path: "overlays/prod".to_string(),
+                }
+            }
+        );
+        assert_eq!(
+            run_command_line(&mut app, "kustomize diff"),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::KustomizeDiff {
+                    path: ".".to_string(),
+                }
+            }
+        );
+
+        assert_eq!(
+            run_command_line(&mut app, "kustomize apply overlays/prod"),
+            AppCommand::None
+        );
+        assert_eq!(
+            app.pending_confirmation_prompt(),
+            Some("Apply kustomize build of overlays/prod to namespace apps")
+        );
+        assert_eq!(
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::KustomizeApply {
+                    path: "overlays/prod".to_string(),
+                }
+            }
+        );
+
+        app.set_read_only(true);
+        assert_eq!(
+            run_command_line(&mut app, "kustomize apply overlays/prod"),
+            AppCommand::None
+        );
+        assert!(app.pending_confirmation_prompt().is_none());
+    }
+
+    #[test]
+    fn helm_rollback_needs_confirmation_and_write_access() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("apps".to_string()),
+        );
+        assert_eq!(
+            run_command_line(&mut app, "helm history web"),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::HelmHistory {
+                    name: "web".to_string(),
+                }
+            }
+        );
+        assert_eq!(
+            run_command_line(&mut app, "helm rollback web two"),
+            AppCommand::None
+        );
+        assert!(app.status().starts_with("Usage: :helm rollback"));
+
+        assert_eq!(
+            run_command_line(&mut app, "helm rollback web 3"),
+            AppCommand::None
+        );
+        assert_eq!(
+            app.pending_confirmation_prompt(),
+            Some("Roll back helm release apps/web to revision 3")
+        );
+        assert_eq!(
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::HelmRollback {
+                    name: "web".to_string(),
+                    revision: "3".to_string(),
+                }
+            }
+        );
+
+        app.set_read_only(true);
+        assert_eq!(
+            run_command_line(&mut app, "helm rollback web 3"),
+            AppCommand::None
+        );
+        assert!(app.pending_confirmation_prompt().is_none());
+        assert!(app.status().contains("Read-only"));
+    }
+
+    #[test]
+    fn helm_release_sections_load_by_name_and_cycle_in_overlay() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        assert_eq!(
+            run_command_line(&mut app, "helm web values"),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::HelmRelease {
+                    name: "web".to_string(),
+                    section: HelmReleaseSection::Values,
+                }
+            }
+        );
+        assert_eq!(
+            run_command_line(&mut app, "helm web chart"),
+            AppCommand::None
+        );
+
+        app.set_helm_release_overlay("web", HelmReleaseSection::Values, "replicas: 2".to_string());
+        assert!(
+            app.table_overlay_title()
+                .is_some_and(|title| title.contains("[values]"))
+        );
+        assert_eq!(
+            app.apply_action(Action::NewerLogWindow),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::HelmRelease {
+                    name: "web".to_string(),
+                    section: HelmReleaseSection::Manifest,
+                }
+            }
+        );
+        assert_eq!(
+            app.apply_action(Action::OlderLogWindow),
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::HelmRelease {
+                    name: "web".to_string(),
+                    section: HelmReleaseSection::Status,
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn rbac_command_requests_rbac_overlay() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "rbac".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::RbacMatrix { subject: None }
+            }
+        );
+    }
+
+    #[test]
+    fn who_can_command_requests_lookup() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "who-can get pods".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::InspectOps {
+                target: OpsInspectTarget::WhoCan {
+                    verb: "get".to_string(),
+                    resource: "pods".to_string(),
+                    namespace: None,
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn pulses_command_requests_pulses_overlay() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "pulses".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::InspectPulses);
+    }
+
+    #[test]
+    fn alerts_command_requests_alerts_overlay() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "alerts".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::InspectAlerts);
+    }
+
+    #[test]
+    fn xray_command_uses_selected_resource() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+        let mut pods = TableData::default();
+        pods.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "api-123".to_string(),
+                namespace: Some("orca-sandbox".to_string()),
+                columns: vec!["api-123".to_string()],
+                detail: "kind: Pod".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::Pods, pods);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+
+        app.apply_action(Action::StartCommand);
+        for c in "xray".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::InspectXray {
+                tab: ResourceTab::Pods,
+                namespace: Some("orca-sandbox".to_string()),
+                name: "api-123".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn readonly_command_enables_read_only_mode() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "readonly on".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::None);
+        assert!(app.read_only());
+    }
+
+    #[test]
+    fn readonly_mode_blocks_scale_command() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        app.set_read_only(true);
+
+        let now = Local::now();
+        let mut deployments = TableData::default();
+        deployments.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "api".to_string(),
+                namespace: Some("orca-sandbox".to_string()),
+                columns: vec!["api".to_string()],
+                detail: "kind: Deployment".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::Deployments, deployments);
+        let _ = app.switch_to_tab(ResourceTab::Deployments);
+
+        app.apply_action(Action::StartCommand);
+        for c in "scale 3".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::None);
+        assert!(app.status().contains("Read-only mode ON"));
+    }
+
+    #[test]
+    fn runtime_alias_expands_to_target_command() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let mut aliases = HashMap::new();
+        aliases.insert("dpl".to_string(), "deploy".to_string());
+        app.set_runtime_config(aliases, Vec::new(), Vec::new(), Some("test".to_string()));
+
+        app.apply_action(Action::StartCommand);
+        for c in "dpl".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let _ = app.apply_action(Action::SubmitInput);
+        assert_eq!(app.active_tab(), ResourceTab::Deployments);
+    }
+
+    #[test]
+    fn plugin_command_builds_run_command_with_placeholders() {
+        let mut app = App::new(
+            "clusterA".to_string(),
+            "contextA".to_string(),
+            NamespaceScope::Named("orca-sandbox".to_string()),
+        );
+        app.set_user("alice".to_string());
+
+        let now = Local::now();
+        let mut pods = TableData::default();
+        pods.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "api-123".to_string(),
+                namespace: Some("orca-sandbox".to_string()),
+                columns: vec!["api-123".to_string()],
+                detail: "kind: Pod".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::Pods, pods);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+
+        let plugin = PluginCommandDef {
+            name: "diag".to_string(),
+            command: "kubectl".to_string(),
+            args: vec![
+                "get".to_string(),
+                "pod".to_string(),
+                "{name}".to_string(),
+                "-n".to_string(),
+                "{namespace}".to_string(),
+                "{extra}".to_string(),
+            ],
+            description: "diag".to_string(),
+            mutating: false,
+            timeout_secs: 15,
+            retries: 2,
+            stream: false,
+            confirm: None,
+        };
+        app.set_runtime_config(
+            HashMap::new(),
+            vec![plugin],
+            Vec::new(),
+            Some("test".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "plugin diag -o yaml".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::RunPlugin {
+                run: PluginRun {
+                    name: "diag".to_string(),
+                    program: "kubectl".to_string(),
+                    args: vec![
+                        "get".to_string(),
+                        "pod".to_string(),
+                        "api-123".to_string(),
+                        "-n".to_string(),
+                        "orca-sandbox".to_string(),
+                        "-o".to_string(),
+                        "yaml".to_string()
+                    ],
+                    mutating: false,
+                    timeout_secs: 15,
+                    retries: 2,
+                    stream: false,
+                    env: vec![
+                        ("ORCA_CONTEXT".to_string(), "contextA".to_string()),
+                        ("ORCA_SELECTED_KIND".to_string(), "Pod".to_string()),
+                        (
+                            "ORCA_SELECTED_NAMESPACE".to_string(),
+                            "orca-sandbox".to_string()
+                        ),
+                        ("ORCA_SELECTED_NAME".to_string(), "api-123".to_string()),
+                    ]
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn plugin_program_expands_scope_placeholders_and_keeps_unknown_ones() {
+        let mut app = App::new(
+            "eu-west".to_string(),
+            "prod-eu".to_string(),
+            NamespaceScope::Named("payments".to_string()),
+        );
+        let _ = app.switch_to_tab(ResourceTab::Nodes);
+        app.set_runtime_config(
+            HashMap::new(),
+            vec![PluginCommandDef {
+                name: "dash".to_string(),
+                command: "./bin/{cluster}-dash".to_string(),
+                args: vec![
+                    "--ns={namespace}".to_string(),
+                    "--ctx={context}".to_string(),
+                    "{grafana_url}".to_string(),
+                ],
+                description: String::new(),
+                mutating: false,
+                timeout_secs: 5,
+                retries: 0,
+                stream: false,
+                confirm: None,
+            }],
+            Vec::new(),
+            None,
+        );
+        let AppCommand::RunPlugin { run } = run_command_line(&mut app, "plugin dash") else {
+            panic!("expected plugin run");
+        };
+        assert_eq!(run.program, "./bin/eu-west-dash");
+        assert_eq!(
+            run.args,
+            vec!["--ns=payments", "--ctx=prod-eu", "{grafana_url}"]
+        );
+    }
+
+    #[test]
+    fn plugin_confirmation_follows_mutating_flag_and_override() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let plugin = |name: &str, mutating: bool, confirm: Option<bool>| PluginCommandDef {
+            name: name.to_string(),
+            command: "kubectl".to_string(),
+            args: vec!["get".to_string(), "pods".to_string()],
+            description: String::new(),
+            mutating,
+            timeout_secs: 5,
+            retries: 0,
+            stream: false,
+            confirm,
+        };
+        app.set_runtime_config(
+            HashMap::new(),
+            vec![
+                plugin("wipe", true, None),
+                plugin("heavy", false, Some(true)),
+                plugin("bounce", true, Some(false)),
+                plugin("peek", false, None),
+            ],
+            Vec::new(),
+            None,
+        );
+
+        assert_eq!(run_command_line(&mut app, "plugin wipe"), AppCommand::None);
+        assert_eq!(
+            app.pending_confirmation_prompt(),
+            Some("Run plugin 'wipe' (kubectl get pods)")
+        );
+        assert!(matches!(
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::RunPlugin { run } if run.name == "wipe"
+        ));
+
+        assert_eq!(run_command_line(&mut app, "plugin heavy"), AppCommand::None);
+        assert!(app.pending_confirmation_prompt().is_some());
+        app.apply_action(Action::ConfirmNo);
+
+        assert!(matches!(
+            run_command_line(&mut app, "plugin bounce"),
+            AppCommand::RunPlugin { .. }
+        ));
+        assert!(matches!(
+            run_command_line(&mut app, "plugin peek"),
+            AppCommand::RunPlugin { .. }
+        ));
+
+        app.set_read_only(true);
+        assert_eq!(run_command_line(&mut app, "plugin wipe"), AppCommand::None);
+        assert!(app.pending_confirmation_prompt().is_none());
+    }
+
+    #[test]
+    fn plugin_env_without_selection_only_carries_context() {
+        let mut app = App::new(
+            "clusterA".to_string(),
+            "contextA".to_string(),
+            NamespaceScope::All,
+        );
+        let _ = app.switch_to_tab(ResourceTab::Nodes);
+        assert_eq!(
+            app.plugin_env(),
+            vec![("ORCA_CONTEXT".to_string(), "contextA".to_string())]
+        );
+    }
+
+    #[test]
+    fn stream_output_only_updates_its_own_overlay() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        app.set_output_overlay("Plugin tail", "stream\n(waiting for output)".to_string());
+        assert!(app.replace_stream_output("Plugin tail", "stream\nline 1".to_string()));
+        assert_eq!(app.table_overlay_text(), Some("stream\nline 1"));
+
+        assert!(!app.replace_stream_output("Plugin other", "stream\nx".to_string()));
+        app.set_output_overlay("Alerts", "quiet".to_string());
+        assert!(!app.replace_stream_output("Plugin tail", "stream\nline 2".to_string()));
+        assert_eq!(app.table_overlay_text(), Some("quiet"));
+    }
+
+    #[test]
+    fn config_command_opens_runtime_config_overlay() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let mut aliases = HashMap::new();
+        aliases.insert("k".to_string(), "pods".to_string());
+        app.set_runtime_config(aliases, Vec::new(), Vec::new(), Some("test".to_string()));
+
+        app.apply_action(Action::StartCommand);
+        for c in "config".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::None);
+        assert!(app.table_overlay_active());
+        assert!(
+            app.table_overlay_text()
+                .unwrap_or_default()
+                .contains("aliases 1")
+        );
+    }
+
+    #[test]
+    fn runtime_hotkey_executes_bound_command() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        app.set_runtime_config(
+            HashMap::new(),
+            Vec::new(),
+            vec![HotkeyCommandDef {
+                key: "ctrl+shift+p".to_string(),
+                command: "pulses".to_string(),
+                jump: false,
+                description: "p".to_string(),
+            }],
+            Some("test".to_string()),
+        );
+
+        let command = app.execute_hotkey_signature("ctrl+shift+p");
+        assert_eq!(command, Some(AppCommand::InspectPulses));
+    }
+
+    #[test]
+    fn ns_command_requests_refresh_all() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "ns kube-system".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::RefreshAll);
+        assert_eq!(
+            app.namespace_scope(),
+            &NamespaceScope::Named("kube-system".to_string())
+        );
+    }
+
+    #[test]
+    fn namespaces_without_arg_switches_to_namespaces_tab() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "namespaces".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let _ = app.apply_action(Action::SubmitInput);
+        assert_eq!(app.active_tab(), ResourceTab::Namespaces);
+    }
+
+    #[test]
+    fn ns_without_arg_opens_namespace_picker() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        assert_eq!(
+            run_command_line(&mut app, "ns"),
+            AppCommand::RefreshTab {
+                tab: ResourceTab::Namespaces
+            }
+        );
+        assert!(app.namespace_picker_active());
+
+        let mut namespaces = TableData::default();
+        namespaces.set_rows(
+            vec!["Name".to_string()],
+            ["kube-system", "default", "apps"]
+                .iter()
+                .map(|name| RowData {
+                    name: name.to_string(),
+                    columns: vec![name.to_string()],
+                    ..RowData::default()
+                })
+                .collect(),
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Namespaces, namespaces);
+        assert_eq!(
+            app.namespace_picker_entries(),
+            vec![
+                NamespaceScope::All,
+                NamespaceScope::Named("apps".to_string()),
+                NamespaceScope::Named("default".to_string()),
+                NamespaceScope::Named("kube-system".to_string()),
+            ]
+        );
+        assert_eq!(app.namespace_picker_selected(), 2);
+
+        app.apply_action(Action::Down);
+        assert_eq!(
+            app.apply_action(Action::EnterResource),
+            AppCommand::RefreshAll
+        );
+        assert!(!app.namespace_picker_active());
+        assert_eq!(
+            app.namespace_scope(),
+            &NamespaceScope::Named("kube-system".to_string())
+        );
+
+        let _ = run_command_line(&mut app, "ns");
+        assert_eq!(app.namespace_picker_selected(), 3);
+        app.apply_action(Action::Top);
+        assert_eq!(
+            app.apply_action(Action::EnterResource),
+            AppCommand::RefreshAll
+        );
+        assert_eq!(app.namespace_scope(), &NamespaceScope::All);
+
+        let _ = run_command_line(&mut app, "ns");
+        assert_eq!(
+            app.apply_action(Action::ClearDetailOverlay),
+            AppCommand::None
+        );
+        assert_eq!(app.namespace_scope(), &NamespaceScope::All);
+    }
+
+    #[test]
+    fn bare_tab_token_switches_tab() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "deployments".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let _ = app.apply_action(Action::SubmitInput);
+        assert_eq!(app.active_tab(), ResourceTab::Deployments);
+    }
+
+    #[test]
+    fn namespace_alias_token_sets_scope() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "namespace:ns kube-system".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::RefreshAll);
+        assert_eq!(
+            app.namespace_scope(),
+            &NamespaceScope::Named("kube-system".to_string())
+        );
+    }
+
+    #[test]
+    fn scale_command_executes_without_confirmation() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+        let mut deployments = TableData::default();
+        deployments.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "web".to_string(),
+                namespace: Some("orca-sandbox".to_string()),
+                columns: vec!["web".to_string()],
+                detail: "kind: Deployment".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::Deployments, deployments);
+        let _ = app.switch_to_tab(ResourceTab::Deployments);
+
+        app.apply_action(Action::StartCommand);
+        for c in "scale 2".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::ScaleWorkload {
+                tab: ResourceTab::Deployments,
+                namespace: "orca-sandbox".to_string(),
+                name: "web".to_string(),
+                replicas: 2
+            }
+        );
+        assert!(app.pending_confirmation_prompt().is_none());
+    }
+
+    #[test]
+    fn prefixed_scale_command_executes_without_confirmation() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+        let mut deployments = TableData::default();
+        deployments.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "web".to_string(),
+                namespace: Some("orca-sandbox".to_string()),
+                columns: vec!["web".to_string()],
+                detail: "kind: Deployment".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::Deployments, deployments);
+        let _ = app.switch_to_tab(ResourceTab::Deployments);
+
+        app.apply_action(Action::StartCommand);
+        for c in ":scale 2".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::ScaleWorkload {
+                tab: ResourceTab::Deployments,
+                namespace: "orca-sandbox".to_string(),
+                name: "web".to_string(),
+                replicas: 2
+            }
+        );
+        assert!(app.pending_confirmation_prompt().is_none());
+    }
+
+    #[test]
+    fn command_completion_empty_does_not_block_submission() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        app.apply_action(Action::StartCommand);
+        for c in ":scale 999".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        // Ensure parse/submit still runs even if completion UI has no candidates.
+        let _ = app.completion_candidates();
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert!(matches!(
+            cmd,
+            AppCommand::ScaleWorkload { replicas: 999, .. } | AppCommand::None
+        ));
+    }
+
+    #[test]
+    fn jump_namespace_path_sets_scope_and_refreshes() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartJump);
+        for c in "ns openclaw/openclaw".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::RefreshAll);
+        assert_eq!(
+            app.namespace_scope(),
+            &NamespaceScope::Named("openclaw".to_string())
+        );
+        assert_eq!(app.filter(), "");
+    }
+
+    #[test]
+    fn ctx_command_returns_switch_context_command() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "ctx dev-cluster".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::SwitchContext {
+                context: "dev-cluster".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn prefixed_ctx_command_returns_switch_context_command() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in ":context dev-cluster".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::SwitchContext {
+                context: "dev-cluster".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn ctx_without_arg_opens_context_catalog_overlay() {
+        let mut app = App::new(
+            "https://cluster".to_string(),
+            "openclaw".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        app.set_kube_catalog(
+            vec!["openclaw".to_string()],
+            vec!["openclaw".to_string()],
+            vec!["openclaw".to_string()],
+            vec![ContextCatalogRow {
+                context: "openclaw".to_string(),
+                cluster: "openclaw".to_string(),
+                auth_info: "openclaw".to_string(),
+                namespace: "openclaw".to_string(),
+            }],
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "ctx".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::None);
+        assert!(app.table_overlay_active());
+        assert_eq!(app.pane_label(), "out");
+        assert!(app.table_overlay_text().unwrap_or("").contains("openclaw"));
+    }
+
+    #[test]
+    fn jump_cluster_returns_switch_cluster_command() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartJump);
+        for c in "cluster homelab".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::SwitchCluster {
+                cluster: "homelab".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn prefixed_jump_cluster_returns_switch_cluster_command() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartJump);
+        for c in ">cluster homelab".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::SwitchCluster {
+                cluster: "homelab".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn user_command_returns_switch_user_command() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "user platform-admin".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            cmd,
+            AppCommand::SwitchUser {
+                user: "platform-admin".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn usr_without_arg_opens_user_catalog_overlay() {
+        let mut app = App::new(
+            "https://cluster".to_string(),
+            "openclaw".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        app.set_user("openclaw".to_string());
+        app.set_kube_catalog(
+            vec!["openclaw".to_string()],
+            vec!["openclaw".to_string()],
+            vec!["openclaw".to_string(), "robot".to_string()],
+            vec![
+                ContextCatalogRow {
+                    context: "openclaw".to_string(),
+                    cluster: "openclaw".to_string(),
+                    auth_info: "openclaw".to_string(),
+                    namespace: "openclaw".to_string(),
+                },
+                ContextCatalogRow {
+                    context: "build".to_string(),
+                    cluster: "openclaw".to_string(),
+                    auth_info: "robot".to_string(),
+                    namespace: "ci".to_string(),
+                },
+            ],
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "usr".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::None);
+        assert!(app.table_overlay_active());
+        let text = app.table_overlay_text().unwrap_or("");
+        assert!(text.contains("openclaw"));
+        assert!(text.contains("robot"));
+    }
+
+    #[test]
+    fn completion_query_normalizes_mode_prefix() {
+        assert_eq!(normalize_mode_prefixed_input(":context"), "context");
+        assert_eq!(
+            normalize_mode_prefixed_input(">cluster home"),
+            "cluster home"
+        );
+    }
+
+    #[test]
+    fn command_completion_excludes_legacy_tab_prefix() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        app.apply_action(Action::StartCommand);
+        let completions = app.completion_candidates();
+        assert!(
+            !completions
+                .iter()
+                .any(|candidate| candidate.starts_with("tab ")),
+            "legacy tab-prefix completions should be hidden"
+        );
+    }
+
+    #[test]
+    fn normalize_status_text_keeps_confirmation_prompt_untrimmed() {
+        let prompt = format!("{} (y/n)", "x".repeat(260));
+        assert_eq!(normalize_status_text(prompt.clone()), prompt);
+    }
+
+    #[test]
+    fn enter_resource_on_pod_requests_container_list() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        let now = Local::now();
+        let mut data = TableData::default();
+        data.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "pod-1".to_string(),
+                namespace: Some("default".to_string()),
+                columns: vec!["pod-1".to_string()],
+                detail: "kind: Pod".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::Pods, data);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+
+        let cmd = app.apply_action(Action::EnterResource);
+        assert_eq!(
+            cmd,
+            AppCommand::LoadPodContainers {
+                namespace: "default".to_string(),
+                pod_name: "pod-1".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn esc_returns_to_dashboard_mode() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+        let mut data = TableData::default();
+        data.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "pod-1".to_string(),
+                namespace: Some("default".to_string()),
+                columns: vec!["pod-1".to_string()],
+                detail: "kind: Pod".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::Pods, data);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+
+        let _ = app.apply_action(Action::ShowDetails);
+        assert_eq!(app.detail_mode(), DetailPaneMode::Details);
+        assert_eq!(app.pane_label(), "det");
+
+        let _ = app.apply_action(Action::ClearDetailOverlay);
+        assert_eq!(app.detail_mode(), DetailPaneMode::Dashboard);
+        assert_eq!(app.pane_label(), "tbl");
+    }
+
+    #[test]
+    fn esc_from_container_logs_returns_to_container_picker_first() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+        let mut data = TableData::default();
+        data.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "pod-1".to_string(),
+                namespace: Some("default".to_string()),
+                columns: vec!["pod-1".to_string()],
+                detail: "kind: Pod".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::Pods, data);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+        app.set_container_picker(
+            "default",
+            "pod-1",
+            vec![crate::model::PodContainerInfo {
+                name: "c1".to_string(),
+                image: "img:v1".to_string(),
+                ready: true,
+                state: "Running".to_string(),
+                restarts: 0,
+                age: "1m".to_string(),
+                ..Default::default()
+            }],
+        );
+        assert!(app.container_picker_active());
+
+        app.set_pod_logs_overlay("Pod Logs default/pod-1:c1", "line".to_string());
+        assert!(app.table_overlay_active());
+        assert!(!app.container_picker_active());
+        assert_eq!(app.pane_label(), "log");
+
+        let _ = app.apply_action(Action::ClearDetailOverlay);
+        assert!(app.container_picker_active());
+        assert!(!app.table_overlay_active());
+        assert_eq!(app.pane_label(), "ctr");
+
+        let _ = app.apply_action(Action::ClearDetailOverlay);
+        assert!(!app.container_picker_active());
+        assert_eq!(app.pane_label(), "tbl");
+    }
+
+    #[test]
+    fn pane_label_uses_uppercase_for_related_logs() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        app.set_related_logs_overlay("Logs default/pod-1", "line".to_string());
+        assert_eq!(app.pane_label(), "LOG");
+    }
+
+    #[test]
+    fn pane_label_uses_shell_for_embedded_shell_overlay() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        app.set_shell_overlay("Pod Shell", "# echo hello\nhello\n".to_string());
+        assert_eq!(app.pane_label(), "sh");
+        assert!(app.shell_overlay_active());
+    }
+
+    #[test]
+    fn enter_namespace_drills_into_pods_scope() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+        let mut namespaces = TableData::default();
+        namespaces.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "orca-sandbox".to_string(),
+                namespace: Some("orca-sandbox".to_string()),
+                columns: vec!["orca-sandbox".to_string()],
+                detail: "kind: Namespace".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::Namespaces, namespaces);
+        app.switch_to_tab(ResourceTab::Namespaces);
+
+        let cmd = app.apply_action(Action::EnterResource);
+        assert_eq!(cmd, AppCommand::RefreshAll);
+        assert_eq!(app.active_tab(), ResourceTab::Pods);
+        assert_eq!(
+            app.namespace_scope(),
+            &NamespaceScope::Named("orca-sandbox".to_string())
+        );
+    }
+
+    #[test]
+    fn esc_returns_to_command_root_after_drilldown() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+
+        let mut deployments = TableData::default();
+        deployments.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "web".to_string(),
+                namespace: Some("openclaw".to_string()),
+                columns: vec!["web".to_string()],
+                detail: "kind: Deployment".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::Deployments, deployments);
+
+        app.apply_action(Action::StartCommand);
+        for c in "deploy".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let _ = app.apply_action(Action::SubmitInput);
+        assert_eq!(app.active_tab(), ResourceTab::Deployments);
+
+        let cmd = app.apply_action(Action::EnterResource);
+        assert_eq!(cmd, AppCommand::RefreshActive);
+        assert_eq!(app.active_tab(), ResourceTab::Pods);
+
+        let _ = app.apply_action(Action::ClearDetailOverlay);
+        assert_eq!(app.active_tab(), ResourceTab::Deployments);
+    }
+
+    #[test]
+    fn shift_l_on_workload_builds_related_logs_command() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+        let mut data = TableData::default();
+        data.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "openclaw-ag".to_string(),
+                namespace: Some("openclaw".to_string()),
+                columns: vec!["openclaw-ag".to_string()],
+                detail: "kind: Deployment".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::Deployments, data);
+        app.switch_to_tab(ResourceTab::Deployments);
+
+        let cmd = app.apply_action(Action::LoadResourceLogs);
+        assert_eq!(
+            cmd,
+            AppCommand::LoadResourceLogs {
+                tab: ResourceTab::Deployments,
+                namespace: Some("openclaw".to_string()),
+                name: "openclaw-ag".to_string(),
+                previous: true,
+                tail: DEFAULT_LOG_TAIL,
+                window: None,
+            }
+        );
+    }
+
+    #[test]
+    fn moving_selection_keeps_logs_overlay_open() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+        let mut data = TableData::default();
+        data.set_rows(
+            vec!["Name".to_string()],
+            vec![
+                RowData {
+                    name: "pod-1".to_string(),
+                    namespace: Some("default".to_string()),
+                    columns: vec!["pod-1".to_string()],
+                    detail: "kind: Pod".to_string(),
+                    ..RowData::default()
+                },
+                RowData {
+                    name: "pod-2".to_string(),
+                    namespace: Some("default".to_string()),
+                    columns: vec!["pod-2".to_string()],
+                    detail: "kind: Pod".to_string(),
+                    ..RowData::default()
+                },
+            ],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::Pods, data);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+        app.set_detail_overlay("Pod Logs", "line".to_string());
+        let _ = app.apply_action(Action::ToggleFocus);
+        let _ = app.apply_action(Action::Down);
+
+        assert!(app.detail_overlay_active());
+        assert_eq!(app.active_selected_index(), Some(1));
+    }
+
+    #[test]
+    fn switching_tabs_keeps_state_consistent() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        let now = Local::now();
+        let mut data = TableData::default();
+        data.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "pod-1".to_string(),
+                namespace: Some("default".to_string()),
+                columns: vec!["pod-1".to_string()],
+                detail: "detail".to_string(),
+                ..RowData::default()
+            }],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::Pods, data);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+
+        let _ = app.apply_action(Action::NextTab);
+        let _ = app.apply_action(Action::PrevTab);
+        assert_eq!(app.active_tab(), ResourceTab::Pods);
+    }
+
+    #[test]
+    fn switching_view_slots_preserves_state_per_slot() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        app.apply_action(Action::StartCommand);
+        for c in "deployments".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let _ = app.apply_action(Action::SubmitInput);
+
+        app.apply_action(Action::StartCommand);
+        for c in "filter web".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let _ = app.apply_action(Action::SubmitInput);
+
+        let _ = app.apply_action(Action::SwitchView(1));
+        assert_eq!(app.active_view_slot(), 1);
+        assert!(app.view_slot_initialized(1));
+
+        let _ = app.apply_action(Action::SwitchView(2));
+        assert_eq!(app.active_view_slot(), 2);
+        assert!(app.view_slot_initialized(2));
+
+        app.apply_action(Action::StartCommand);
+        for c in "pods".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let _ = app.apply_action(Action::SubmitInput);
+        assert_eq!(app.active_tab(), ResourceTab::Pods);
+
+        let _ = app.apply_action(Action::SwitchView(1));
+        assert_eq!(app.active_view_slot(), 1);
+        assert_eq!(app.active_tab(), ResourceTab::Deployments);
+        assert_eq!(app.filter(), "web");
+    }
+
+    #[test]
+    fn deleting_inactive_view_slot_clears_it() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        let _ = app.apply_action(Action::SwitchView(2));
+        assert_eq!(app.active_view_slot(), 2);
+        assert!(app.view_slot_initialized(1));
+        assert!(app.view_slot_initialized(2));
+
+        let cmd = app.apply_action(Action::DeleteView(1));
+        assert_eq!(cmd, AppCommand::None);
+        assert_eq!(app.active_view_slot(), 2);
+        assert!(!app.view_slot_initialized(1));
+        assert!(app.view_slot_initialized(2));
+    }
+
+    #[test]
+    fn deleting_active_view_slot_switches_to_fallback() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        let _ = app.apply_action(Action::SwitchView(2));
+        assert_eq!(app.active_view_slot(), 2);
+
+        let cmd = app.apply_action(Action::DeleteView(2));
+        assert_eq!(cmd, AppCommand::RefreshActive);
+        assert_eq!(app.active_view_slot(), 1);
+        assert!(!app.view_slot_initialized(2));
+        assert!(app.view_slot_initialized(1));
+    }
+
+    #[test]
+    fn deleting_last_active_view_is_rejected() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+
+        let cmd = app.apply_action(Action::DeleteView(1));
+        assert_eq!(cmd, AppCommand::None);
+        assert_eq!(app.active_view_slot(), 1);
+        assert!(app.view_slot_initialized(1));
+    }
+
+    #[test]
+    fn refresh_keeps_previous_index_when_identity_disappears() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let now = Local::now();
+
+        let mut initial = TableData::default();
+        initial.set_rows(
+            vec!["Name".to_string()],
+            vec![
+                RowData {
+                    name: "a".to_string(),
+                    namespace: Some("default".to_string()),
+                    columns: vec!["a".to_string()],
+                    detail: "a".to_string(),
+                    ..RowData::default()
+                },
+                RowData {
+                    name: "b".to_string(),
+                    namespace: Some("default".to_string()),
+                    columns: vec!["b".to_string()],
+                    detail: "b".to_string(),
+                    ..RowData::default()
+                },
+                RowData {
+                    name: "c".to_string(),
+                    namespace: Some("default".to_string()),
+                    columns: vec!["c".to_string()],
+                    detail: "c".to_string(),
+                    ..RowData::default()
+                },
+            ],
+            now,
+        );
+        app.set_active_table_data(ResourceTab::Pods, initial);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+        let _ = app.apply_action(Action::Down);
+        let _ = app.apply_action(Action::Down);
+        assert_eq!(app.active_selected_index(), Some(2));
+
+        let mut refreshed = TableData::default();
+        refreshed.set_rows(
+            vec!["Name".to_string()],
+            vec![
+                RowData {
+                    name: "x".to_string(),
+                    namespace: Some("default".to_string()),
+                    columns: vec!["x".to_string()],
+                    detail: "x".to_string(),
+                    ..RowData::default()
+                },
+                RowData {
+                    name: "y".to_string(),
+                    namespace: Some("default".to_string()),
+                    columns: vec!["y".to_string()],
+                    detail: "y".to_string(),
+                    ..RowData::default()
+                },
+                RowData {
+                    name: "z".to_string(),
+                    namespace: Some("default".to_string()),
+                    columns: vec!["z".to_string()],
+                    detail: "z".to_string(),
+                    ..RowData::default()
+                },
+            ],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Pods, refreshed);
 
-fn normalize_shell_token(token: &str) -> String {
-    match token {
-        "sh" => "/bin/sh".to_string(),
-        "bash" => "/bin/bash".to_string(),
-        "auto" => "auto".to_string(),
-        _ => token.to_string(),
+        assert_eq!(app.active_selected_index(), Some(2));
     }
-}
-
-fn filter_completions(mut candidates: Vec<String>, input: &str, limit: usize) -> Vec<String> {
-    candidates.sort();
-    candidates.dedup();
 
-    let query = normalize_mode_prefixed_input(input).to_ascii_lowercase();
-    if !query.is_empty() {
-        candidates = candidates
-            .into_iter()
-            .filter(|candidate| completion_matches(candidate, &query))
-            .collect::<Vec<_>>();
-    }
+    #[test]
+    fn log_filter_narrows_overlay_lines_and_esc_restores_them() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        app.set_pod_logs_overlay(
+            "Pod Logs default/pod-1",
+            "INFO boot\nERROR disk full\nwarn retry\nerror timeout".to_string(),
+        );
 
-    candidates.truncate(limit);
-    candidates
-}
+        app.apply_action(Action::StartFilter);
+        for c in "error".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(cmd, AppCommand::None);
+        assert_eq!(app.filter(), "");
+        assert_eq!(
+            app.table_overlay_lines(),
+            vec!["ERROR disk full", "error timeout"]
+        );
+        assert_eq!(
+            app.log_filter_summary().as_deref(),
+            Some("/error 2/4 nocase")
+        );
 
-fn normalize_mode_prefixed_input(input: &str) -> String {
-    let mut query = input.trim();
-    while let Some(stripped) = query.strip_prefix(':').or_else(|| query.strip_prefix('>')) {
-        query = stripped.trim_start();
-    }
-    query.to_string()
-}
+        app.apply_action(Action::ToggleLogFilterCase);
+        assert_eq!(app.table_overlay_lines(), vec!["error timeout"]);
 
-fn table_cell(value: &str, width: usize) -> String {
-    let count = value.chars().count();
-    if count <= width {
-        return value.to_string();
+        let _ = app.apply_action(Action::ClearDetailOverlay);
+        assert!(app.table_overlay_active());
+        assert_eq!(app.table_overlay_lines().len(), 4);
+        assert!(app.log_filter_summary().is_none());
     }
 
-    if width <= 1 {
-        return "…".to_string();
-    }
+    #[test]
+    fn log_filter_accepts_regex_and_falls_back_to_literal() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        app.set_related_logs_overlay(
+            "Logs default/pod-1",
+            "GET /health 200\nGET /api 500\nPOST /api (retry 503\n".to_string(),
+        );
 
-    let mut out = value
-        .chars()
-        .take(width.saturating_sub(1))
-        .collect::<String>();
-    out.push('…');
-    out
-}
+        app.apply_action(Action::StartFilter);
+        for c in r"\s5\d\d".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let _ = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            app.table_overlay_lines(),
+            vec!["GET /api 500", "POST /api (retry 503"]
+        );
 
-fn completion_matches(candidate: &str, query: &str) -> bool {
-    let lower = candidate.to_ascii_lowercase();
-    if lower.starts_with(query) {
-        return true;
+        app.apply_action(Action::StartFilter);
+        for _ in 0..8 {
+            app.apply_action(Action::Backspace);
+        }
+        for c in "(retry".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let _ = app.apply_action(Action::SubmitInput);
+        assert_eq!(app.table_overlay_lines(), vec!["POST /api (retry 503"]);
     }
 
-    let words = lower
-        .split(|ch: char| ch.is_ascii_whitespace() || matches!(ch, '/' | ':' | '-' | '.'))
-        .filter(|word| !word.is_empty())
-        .collect::<Vec<_>>();
-    query
-        .split_whitespace()
-        .all(|token| words.iter().any(|word| word.starts_with(token)))
-}
+    #[test]
+    fn namespace_details_request_summary_and_prepend_it_to_yaml() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::All,
+        );
+        let mut namespaces = TableData::default();
+        namespaces.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "payments".to_string(),
+                namespace: None,
+                columns: vec!["payments".to_string()],
+                detail: "kind: Namespace".to_string(),
+                ..RowData::default()
+            }],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Namespaces, namespaces);
+        let _ = app.switch_to_tab(ResourceTab::Namespaces);
 
-fn summarize_error_line(error: &str) -> String {
-    error
-        .lines()
-        .find(|line| !line.trim().is_empty())
-        .map(|line| line.trim().to_string())
-        .unwrap_or_else(|| "unknown error".to_string())
-}
+        let cmd = app.apply_action(Action::ShowDetails);
+        assert_eq!(
+            cmd,
+            AppCommand::LoadNamespaceSummary {
+                name: "payments".to_string(),
+            }
+        );
 
-fn normalize_status_text(status: String) -> String {
-    if status.contains("(y/n)") || status.contains("[y/n]") {
-        return status;
-    }
-    const MAX_STATUS_LEN: usize = 180;
-    if status.chars().count() <= MAX_STATUS_LEN {
-        return status;
+        app.show_namespace_summary("payments", "# summary\npods: 3".to_string());
+        assert_eq!(app.detail_text(), "# summary\npods: 3\n\nkind: Namespace");
+
+        app.show_namespace_summary("other", "# summary".to_string());
+        assert_eq!(app.detail_text(), "# summary\npods: 3\n\nkind: Namespace");
     }
 
-    let mut shortened = status
-        .chars()
-        .take(MAX_STATUS_LEN.saturating_sub(1))
-        .collect::<String>();
-    shortened.push('…');
-    shortened
-}
+    #[test]
+    fn namespace_counts_reuse_loaded_tables_or_a_recent_fetch() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::All,
+        );
+        let table = |headers: &[&str], rows: &[(&str, &str, &[&str])]| {
+            let mut table = TableData::default();
+            table.set_rows(
+                headers.iter().map(|header| header.to_string()).collect(),
+                rows.iter()
+                    .map(|(namespace, name, columns)| RowData {
+                        name: name.to_string(),
+                        namespace: Some(namespace.to_string()),
+                        columns: columns.iter().map(|column| column.to_string()).collect(),
+                        ..RowData::default()
+                    })
+                    .collect(),
+                Local::now(),
+            );
+            table
+        };
+        let now = Instant::now();
+        assert_eq!(app.namespace_counts("payments", now), None);
+
+        let mut pods = table(
+            &["Name", "Status"],
+            &[
+                ("payments", "api-1", &["api-1", "Running"]),
+                ("payments", "api-2", &["api-2", "Pending"]),
+                ("payments", "api-3", &["api-3", "CrashLoopBackOff"]),
+                ("other", "web-1", &["web-1", "Running"]),
+            ],
+        );
+        for (row, phase) in pods
+            .rows
+            .iter_mut()
+            .zip(["Running", "Pending", "Running", "Running"])
+        {
+            row.phase = Some(phase.to_string());
+        }
+        app.set_active_table_data(ResourceTab::Pods, pods);
+        app.set_active_table_data(
+            ResourceTab::Deployments,
+            table(
+                &["Name", "Ready"],
+                &[
+                    ("payments", "api", &["api", "1/2"]),
+                    ("payments", "worker", &["worker", "3/3"]),
+                ],
+            ),
+        );
+        app.set_active_table_data(
+            ResourceTab::Services,
+            table(&["Name"], &[("payments", "api", &["api"])]),
+        );
+        assert_eq!(
+            app.namespace_counts("payments", now),
+            Some(NamespaceCounts {
+                pods: 3,
+                running_pods: 2,
+                deployments: 2,
+                ready_deployments: 1,
+                services: 1,
+            })
+        );
 
-#[cfg(test)]
-mod tests {
-    use super::{
-        App, AppCommand, ArgoResourcePanelSection, DetailPaneMode, HotkeyCommandDef,
-        OpsInspectTarget, PluginCommandDef, PluginRun, normalize_mode_prefixed_input,
-        normalize_status_text,
-    };
-    use crate::input::Action;
-    use crate::model::{ContextCatalogRow, NamespaceScope, ResourceTab, RowData, TableData};
-    use chrono::Local;
-    use std::collections::HashMap;
+        app.namespace_scope = NamespaceScope::Named("other".to_string());
+        assert_eq!(app.namespace_counts("payments", now), None);
+        let fetched = NamespaceCounts {
+            pods: 5,
+            ..NamespaceCounts::default()
+        };
+        app.cache_namespace_counts("payments", fetched, now);
+        assert_eq!(
+            app.namespace_counts("payments", now + Duration::from_secs(5)),
+            Some(fetched)
+        );
+        assert_eq!(
+            app.namespace_counts("payments", now + Duration::from_secs(31)),
+            None
+        );
+    }
 
     #[test]
-    fn filter_command_sets_filter() {
+    fn tail_command_sets_log_tail_for_following_log_requests() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        let mut pods = TableData::default();
+        pods.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "api-123".to_string(),
+                namespace: Some("default".to_string()),
+                columns: vec!["api-123".to_string()],
+                detail: "kind: Pod".to_string(),
+                ..RowData::default()
+            }],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Pods, pods);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
 
         app.apply_action(Action::StartCommand);
-        for c in "filter api".chars() {
+        for c in "tail 0".chars() {
             app.apply_action(Action::InputChar(c));
         }
+        let _ = app.apply_action(Action::SubmitInput);
+        assert_eq!(app.status(), "Log tail set to all lines");
 
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::None);
-        assert_eq!(app.filter(), "api");
+        let cmd = app.apply_action(Action::LoadPodLogs);
+        assert_eq!(
+            cmd,
+            AppCommand::LoadPodLogs {
+                namespace: "default".to_string(),
+                pod_name: "api-123".to_string(),
+                container: None,
+                previous: false,
+                tail: 0,
+                window: None,
+            }
+        );
     }
 
     #[test]
-    fn tools_command_requests_tooling_inspection() {
+    fn describe_omits_namespace_for_cluster_scoped_resources() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        let mut nodes = TableData::default();
+        nodes.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "worker-1".to_string(),
+                namespace: None,
+                columns: vec!["worker-1".to_string()],
+                detail: "kind: Node".to_string(),
+                ..RowData::default()
+            }],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Nodes, nodes);
+        let _ = app.switch_to_tab(ResourceTab::Nodes);
+
+        let cmd = app.apply_action(Action::DescribeResource);
+        assert_eq!(
+            cmd,
+            AppCommand::DescribeSelected {
+                tab: ResourceTab::Nodes,
+                resource: "node".to_string(),
+                namespace: None,
+                name: "worker-1".to_string(),
+            }
+        );
+
+        let mut pods = TableData::default();
+        pods.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "api-123".to_string(),
+                namespace: Some("orca-sandbox".to_string()),
+                columns: vec!["api-123".to_string()],
+                detail: "kind: Pod".to_string(),
+                ..RowData::default()
+            }],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Pods, pods);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
 
         app.apply_action(Action::StartCommand);
-        for c in "tools".chars() {
+        for c in "describe".chars() {
             app.apply_action(Action::InputChar(c));
         }
-
         let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::InspectTooling);
+        assert_eq!(
+            cmd,
+            AppCommand::DescribeSelected {
+                tab: ResourceTab::Pods,
+                resource: "pod".to_string(),
+                namespace: Some("orca-sandbox".to_string()),
+                name: "api-123".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn focus_row_namespace_narrows_all_scope_and_restore_widens_it() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::All,
+        );
+        let mut pods = TableData::default();
+        pods.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "api-123".to_string(),
+                namespace: Some("payments".to_string()),
+                columns: vec!["api-123".to_string()],
+                detail: "kind: Pod".to_string(),
+                ..RowData::default()
+            }],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Pods, pods);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+
+        let cmd = app.apply_action(Action::FocusRowNamespace);
+        assert_eq!(cmd, AppCommand::RefreshAll);
+        assert_eq!(
+            app.namespace_scope(),
+            &NamespaceScope::Named("payments".to_string())
+        );
+
+        let cmd = app.apply_action(Action::FocusRowNamespace);
+        assert_eq!(cmd, AppCommand::None);
+
+        let cmd = app.apply_action(Action::RestoreAllNamespaces);
+        assert_eq!(cmd, AppCommand::RefreshAll);
+        assert_eq!(app.namespace_scope(), &NamespaceScope::All);
     }
 
     #[test]
-    fn git_command_without_args_opens_catalog() {
+    fn node_neighbors_uses_selected_pod_node() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        let mut pods = TableData::default();
+        pods.set_rows(
+            vec![
+                "Name".to_string(),
+                "Namespace".to_string(),
+                "Node".to_string(),
+            ],
+            vec![
+                RowData {
+                    name: "api-123".to_string(),
+                    namespace: Some("default".to_string()),
+                    columns: vec![
+                        "api-123".to_string(),
+                        "default".to_string(),
+                        "worker-2".to_string(),
+                    ],
+                    detail: "kind: Pod".to_string(),
+                    ..RowData::default()
+                },
+                RowData {
+                    name: "pending-1".to_string(),
+                    namespace: Some("default".to_string()),
+                    columns: vec![
+                        "pending-1".to_string(),
+                        "default".to_string(),
+                        "-".to_string(),
+                    ],
+                    detail: "kind: Pod".to_string(),
+                    ..RowData::default()
+                },
+            ],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Pods, pods);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
 
-        app.apply_action(Action::StartCommand);
-        for c in "git".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-
-        let cmd = app.apply_action(Action::SubmitInput);
+        let cmd = app.apply_action(Action::ShowNodeNeighbors);
         assert_eq!(
             cmd,
-            AppCommand::InspectOps {
-                target: OpsInspectTarget::GitCatalog
+            AppCommand::LoadNodeNeighbors {
+                node: "worker-2".to_string(),
+                pod: "default/api-123".to_string(),
             }
         );
+
+        app.apply_action(Action::Down);
+        let cmd = app.apply_action(Action::ShowNodeNeighbors);
+        assert_eq!(cmd, AppCommand::None);
     }
 
     #[test]
-    fn git_fetch_url_builds_target() {
+    fn secret_overlay_masks_values_until_revealed() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        let mut secrets = TableData::default();
+        secrets.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "db-creds".to_string(),
+                namespace: Some("default".to_string()),
+                columns: vec!["db-creds".to_string()],
+                detail: "kind: Secret".to_string(),
+                ..RowData::default()
+            }],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Secrets, secrets);
+        let _ = app.switch_to_tab(ResourceTab::Secrets);
 
-        app.apply_action(Action::StartCommand);
-        for c in "git fetch https://github.com/example/app.git main".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-
-        let cmd = app.apply_action(Action::SubmitInput);
+        let cmd = app.apply_action(Action::DecodeSecret);
         assert_eq!(
             cmd,
-            AppCommand::InspectOps {
-                target: OpsInspectTarget::GitFetch {
-                    repo: "https://github.com/example/app.git".to_string(),
-                    reference: Some("main".to_string()),
-                }
+            AppCommand::DecodeSecret {
+                namespace: "default".to_string(),
+                name: "db-creds".to_string(),
             }
         );
-    }
 
-    #[test]
-    fn argocd_command_switches_to_argo_apps_tab() {
-        let mut app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+        app.set_secret_overlay(
+            "Secret default/db-creds",
+            vec![
+                SecretEntry {
+                    key: "password".to_string(),
+                    value: Some("hunter2".to_string()),
+                    bytes: 7,
+                },
+                SecretEntry {
+                    key: "keystore".to_string(),
+                    value: None,
+                    bytes: 512,
+                },
+            ],
         );
-
-        app.apply_action(Action::StartCommand);
-        for c in "argocd".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::RefreshActive);
-        assert_eq!(app.active_tab(), ResourceTab::ArgoCdApps);
-    }
-
-    #[test]
-    fn app_starts_in_orca_tab() {
-        let app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+        assert_eq!(
+            app.table_overlay_text(),
+            Some("password: ******** (7 bytes)\nkeystore: <binary, 512 bytes>")
         );
-        assert_eq!(app.active_tab(), ResourceTab::Orca);
-    }
 
-    #[test]
-    fn k8s_command_switches_to_pods_tab() {
-        let mut app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+        let _ = app.apply_action(Action::DecodeSecret);
+        assert_eq!(
+            app.table_overlay_text(),
+            Some("password: hunter2\nkeystore: <binary, 512 bytes>")
         );
-        app.apply_action(Action::StartCommand);
-        for c in "k8s".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::RefreshActive);
-        assert_eq!(app.active_tab(), ResourceTab::Pods);
-    }
 
-    #[test]
-    fn orca_command_switches_to_orca_tab() {
-        let mut app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
-        );
-        let _ = app.switch_to_tab(ResourceTab::Pods);
-        app.apply_action(Action::StartCommand);
-        for c in "orca".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::RefreshActive);
-        assert_eq!(app.active_tab(), ResourceTab::Orca);
+        let _ = app.apply_action(Action::ClearDetailOverlay);
+        assert!(!app.table_overlay_active());
     }
 
     #[test]
-    fn argocd_with_app_name_switches_to_resource_tab() {
+    fn refresh_interval_slows_down_after_idle_threshold() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        assert_eq!(app.refresh_interval_ms(1_500), 1_500);
 
-        app.apply_action(Action::StartCommand);
-        for c in "argocd guestbook".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
+        app.set_idle_refresh(Some(Duration::from_secs(60)), 20_000);
+        assert_eq!(app.refresh_interval_ms(1_500), 1_500);
 
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::RefreshActive);
-        assert_eq!(app.active_tab(), ResourceTab::ArgoCdResources);
-        assert_eq!(app.argocd_selected_app(), Some("guestbook"));
+        app.last_input_at = Instant::now() - Duration::from_secs(61);
+        assert!(app.is_idle());
+        assert_eq!(app.refresh_interval_ms(1_500), 20_000);
+
+        app.mark_input();
+        assert!(!app.is_idle());
+        assert_eq!(app.refresh_interval_ms(1_500), 1_500);
     }
 
     #[test]
-    fn open_shell_from_argocd_pod_node_targets_selected_pod() {
+    fn top_command_remembers_sort_and_limit() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+            NamespaceScope::All,
         );
-        let now = Local::now();
-        let mut resources = TableData::default();
-        resources.set_rows(
-            vec![
-                "Tree".to_string(),
-                "Namespace".to_string(),
-                "Name".to_string(),
-            ],
-            vec![RowData {
-                name: "Pod/guestbook-ui-6595f948db-abcde".to_string(),
-                namespace: Some("argocd-demo".to_string()),
-                columns: vec![
-                    "└── Pod".to_string(),
-                    "argocd-demo".to_string(),
-                    "guestbook-ui-6595f948db-abcde".to_string(),
-                ],
-                detail: "kind: Pod".to_string(),
-            }],
-            now,
+        assert_eq!(run_command_line(&mut app, "top"), AppCommand::None);
+        assert!(app.status().contains("No pod usage sampled"));
+
+        let mut metrics = OverviewMetrics::default();
+        metrics
+            .pod_usage
+            .insert("shop/web".to_string(), (120, 1_048_576));
+        app.set_overview_metrics(metrics);
+        assert_eq!(
+            run_command_line(&mut app, "top mem 5"),
+            AppCommand::ShowTopPods {
+                sort: UsageSort::Memory,
+                limit: 5,
+            }
         );
-        app.set_active_table_data(ResourceTab::ArgoCdResources, resources);
-        app.switch_to_tab(ResourceTab::ArgoCdResources);
-
-        let cmd = app.apply_action(Action::OpenPodShell);
         assert_eq!(
-            cmd,
-            AppCommand::OpenPodShell {
-                namespace: "argocd-demo".to_string(),
-                pod_name: "guestbook-ui-6595f948db-abcde".to_string(),
-                container: None,
-                shell: "auto".to_string(),
+            run_command_line(&mut app, "top flip"),
+            AppCommand::ShowTopPods {
+                sort: UsageSort::Cpu,
+                limit: 5,
             }
         );
+        assert_eq!(run_command_line(&mut app, "top 0"), AppCommand::None);
+        assert!(app.status().starts_with("Usage: :top"));
     }
 
     #[test]
-    fn load_logs_from_argocd_pod_node_targets_selected_pod() {
+    fn per_tab_refresh_intervals_override_global_cadence() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
-        let now = Local::now();
-        let mut resources = TableData::default();
-        resources.set_rows(
-            vec![
-                "Tree".to_string(),
-                "Namespace".to_string(),
-                "Name".to_string(),
-            ],
-            vec![RowData {
-                name: "Pod/guestbook-ui-6595f948db-abcde".to_string(),
-                namespace: Some("argocd-demo".to_string()),
-                columns: vec![
-                    "└── Pod".to_string(),
-                    "argocd-demo".to_string(),
-                    "guestbook-ui-6595f948db-abcde".to_string(),
-                ],
-                detail: "kind: Pod".to_string(),
-            }],
-            now,
-        );
-        app.set_active_table_data(ResourceTab::ArgoCdResources, resources);
-        app.switch_to_tab(ResourceTab::ArgoCdResources);
-
-        let cmd = app.apply_action(Action::LoadPodLogs);
-        assert_eq!(
-            cmd,
-            AppCommand::LoadPodLogs {
-                namespace: "argocd-demo".to_string(),
-                pod_name: "guestbook-ui-6595f948db-abcde".to_string(),
-                container: None,
-                previous: false,
-            }
-        );
+        app.set_tab_refresh_intervals(HashMap::from([
+            (ResourceTab::Nodes, 30_000),
+            (ResourceTab::Events, 0),
+        ]));
+        assert_eq!(app.tab_refresh_ms(ResourceTab::Nodes, 2_000), 30_000);
+        assert_eq!(app.tab_refresh_ms(ResourceTab::Events, 2_000), 2_000);
+        assert_eq!(app.refresh_tick_ms(2_000), 2_000);
+
+        let now = Instant::now();
+        assert!(app.tab_refresh_due(ResourceTab::Nodes, 2_000, now));
+        app.set_active_table_data(ResourceTab::Nodes, TableData::default());
+        app.set_active_table_data(ResourceTab::Pods, TableData::default());
+        let later = Instant::now() + Duration::from_secs(5);
+        assert!(!app.tab_refresh_due(ResourceTab::Nodes, 2_000, later));
+        assert!(app.tab_refresh_due(ResourceTab::Pods, 2_000, later));
+        assert!(app.tab_refresh_due(ResourceTab::Nodes, 2_000, later + Duration::from_secs(25)));
+
+        app.set_tab_refresh_intervals(HashMap::from([(ResourceTab::Pods, 500)]));
+        assert_eq!(app.refresh_tick_ms(2_000), 500);
     }
 
     #[test]
-    fn load_logs_from_argocd_deployment_node_targets_workload_logs() {
+    fn argocd_live_objects_are_reused_within_the_refresh_interval() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
-        let now = Local::now();
-        let mut resources = TableData::default();
-        resources.set_rows(
-            vec![
-                "Tree".to_string(),
-                "Namespace".to_string(),
-                "Name".to_string(),
-            ],
-            vec![RowData {
-                name: "Deployment/guestbook-ui".to_string(),
-                namespace: Some("argocd-demo".to_string()),
-                columns: vec![
-                    "󰹑 Deployment".to_string(),
-                    "argocd-demo".to_string(),
-                    "guestbook-ui".to_string(),
-                ],
-                detail: "kind: Deployment".to_string(),
-            }],
-            now,
+        app.set_default_refresh_ms(2_000);
+        let now = Instant::now();
+        let live = ArgoNamespaceLive {
+            workloads: vec![serde_json::json!({"kind": "Pod"})],
+            endpoints: Vec::new(),
+        };
+        app.cache_argocd_live("shop".to_string(), live, false, now);
+
+        let reused = app.cached_argocd_live("shop", false, now + Duration::from_secs(1));
+        assert_eq!(reused.map(|live| live.workloads.len()), Some(1));
+        assert!(app.cached_argocd_live("shop", true, now).is_none());
+        assert!(app.cached_argocd_live("other", false, now).is_none());
+        assert!(
+            app.cached_argocd_live("shop", false, now + Duration::from_secs(2))
+                .is_none()
         );
-        app.set_active_table_data(ResourceTab::ArgoCdResources, resources);
-        app.switch_to_tab(ResourceTab::ArgoCdResources);
 
-        let cmd = app.apply_action(Action::LoadPodLogs);
-        assert_eq!(
-            cmd,
-            AppCommand::LoadResourceLogs {
-                tab: ResourceTab::Deployments,
-                namespace: Some("argocd-demo".to_string()),
-                name: "guestbook-ui".to_string(),
-                previous: false,
-            }
+        app.set_tab_refresh_intervals(HashMap::from([(ResourceTab::ArgoCdResources, 10_000)]));
+        assert!(
+            app.cached_argocd_live("shop", false, now + Duration::from_secs(5))
+                .is_some()
+        );
+
+        app.cache_argocd_live(
+            "web".to_string(),
+            ArgoNamespaceLive::default(),
+            false,
+            now + Duration::from_secs(20),
         );
+        assert_eq!(app.argocd_live.len(), 1);
+        app.set_kube_target(
+            "cluster".to_string(),
+            "staging".to_string(),
+            "user".to_string(),
+            "default".to_string(),
+            false,
+        );
+        assert!(app.argocd_live.is_empty());
     }
 
     #[test]
-    fn edit_on_argocd_resource_opens_events_section() {
+    fn sort_keys_cycle_columns_and_keep_selected_row() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
-        let now = Local::now();
-        let mut resources = TableData::default();
-        resources.set_rows(
-            vec![
-                "Tree".to_string(),
-                "Namespace".to_string(),
-                "Name".to_string(),
-            ],
-            vec![RowData {
-                name: "ReplicaSet/guestbook-ui-6595f948db".to_string(),
-                namespace: Some("argocd-demo".to_string()),
-                columns: vec![
-                    "└─󰹍 ReplicaSe".to_string(),
-                    "argocd-demo".to_string(),
-                    "guestbook-ui-6595f948db".to_string(),
-                ],
-                detail: "kind: ReplicaSet".to_string(),
-            }],
-            now,
+        let row = |name: &str, restarts: &str| RowData {
+            name: name.to_string(),
+            namespace: Some("default".to_string()),
+            columns: vec![name.to_string(), restarts.to_string()],
+            detail: "kind: Pod".to_string(),
+            ..RowData::default()
+        };
+        let mut pods = TableData::default();
+        pods.set_rows(
+            vec!["Name".to_string(), "Restarts".to_string()],
+            vec![row("alpha", "12"), row("beta", "3")],
+            Local::now(),
         );
-        app.set_active_table_data(ResourceTab::ArgoCdResources, resources);
-        app.switch_to_tab(ResourceTab::ArgoCdResources);
+        app.set_active_table_data(ResourceTab::Pods, pods);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
 
-        let cmd = app.apply_action(Action::EditResource);
+        let _ = app.apply_action(Action::CycleSortColumn);
+        let _ = app.apply_action(Action::CycleSortColumn);
+        assert_eq!(app.status(), "Sorted by Restarts (asc)");
+        let names = app
+            .active_visible_rows()
+            .iter()
+            .map(|row| row.name.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["beta", "alpha"]);
         assert_eq!(
-            cmd,
-            AppCommand::LoadArgoResourcePanelSection {
-                kind: "ReplicaSet".to_string(),
-                namespace: Some("argocd-demo".to_string()),
-                name: "guestbook-ui-6595f948db".to_string(),
-                section: ArgoResourcePanelSection::Events,
-            }
+            app.active_selected_row().map(|row| row.name.as_str()),
+            Some("alpha")
+        );
+
+        let _ = app.apply_action(Action::ToggleSortDirection);
+        assert_eq!(app.active_visible_rows()[0].name, "alpha");
+
+        let mut refreshed = TableData::default();
+        refreshed.set_rows(
+            vec!["Name".to_string(), "Restarts".to_string()],
+            vec![row("alpha", "12"), row("beta", "3"), row("gamma", "40")],
+            Local::now(),
         );
+        app.set_active_table_data(ResourceTab::Pods, refreshed);
+        assert_eq!(app.active_visible_rows()[0].name, "gamma");
+
+        let _ = app.apply_action(Action::CycleSortColumn);
+        assert_eq!(app.active_sort(), None);
+        assert_eq!(app.active_visible_rows()[0].name, "alpha");
     }
 
     #[test]
-    fn edit_on_argocd_apps_uses_kubectl_edit_flow() {
+    fn argo_source_command_reads_repo_and_path_from_app_json() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+            NamespaceScope::Named("argocd".to_string()),
         );
-        let now = Local::now();
         let mut apps = TableData::default();
         apps.set_rows(
-            vec![
-                "Name".to_string(),
-                "Project".to_string(),
-                "Namespace".to_string(),
-            ],
+            vec!["Name".to_string()],
             vec![RowData {
                 name: "guestbook".to_string(),
                 namespace: Some("argocd".to_string()),
-                columns: vec![
-                    "guestbook".to_string(),
-                    "default".to_string(),
-                    "argocd-demo".to_string(),
-                ],
-                detail: "kind: Application".to_string(),
+                columns: vec!["guestbook".to_string()],
+                detail: r#"{"spec":{"source":{"repoURL":"https://github.com/argoproj/argocd-example-apps.git","path":"guestbook","targetRevision":"HEAD"}}}"#.to_string(),
+                ..RowData::default()
             }],
-            now,
+            Local::now(),
         );
         app.set_active_table_data(ResourceTab::ArgoCdApps, apps);
-        app.switch_to_tab(ResourceTab::ArgoCdApps);
+        let _ = app.switch_to_tab(ResourceTab::ArgoCdApps);
 
-        let cmd = app.apply_action(Action::EditResource);
+        let cmd = app.apply_action(Action::OpenArgoSource);
         assert_eq!(
             cmd,
-            AppCommand::EditSelected {
-                resource: "applications.argoproj.io".to_string(),
-                namespace: Some("argocd".to_string()),
-                name: "guestbook".to_string(),
+            AppCommand::OpenArgoSource {
+                app: "guestbook".to_string(),
+                repo: "https://github.com/argoproj/argocd-example-apps.git".to_string(),
+                path: "guestbook".to_string(),
+                revision: None,
             }
         );
     }
 
     #[test]
-    fn m_on_argocd_resource_opens_manifest_section() {
+    fn filter_narrows_rows_live_and_esc_restores_full_list() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
-        let now = Local::now();
-        let mut resources = TableData::default();
-        resources.set_rows(
+        let row = |name: &str| RowData {
+            name: name.to_string(),
+            namespace: Some("default".to_string()),
+            columns: vec![name.to_string()],
+            detail: String::new(),
+            ..RowData::default()
+        };
+        let mut pods = TableData::default();
+        pods.set_rows(
+            vec!["Name".to_string()],
             vec![
-                "Tree".to_string(),
-                "Namespace".to_string(),
-                "Name".to_string(),
+                row("checkout-api"),
+                row("payments-worker"),
+                row("payments-api"),
             ],
-            vec![RowData {
-                name: "Service/guestbook-ui".to_string(),
-                namespace: Some("argocd-demo".to_string()),
-                columns: vec![
-                    "󰒓 Service".to_string(),
-                    "argocd-demo".to_string(),
-                    "guestbook-ui".to_string(),
-                ],
-                detail: "kind: Service".to_string(),
-            }],
-            now,
+            Local::now(),
         );
-        app.set_active_table_data(ResourceTab::ArgoCdResources, resources);
-        app.switch_to_tab(ResourceTab::ArgoCdResources);
+        app.set_active_table_data(ResourceTab::Pods, pods);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+        app.apply_action(Action::Bottom);
 
-        let cmd = app.apply_action(Action::ShowManifest);
+        app.apply_action(Action::StartFilter);
+        for c in "pyapi".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let names = app
+            .active_visible_rows()
+            .iter()
+            .map(|row| row.name.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["payments-api"]);
         assert_eq!(
-            cmd,
-            AppCommand::LoadArgoResourcePanelSection {
-                kind: "Service".to_string(),
-                namespace: Some("argocd-demo".to_string()),
-                name: "guestbook-ui".to_string(),
-                section: ArgoResourcePanelSection::Manifest,
-            }
+            app.active_selected_row().map(|row| row.name.as_str()),
+            Some("payments-api")
         );
+
+        app.apply_action(Action::CancelInput);
+        assert_eq!(app.filter(), "");
+        assert_eq!(app.active_visible_rows().len(), 3);
     }
 
     #[test]
-    fn argocd_projects_switches_to_projects_tab() {
+    fn has_label_command_filters_rows_by_label() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        let row = |name: &str, managed_by: &str| RowData {
+            name: name.to_string(),
+            namespace: Some("default".to_string()),
+            columns: vec![name.to_string()],
+            labels: [(
+                "app.kubernetes.io/managed-by".to_string(),
+                managed_by.to_string(),
+            )]
+            .into_iter()
+            .collect(),
+            ..RowData::default()
+        };
+        let mut deployments = TableData::default();
+        deployments.set_rows(
+            vec!["Name".to_string()],
+            vec![row("checkout", "Helm"), row("payments", "argocd")],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Deployments, deployments);
+        let _ = app.switch_to_tab(ResourceTab::Deployments);
 
         app.apply_action(Action::StartCommand);
-        for c in "argocd projects".chars() {
+        for c in "has label app.kubernetes.io/managed-by=Helm".chars() {
             app.apply_action(Action::InputChar(c));
         }
+        app.apply_action(Action::SubmitInput);
 
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::RefreshActive);
-        assert_eq!(app.active_tab(), ResourceTab::ArgoCdProjects);
+        assert_eq!(app.filter(), "label:app.kubernetes.io/managed-by=Helm");
+        let names = app
+            .active_visible_rows()
+            .iter()
+            .map(|row| row.name.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["checkout"]);
     }
 
     #[test]
-    fn argocd_sync_builds_ops_target() {
+    fn scratch_yaml_strips_server_fields_from_selected_manifest() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        let detail = "apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: app-config\n  namespace: default\n  uid: 1234\n  resourceVersion: '42'\n  managedFields:\n  - manager: kubectl\n  annotations:\n    kubectl.kubernetes.io/last-applied-configuration: '{}'\ndata:\n  mode: prod\nstatus: {}\n";
+        let mut configmaps = TableData::default();
+        configmaps.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "app-config".to_string(),
+                namespace: Some("default".to_string()),
+                columns: vec!["app-config".to_string()],
+                detail: detail.to_string(),
+                ..RowData::default()
+            }],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::ConfigMaps, configmaps);
+        let _ = app.switch_to_tab(ResourceTab::ConfigMaps);
+
+        let AppCommand::OpenScratchYaml {
+            file_stem,
+            manifest,
+        } = app.apply_action(Action::ScratchYaml)
+        else {
+            panic!("expected scratch yaml command");
+        };
+        assert_eq!(file_stem, "cm-default-app-config");
+        assert!(manifest.contains("name: app-config"));
+        assert!(manifest.contains("mode: prod"));
+        for noise in [
+            "uid",
+            "resourceVersion",
+            "managedFields",
+            "annotations",
+            "status",
+        ] {
+            assert!(!manifest.contains(noise), "{noise} should be stripped");
+        }
+    }
+
+    #[test]
+    fn slices_command_jumps_from_service_to_its_endpoint_slices() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::All,
+        );
+        let mut services = TableData::default();
+        services.set_rows(
+            vec!["Name".to_string(), "Namespace".to_string()],
+            vec![RowData {
+                name: "checkout".to_string(),
+                namespace: Some("shop".to_string()),
+                columns: vec!["checkout".to_string(), "shop".to_string()],
+                ..RowData::default()
+            }],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Services, services);
+        let _ = app.switch_to_tab(ResourceTab::Services);
+        app.filter = "check".to_string();
+
+        let slice = |name: &str, service: &str| RowData {
+            name: name.to_string(),
+            namespace: Some("shop".to_string()),
+            columns: vec![name.to_string(), "shop".to_string()],
+            labels: [(
+                "kubernetes.io/service-name".to_string(),
+                service.to_string(),
+            )]
+            .into_iter()
+            .collect(),
+            ..RowData::default()
+        };
+        let mut slices = TableData::default();
+        slices.set_rows(
+            vec!["Name".to_string(), "Namespace".to_string()],
+            vec![
+                slice("checkout-x7k2p", "checkout"),
+                slice("cart-9qz4m", "cart"),
+            ],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::EndpointSlices, slices);
 
         app.apply_action(Action::StartCommand);
-        for c in "argocd sync guestbook".chars() {
+        for c in "slices".chars() {
             app.apply_action(Action::InputChar(c));
         }
+        app.apply_action(Action::SubmitInput);
 
-        let cmd = app.apply_action(Action::SubmitInput);
+        assert_eq!(app.active_tab(), ResourceTab::EndpointSlices);
         assert_eq!(
-            cmd,
-            AppCommand::InspectOps {
-                target: OpsInspectTarget::ArgoCdSync {
-                    name: "guestbook".to_string()
-                }
-            }
+            app.namespace_scope(),
+            &NamespaceScope::Named("shop".to_string())
+        );
+        assert_eq!(
+            app.label_selector_for(ResourceTab::EndpointSlices),
+            Some("kubernetes.io/service-name=checkout")
         );
+        assert_eq!(app.filter, "");
+
+        app.apply_action(Action::ClearDetailOverlay);
+        assert_eq!(app.active_tab(), ResourceTab::Services);
+        assert_eq!(app.filter, "check");
+        assert_eq!(app.namespace_scope(), &NamespaceScope::All);
+        assert_eq!(app.label_selector_for(ResourceTab::EndpointSlices), None);
     }
 
     #[test]
-    fn argocd_rollback_accepts_id_and_app() {
+    fn exec_command_with_tty_flag_requests_pty_exec() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        let mut pods = TableData::default();
+        pods.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "api-123".to_string(),
+                namespace: Some("default".to_string()),
+                columns: vec!["api-123".to_string()],
+                ..RowData::default()
+            }],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Pods, pods);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
 
-        app.apply_action(Action::StartCommand);
-        for c in "argocd rollback 3 guestbook".chars() {
-            app.apply_action(Action::InputChar(c));
+        for (line, tty, command) in [
+            ("exec -t watch date", true, vec!["watch", "date"]),
+            (
+                "exec cat /etc/hostname",
+                false,
+                vec!["cat", "/etc/hostname"],
+            ),
+        ] {
+            app.apply_action(Action::StartCommand);
+            for c in line.chars() {
+                app.apply_action(Action::InputChar(c));
+            }
+            assert_eq!(
+                app.apply_action(Action::SubmitInput),
+                AppCommand::ExecInPod {
+                    namespace: "default".to_string(),
+                    pod_name: "api-123".to_string(),
+                    command: command.into_iter().map(str::to_string).collect(),
+                    tty,
+                }
+            );
         }
+    }
 
-        let cmd = app.apply_action(Action::SubmitInput);
+    #[test]
+    fn confirm_skip_list_bypasses_restart_but_never_delete() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        let mut deployments = TableData::default();
+        deployments.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "web".to_string(),
+                namespace: Some("orca-sandbox".to_string()),
+                columns: vec!["web".to_string()],
+                detail: "kind: Deployment".to_string(),
+                ..RowData::default()
+            }],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Deployments, deployments);
+        let _ = app.switch_to_tab(ResourceTab::Deployments);
+        app.set_confirm_skip(vec!["Restart".to_string(), "delete".to_string()]);
+
+        let mut run = |line: &str| {
+            app.apply_action(Action::StartCommand);
+            for c in line.chars() {
+                app.apply_action(Action::InputChar(c));
+            }
+            app.apply_action(Action::SubmitInput)
+        };
         assert_eq!(
-            cmd,
-            AppCommand::InspectOps {
-                target: OpsInspectTarget::ArgoCdRollback {
-                    name: "guestbook".to_string(),
-                    id: "3".to_string(),
-                }
+            run("restart"),
+            AppCommand::RestartWorkload {
+                tab: ResourceTab::Deployments,
+                namespace: "orca-sandbox".to_string(),
+                name: "web".to_string(),
             }
         );
+        assert_eq!(run("delete"), AppCommand::None);
+        assert_eq!(
+            app.pending_confirmation_prompt(),
+            Some("Delete Deployments orca-sandbox/web")
+        );
     }
 
     #[test]
-    fn helm_release_command_requests_release_overlay() {
+    fn restart_command_supports_daemonsets() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        let mut daemonsets = TableData::default();
+        daemonsets.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "node-exporter".to_string(),
+                namespace: Some("monitoring".to_string()),
+                columns: vec!["node-exporter".to_string()],
+                ..RowData::default()
+            }],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::DaemonSets, daemonsets);
+        let _ = app.switch_to_tab(ResourceTab::DaemonSets);
 
         app.apply_action(Action::StartCommand);
-        for c in "helm my-release".chars() {
+        for c in "restart".chars() {
             app.apply_action(Action::InputChar(c));
         }
+        assert_eq!(app.apply_action(Action::SubmitInput), AppCommand::None);
+        assert_eq!(
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::RestartWorkload {
+                tab: ResourceTab::DaemonSets,
+                namespace: "monitoring".to_string(),
+                name: "node-exporter".to_string(),
+            }
+        );
+    }
 
-        let cmd = app.apply_action(Action::SubmitInput);
+    #[test]
+    fn rerun_recreates_jobs_and_spawns_jobs_from_cronjobs() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("batch".to_string()),
+        );
+        let table = |name: &str| {
+            let mut table = TableData::default();
+            table.set_rows(
+                vec!["Name".to_string()],
+                vec![RowData {
+                    name: name.to_string(),
+                    namespace: Some("batch".to_string()),
+                    columns: vec![name.to_string()],
+                    ..RowData::default()
+                }],
+                Local::now(),
+            );
+            table
+        };
+        app.set_active_table_data(ResourceTab::Jobs, table("migrate"));
+        app.set_active_table_data(ResourceTab::CronJobs, table("nightly"));
+
+        app.set_confirm_skip(vec!["rerun job".to_string(), "create job".to_string()]);
+        let _ = app.switch_to_tab(ResourceTab::Jobs);
+        assert_eq!(
+            app.apply_action(Action::LoadPodLogs),
+            AppCommand::LoadResourceLogs {
+                tab: ResourceTab::Jobs,
+                namespace: Some("batch".to_string()),
+                name: "migrate".to_string(),
+                previous: false,
+                tail: DEFAULT_LOG_TAIL,
+                window: None,
+            }
+        );
+        assert_eq!(run_command_line(&mut app, "rerun"), AppCommand::None);
+        assert_eq!(
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::RerunJob {
+                namespace: "batch".to_string(),
+                name: "migrate".to_string(),
+            }
+        );
+
+        let _ = app.switch_to_tab(ResourceTab::CronJobs);
+        assert_eq!(
+            run_command_line(&mut app, "rerun"),
+            AppCommand::CreateJobFromCronJob {
+                namespace: "batch".to_string(),
+                cronjob: "nightly".to_string(),
+            }
+        );
+
+        app.set_read_only(true);
+        assert_eq!(run_command_line(&mut app, "rerun"), AppCommand::None);
+        assert!(app.pending_confirmation_prompt().is_none());
+    }
+
+    #[test]
+    fn suspend_toggle_flips_cronjob_state_behind_confirmation() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("batch".to_string()),
+        );
+        let mut cronjobs = TableData::default();
+        cronjobs.set_rows(
+            vec![
+                "Name".to_string(),
+                "Namespace".to_string(),
+                "Schedule".to_string(),
+                "Suspend".to_string(),
+            ],
+            vec![RowData {
+                name: "nightly".to_string(),
+                namespace: Some("batch".to_string()),
+                columns: vec![
+                    "nightly".to_string(),
+                    "batch".to_string(),
+                    "0 3 * * *".to_string(),
+                    "No".to_string(),
+                ],
+                ..RowData::default()
+            }],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::CronJobs, cronjobs);
+        let _ = app.switch_to_tab(ResourceTab::CronJobs);
+
+        assert_eq!(
+            app.apply_action(Action::ToggleCronJobSuspend),
+            AppCommand::None
+        );
+        assert!(app.pending_confirmation_prompt().is_some());
         assert_eq!(
-            cmd,
-            AppCommand::InspectOps {
-                target: OpsInspectTarget::HelmRelease {
-                    name: "my-release".to_string()
-                }
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::ToggleCronJobSuspend {
+                namespace: "batch".to_string(),
+                name: "nightly".to_string(),
+                suspend: true,
             }
         );
+
+        assert_eq!(run_command_line(&mut app, "resume"), AppCommand::None);
+        assert!(app.pending_confirmation_prompt().is_none());
+        assert!(app.status().contains("already active"));
+
+        app.set_read_only(true);
+        assert_eq!(run_command_line(&mut app, "suspend"), AppCommand::None);
+        assert!(app.pending_confirmation_prompt().is_none());
     }
 
     #[test]
-    fn rbac_command_requests_rbac_overlay() {
+    fn trigger_hotkey_runs_cronjob_and_focuses_created_job() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+            NamespaceScope::Named("batch".to_string()),
+        );
+        let mut cronjobs = TableData::default();
+        cronjobs.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "nightly".to_string(),
+                namespace: Some("batch".to_string()),
+                columns: vec!["nightly".to_string()],
+                ..RowData::default()
+            }],
+            Local::now(),
         );
+        app.set_active_table_data(ResourceTab::CronJobs, cronjobs);
 
-        app.apply_action(Action::StartCommand);
-        for c in "rbac".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
+        assert_eq!(app.apply_action(Action::TriggerCronJob), AppCommand::None);
+        assert!(app.pending_confirmation_prompt().is_none());
 
-        let cmd = app.apply_action(Action::SubmitInput);
+        let _ = app.switch_to_tab(ResourceTab::CronJobs);
+        assert_eq!(app.apply_action(Action::TriggerCronJob), AppCommand::None);
         assert_eq!(
-            cmd,
-            AppCommand::InspectOps {
-                target: OpsInspectTarget::RbacMatrix { subject: None }
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::CreateJobFromCronJob {
+                namespace: "batch".to_string(),
+                cronjob: "nightly".to_string(),
             }
         );
+
+        app.focus_row_in_tab(
+            ResourceTab::Jobs,
+            Some("batch".to_string()),
+            "nightly-manual-1".to_string(),
+        );
+        assert_eq!(app.active_tab(), ResourceTab::Jobs);
+        let mut jobs = TableData::default();
+        jobs.set_rows(
+            vec!["Name".to_string()],
+            ["older", "nightly-manual-1"]
+                .iter()
+                .map(|name| RowData {
+                    name: name.to_string(),
+                    namespace: Some("batch".to_string()),
+                    columns: vec![name.to_string()],
+                    ..RowData::default()
+                })
+                .collect(),
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Jobs, jobs);
+        assert_eq!(
+            app.selected_row_name_for(ResourceTab::Jobs).as_deref(),
+            Some("nightly-manual-1")
+        );
     }
 
     #[test]
-    fn who_can_command_requests_lookup() {
+    fn rewatch_command_requests_watcher_restart() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
-
         app.apply_action(Action::StartCommand);
-        for c in "who-can get pods".chars() {
+        for c in "rewatch".chars() {
             app.apply_action(Action::InputChar(c));
         }
-
-        let cmd = app.apply_action(Action::SubmitInput);
         assert_eq!(
-            cmd,
-            AppCommand::InspectOps {
-                target: OpsInspectTarget::WhoCan {
-                    verb: "get".to_string(),
-                    resource: "pods".to_string(),
-                    namespace: None,
-                }
-            }
+            app.apply_action(Action::SubmitInput),
+            AppCommand::RestartWatchers
         );
     }
 
     #[test]
-    fn pulses_command_requests_pulses_overlay() {
+    fn port_forward_overlay_selects_and_stops_session() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        app.register_port_forward(
+            ResourceTab::Pods,
+            "default".to_string(),
+            "api-123".to_string(),
+            8080,
+            80,
+            4242,
+        );
+        app.register_port_forward(
+            ResourceTab::Services,
+            "default".to_string(),
+            "db".to_string(),
+            5432,
+            5432,
+            4343,
+        );
 
         app.apply_action(Action::StartCommand);
-        for c in "pulses".chars() {
+        for c in "pf".chars() {
             app.apply_action(Action::InputChar(c));
         }
+        app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            app.table_overlay_kind(),
+            Some(TableOverlayKind::PortForwards)
+        );
+        assert!(
+            app.table_overlay_text()
+                .is_some_and(|text| text.contains("default/db"))
+        );
 
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::InspectPulses);
+        app.apply_action(Action::Down);
+        assert_eq!(
+            app.apply_action(Action::DecodeSecret),
+            AppCommand::StopPortForward { pid: 4343 }
+        );
+
+        app.remove_port_forward_by_pid(4343);
+        app.refresh_port_forward_overlay();
+        let text = app.table_overlay_text().unwrap_or_default().to_string();
+        assert!(!text.contains("default/db"));
+        assert!(text.contains("▶ po"));
     }
 
     #[test]
-    fn alerts_command_requests_alerts_overlay() {
-        let mut app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
-        );
+    fn port_mapping_accepts_zero_or_blank_local_port_for_auto_pick() {
+        assert_eq!(parse_port_mapping("8080:80"), Some((8080, 80)));
+        assert_eq!(parse_port_mapping("0:80"), Some((0, 80)));
+        assert_eq!(parse_port_mapping(":80"), Some((0, 80)));
+        assert_eq!(parse_port_mapping("80"), None);
+        assert_eq!(parse_port_mapping("a:80"), None);
+    }
 
-        app.apply_action(Action::StartCommand);
-        for c in "alerts".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::InspectAlerts);
+    #[test]
+    fn named_port_mapping_accepts_service_port_name_on_either_side() {
+        assert_eq!(
+            parse_named_port_mapping("http:8080"),
+            Some((8080, "http".to_string()))
+        );
+        assert_eq!(
+            parse_named_port_mapping("8080:http"),
+            Some((8080, "http".to_string()))
+        );
+        assert_eq!(
+            parse_named_port_mapping(":grpc-web"),
+            Some((0, "grpc-web".to_string()))
+        );
+        assert_eq!(parse_named_port_mapping("8080:80"), None);
+        assert_eq!(parse_named_port_mapping("http:https"), None);
+        assert_eq!(parse_named_port_mapping("http"), None);
     }
 
     #[test]
-    fn xray_command_uses_selected_resource() {
+    fn rollout_watch_command_targets_selected_workload() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
-        let now = Local::now();
-        let mut pods = TableData::default();
-        pods.set_rows(
-            vec!["Name".to_string()],
+        let mut deployments = TableData::default();
+        deployments.set_rows(
+            vec!["Name".to_string(), "Namespace".to_string()],
             vec![RowData {
-                name: "api-123".to_string(),
-                namespace: Some("orca-sandbox".to_string()),
-                columns: vec!["api-123".to_string()],
-                detail: "kind: Pod".to_string(),
+                name: "api".to_string(),
+                namespace: Some("default".to_string()),
+                columns: vec!["api".to_string(), "default".to_string()],
+                ..RowData::default()
             }],
-            now,
+            Local::now(),
         );
-        app.set_active_table_data(ResourceTab::Pods, pods);
-        let _ = app.switch_to_tab(ResourceTab::Pods);
-        let _ = app.switch_to_tab(ResourceTab::Pods);
-        let _ = app.switch_to_tab(ResourceTab::Pods);
+        app.set_active_table_data(ResourceTab::Deployments, deployments);
+        let _ = app.switch_to_tab(ResourceTab::Deployments);
 
-        app.apply_action(Action::StartCommand);
-        for c in "xray".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
+        let run = |app: &mut App, line: &str| {
+            app.apply_action(Action::StartCommand);
+            for c in line.chars() {
+                app.apply_action(Action::InputChar(c));
+            }
+            app.apply_action(Action::SubmitInput)
+        };
 
-        let cmd = app.apply_action(Action::SubmitInput);
         assert_eq!(
-            cmd,
-            AppCommand::InspectXray {
-                tab: ResourceTab::Pods,
-                namespace: Some("orca-sandbox".to_string()),
-                name: "api-123".to_string(),
+            run(&mut app, "rollout watch 90"),
+            AppCommand::WatchRollout {
+                tab: ResourceTab::Deployments,
+                namespace: "default".to_string(),
+                name: "api".to_string(),
+                timeout_secs: 90,
             }
         );
-    }
-
-    #[test]
-    fn readonly_command_enables_read_only_mode() {
-        let mut app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+        assert!(matches!(
+            run(&mut app, "rollout watch"),
+            AppCommand::WatchRollout {
+                timeout_secs: 300,
+                ..
+            }
+        ));
+        assert_eq!(run(&mut app, "rollout watch soon"), AppCommand::None);
+        assert!(app.status().contains("Invalid rollout timeout"));
+        assert_eq!(
+            run(&mut app, "rollout status"),
+            AppCommand::RolloutStatus {
+                tab: ResourceTab::Deployments,
+                namespace: "default".to_string(),
+                name: "api".to_string(),
+            }
+        );
+        assert_eq!(
+            run(&mut app, "rollout history"),
+            AppCommand::RolloutHistory {
+                tab: ResourceTab::Deployments,
+                namespace: "default".to_string(),
+                name: "api".to_string(),
+            }
         );
 
-        app.apply_action(Action::StartCommand);
-        for c in "readonly on".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::None);
-        assert!(app.read_only());
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+        assert_eq!(run(&mut app, "rollout watch"), AppCommand::None);
+        assert_eq!(run(&mut app, "rollout history"), AppCommand::None);
+        assert!(app.status().contains("Rollout history is available only"));
     }
 
     #[test]
-    fn readonly_mode_blocks_scale_command() {
+    fn enter_on_event_jumps_to_involved_object() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+            NamespaceScope::All,
         );
-        app.set_read_only(true);
+        let event = |name: &str, kind: &str, object: &str| RowData {
+            name: name.to_string(),
+            namespace: Some("shop".to_string()),
+            columns: vec![
+                "shop".to_string(),
+                kind.to_string(),
+                object.to_string(),
+                "ScalingReplicaSet".to_string(),
+            ],
+            ..RowData::default()
+        };
+        let mut events = TableData::default();
+        events.set_rows(
+            vec![
+                "Namespace".to_string(),
+                "Kind".to_string(),
+                "Object".to_string(),
+                "Reason".to_string(),
+            ],
+            vec![
+                event("cart.1", "Deployment", "cart"),
+                event("quota.1", "Telemetry", "default"),
+            ],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Events, events);
+        let _ = app.switch_to_tab(ResourceTab::Events);
 
-        let now = Local::now();
+        let cmd = app.apply_action(Action::EnterResource);
+        assert_eq!(app.active_tab(), ResourceTab::Deployments);
+        assert_eq!(cmd, AppCommand::RefreshActive);
+
+        let deployment = |name: &str| RowData {
+            name: name.to_string(),
+            namespace: Some("shop".to_string()),
+            columns: vec![name.to_string()],
+            ..RowData::default()
+        };
         let mut deployments = TableData::default();
         deployments.set_rows(
             vec!["Name".to_string()],
-            vec![RowData {
-                name: "api".to_string(),
-                namespace: Some("orca-sandbox".to_string()),
-                columns: vec!["api".to_string()],
-                detail: "kind: Deployment".to_string(),
-            }],
-            now,
+            vec![deployment("api"), deployment("cart")],
+            Local::now(),
         );
         app.set_active_table_data(ResourceTab::Deployments, deployments);
-        let _ = app.switch_to_tab(ResourceTab::Deployments);
+        assert_eq!(
+            app.active_selected_row().map(|row| row.name.as_str()),
+            Some("cart")
+        );
 
-        app.apply_action(Action::StartCommand);
-        for c in "scale 3".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::None);
-        assert!(app.status().contains("Read-only mode ON"));
+        let _ = app.switch_to_tab(ResourceTab::Events);
+        app.apply_action(Action::Down);
+        assert!(matches!(
+            app.apply_action(Action::EnterResource),
+            AppCommand::DescribeSelected { ref resource, ref name, .. }
+                if resource == "telemetry" && name == "default"
+        ));
     }
 
     #[test]
-    fn runtime_alias_expands_to_target_command() {
+    fn debug_command_defaults_image_and_targets_picked_container() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
-        let mut aliases = HashMap::new();
-        aliases.insert("dpl".to_string(), "deploy".to_string());
-        app.set_runtime_config(aliases, Vec::new(), Vec::new(), Some("test".to_string()));
-
-        app.apply_action(Action::StartCommand);
-        for c in "dpl".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-        let _ = app.apply_action(Action::SubmitInput);
-        assert_eq!(app.active_tab(), ResourceTab::Deployments);
-    }
-
-    #[test]
-    fn plugin_command_builds_run_command_with_placeholders() {
-        let mut app = App::new(
-            "clusterA".to_string(),
-            "contextA".to_string(),
-            NamespaceScope::Named("orca-sandbox".to_string()),
-        );
-        app.set_user("alice".to_string());
-
-        let now = Local::now();
-        let mut pods = TableData::default();
-        pods.set_rows(
+        let mut data = TableData::default();
+        data.set_rows(
             vec!["Name".to_string()],
             vec![RowData {
-                name: "api-123".to_string(),
-                namespace: Some("orca-sandbox".to_string()),
-                columns: vec!["api-123".to_string()],
-                detail: "kind: Pod".to_string(),
+                name: "api-0".to_string(),
+                namespace: Some("default".to_string()),
+                columns: vec!["api-0".to_string()],
+                ..RowData::default()
             }],
-            now,
+            Local::now(),
         );
-        app.set_active_table_data(ResourceTab::Pods, pods);
+        app.set_active_table_data(ResourceTab::Pods, data);
         let _ = app.switch_to_tab(ResourceTab::Pods);
 
-        let plugin = PluginCommandDef {
-            name: "diag".to_string(),
-            command: "kubectl".to_string(),
-            args: vec![
-                "get".to_string(),
-                "pod".to_string(),
-                "{name}".to_string(),
-                "-n".to_string(),
-                "{namespace}".to_string(),
-                "{extra}".to_string(),
-            ],
-            description: "diag".to_string(),
-            mutating: false,
-            timeout_secs: 15,
-            retries: 2,
+        let run = |app: &mut App, line: &str| {
+            app.apply_action(Action::StartCommand);
+            for c in line.chars() {
+                app.apply_action(Action::InputChar(c));
+            }
+            app.apply_action(Action::SubmitInput)
         };
-        app.set_runtime_config(
-            HashMap::new(),
-            vec![plugin],
-            Vec::new(),
-            Some("test".to_string()),
+
+        assert_eq!(
+            run(&mut app, "debug"),
+            AppCommand::DebugPod {
+                namespace: "default".to_string(),
+                pod_name: "api-0".to_string(),
+                image: "busybox".to_string(),
+                target_container: None,
+            }
         );
 
-        app.apply_action(Action::StartCommand);
-        for c in "plugin diag -o yaml".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-        let cmd = app.apply_action(Action::SubmitInput);
+        app.set_container_picker(
+            "default",
+            "api-0",
+            vec![crate::model::PodContainerInfo {
+                name: "app".to_string(),
+                image: "gcr.io/distroless/static".to_string(),
+                ready: true,
+                state: "Running".to_string(),
+                restarts: 0,
+                age: "1m".to_string(),
+                ..Default::default()
+            }],
+        );
         assert_eq!(
-            cmd,
-            AppCommand::RunPlugin {
-                run: PluginRun {
-                    name: "diag".to_string(),
-                    program: "kubectl".to_string(),
-                    args: vec![
-                        "get".to_string(),
-                        "pod".to_string(),
-                        "api-123".to_string(),
-                        "-n".to_string(),
-                        "orca-sandbox".to_string(),
-                        "-o".to_string(),
-                        "yaml".to_string()
-                    ],
-                    mutating: false,
-                    timeout_secs: 15,
-                    retries: 2
-                }
+            run(&mut app, "debug nicolaka/netshoot"),
+            AppCommand::DebugPod {
+                namespace: "default".to_string(),
+                pod_name: "api-0".to_string(),
+                image: "nicolaka/netshoot".to_string(),
+                target_container: Some("app".to_string()),
             }
         );
     }
 
     #[test]
-    fn config_command_opens_runtime_config_overlay() {
+    fn context_switch_adopts_catalog_namespace_only_when_enabled() {
         let mut app = App::new(
             "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+            "prod".to_string(),
+            NamespaceScope::All,
         );
-        let mut aliases = HashMap::new();
-        aliases.insert("k".to_string(), "pods".to_string());
-        app.set_runtime_config(aliases, Vec::new(), Vec::new(), Some("test".to_string()));
+        let row = |context: &str, namespace: &str| ContextCatalogRow {
+            context: context.to_string(),
+            cluster: "cluster".to_string(),
+            auth_info: "admin".to_string(),
+            namespace: namespace.to_string(),
+        };
+        app.set_kube_catalog(
+            vec!["prod".to_string(), "staging".to_string()],
+            vec!["cluster".to_string()],
+            vec!["admin".to_string()],
+            vec![row("prod", "payments"), row("staging", "checkout")],
+        );
+        let switch = |app: &mut App, context: &str| {
+            app.set_kube_target(
+                "cluster".to_string(),
+                context.to_string(),
+                "admin".to_string(),
+                "default".to_string(),
+                true,
+            );
+        };
 
-        app.apply_action(Action::StartCommand);
-        for c in "config".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::None);
-        assert!(app.table_overlay_active());
-        assert!(
-            app.table_overlay_text()
-                .unwrap_or_default()
-                .contains("aliases 1")
+        switch(&mut app, "staging");
+        assert_eq!(app.namespace_scope(), &NamespaceScope::All);
+
+        app.set_adopt_context_namespace(true);
+        switch(&mut app, "staging");
+        assert_eq!(
+            app.namespace_scope(),
+            &NamespaceScope::Named("checkout".to_string())
+        );
+        switch(&mut app, "prod");
+        assert_eq!(
+            app.namespace_scope(),
+            &NamespaceScope::Named("payments".to_string())
         );
     }
 
     #[test]
-    fn runtime_hotkey_executes_bound_command() {
+    fn images_command_targets_selected_workload() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
-        app.set_runtime_config(
-            HashMap::new(),
-            Vec::new(),
-            vec![HotkeyCommandDef {
-                key: "ctrl+shift+p".to_string(),
-                command: "pulses".to_string(),
-                jump: false,
-                description: "p".to_string(),
+        let mut jobs = TableData::default();
+        jobs.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "migrate".to_string(),
+                namespace: Some("default".to_string()),
+                columns: vec!["migrate".to_string()],
+                ..RowData::default()
             }],
-            Some("test".to_string()),
+            Local::now(),
         );
+        app.set_active_table_data(ResourceTab::Jobs, jobs);
+        let _ = app.switch_to_tab(ResourceTab::Jobs);
 
-        let command = app.execute_hotkey_signature("ctrl+shift+p");
-        assert_eq!(command, Some(AppCommand::InspectPulses));
+        app.apply_action(Action::StartCommand);
+        for c in "images".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        assert_eq!(
+            app.apply_action(Action::SubmitInput),
+            AppCommand::InspectImages {
+                tab: ResourceTab::Jobs,
+                namespace: "default".to_string(),
+                name: "migrate".to_string(),
+            }
+        );
     }
 
     #[test]
-    fn ns_command_requests_refresh_all() {
+    fn export_ns_command_requires_named_scope_and_dir() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+            NamespaceScope::Named("shop".to_string()),
         );
+        let run = |app: &mut App, line: &str| {
+            app.apply_action(Action::StartCommand);
+            for c in line.chars() {
+                app.apply_action(Action::InputChar(c));
+            }
+            app.apply_action(Action::SubmitInput)
+        };
 
-        app.apply_action(Action::StartCommand);
-        for c in "ns kube-system".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::RefreshAll);
         assert_eq!(
-            app.namespace_scope(),
-            &NamespaceScope::Named("kube-system".to_string())
+            run(&mut app, "export ns backup --secrets"),
+            AppCommand::ExportNamespace {
+                namespace: "shop".to_string(),
+                dir: "backup".to_string(),
+                include_secrets: true,
+            }
+        );
+        assert_eq!(run(&mut app, "export ns"), AppCommand::None);
+        assert!(app.status().starts_with("Usage: :export ns"));
+
+        let mut all = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::All,
         );
+        assert_eq!(run(&mut all, "export ns backup"), AppCommand::None);
     }
 
     #[test]
-    fn ns_without_arg_switches_to_namespaces_tab() {
+    fn read_only_guard_blocks_mutating_commands_only() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        app.set_read_only(true);
 
-        app.apply_action(Action::StartCommand);
-        for c in "ns".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
+        let sync = AppCommand::InspectOps {
+            target: OpsInspectTarget::ArgoCdSync {
+                name: "guestbook".to_string(),
+                prune: false,
+                dry_run: false,
+                resource: None,
+            },
+        };
+        assert!(!app.allow_command(&sync));
+        assert_eq!(app.status(), "Read-only mode ON: 'argocd sync' is blocked");
 
-        let _ = app.apply_action(Action::SubmitInput);
-        assert_eq!(app.active_tab(), ResourceTab::Namespaces);
+        let plugin = |mutating: bool| AppCommand::RunPlugin {
+            run: PluginRun {
+                name: "bounce".to_string(),
+                program: "kubectl".to_string(),
+                args: Vec::new(),
+                mutating,
+                timeout_secs: 10,
+                retries: 0,
+                stream: false,
+                env: Vec::new(),
+            },
+        };
+        assert!(!app.allow_command(&plugin(true)));
+        assert!(app.allow_command(&plugin(false)));
+        assert!(app.allow_command(&AppCommand::InspectOps {
+            target: OpsInspectTarget::ArgoCdDiff {
+                name: "guestbook".to_string(),
+            },
+        }));
+        assert!(app.allow_command(&AppCommand::RefreshActive));
+
+        app.set_read_only(false);
+        assert!(app.allow_command(&sync));
     }
 
     #[test]
-    fn bare_tab_token_switches_tab() {
+    fn export_yaml_action_builds_default_and_override_paths() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+            NamespaceScope::Named("shop".to_string()),
+        );
+        let mut config_maps = TableData::default();
+        config_maps.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "settings".to_string(),
+                namespace: Some("shop".to_string()),
+                columns: vec!["settings".to_string()],
+                detail: "kind: ConfigMap".to_string(),
+                ..RowData::default()
+            }],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::ConfigMaps, config_maps);
+        let _ = app.switch_to_tab(ResourceTab::ConfigMaps);
+
+        let export = |path: &str| AppCommand::ExportSelectedYaml {
+            tab: ResourceTab::ConfigMaps,
+            namespace: Some("shop".to_string()),
+            name: "settings".to_string(),
+            path: path.to_string(),
+        };
+        assert_eq!(
+            app.apply_action(Action::ExportSelectedYaml),
+            export(".manifests/exports/shop-settings.yaml")
+        );
+        assert_eq!(
+            app.row_detail(ResourceTab::ConfigMaps, Some("shop"), "settings"),
+            Some("kind: ConfigMap".to_string())
+        );
+
+        app.set_export_dir(Some("/tmp/backups".to_string()));
+        assert_eq!(
+            app.apply_action(Action::ExportSelectedYaml),
+            export("/tmp/backups/shop-settings.yaml")
         );
 
         app.apply_action(Action::StartCommand);
-        for c in "deployments".chars() {
+        for c in "save out/cm.yaml".chars() {
             app.apply_action(Action::InputChar(c));
         }
-
-        let _ = app.apply_action(Action::SubmitInput);
-        assert_eq!(app.active_tab(), ResourceTab::Deployments);
+        assert_eq!(app.apply_action(Action::SubmitInput), export("out/cm.yaml"));
     }
 
     #[test]
-    fn namespace_alias_token_sets_scope() {
+    fn display_name_strips_configured_prefixes_only_for_render() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+            NamespaceScope::Named("shop".to_string()),
         );
+        let row = |name: &str, namespace: Option<&str>| RowData {
+            name: name.to_string(),
+            namespace: namespace.map(str::to_string),
+            ..RowData::default()
+        };
 
-        app.apply_action(Action::StartCommand);
-        for c in "namespace:ns kube-system".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
+        assert_eq!(app.display_name(&row("shop-api", Some("shop"))), "shop-api");
 
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::RefreshAll);
+        app.set_strip_name_prefixes(vec!["{namespace}-".to_string(), "release-".to_string()]);
         assert_eq!(
-            app.namespace_scope(),
-            &NamespaceScope::Named("kube-system".to_string())
+            app.display_name(&row("shop-api-7d9f", Some("shop"))),
+            "…api-7d9f"
+        );
+        assert_eq!(app.display_name(&row("release-web", None)), "…web");
+        assert_eq!(app.display_name(&row("shop-", Some("shop"))), "shop-");
+        assert_eq!(
+            app.display_name(&row("other-api", Some("shop"))),
+            "other-api"
         );
     }
 
     #[test]
-    fn scale_command_executes_without_confirmation() {
+    fn activity_feed_keeps_newest_events_and_refreshes_while_open() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+            NamespaceScope::All,
         );
-        let now = Local::now();
-        let mut deployments = TableData::default();
-        deployments.set_rows(
-            vec!["Name".to_string()],
-            vec![RowData {
-                name: "web".to_string(),
-                namespace: Some("orca-sandbox".to_string()),
-                columns: vec!["web".to_string()],
-                detail: "kind: Deployment".to_string(),
-            }],
-            now,
+        let activity = |name: String| crate::model::WatchActivity {
+            at: Local::now(),
+            tab: ResourceTab::Pods,
+            change: crate::model::WatchChange::Deleted,
+            namespace: Some("default".to_string()),
+            name,
+        };
+        let _ = run_command_line(&mut app, "activity");
+        assert_eq!(app.table_overlay_kind(), Some(TableOverlayKind::Activity));
+        assert!(
+            app.table_overlay_text()
+                .is_some_and(|text| text.starts_with("No watch events yet"))
         );
-        app.set_active_table_data(ResourceTab::Deployments, deployments);
-        let _ = app.switch_to_tab(ResourceTab::Deployments);
 
-        app.apply_action(Action::StartCommand);
-        for c in "scale 2".chars() {
-            app.apply_action(Action::InputChar(c));
+        for index in 0..WATCH_ACTIVITY_LIMIT + 3 {
+            app.record_watch_activity(activity(format!("web-{index}")));
         }
+        let text = app.table_overlay_text().unwrap_or_default().to_string();
+        assert_eq!(text.lines().count(), WATCH_ACTIVITY_LIMIT);
+        assert!(
+            text.lines()
+                .next()
+                .is_some_and(|line| line.ends_with(&format!(
+                    "Pod default/web-{} deleted",
+                    WATCH_ACTIVITY_LIMIT + 2
+                )))
+        );
+        assert!(!text.contains("web-2 deleted"));
+    }
 
-        let cmd = app.apply_action(Action::SubmitInput);
+    #[test]
+    fn auth_expiry_retries_reauth_on_a_backoff_until_recovered() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::All,
+        );
+        let now = Instant::now();
+        assert!(!app.reauth_due(now));
+
+        app.mark_auth_expired();
+        assert!(app.status().starts_with("Authentication expired"));
+        assert!(app.reauth_due(now));
+        app.note_reauth_attempt(now);
+        assert!(!app.reauth_due(now + Duration::from_secs(5)));
+        assert!(app.reauth_due(now + Duration::from_secs(31)));
+
+        app.mark_auth_recovered();
+        assert!(!app.auth_expired());
+        assert!(!app.reauth_due(now + Duration::from_secs(60)));
         assert_eq!(
-            cmd,
-            AppCommand::ScaleWorkload {
-                tab: ResourceTab::Deployments,
-                namespace: "orca-sandbox".to_string(),
-                name: "web".to_string(),
-                replicas: 2
-            }
+            app.status(),
+            "Authentication recovered with refreshed credentials"
         );
-        assert!(app.pending_confirmation_prompt().is_none());
     }
 
     #[test]
-    fn prefixed_scale_command_executes_without_confirmation() {
+    fn namespace_cycling_wraps_through_sorted_namespaces() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+            NamespaceScope::All,
         );
-        let now = Local::now();
-        let mut deployments = TableData::default();
-        deployments.set_rows(
-            vec!["Name".to_string()],
-            vec![RowData {
-                name: "web".to_string(),
-                namespace: Some("orca-sandbox".to_string()),
-                columns: vec!["web".to_string()],
-                detail: "kind: Deployment".to_string(),
-            }],
-            now,
+        assert_eq!(
+            app.apply_action(Action::NextNamespace),
+            AppCommand::RefreshTab {
+                tab: ResourceTab::Namespaces
+            }
         );
-        app.set_active_table_data(ResourceTab::Deployments, deployments);
-        let _ = app.switch_to_tab(ResourceTab::Deployments);
+        assert_eq!(app.namespace_scope(), &NamespaceScope::All);
 
-        app.apply_action(Action::StartCommand);
-        for c in ":scale 2".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
+        let mut namespaces = TableData::default();
+        namespaces.set_rows(
+            vec!["Name".to_string()],
+            ["default", "apps"]
+                .iter()
+                .map(|name| RowData {
+                    name: name.to_string(),
+                    columns: vec![name.to_string()],
+                    ..RowData::default()
+                })
+                .collect(),
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Namespaces, namespaces);
 
-        let cmd = app.apply_action(Action::SubmitInput);
         assert_eq!(
-            cmd,
-            AppCommand::ScaleWorkload {
-                tab: ResourceTab::Deployments,
-                namespace: "orca-sandbox".to_string(),
-                name: "web".to_string(),
-                replicas: 2
-            }
+            app.apply_action(Action::NextNamespace),
+            AppCommand::RefreshAll
+        );
+        assert_eq!(
+            app.namespace_scope(),
+            &NamespaceScope::Named("apps".to_string())
+        );
+        let _ = app.apply_action(Action::NextNamespace);
+        let _ = app.apply_action(Action::NextNamespace);
+        assert_eq!(app.namespace_scope(), &NamespaceScope::All);
+        let _ = app.apply_action(Action::PrevNamespace);
+        assert_eq!(
+            app.namespace_scope(),
+            &NamespaceScope::Named("default".to_string())
         );
-        assert!(app.pending_confirmation_prompt().is_none());
     }
 
     #[test]
-    fn command_completion_empty_does_not_block_submission() {
+    fn toggle_namespace_scope_returns_to_last_named_namespace() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+            NamespaceScope::Named("payments".to_string()),
+        );
+
+        assert_eq!(
+            app.apply_action(Action::ToggleNamespaceScope),
+            AppCommand::RefreshAll
+        );
+        assert_eq!(app.namespace_scope(), &NamespaceScope::All);
+
+        assert_eq!(
+            app.apply_action(Action::ToggleNamespaceScope),
+            AppCommand::RefreshAll
+        );
+        assert_eq!(
+            app.namespace_scope(),
+            &NamespaceScope::Named("payments".to_string())
         );
+
         app.apply_action(Action::StartCommand);
-        for c in ":scale 999".chars() {
+        for c in "ns shop".chars() {
             app.apply_action(Action::InputChar(c));
         }
-        // Ensure parse/submit still runs even if completion UI has no candidates.
-        let _ = app.completion_candidates();
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert!(matches!(
-            cmd,
-            AppCommand::ScaleWorkload { replicas: 999, .. } | AppCommand::None
-        ));
+        app.apply_action(Action::SubmitInput);
+        let _ = app.apply_action(Action::RestoreAllNamespaces);
+        let _ = app.apply_action(Action::ToggleNamespaceScope);
+        assert_eq!(
+            app.namespace_scope(),
+            &NamespaceScope::Named("shop".to_string())
+        );
     }
 
     #[test]
-    fn jump_namespace_path_sets_scope_and_refreshes() {
+    fn inline_yaml_edit_validates_before_applying() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+            NamespaceScope::Named("shop".to_string()),
+        );
+        let mut config_maps = TableData::default();
+        config_maps.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "settings".to_string(),
+                namespace: Some("shop".to_string()),
+                columns: vec!["settings".to_string()],
+                detail: "apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: settings\n  namespace: shop\n  resourceVersion: \"42\"\ndata:\n  mode: a\n".to_string(),
+                ..RowData::default()
+            }],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::ConfigMaps, config_maps);
+        let _ = app.switch_to_tab(ResourceTab::ConfigMaps);
+
+        assert_eq!(app.apply_action(Action::EditYamlInline), AppCommand::None);
+        assert_eq!(app.mode(), InputMode::Edit);
+        assert!(
+            !app.yaml_editor_lines()
+                .iter()
+                .any(|line| line.contains("resourceVersion"))
         );
 
-        app.apply_action(Action::StartJump);
-        for c in "ns openclaw/openclaw".chars() {
+        let last = app.yaml_editor_lines().len();
+        for _ in 0..last {
+            app.apply_action(Action::Down);
+        }
+        app.apply_action(Action::Bottom);
+        app.apply_action(Action::Backspace);
+        app.apply_action(Action::InputChar('b'));
+        app.apply_action(Action::InsertNewline);
+        for c in "level: x".chars() {
             app.apply_action(Action::InputChar(c));
         }
 
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::RefreshAll);
-        assert_eq!(
-            app.namespace_scope(),
-            &NamespaceScope::Named("openclaw".to_string())
+        match app.apply_action(Action::SubmitInput) {
+            AppCommand::ApplyYaml {
+                yaml,
+                resource_version,
+                ..
+            } => {
+                assert!(yaml.contains("  mode: b\n  level: x\n"));
+                assert_eq!(resource_version.as_deref(), Some("42"));
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+
+        let kind_row = app
+            .yaml_editor_lines()
+            .iter()
+            .position(|line| line == "kind: ConfigMap")
+            .expect("kind line");
+        for _ in 0..last {
+            app.apply_action(Action::Up);
+        }
+        for _ in 0..kind_row {
+            app.apply_action(Action::Down);
+        }
+        app.apply_action(Action::Bottom);
+        app.apply_action(Action::InputChar('x'));
+        assert_eq!(app.apply_action(Action::SubmitInput), AppCommand::None);
+        assert_eq!(app.yaml_editor_error(), Some("kind must stay 'ConfigMap'"));
+        app.apply_action(Action::Backspace);
+
+        app.apply_action(Action::InsertNewline);
+        for c in "bad: [".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        assert_eq!(app.apply_action(Action::SubmitInput), AppCommand::None);
+        assert!(
+            app.yaml_editor_error()
+                .is_some_and(|error| error.starts_with("invalid YAML"))
         );
-        assert_eq!(app.filter(), "");
+        assert_eq!(app.mode(), InputMode::Edit);
+
+        app.finish_yaml_apply(Err("conflict".to_string()));
+        assert_eq!(app.yaml_editor_error(), Some("conflict"));
+        app.finish_yaml_apply(Ok(()));
+        assert!(!app.yaml_editor_active());
+        assert_eq!(app.mode(), InputMode::Normal);
     }
 
     #[test]
-    fn ctx_command_returns_switch_context_command() {
+    fn tab_in_filter_mode_cycles_column_scope() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        let mut pods = TableData::default();
+        pods.set_rows(
+            vec!["Name".to_string(), "Status".to_string()],
+            vec![
+                RowData {
+                    name: "api".to_string(),
+                    columns: vec!["api".to_string(), "CrashLoopBackOff".to_string()],
+                    ..RowData::default()
+                },
+                RowData {
+                    name: "crash-reporter".to_string(),
+                    columns: vec!["crash-reporter".to_string(), "Running".to_string()],
+                    ..RowData::default()
+                },
+            ],
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Pods, pods);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
 
-        app.apply_action(Action::StartCommand);
-        for c in "ctx dev-cluster".chars() {
+        app.apply_action(Action::StartFilter);
+        for c in "crash".chars() {
             app.apply_action(Action::InputChar(c));
         }
+        assert_eq!(app.active_visible_rows().len(), 2);
 
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(
-            cmd,
-            AppCommand::SwitchContext {
-                context: "dev-cluster".to_string()
-            }
-        );
+        app.apply_action(Action::CompleteInput);
+        app.apply_action(Action::CompleteInput);
+        assert_eq!(app.input(), "status:crash");
+        let visible = app.active_visible_rows();
+        assert_eq!(visible.len(), 1);
+        assert_eq!(visible[0].name, "api");
+
+        app.apply_action(Action::CompleteInput);
+        assert_eq!(app.input(), "crash");
     }
 
     #[test]
-    fn prefixed_ctx_command_returns_switch_context_command() {
+    fn metrics_unavailable_is_reset_on_context_switch() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        app.mark_metrics_unavailable();
+        assert!(app.metrics_unavailable());
+        assert_eq!(
+            app.status(),
+            "metrics-server not installed — CPU/MEM unavailable"
+        );
 
-        app.apply_action(Action::StartCommand);
-        for c in ":context dev-cluster".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
+        app.set_status("other".to_string());
+        app.mark_metrics_unavailable();
+        assert_eq!(app.status(), "other");
 
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(
-            cmd,
-            AppCommand::SwitchContext {
-                context: "dev-cluster".to_string()
-            }
+        app.set_kube_target(
+            "cluster".to_string(),
+            "context".to_string(),
+            "user".to_string(),
+            "default".to_string(),
+            false,
         );
+        assert!(app.metrics_unavailable());
+        app.set_kube_target(
+            "cluster-b".to_string(),
+            "context-b".to_string(),
+            "user".to_string(),
+            "default".to_string(),
+            false,
+        );
+        assert!(!app.metrics_unavailable());
     }
 
     #[test]
-    fn ctx_without_arg_opens_context_catalog_overlay() {
+    fn render_table_text_aligns_columns() {
+        let mut table = TableData::default();
+        table.set_rows(
+            vec!["Name".to_string(), "CPU%".to_string()],
+            vec![
+                RowData {
+                    name: "worker-long-name".to_string(),
+                    columns: vec!["worker-long-name".to_string(), "91%".to_string()],
+                    ..RowData::default()
+                },
+                RowData {
+                    name: "cp".to_string(),
+                    columns: vec!["cp".to_string(), "4%".to_string()],
+                    ..RowData::default()
+                },
+            ],
+            Local::now(),
+        );
+        assert_eq!(
+            render_table_text(&table),
+            "Name              CPU%\nworker-long-name  91%\ncp                4%"
+        );
+    }
+
+    fn custom_resource_app(scalable: bool) -> App {
         let mut app = App::new(
-            "https://cluster".to_string(),
-            "openclaw".to_string(),
+            "cluster".to_string(),
+            "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
-        app.set_kube_catalog(
-            vec!["openclaw".to_string()],
-            vec!["openclaw".to_string()],
-            vec!["openclaw".to_string()],
-            vec![ContextCatalogRow {
-                context: "openclaw".to_string(),
-                cluster: "openclaw".to_string(),
-                auth_info: "openclaw".to_string(),
-                namespace: "openclaw".to_string(),
+        app.set_custom_resources(vec![CustomResourceDef {
+            name: "widgets".to_string(),
+            group: "example.com".to_string(),
+            version: "v1".to_string(),
+            kind: "Widget".to_string(),
+            plural: "widgets".to_string(),
+            namespaced: true,
+            scalable,
+        }]);
+        let mut widgets = TableData::default();
+        widgets.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "gear".to_string(),
+                namespace: Some("default".to_string()),
+                columns: vec!["gear".to_string()],
+                ..RowData::default()
             }],
+            Local::now(),
         );
+        app.set_active_table_data(ResourceTab::CustomResources, widgets);
+        let _ = app.switch_to_tab(ResourceTab::CustomResources);
+        app
+    }
 
+    fn run_command_line(app: &mut App, line: &str) -> AppCommand {
         app.apply_action(Action::StartCommand);
-        for c in "ctx".chars() {
+        for c in line.chars() {
             app.apply_action(Action::InputChar(c));
         }
+        app.apply_action(Action::SubmitInput)
+    }
 
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::None);
-        assert!(app.table_overlay_active());
-        assert_eq!(app.pane_label(), "out");
-        assert!(app.table_overlay_text().unwrap_or("").contains("openclaw"));
+    #[test]
+    fn custom_resource_delete_requires_confirmation() {
+        let mut app = custom_resource_app(false);
+
+        assert_eq!(run_command_line(&mut app, "delete"), AppCommand::None);
+        assert_eq!(app.status, "Delete Widget default/gear? (y/n)");
+        match app.apply_action(Action::ConfirmYes) {
+            AppCommand::DeleteCustomResource {
+                custom,
+                namespace,
+                name,
+                propagation,
+            } => {
+                assert_eq!(propagation, DeletePropagation::Background);
+                assert_eq!(custom.kind, "Widget");
+                assert_eq!(namespace.as_deref(), Some("default"));
+                assert_eq!(name, "gear");
+            }
+            other => panic!("unexpected command: {other:?}"),
+        }
     }
 
     #[test]
-    fn jump_cluster_returns_switch_cluster_command() {
+    fn custom_resource_delete_is_blocked_in_read_only_mode() {
+        let mut app = custom_resource_app(false);
+        app.set_read_only(true);
+
+        assert_eq!(run_command_line(&mut app, "delete"), AppCommand::None);
+        assert!(app.pending_confirmation.is_none());
+        assert!(app.status.contains("Read-only"));
+    }
+
+    #[test]
+    fn custom_resource_scale_requires_scale_subresource() {
+        let mut app = custom_resource_app(false);
+        assert_eq!(run_command_line(&mut app, "scale 3"), AppCommand::None);
+        assert_eq!(app.status, "Widget does not expose a /scale subresource");
+
+        let mut app = custom_resource_app(true);
+        match run_command_line(&mut app, "scale 3") {
+            AppCommand::ScaleCustomResource {
+                custom,
+                namespace,
+                name,
+                replicas,
+            } => {
+                assert!(custom.scalable);
+                assert_eq!(namespace.as_deref(), Some("default"));
+                assert_eq!(name, "gear");
+                assert_eq!(replicas, 3);
+            }
+            other => panic!("unexpected command: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn more_command_loads_next_pod_page_for_current_scope() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        let mut pods = TableData::default();
+        pods.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "api-0".to_string(),
+                namespace: Some("default".to_string()),
+                columns: vec!["api-0".to_string()],
+                ..RowData::default()
+            }],
+            Local::now(),
+        );
+        pods.has_more = true;
+        app.set_active_table_data(ResourceTab::Pods, pods);
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+        assert_eq!(app.list_pages_for(ResourceTab::Pods), 1);
 
-        app.apply_action(Action::StartJump);
-        for c in "cluster homelab".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-
-        let cmd = app.apply_action(Action::SubmitInput);
         assert_eq!(
-            cmd,
-            AppCommand::SwitchCluster {
-                cluster: "homelab".to_string()
-            }
+            run_command_line(&mut app, "more"),
+            AppCommand::RefreshActive
         );
+        assert_eq!(app.list_pages_for(ResourceTab::Pods), 2);
+        assert_eq!(app.list_pages_for(ResourceTab::Deployments), 1);
+
+        app.apply_action(Action::ToggleNamespaceScope);
+        assert_eq!(app.list_pages_for(ResourceTab::Pods), 1);
     }
 
     #[test]
-    fn prefixed_jump_cluster_returns_switch_cluster_command() {
+    fn copy_reference_menu_offers_kubectl_selector_and_argo_forms() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+            NamespaceScope::Named("payments".to_string()),
+        );
+        let mut deployments = TableData::default();
+        deployments.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "api".to_string(),
+                namespace: Some("payments".to_string()),
+                columns: vec!["api".to_string()],
+                labels: [("team".to_string(), "core".to_string())].into(),
+                detail: "apiVersion: apps/v1\nkind: Deployment\nspec:\n  selector:\n    matchLabels:\n      app: api\n      tier: web\n".to_string(),
+                ..RowData::default()
+            }],
+            Local::now(),
         );
+        app.set_active_table_data(ResourceTab::Deployments, deployments);
+        let _ = app.switch_to_tab(ResourceTab::Deployments);
 
-        app.apply_action(Action::StartJump);
-        for c in ">cluster homelab".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
+        assert_eq!(app.apply_action(Action::CopyReference), AppCommand::None);
+        let values = app
+            .reference_menu_entries()
+            .iter()
+            .map(|entry| (entry.label.as_str(), entry.value.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            vec![
+                ("kubectl", "deployment/api -n payments"),
+                ("selector", "app=api,tier=web"),
+                ("argo", "apps:Deployment:api"),
+            ]
+        );
 
-        let cmd = app.apply_action(Action::SubmitInput);
+        app.apply_action(Action::Down);
         assert_eq!(
-            cmd,
-            AppCommand::SwitchCluster {
-                cluster: "homelab".to_string()
+            app.apply_action(Action::EnterResource),
+            AppCommand::CopyToClipboard {
+                label: "selector".to_string(),
+                text: "app=api,tier=web".to_string(),
+            }
+        );
+        assert!(!app.reference_menu_active());
+
+        assert_eq!(
+            run_command_line(&mut app, "ref argo"),
+            AppCommand::CopyToClipboard {
+                label: "argo".to_string(),
+                text: "apps:Deployment:api".to_string(),
             }
         );
     }
 
     #[test]
-    fn user_command_returns_switch_user_command() {
+    fn copy_reference_falls_back_to_row_labels_for_core_objects() {
+        let row = RowData {
+            name: "settings".to_string(),
+            namespace: Some("default".to_string()),
+            labels: [("app".to_string(), "web".to_string())].into(),
+            detail: "apiVersion: v1\nkind: ConfigMap\n".to_string(),
+            ..RowData::default()
+        };
+        let entries = resource_reference_entries(&row, Some(("configmap".to_string(), true)));
+        assert_eq!(entries[1].value, "app=web");
+        assert_eq!(entries[2].value, ":ConfigMap:settings");
+    }
+
+    #[test]
+    fn selector_command_is_stored_per_tab_and_clears() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        let _ = app.switch_to_tab(ResourceTab::Pods);
 
-        app.apply_action(Action::StartCommand);
-        for c in "user platform-admin".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-
-        let cmd = app.apply_action(Action::SubmitInput);
         assert_eq!(
-            cmd,
-            AppCommand::SwitchUser {
-                user: "platform-admin".to_string()
-            }
+            run_command_line(&mut app, "selector app=nginx,tier=frontend"),
+            AppCommand::RefreshActive
+        );
+        assert_eq!(
+            app.label_selector_for(ResourceTab::Pods),
+            Some("app=nginx,tier=frontend")
         );
+        assert_eq!(app.label_selector_for(ResourceTab::Deployments), None);
+
+        assert_eq!(run_command_line(&mut app, "sel"), AppCommand::RefreshActive);
+        assert_eq!(app.label_selector_for(ResourceTab::Pods), None);
+        assert_eq!(run_command_line(&mut app, "sel"), AppCommand::None);
     }
 
     #[test]
-    fn usr_without_arg_opens_user_catalog_overlay() {
+    fn enter_on_node_lists_its_pods_with_field_selector() {
         let mut app = App::new(
-            "https://cluster".to_string(),
-            "openclaw".to_string(),
+            "cluster".to_string(),
+            "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
-        app.set_user("openclaw".to_string());
-        app.set_kube_catalog(
-            vec!["openclaw".to_string()],
-            vec!["openclaw".to_string()],
-            vec!["openclaw".to_string(), "robot".to_string()],
-            vec![
-                ContextCatalogRow {
-                    context: "openclaw".to_string(),
-                    cluster: "openclaw".to_string(),
-                    auth_info: "openclaw".to_string(),
-                    namespace: "openclaw".to_string(),
-                },
-                ContextCatalogRow {
-                    context: "build".to_string(),
-                    cluster: "openclaw".to_string(),
-                    auth_info: "robot".to_string(),
-                    namespace: "ci".to_string(),
-                },
-            ],
+        let mut nodes = TableData::default();
+        nodes.set_rows(
+            vec!["Name".to_string()],
+            vec![RowData {
+                name: "node-1".to_string(),
+                columns: vec!["node-1".to_string()],
+                ..RowData::default()
+            }],
+            Local::now(),
         );
+        app.set_active_table_data(ResourceTab::Nodes, nodes);
+        let _ = app.switch_to_tab(ResourceTab::Nodes);
 
-        app.apply_action(Action::StartCommand);
-        for c in "usr".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-
-        let cmd = app.apply_action(Action::SubmitInput);
-        assert_eq!(cmd, AppCommand::None);
-        assert!(app.table_overlay_active());
-        let text = app.table_overlay_text().unwrap_or("");
-        assert!(text.contains("openclaw"));
-        assert!(text.contains("robot"));
-    }
+        assert_ne!(app.apply_action(Action::EnterResource), AppCommand::None);
+        assert_eq!(app.active_tab(), ResourceTab::Pods);
+        assert_eq!(app.namespace_scope(), &NamespaceScope::All);
+        assert_eq!(
+            app.field_selector_for(ResourceTab::Pods),
+            Some("spec.nodeName=node-1")
+        );
 
-    #[test]
-    fn completion_query_normalizes_mode_prefix() {
-        assert_eq!(normalize_mode_prefixed_input(":context"), "context");
+        app.apply_action(Action::ClearDetailOverlay);
+        assert_eq!(app.active_tab(), ResourceTab::Nodes);
+        assert_eq!(app.field_selector_for(ResourceTab::Pods), None);
         assert_eq!(
-            normalize_mode_prefixed_input(">cluster home"),
-            "cluster home"
+            app.namespace_scope(),
+            &NamespaceScope::Named("default".to_string())
         );
     }
 
     #[test]
-    fn command_completion_excludes_legacy_tab_prefix() {
+    fn fields_command_sets_field_selector_for_active_tab() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
-        app.apply_action(Action::StartCommand);
-        let completions = app.completion_candidates();
-        assert!(
-            !completions
-                .iter()
-                .any(|candidate| candidate.starts_with("tab ")),
-            "legacy tab-prefix completions should be hidden"
-        );
-    }
+        let _ = app.switch_to_tab(ResourceTab::Pods);
 
-    #[test]
-    fn normalize_status_text_keeps_confirmation_prompt_untrimmed() {
-        let prompt = format!("{} (y/n)", "x".repeat(260));
-        assert_eq!(normalize_status_text(prompt.clone()), prompt);
+        assert_eq!(
+            run_command_line(&mut app, "fields status.phase=Running"),
+            AppCommand::RefreshActive
+        );
+        assert_eq!(
+            app.field_selector_for(ResourceTab::Pods),
+            Some("status.phase=Running")
+        );
+        assert_eq!(app.label_selector_for(ResourceTab::Pods), None);
+        assert_eq!(
+            run_command_line(&mut app, "fsel -"),
+            AppCommand::RefreshActive
+        );
+        assert_eq!(app.field_selector_for(ResourceTab::Pods), None);
     }
 
     #[test]
-    fn enter_resource_on_pod_requests_container_list() {
+    fn log_window_keys_step_back_and_forward_through_time() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
-
-        let now = Local::now();
-        let mut data = TableData::default();
-        data.set_rows(
+        let mut pods = TableData::default();
+        pods.set_rows(
             vec!["Name".to_string()],
             vec![RowData {
-                name: "pod-1".to_string(),
+                name: "api-0".to_string(),
                 namespace: Some("default".to_string()),
-                columns: vec!["pod-1".to_string()],
-                detail: "kind: Pod".to_string(),
+                columns: vec!["api-0".to_string()],
+                ..RowData::default()
             }],
-            now,
+            Local::now(),
         );
-        app.set_active_table_data(ResourceTab::Pods, data);
+        app.set_active_table_data(ResourceTab::Pods, pods);
         let _ = app.switch_to_tab(ResourceTab::Pods);
 
-        let cmd = app.apply_action(Action::EnterResource);
+        assert_eq!(app.apply_action(Action::OlderLogWindow), AppCommand::None);
+        let _ = app.apply_action(Action::LoadPodLogs);
+        app.set_pod_logs_overlay("Pod Logs default/api-0", "line".to_string());
+
+        let window = |command: &AppCommand| match command {
+            AppCommand::LoadPodLogs { window, .. } => *window,
+            other => panic!("unexpected command: {other:?}"),
+        };
+        let older = app.apply_action(Action::OlderLogWindow);
         assert_eq!(
-            cmd,
-            AppCommand::LoadPodContainers {
-                namespace: "default".to_string(),
-                pod_name: "pod-1".to_string()
-            }
+            window(&older),
+            Some(LogWindow {
+                width_secs: DEFAULT_LOG_WINDOW_SECS,
+                offset: 0,
+            })
         );
+        app.set_pod_logs_overlay("Pod Logs default/api-0 (last 15m)", "line".to_string());
+        let older = app.apply_action(Action::OlderLogWindow);
+        assert_eq!(window(&older).map(|window| window.offset), Some(1));
+        app.set_pod_logs_overlay("Pod Logs default/api-0 (30m-15m ago)", "line".to_string());
+
+        let newer = app.apply_action(Action::NewerLogWindow);
+        assert_eq!(window(&newer).map(|window| window.offset), Some(0));
+        app.set_pod_logs_overlay("Pod Logs default/api-0 (last 15m)", "line".to_string());
+        assert_eq!(window(&app.apply_action(Action::NewerLogWindow)), None);
     }
 
     #[test]
-    fn esc_returns_to_dashboard_mode() {
+    fn log_window_command_parses_durations() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
-        let now = Local::now();
-        let mut data = TableData::default();
-        data.set_rows(
-            vec!["Name".to_string()],
-            vec![RowData {
-                name: "pod-1".to_string(),
-                namespace: Some("default".to_string()),
-                columns: vec!["pod-1".to_string()],
-                detail: "kind: Pod".to_string(),
-            }],
-            now,
+        run_command_line(&mut app, "log-window 2h");
+        assert_eq!(app.status, "Log window set to 2h");
+        run_command_line(&mut app, "log-window 90");
+        assert_eq!(app.status, "Log window set to 1m30s");
+        run_command_line(&mut app, "log-window soon");
+        assert!(app.status.starts_with("Usage: :log-window"));
+    }
+
+    #[test]
+    fn degraded_watch_flags_until_healthy_or_cleared() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
         );
-        app.set_active_table_data(ResourceTab::Pods, data);
-        let _ = app.switch_to_tab(ResourceTab::Pods);
+        let tab = app.active_tab();
+        app.mark_watch_degraded(tab, "connection refused".to_string(), 4);
+        assert!(app.watch_degraded(tab));
+        assert!(app.status.contains("Watch degraded"));
+        assert!(app.status.contains("retrying in 4s"));
 
-        let _ = app.apply_action(Action::ShowDetails);
-        assert_eq!(app.detail_mode(), DetailPaneMode::Details);
-        assert_eq!(app.pane_label(), "det");
+        app.mark_watch_healthy(tab);
+        assert!(!app.watch_degraded(tab));
+        assert!(app.status.contains("recovered"));
 
-        let _ = app.apply_action(Action::ClearDetailOverlay);
-        assert_eq!(app.detail_mode(), DetailPaneMode::Dashboard);
-        assert_eq!(app.pane_label(), "tbl");
+        app.mark_watch_degraded(tab, "timeout".to_string(), 8);
+        app.clear_degraded_watches();
+        assert!(!app.watch_degraded(tab));
     }
 
-    #[test]
-    fn esc_from_container_logs_returns_to_container_picker_first() {
+    fn deployments_app(labels: &[(&str, &str)]) -> App {
         let mut app = App::new(
             "cluster".to_string(),
-            "context".to_string(),
+            "prod-eu".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
-        let now = Local::now();
-        let mut data = TableData::default();
-        data.set_rows(
+        let mut deployments = TableData::default();
+        deployments.set_rows(
             vec!["Name".to_string()],
             vec![RowData {
-                name: "pod-1".to_string(),
-                namespace: Some("default".to_string()),
-                columns: vec!["pod-1".to_string()],
-                detail: "kind: Pod".to_string(),
+                name: "web".to_string(),
+                namespace: Some("shop".to_string()),
+                columns: vec!["web".to_string()],
+                labels: labels
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect(),
+                ..RowData::default()
             }],
-            now,
+            Local::now(),
         );
-        app.set_active_table_data(ResourceTab::Pods, data);
-        let _ = app.switch_to_tab(ResourceTab::Pods);
-        app.set_container_picker(
-            "default",
-            "pod-1",
-            vec![crate::model::PodContainerInfo {
-                name: "c1".to_string(),
-                image: "img:v1".to_string(),
-                ready: true,
-                state: "Running".to_string(),
-                restarts: 0,
-                age: "1m".to_string(),
-            }],
+        app.set_active_table_data(ResourceTab::Deployments, deployments);
+        let _ = app.switch_to_tab(ResourceTab::Deployments);
+        app
+    }
+
+    #[test]
+    fn dangerous_delete_requires_typed_name_even_when_skipped() {
+        let mut app = deployments_app(&[]);
+        app.set_dangerous_commands(vec![DangerousCommandRule {
+            action: "delete".to_string(),
+            resource: Some("deploy".to_string()),
+            ..DangerousCommandRule::default()
+        }]);
+
+        assert_eq!(run_command_line(&mut app, "delete"), AppCommand::None);
+        assert_eq!(app.key_mode(), InputMode::Command);
+        assert_eq!(app.apply_action(Action::ConfirmYes), AppCommand::None);
+        for c in "wbe".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        assert_eq!(app.apply_action(Action::SubmitInput), AppCommand::None);
+        assert!(app.status.contains("does not match 'web'"));
+
+        for c in "web".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
+        let command = app.apply_action(Action::SubmitInput);
+        assert_eq!(
+            command,
+            AppCommand::DeleteSelected {
+                tab: ResourceTab::Deployments,
+                namespace: Some("shop".to_string()),
+                name: "web".to_string(),
+                propagation: DeletePropagation::Background,
+            }
         );
-        assert!(app.container_picker_active());
+        assert!(app.allow_command(&command));
+        assert!(!app.allow_command(&command));
+        assert!(
+            app.pending_confirmation_text()
+                .is_some_and(|text| text.contains("type 'web'"))
+        );
+    }
 
-        app.set_pod_logs_overlay("Pod Logs default/pod-1:c1", "line".to_string());
-        assert!(app.table_overlay_active());
-        assert!(!app.container_picker_active());
-        assert_eq!(app.pane_label(), "log");
+    #[test]
+    fn dangerous_scale_rule_matches_replicas_labels_and_context() {
+        let rule = DangerousCommandRule {
+            action: "scale".to_string(),
+            context: Some("prod-*".to_string()),
+            labels: BTreeMap::from([("tier".to_string(), "critical".to_string())]),
+            replicas: Some(0),
+            ..DangerousCommandRule::default()
+        };
 
-        let _ = app.apply_action(Action::ClearDetailOverlay);
-        assert!(app.container_picker_active());
-        assert!(!app.table_overlay_active());
-        assert_eq!(app.pane_label(), "ctr");
+        let mut app = deployments_app(&[("tier", "critical")]);
+        app.set_dangerous_commands(vec![rule.clone()]);
+        let scale_up = run_command_line(&mut app, "scale 2");
+        assert!(matches!(scale_up, AppCommand::ScaleWorkload { .. }));
+        assert!(app.allow_command(&scale_up));
+        let scale_down = run_command_line(&mut app, "scale 0");
+        assert!(!app.allow_command(&scale_down));
+        assert!(app.status.contains("Dangerous command"));
+        assert_eq!(app.apply_action(Action::CancelInput), AppCommand::None);
+        assert!(app.pending_confirmation_prompt().is_none());
 
-        let _ = app.apply_action(Action::ClearDetailOverlay);
-        assert!(!app.container_picker_active());
-        assert_eq!(app.pane_label(), "tbl");
+        let mut app = deployments_app(&[("tier", "batch")]);
+        app.set_dangerous_commands(vec![rule]);
+        let scale_down = run_command_line(&mut app, "scale 0");
+        assert!(app.allow_command(&scale_down));
     }
 
     #[test]
-    fn pane_label_uses_uppercase_for_related_logs() {
-        let mut app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
-        );
-        app.set_related_logs_overlay("Logs default/pod-1", "line".to_string());
-        assert_eq!(app.pane_label(), "LOG");
+    fn wildcard_match_handles_prefix_suffix_and_infix() {
+        assert!(wildcard_match("prod-*", "prod-eu"));
+        assert!(wildcard_match("*-prod", "eu-prod"));
+        assert!(wildcard_match("*prod*", "eu-prod-1"));
+        assert!(wildcard_match("prod", "prod"));
+        assert!(!wildcard_match("prod", "prod-eu"));
+        assert!(!wildcard_match("prod-*", "staging"));
     }
 
     #[test]
-    fn pane_label_uses_shell_for_embedded_shell_overlay() {
+    fn refresh_keeps_selection_on_same_row_when_order_shifts() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
-        app.set_shell_overlay("Pod Shell", "# echo hello\nhello\n".to_string());
-        assert_eq!(app.pane_label(), "sh");
-        assert!(app.shell_overlay_active());
+        let pods = |names: &[&str], status: &str| {
+            let mut table = TableData::default();
+            table.set_rows(
+                vec!["Name".to_string(), "Status".to_string()],
+                names
+                    .iter()
+                    .map(|name| RowData {
+                        name: name.to_string(),
+                        namespace: Some("default".to_string()),
+                        columns: vec![name.to_string(), status.to_string()],
+                        ..RowData::default()
+                    })
+                    .collect(),
+                Local::now(),
+            );
+            table
+        };
+        app.set_active_table_data(ResourceTab::Pods, pods(&["api", "db", "web"], "Running"));
+        let _ = app.switch_to_tab(ResourceTab::Pods);
+        app.apply_action(Action::Down);
+        app.apply_action(Action::Down);
+        assert_eq!(
+            app.active_selected_row().map(|row| row.name.as_str()),
+            Some("web")
+        );
+
+        app.set_active_table_data(ResourceTab::Pods, pods(&["api", "db", "web"], "Running"));
+        assert_eq!(app.status, "Pods up to date");
+
+        app.set_active_table_data(
+            ResourceTab::Pods,
+            pods(&["web", "api", "cache", "db"], "Running"),
+        );
+        assert_eq!(
+            app.active_selected_row().map(|row| row.name.as_str()),
+            Some("web")
+        );
+        assert_eq!(app.status, "Pods updated (+1 ~0 -0)");
     }
 
     #[test]
-    fn enter_namespace_drills_into_pods_scope() {
-        let mut app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+    fn rollout_undo_confirms_and_respects_read_only() {
+        let mut app = deployments_app(&[]);
+        assert_eq!(
+            run_command_line(&mut app, "rollout undo 3"),
+            AppCommand::None
         );
-        let now = Local::now();
+        assert_eq!(
+            app.pending_confirmation_prompt(),
+            Some("Roll back Deployments shop/web to revision 3")
+        );
+        assert_eq!(
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::RolloutUndo {
+                tab: ResourceTab::Deployments,
+                namespace: "shop".to_string(),
+                name: "web".to_string(),
+                to_revision: Some(3),
+            }
+        );
+
+        assert_eq!(
+            run_command_line(&mut app, "rollout undo latest"),
+            AppCommand::None
+        );
+        assert!(app.status.contains("Invalid revision"));
+
+        app.set_read_only(true);
+        assert_eq!(run_command_line(&mut app, "rollout undo"), AppCommand::None);
+        assert!(app.pending_confirmation_prompt().is_none());
+        assert!(app.status.contains("Read-only"));
+    }
+
+    #[test]
+    fn delete_modal_needs_y_and_typed_name_for_namespaces() {
+        let mut app = deployments_app(&[]);
+        assert_eq!(run_command_line(&mut app, "delete"), AppCommand::None);
+        let details = app.pending_confirmation_details().unwrap();
+        assert!(details.contains(&"Namespace: shop".to_string()));
+        assert!(details.contains(&"Name:      web".to_string()));
+        assert_eq!(app.apply_action(Action::EnterResource), AppCommand::None);
+        assert!(app.pending_confirmation_prompt().is_some());
+        assert!(matches!(
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::DeleteSelected { .. }
+        ));
+
         let mut namespaces = TableData::default();
         namespaces.set_rows(
             vec!["Name".to_string()],
             vec![RowData {
-                name: "orca-sandbox".to_string(),
-                namespace: Some("orca-sandbox".to_string()),
-                columns: vec!["orca-sandbox".to_string()],
-                detail: "kind: Namespace".to_string(),
+                name: "shop".to_string(),
+                columns: vec!["shop".to_string()],
+                ..RowData::default()
             }],
-            now,
+            Local::now(),
         );
         app.set_active_table_data(ResourceTab::Namespaces, namespaces);
-        app.switch_to_tab(ResourceTab::Namespaces);
-
-        let cmd = app.apply_action(Action::EnterResource);
-        assert_eq!(cmd, AppCommand::RefreshAll);
-        assert_eq!(app.active_tab(), ResourceTab::Pods);
+        let _ = app.switch_to_tab(ResourceTab::Namespaces);
+        assert_eq!(run_command_line(&mut app, "delete"), AppCommand::None);
+        assert!(
+            app.pending_confirmation_details()
+                .unwrap()
+                .iter()
+                .any(|line| line.starts_with("WARNING"))
+        );
+        assert_eq!(app.pending_confirmation_typed(), Some(("shop", "")));
+        assert_eq!(app.apply_action(Action::ConfirmYes), AppCommand::None);
+        for c in "shop".chars() {
+            app.apply_action(Action::InputChar(c));
+        }
         assert_eq!(
-            app.namespace_scope(),
-            &NamespaceScope::Named("orca-sandbox".to_string())
+            app.apply_action(Action::SubmitInput),
+            AppCommand::DeleteSelected {
+                tab: ResourceTab::Namespaces,
+                namespace: None,
+                name: "shop".to_string(),
+                propagation: DeletePropagation::Background,
+            }
         );
     }
 
     #[test]
-    fn esc_returns_to_command_root_after_drilldown() {
-        let mut app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
-        );
-        let now = Local::now();
-
+    fn marked_rows_drive_bulk_delete_and_restart() {
+        let mut app = deployments_app(&[]);
         let mut deployments = TableData::default();
         deployments.set_rows(
             vec!["Name".to_string()],
-            vec![RowData {
-                name: "web".to_string(),
-                namespace: Some("openclaw".to_string()),
-                columns: vec!["web".to_string()],
-                detail: "kind: Deployment".to_string(),
-            }],
-            now,
+            ["api", "web", "worker"]
+                .iter()
+                .map(|name| RowData {
+                    name: name.to_string(),
+                    namespace: Some("shop".to_string()),
+                    columns: vec![name.to_string()],
+                    ..RowData::default()
+                })
+                .collect(),
+            Local::now(),
         );
         app.set_active_table_data(ResourceTab::Deployments, deployments);
+        app.apply_action(Action::Top);
+        app.apply_action(Action::ToggleRowMark);
+        app.apply_action(Action::Down);
+        app.apply_action(Action::ToggleRowMark);
+        assert_eq!(app.marked_count(), 2);
+        let marked = app.active_visible_rows();
+        let marked = marked
+            .iter()
+            .filter(|row| app.row_marked(row))
+            .map(|row| row.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(marked, vec!["api", "worker"]);
 
-        app.apply_action(Action::StartCommand);
-        for c in "deploy".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-        let _ = app.apply_action(Action::SubmitInput);
-        assert_eq!(app.active_tab(), ResourceTab::Deployments);
+        assert_eq!(run_command_line(&mut app, "delete"), AppCommand::None);
+        assert_eq!(
+            app.pending_confirmation_prompt(),
+            Some("Delete 2 Deployments")
+        );
+        let details = app.pending_confirmation_details().unwrap();
+        assert!(details.contains(&"Count:     2".to_string()));
+        assert!(details.contains(&"  shop/worker".to_string()));
+        assert_eq!(
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::BulkDelete {
+                tab: ResourceTab::Deployments,
+                custom: None,
+                targets: vec![
+                    (Some("shop".to_string()), "api".to_string()),
+                    (Some("shop".to_string()), "worker".to_string()),
+                ],
+                propagation: DeletePropagation::Background,
+            }
+        );
 
-        let cmd = app.apply_action(Action::EnterResource);
-        assert_eq!(cmd, AppCommand::RefreshActive);
-        assert_eq!(app.active_tab(), ResourceTab::Pods);
+        assert_eq!(run_command_line(&mut app, "restart"), AppCommand::None);
+        assert_eq!(
+            app.pending_confirmation_prompt(),
+            Some("Restart 2 Deployments")
+        );
+        assert!(matches!(
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::BulkRestart { targets, .. } if targets.len() == 2
+        ));
 
-        let _ = app.apply_action(Action::ClearDetailOverlay);
-        assert_eq!(app.active_tab(), ResourceTab::Deployments);
+        app.set_row_marks(
+            ResourceTab::Deployments,
+            vec![(Some("shop".to_string()), "web".to_string())],
+        );
+        assert_eq!(app.marked_count(), 1);
+        app.apply_action(Action::ClearDetailOverlay);
+        assert_eq!(app.marked_count(), 0);
+        assert!(matches!(
+            run_command_line(&mut app, "delete"),
+            AppCommand::None
+        ));
+        assert!(matches!(
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::DeleteSelected { .. }
+        ));
     }
 
     #[test]
-    fn shift_l_on_workload_builds_related_logs_command() {
-        let mut app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+    fn bulk_actions_check_dangerous_label_rules_per_marked_row() {
+        let mut app = deployments_app(&[]);
+        let mut deployments = TableData::default();
+        deployments.set_rows(
+            vec!["Name".to_string()],
+            [("api", "prod"), ("web", "dev"), ("worker", "dev")]
+                .iter()
+                .map(|(name, env)| RowData {
+                    name: name.to_string(),
+                    namespace: Some("shop".to_string()),
+                    columns: vec![name.to_string()],
+                    labels: BTreeMap::from([("env".to_string(), env.to_string())]),
+                    ..RowData::default()
+                })
+                .collect(),
+            Local::now(),
+        );
+        app.set_active_table_data(ResourceTab::Deployments, deployments);
+        app.set_dangerous_commands(
+            ["delete", "restart"]
+                .iter()
+                .map(|action| DangerousCommandRule {
+                    action: action.to_string(),
+                    resource: Some("deployments".to_string()),
+                    labels: BTreeMap::from([("env".to_string(), "prod".to_string())]),
+                    ..DangerousCommandRule::default()
+                })
+                .collect(),
+        );
+        app.set_row_marks(
+            ResourceTab::Deployments,
+            vec![
+                (Some("shop".to_string()), "api".to_string()),
+                (Some("shop".to_string()), "web".to_string()),
+            ],
+        );
+
+        assert_eq!(run_command_line(&mut app, "delete"), AppCommand::None);
+        assert!(
+            app.pending_confirmation_text()
+                .is_some_and(|text| text.contains("type 'delete'"))
         );
-        let now = Local::now();
-        let mut data = TableData::default();
-        data.set_rows(
-            vec!["Name".to_string()],
-            vec![RowData {
-                name: "openclaw-ag".to_string(),
-                namespace: Some("openclaw".to_string()),
-                columns: vec!["openclaw-ag".to_string()],
-                detail: "kind: Deployment".to_string(),
-            }],
-            now,
+        let details = app.pending_confirmation_details().unwrap();
+        let matched = details
+            .iter()
+            .position(|line| line == "Dangerous (delete deployments -l env=prod), 1 matching:")
+            .expect("matching rows listed");
+        assert_eq!(details[matched + 1..], ["  shop/api".to_string()]);
+        assert_eq!(app.apply_action(Action::ConfirmYes), AppCommand::None);
+        assert_eq!(app.apply_action(Action::CancelInput), AppCommand::None);
+
+        assert_eq!(run_command_line(&mut app, "restart"), AppCommand::None);
+        assert!(
+            app.pending_confirmation_text()
+                .is_some_and(|text| text.contains("type 'restart'"))
         );
-        app.set_active_table_data(ResourceTab::Deployments, data);
-        app.switch_to_tab(ResourceTab::Deployments);
+        assert!(
+            app.pending_confirmation_details()
+                .unwrap()
+                .contains(&"Dangerous (restart deployments -l env=prod), 1 matching:".to_string())
+        );
+        assert_eq!(app.apply_action(Action::CancelInput), AppCommand::None);
 
-        let cmd = app.apply_action(Action::LoadResourceLogs);
-        assert_eq!(
-            cmd,
-            AppCommand::LoadResourceLogs {
-                tab: ResourceTab::Deployments,
-                namespace: Some("openclaw".to_string()),
-                name: "openclaw-ag".to_string(),
-                previous: true
-            }
+        app.set_row_marks(
+            ResourceTab::Deployments,
+            vec![(Some("shop".to_string()), "web".to_string())],
         );
+        assert_eq!(run_command_line(&mut app, "delete"), AppCommand::None);
+        assert!(app.status.ends_with("(y/n)"));
     }
 
     #[test]
-    fn moving_selection_keeps_logs_overlay_open() {
-        let mut app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+    fn delete_propagation_defaults_to_background_and_cycles_in_modal() {
+        let mut app = deployments_app(&[]);
+        assert_eq!(run_command_line(&mut app, "delete"), AppCommand::None);
+        assert!(
+            app.pending_confirmation_details()
+                .unwrap()
+                .iter()
+                .any(|line| line.starts_with("Cascade:   background"))
         );
-        let now = Local::now();
-        let mut data = TableData::default();
-        data.set_rows(
-            vec!["Name".to_string()],
-            vec![
-                RowData {
-                    name: "pod-1".to_string(),
-                    namespace: Some("default".to_string()),
-                    columns: vec!["pod-1".to_string()],
-                    detail: "kind: Pod".to_string(),
-                },
-                RowData {
-                    name: "pod-2".to_string(),
-                    namespace: Some("default".to_string()),
-                    columns: vec!["pod-2".to_string()],
-                    detail: "kind: Pod".to_string(),
-                },
-            ],
-            now,
+        app.apply_action(Action::ToggleFocus);
+        app.apply_action(Action::ToggleFocus);
+        assert!(
+            app.pending_confirmation_details()
+                .unwrap()
+                .iter()
+                .any(|line| line.starts_with("Cascade:   orphan"))
         );
-        app.set_active_table_data(ResourceTab::Pods, data);
-        let _ = app.switch_to_tab(ResourceTab::Pods);
-        app.set_detail_overlay("Pod Logs", "line".to_string());
-        let _ = app.apply_action(Action::ToggleFocus);
-        let _ = app.apply_action(Action::Down);
+        assert!(matches!(
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::DeleteSelected {
+                propagation: DeletePropagation::Orphan,
+                ..
+            }
+        ));
 
-        assert!(app.detail_overlay_active());
-        assert_eq!(app.active_selected_index(), Some(1));
+        assert_eq!(
+            run_command_line(&mut app, "delete foreground"),
+            AppCommand::None
+        );
+        assert!(matches!(
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::DeleteSelected {
+                propagation: DeletePropagation::Foreground,
+                ..
+            }
+        ));
+        assert_eq!(run_command_line(&mut app, "delete later"), AppCommand::None);
+        assert!(app.status.contains("Unknown propagation policy"));
     }
 
     #[test]
-    fn switching_tabs_keeps_state_consistent() {
-        let mut app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
-        );
+    fn force_delete_only_targets_pods_and_warns_in_modal() {
+        let mut app = deployments_app(&[]);
+        assert_eq!(app.apply_action(Action::ForceDeletePod), AppCommand::None);
+        assert!(app.pending_confirmation_prompt().is_none());
+        assert!(app.status.contains("only on the Pods tab"));
 
-        let now = Local::now();
-        let mut data = TableData::default();
-        data.set_rows(
+        let mut pods = TableData::default();
+        pods.set_rows(
             vec!["Name".to_string()],
             vec![RowData {
-                name: "pod-1".to_string(),
-                namespace: Some("default".to_string()),
-                columns: vec!["pod-1".to_string()],
-                detail: "detail".to_string(),
+                name: "api-0".to_string(),
+                namespace: Some("shop".to_string()),
+                columns: vec!["api-0".to_string()],
+                ..RowData::default()
             }],
-            now,
+            Local::now(),
         );
-        app.set_active_table_data(ResourceTab::Pods, data);
+        app.set_active_table_data(ResourceTab::Pods, pods);
         let _ = app.switch_to_tab(ResourceTab::Pods);
-
-        let _ = app.apply_action(Action::NextTab);
-        let _ = app.apply_action(Action::PrevTab);
-        assert_eq!(app.active_tab(), ResourceTab::Pods);
-    }
-
-    #[test]
-    fn switching_view_slots_preserves_state_per_slot() {
-        let mut app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+        assert_eq!(run_command_line(&mut app, "kill"), AppCommand::None);
+        assert!(
+            app.pending_confirmation_prompt()
+                .unwrap()
+                .starts_with("Force delete Pods shop/api-0")
+        );
+        let details = app.pending_confirmation_details().unwrap();
+        assert!(details.iter().any(|line| line.contains("grace period 0")));
+        assert!(
+            details
+                .iter()
+                .any(|line| line.starts_with("WARNING: force delete"))
+        );
+        assert_eq!(
+            app.apply_action(Action::ConfirmYes),
+            AppCommand::ForceDeletePod {
+                namespace: "shop".to_string(),
+                name: "api-0".to_string(),
+            }
         );
-
-        app.apply_action(Action::StartCommand);
-        for c in "deployments".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-        let _ = app.apply_action(Action::SubmitInput);
-
-        app.apply_action(Action::StartCommand);
-        for c in "filter web".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-        let _ = app.apply_action(Action::SubmitInput);
-
-        let _ = app.apply_action(Action::SwitchView(1));
-        assert_eq!(app.active_view_slot(), 1);
-        assert!(app.view_slot_initialized(1));
-
-        let _ = app.apply_action(Action::SwitchView(2));
-        assert_eq!(app.active_view_slot(), 2);
-        assert!(app.view_slot_initialized(2));
-
-        app.apply_action(Action::StartCommand);
-        for c in "pods".chars() {
-            app.apply_action(Action::InputChar(c));
-        }
-        let _ = app.apply_action(Action::SubmitInput);
-        assert_eq!(app.active_tab(), ResourceTab::Pods);
-
-        let _ = app.apply_action(Action::SwitchView(1));
-        assert_eq!(app.active_view_slot(), 1);
-        assert_eq!(app.active_tab(), ResourceTab::Deployments);
-        assert_eq!(app.filter(), "web");
     }
 
     #[test]
-    fn deleting_inactive_view_slot_clears_it() {
+    fn volume_snapshot_tab_follows_crd_discovery() {
         let mut app = App::new(
             "cluster".to_string(),
             "context".to_string(),
             NamespaceScope::Named("default".to_string()),
         );
+        assert!(!app.visible_tabs().contains(&ResourceTab::VolumeSnapshots));
+        assert_eq!(run_command_line(&mut app, "vs"), AppCommand::None);
+        assert!(app.status.contains("not served"));
+        assert_eq!(app.active_tab(), ResourceTab::Orca);
 
-        let _ = app.apply_action(Action::SwitchView(2));
-        assert_eq!(app.active_view_slot(), 2);
-        assert!(app.view_slot_initialized(1));
-        assert!(app.view_slot_initialized(2));
+        let _ = app.switch_to_tab(ResourceTab::PersistentVolumes);
+        app.apply_action(Action::NextTab);
+        assert_eq!(app.active_tab(), ResourceTab::ServiceAccounts);
+
+        let snapshots = CustomResourceDef {
+            name: "volumesnapshots".to_string(),
+            group: "snapshot.storage.k8s.io".to_string(),
+            version: "v1".to_string(),
+            kind: "VolumeSnapshot".to_string(),
+            plural: "volumesnapshots".to_string(),
+            namespaced: true,
+            scalable: false,
+        };
+        app.set_custom_resources(vec![snapshots]);
+        assert!(app.visible_tabs().contains(&ResourceTab::VolumeSnapshots));
+        let _ = run_command_line(&mut app, "vs");
+        assert_eq!(app.active_tab(), ResourceTab::VolumeSnapshots);
 
-        let cmd = app.apply_action(Action::DeleteView(1));
-        assert_eq!(cmd, AppCommand::None);
-        assert_eq!(app.active_view_slot(), 2);
-        assert!(!app.view_slot_initialized(1));
-        assert!(app.view_slot_initialized(2));
+        app.set_custom_resources(Vec::new());
+        assert!(!app.visible_tabs().contains(&ResourceTab::VolumeSnapshots));
+        assert_eq!(app.active_tab(), ResourceTab::Orca);
     }
 
     #[test]
-    fn deleting_active_view_slot_switches_to_fallback() {
-        let mut app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
-        );
+    fn unserved_tabs_are_hidden_until_tabs_all() {
+        let mut app = deployments_app(&[]);
+        let _ = app.switch_to_tab(ResourceTab::NetworkPolicies);
+        app.set_unserved_tabs(HashSet::from([ResourceTab::NetworkPolicies]));
+        assert_eq!(app.active_tab(), ResourceTab::Orca);
+        assert!(!app.visible_tabs().contains(&ResourceTab::NetworkPolicies));
+        assert_eq!(run_command_line(&mut app, "np"), AppCommand::None);
+        assert_eq!(app.active_tab(), ResourceTab::Orca);
 
-        let _ = app.apply_action(Action::SwitchView(2));
-        assert_eq!(app.active_view_slot(), 2);
+        let _ = run_command_line(&mut app, "tabs all");
+        assert!(app.status.contains("NetworkPolicies"));
+        let _ = run_command_line(&mut app, "np");
+        assert_eq!(app.active_tab(), ResourceTab::NetworkPolicies);
 
-        let cmd = app.apply_action(Action::DeleteView(2));
-        assert_eq!(cmd, AppCommand::RefreshActive);
-        assert_eq!(app.active_view_slot(), 1);
-        assert!(!app.view_slot_initialized(2));
-        assert!(app.view_slot_initialized(1));
+        let _ = run_command_line(&mut app, "tabs served");
+        assert_eq!(app.active_tab(), ResourceTab::Orca);
+        app.set_unserved_tabs(HashSet::new());
+        assert!(app.visible_tabs().contains(&ResourceTab::NetworkPolicies));
     }
 
     #[test]
-    fn deleting_last_active_view_is_rejected() {
-        let mut app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+    fn copy_selection_copies_name_or_open_yaml() {
+        let mut app = deployments_app(&[]);
+        assert_eq!(
+            app.apply_action(Action::CopySelection),
+            AppCommand::CopyToClipboard {
+                label: "name".to_string(),
+                text: "web".to_string(),
+            }
         );
 
-        let cmd = app.apply_action(Action::DeleteView(1));
-        assert_eq!(cmd, AppCommand::None);
-        assert_eq!(app.active_view_slot(), 1);
-        assert!(app.view_slot_initialized(1));
+        app.set_detail_overlay("Manifest shop/web", "kind: Deployment\n".to_string());
+        assert_eq!(
+            app.apply_action(Action::CopySelection),
+            AppCommand::CopyToClipboard {
+                label: "Manifest shop/web".to_string(),
+                text: "kind: Deployment\n".to_string(),
+            }
+        );
     }
 
     #[test]
-    fn refresh_keeps_previous_index_when_identity_disappears() {
-        let mut app = App::new(
-            "cluster".to_string(),
-            "context".to_string(),
-            NamespaceScope::Named("default".to_string()),
+    fn resource_events_use_the_selected_row_kind() {
+        let mut app = deployments_app(&[]);
+        assert_eq!(
+            app.apply_action(Action::ShowResourceEvents),
+            AppCommand::ShowResourceEvents {
+                kind: "Deployment".to_string(),
+                namespace: Some("shop".to_string()),
+                name: "web".to_string(),
+            }
         );
-        let now = Local::now();
 
-        let mut initial = TableData::default();
-        initial.set_rows(
-            vec!["Name".to_string()],
-            vec![
-                RowData {
-                    name: "a".to_string(),
-                    namespace: Some("default".to_string()),
-                    columns: vec!["a".to_string()],
-                    detail: "a".to_string(),
-                },
-                RowData {
-                    name: "b".to_string(),
-                    namespace: Some("default".to_string()),
-                    columns: vec!["b".to_string()],
-                    detail: "b".to_string(),
-                },
-                RowData {
-                    name: "c".to_string(),
-                    namespace: Some("default".to_string()),
-                    columns: vec!["c".to_string()],
-                    detail: "c".to_string(),
-                },
-            ],
-            now,
+        let mut app = custom_resource_app(false);
+        assert_eq!(
+            run_command_line(&mut app, "rev"),
+            AppCommand::ShowResourceEvents {
+                kind: "Widget".to_string(),
+                namespace: Some("default".to_string()),
+                name: "gear".to_string(),
+            }
         );
-        app.set_active_table_data(ResourceTab::Pods, initial);
-        let _ = app.switch_to_tab(ResourceTab::Pods);
-        let _ = app.apply_action(Action::Down);
-        let _ = app.apply_action(Action::Down);
-        assert_eq!(app.active_selected_index(), Some(2));
+    }
 
-        let mut refreshed = TableData::default();
-        refreshed.set_rows(
-            vec!["Name".to_string()],
-            vec![
-                RowData {
-                    name: "x".to_string(),
-                    namespace: Some("default".to_string()),
-                    columns: vec!["x".to_string()],
-                    detail: "x".to_string(),
-                },
-                RowData {
-                    name: "y".to_string(),
-                    namespace: Some("default".to_string()),
-                    columns: vec!["y".to_string()],
-                    detail: "y".to_string(),
-                },
-                RowData {
-                    name: "z".to_string(),
-                    namespace: Some("default".to_string()),
-                    columns: vec!["z".to_string()],
-                    detail: "z".to_string(),
-                },
-            ],
-            Local::now(),
+    #[test]
+    fn age_toggle_switches_to_creation_timestamps() {
+        let mut app = deployments_app(&[]);
+        let row = RowData {
+            name: "web".to_string(),
+            created: chrono::DateTime::from_timestamp(1_700_000_000, 0),
+            ..RowData::default()
+        };
+        assert_eq!(app.display_age(&row), None);
+        app.apply_action(Action::ToggleAgeFormat);
+        assert_eq!(
+            app.display_age(&row).as_deref(),
+            Some("2023-11-14T22:13:20Z")
         );
-        app.set_active_table_data(ResourceTab::Pods, refreshed);
-
-        assert_eq!(app.active_selected_index(), Some(2));
+        assert_eq!(app.display_age(&RowData::default()), None);
+        app.apply_action(Action::ToggleAgeFormat);
+        assert_eq!(app.display_age(&row), None);
     }
 }
@@ -1,4 +1,4 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 
 #[derive(Debug, Clone, Parser)]
 #[command(
@@ -11,6 +11,26 @@ pub struct CliArgs {
     #[arg(long, default_value_t = 1_500)]
     pub refresh_ms: u64,
 
+    /// Seconds without keypresses before refresh slows down (0 = never)
+    #[arg(long, default_value_t = 300)]
+    pub idle_after_secs: u64,
+
+    /// Refresh interval in milliseconds while idle
+    #[arg(long, default_value_t = 15_000)]
+    pub idle_refresh_ms: u64,
+
+    /// Skip resource watchers and rely on polling only (refresh follows --refresh-ms)
+    #[arg(long)]
+    pub no_watch: bool,
+
+    /// Start in a specific kubeconfig context instead of the current one
+    #[arg(long)]
+    pub context: Option<String>,
+
+    /// Override the cluster of the selected context (kubeconfig cluster name)
+    #[arg(long)]
+    pub cluster: Option<String>,
+
     /// Start in a specific namespace
     #[arg(short, long)]
     pub namespace: Option<String>,
@@ -19,7 +39,30 @@ pub struct CliArgs {
     #[arg(short = 'A', long)]
     pub all_namespaces: bool,
 
+    /// Number of log lines to fetch per container (0 = all available)
+    #[arg(long, default_value_t = crate::app::DEFAULT_LOG_TAIL)]
+    pub log_tail: usize,
+
+    /// Pods fetched per list request; `:more` loads the next page (0 = no limit)
+    #[arg(long, default_value_t = crate::k8s::DEFAULT_PAGE_SIZE)]
+    pub page_size: u32,
+
     /// tracing filter (for example: info,debug,trace)
     #[arg(long, default_value = "info")]
     pub log_filter: String,
+
+    /// Print one resource table (for example: pods, deploy, svc) and exit without the TUI
+    #[arg(long, value_name = "RESOURCE")]
+    pub dump: Option<String>,
+
+    /// Output format for --dump
+    #[arg(short, long, value_enum, default_value_t = DumpFormat::Json)]
+    pub output: DumpFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DumpFormat {
+    Json,
+    Yaml,
+    Wide,
 }
@@ -1,4 +1,5 @@
-use crate::app::{HotkeyCommandDef, PluginCommandDef};
+use crate::app::{DangerousCommandRule, HotkeyCommandDef, PluginCommandDef};
+use crate::model::ResourceTab;
 use anyhow::{Context, Result};
 use serde::Deserialize;
 use std::collections::{BTreeMap, HashMap};
@@ -12,6 +13,14 @@ pub struct RuntimeConfigSnapshot {
     pub aliases: HashMap<String, String>,
     pub plugins: Vec<PluginCommandDef>,
     pub hotkeys: Vec<HotkeyCommandDef>,
+    pub skip_confirm: Vec<String>,
+    pub dangerous_commands: Vec<DangerousCommandRule>,
+    pub adopt_context_namespace: bool,
+    pub export_dir: Option<String>,
+    pub strip_name_prefixes: Vec<String>,
+    pub whats_new: bool,
+    pub refresh_intervals: HashMap<ResourceTab, u64>,
+    pub terraform_plan_timeout_secs: Option<u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -28,6 +37,22 @@ struct OrcaConfigFile {
     plugins: Vec<PluginSpec>,
     #[serde(default)]
     hotkeys: Vec<HotkeySpec>,
+    #[serde(default, alias = "confirm_skip")]
+    skip_confirm: Vec<String>,
+    #[serde(default, alias = "dangerous")]
+    dangerous_commands: Vec<DangerousCommandSpec>,
+    #[serde(default, alias = "auto_namespace")]
+    adopt_context_namespace: bool,
+    #[serde(default)]
+    export_dir: Option<String>,
+    #[serde(default, alias = "strip_name_prefix")]
+    strip_name_prefixes: Vec<String>,
+    #[serde(default = "default_true")]
+    whats_new: bool,
+    #[serde(default, alias = "refresh")]
+    refresh_intervals: BTreeMap<String, u64>,
+    #[serde(default, alias = "terraform_plan_timeout")]
+    terraform_plan_timeout_secs: Option<u64>,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -49,6 +74,63 @@ struct PluginSpec {
     timeout_secs: u64,
     #[serde(default)]
     retries: u8,
+    #[serde(default)]
+    stream: bool,
+    #[serde(default)]
+    confirm: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum DangerousCommandSpec {
+    Pattern(String),
+    Rule {
+        action: String,
+        #[serde(default)]
+        resource: Option<String>,
+        #[serde(default)]
+        context: Option<String>,
+        #[serde(default)]
+        labels: BTreeMap<String, String>,
+        #[serde(default)]
+        replicas: Option<i32>,
+    },
+}
+
+impl DangerousCommandSpec {
+    fn into_rule(self) -> DangerousCommandRule {
+        match self {
+            DangerousCommandSpec::Pattern(pattern) => {
+                let pattern = pattern.trim().to_ascii_lowercase();
+                match pattern.rsplit_once(' ') {
+                    Some((action, resource)) if ResourceTab::from_token(resource).is_some() => {
+                        DangerousCommandRule {
+                            action: action.trim().to_string(),
+                            resource: Some(resource.to_string()),
+                            ..DangerousCommandRule::default()
+                        }
+                    }
+                    _ => DangerousCommandRule {
+                        action: pattern,
+                        ..DangerousCommandRule::default()
+                    },
+                }
+            }
+            DangerousCommandSpec::Rule {
+                action,
+                resource,
+                context,
+                labels,
+                replicas,
+            } => DangerousCommandRule {
+                action: action.trim().to_ascii_lowercase(),
+                resource: resource.filter(|resource| !resource.trim().is_empty()),
+                context: context.filter(|context| !context.trim().is_empty()),
+                labels,
+                replicas,
+            },
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -77,6 +159,14 @@ impl RuntimeConfigWatcher {
                 aliases: HashMap::new(),
                 plugins: Vec::new(),
                 hotkeys: Vec::new(),
+                skip_confirm: Vec::new(),
+                dangerous_commands: Vec::new(),
+                adopt_context_namespace: false,
+                export_dir: None,
+                strip_name_prefixes: Vec::new(),
+                whats_new: true,
+                refresh_intervals: HashMap::new(),
+                terraform_plan_timeout_secs: None,
             });
         };
 
@@ -100,6 +190,8 @@ impl RuntimeConfigWatcher {
                 mutating: plugin.mutating,
                 timeout_secs: plugin.timeout_secs,
                 retries: plugin.retries,
+                stream: plugin.stream,
+                confirm: plugin.confirm,
             })
             .collect::<Vec<_>>();
         let hotkeys = parsed
@@ -118,6 +210,28 @@ impl RuntimeConfigWatcher {
             aliases,
             plugins,
             hotkeys,
+            skip_confirm: parsed.skip_confirm,
+            dangerous_commands: parsed
+                .dangerous_commands
+                .into_iter()
+                .map(DangerousCommandSpec::into_rule)
+                .collect(),
+            adopt_context_namespace: parsed.adopt_context_namespace,
+            export_dir: parsed.export_dir.filter(|dir| !dir.trim().is_empty()),
+            strip_name_prefixes: parsed
+                .strip_name_prefixes
+                .into_iter()
+                .filter(|prefix| !prefix.trim().is_empty())
+                .collect(),
+            whats_new: parsed.whats_new,
+            refresh_intervals: parsed
+                .refresh_intervals
+                .into_iter()
+                .filter_map(|(token, ms)| {
+                    ResourceTab::from_token(token.trim()).map(|tab| (tab, ms))
+                })
+                .collect(),
+            terraform_plan_timeout_secs: parsed.terraform_plan_timeout_secs,
         })
     }
 
@@ -142,6 +256,14 @@ impl RuntimeConfigWatcher {
                 aliases: HashMap::new(),
                 plugins: Vec::new(),
                 hotkeys: Vec::new(),
+                skip_confirm: Vec::new(),
+                dangerous_commands: Vec::new(),
+                adopt_context_namespace: false,
+                export_dir: None,
+                strip_name_prefixes: Vec::new(),
+                whats_new: true,
+                refresh_intervals: HashMap::new(),
+                terraform_plan_timeout_secs: None,
             }));
         }
 
@@ -156,6 +278,10 @@ impl RuntimeConfigWatcher {
     }
 }
 
+fn default_true() -> bool {
+    true
+}
+
 fn default_plugin_timeout_secs() -> u64 {
     20
 }
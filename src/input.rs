@@ -24,9 +24,34 @@ pub enum Action {
     LoadResourceLogs,
     OpenPodShell,
     EditResource,
+    DescribeResource,
+    ForceDeletePod,
+    ScratchYaml,
+    EditYamlInline,
+    CopyReference,
+    CopySelection,
+    ShowResourceEvents,
+    ToggleAgeFormat,
+    TriggerCronJob,
+    ToggleCronJobSuspend,
+    ToggleRowMark,
+    OlderLogWindow,
+    NewerLogWindow,
+    ExportSelectedYaml,
     ShowManifest,
     StartPortForwardPrompt,
     ToggleOverview,
+    ToggleLogFilterCase,
+    FocusRowNamespace,
+    ToggleNamespaceScope,
+    NextNamespace,
+    PrevNamespace,
+    ShowNodeNeighbors,
+    OpenArgoSource,
+    DecodeSecret,
+    CycleSortColumn,
+    ToggleSortDirection,
+    RestoreAllNamespaces,
     ClearDetailOverlay,
     GPrefix,
     SubmitInput,
@@ -36,6 +61,9 @@ pub enum Action {
     CancelInput,
     Backspace,
     Delete,
+    Left,
+    Right,
+    InsertNewline,
     InputChar(char),
     ConfirmYes,
     ConfirmNo,
@@ -47,6 +75,32 @@ pub fn map_key(mode: InputMode, key: KeyEvent) -> Option<Action> {
     match mode {
         InputMode::Normal => map_normal_mode_key(key),
         InputMode::Command | InputMode::Filter | InputMode::Jump => map_input_mode_key(key),
+        InputMode::Edit => map_edit_mode_key(key),
+    }
+}
+
+fn map_edit_mode_key(key: KeyEvent) -> Option<Action> {
+    match key.code {
+        KeyCode::Esc => Some(Action::CancelInput),
+        KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Some(Action::SubmitInput)
+        }
+        KeyCode::Enter => Some(Action::InsertNewline),
+        KeyCode::Tab => Some(Action::CompleteInput),
+        KeyCode::Backspace => Some(Action::Backspace),
+        KeyCode::Delete => Some(Action::Delete),
+        KeyCode::Up => Some(Action::Up),
+        KeyCode::Down => Some(Action::Down),
+        KeyCode::Left => Some(Action::Left),
+        KeyCode::Right => Some(Action::Right),
+        KeyCode::Home => Some(Action::Top),
+        KeyCode::End => Some(Action::Bottom),
+        KeyCode::PageUp => Some(Action::PageUp),
+        KeyCode::PageDown => Some(Action::PageDown),
+        KeyCode::Char(c) if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT => {
+            Some(Action::InputChar(c))
+        }
+        _ => None,
     }
 }
 
@@ -87,10 +141,34 @@ fn map_normal_mode_key(key: KeyEvent) -> Option<Action> {
         KeyCode::Char('L') => Some(Action::LoadResourceLogs),
         KeyCode::Char('s') => Some(Action::OpenPodShell),
         KeyCode::Char('e') => Some(Action::EditResource),
+        KeyCode::Char('E') => Some(Action::OpenArgoSource),
         KeyCode::Char('m') if key.modifiers.is_empty() => Some(Action::ShowManifest),
+        KeyCode::Char('w') if key.modifiers.is_empty() => Some(Action::ScratchYaml),
+        KeyCode::Char('W') => Some(Action::ExportSelectedYaml),
+        KeyCode::Char('I') => Some(Action::EditYamlInline),
+        KeyCode::Char('c') if key.modifiers.is_empty() => Some(Action::CopyReference),
+        KeyCode::Char('C') => Some(Action::CopySelection),
+        KeyCode::Char('v') if key.modifiers.is_empty() => Some(Action::ShowResourceEvents),
+        KeyCode::Char('[') if key.modifiers.is_empty() => Some(Action::OlderLogWindow),
+        KeyCode::Char(']') if key.modifiers.is_empty() => Some(Action::NewerLogWindow),
         KeyCode::Char('p') => Some(Action::StartPortForwardPrompt),
         KeyCode::Char('o') => Some(Action::ToggleOverview),
+        KeyCode::Char('i') if key.modifiers.is_empty() => Some(Action::ToggleLogFilterCase),
         KeyCode::Char('d') if key.modifiers.is_empty() => Some(Action::ShowDetails),
+        KeyCode::Char('D') => Some(Action::DescribeResource),
+        KeyCode::Char('f') if key.modifiers.is_empty() => Some(Action::FocusRowNamespace),
+        KeyCode::Char('F') => Some(Action::RestoreAllNamespaces),
+        KeyCode::Char('a') if key.modifiers.is_empty() => Some(Action::ToggleNamespaceScope),
+        KeyCode::Char('}') => Some(Action::NextNamespace),
+        KeyCode::Char('{') => Some(Action::PrevNamespace),
+        KeyCode::Char('h') if key.modifiers.is_empty() => Some(Action::ShowNodeNeighbors),
+        KeyCode::Char('x') if key.modifiers.is_empty() => Some(Action::DecodeSecret),
+        KeyCode::Char('S') => Some(Action::CycleSortColumn),
+        KeyCode::Char('A') => Some(Action::ToggleSortDirection),
+        KeyCode::Char('T') => Some(Action::ToggleAgeFormat),
+        KeyCode::Char('t') if key.modifiers.is_empty() => Some(Action::TriggerCronJob),
+        KeyCode::Char('z') if key.modifiers.is_empty() => Some(Action::ToggleCronJobSuspend),
+        KeyCode::Char(' ') if key.modifiers.is_empty() => Some(Action::ToggleRowMark),
         KeyCode::Char('y') | KeyCode::Char('Y') => Some(Action::ConfirmYes),
         KeyCode::Char('n') | KeyCode::Char('N') => Some(Action::ConfirmNo),
         KeyCode::Tab if key.modifiers.contains(KeyModifiers::CONTROL) => {
@@ -112,6 +190,9 @@ fn map_normal_mode_key(key: KeyEvent) -> Option<Action> {
         KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             Some(Action::PageDown)
         }
+        KeyCode::Char('k') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Some(Action::ForceDeletePod)
+        }
         KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => Some(Action::PageUp),
         KeyCode::Char(c)
             if key.modifiers.contains(KeyModifiers::CONTROL)
@@ -359,6 +440,48 @@ mod tests {
         assert_eq!(action, Some(Action::ShowDetails));
     }
 
+    #[test]
+    fn normal_mode_maps_c_to_copy_reference() {
+        let key = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE);
+        let action = map_key(InputMode::Normal, key);
+        assert_eq!(action, Some(Action::CopyReference));
+    }
+
+    #[test]
+    fn normal_mode_maps_shift_d_to_describe() {
+        let key = KeyEvent::new(KeyCode::Char('D'), KeyModifiers::SHIFT);
+        let action = map_key(InputMode::Normal, key);
+        assert_eq!(action, Some(Action::DescribeResource));
+    }
+
+    #[test]
+    fn normal_mode_maps_f_pair_to_namespace_focus_and_restore() {
+        let focus = KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE);
+        let restore = KeyEvent::new(KeyCode::Char('F'), KeyModifiers::SHIFT);
+        assert_eq!(
+            map_key(InputMode::Normal, focus),
+            Some(Action::FocusRowNamespace)
+        );
+        assert_eq!(
+            map_key(InputMode::Normal, restore),
+            Some(Action::RestoreAllNamespaces)
+        );
+    }
+
+    #[test]
+    fn normal_mode_maps_braces_to_namespace_cycling() {
+        let next = KeyEvent::new(KeyCode::Char('}'), KeyModifiers::SHIFT);
+        let prev = KeyEvent::new(KeyCode::Char('{'), KeyModifiers::SHIFT);
+        assert_eq!(
+            map_key(InputMode::Normal, next),
+            Some(Action::NextNamespace)
+        );
+        assert_eq!(
+            map_key(InputMode::Normal, prev),
+            Some(Action::PrevNamespace)
+        );
+    }
+
     #[test]
     fn normal_mode_maps_shift_l_to_related_logs() {
         let key = KeyEvent::new(KeyCode::Char('L'), KeyModifiers::SHIFT);
@@ -572,4 +695,46 @@ mod tests {
         let action = map_key(InputMode::Command, key);
         assert_eq!(action, Some(Action::DeleteView(7)));
     }
+
+    #[test]
+    fn normal_mode_maps_i_to_log_filter_case_toggle() {
+        let key = KeyEvent::new(KeyCode::Char('i'), KeyModifiers::NONE);
+        let action = map_key(InputMode::Normal, key);
+        assert_eq!(action, Some(Action::ToggleLogFilterCase));
+    }
+
+    #[test]
+    fn normal_mode_maps_ctrl_k_to_force_delete() {
+        let key = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::CONTROL);
+        let action = map_key(InputMode::Normal, key);
+        assert_eq!(action, Some(Action::ForceDeletePod));
+    }
+
+    #[test]
+    fn normal_mode_maps_shift_c_to_copy_selection() {
+        let key = KeyEvent::new(KeyCode::Char('C'), KeyModifiers::SHIFT);
+        let action = map_key(InputMode::Normal, key);
+        assert_eq!(action, Some(Action::CopySelection));
+    }
+
+    #[test]
+    fn normal_mode_maps_v_to_resource_events() {
+        let key = KeyEvent::new(KeyCode::Char('v'), KeyModifiers::NONE);
+        let action = map_key(InputMode::Normal, key);
+        assert_eq!(action, Some(Action::ShowResourceEvents));
+    }
+
+    #[test]
+    fn normal_mode_maps_t_to_trigger_cronjob() {
+        let key = KeyEvent::new(KeyCode::Char('t'), KeyModifiers::NONE);
+        let action = map_key(InputMode::Normal, key);
+        assert_eq!(action, Some(Action::TriggerCronJob));
+    }
+
+    #[test]
+    fn normal_mode_maps_z_to_cronjob_suspend_toggle() {
+        let key = KeyEvent::new(KeyCode::Char('z'), KeyModifiers::NONE);
+        let action = map_key(InputMode::Normal, key);
+        assert_eq!(action, Some(Action::ToggleCronJobSuspend));
+    }
 }
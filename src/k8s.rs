@@ -1,39 +1,110 @@
 use anyhow::{Context, Result};
 use chrono::{Local, Utc};
+use k8s_openapi::api::admissionregistration::v1::{
+    MutatingWebhookConfiguration, ValidatingWebhookConfiguration, WebhookClientConfig,
+};
 use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
 use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::coordination::v1::Lease;
 use k8s_openapi::api::core::v1::{
     ConfigMap, Event, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod,
-    ReplicationController, Secret, Service, ServiceAccount,
+    ReplicationController, ResourceQuota, Secret, Service, ServiceAccount, ServicePort,
 };
+use k8s_openapi::api::discovery::v1::EndpointSlice;
 use k8s_openapi::api::networking::v1::{Ingress, IngressClass, NetworkPolicy};
 use k8s_openapi::api::rbac::v1::{ClusterRole, ClusterRoleBinding, Role, RoleBinding};
+use k8s_openapi::api::scheduling::v1::PriorityClass;
 use k8s_openapi::api::storage::v1::StorageClass;
 use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
-use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
-use kube::api::{DeleteParams, ListParams, LogParams, Patch, PatchParams};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ManagedFieldsEntry, ObjectMeta, Time};
+use kube::api::{
+    DeleteParams, ListParams, LogParams, Patch, PatchParams, PostParams, PropagationPolicy,
+};
 use kube::config::{KubeConfigOptions, Kubeconfig};
 use kube::core::{ApiResource, DynamicObject, GroupVersionKind};
 use kube::{Api, Client, Config, ResourceExt};
 use serde::Serialize;
+use serde::de::DeserializeOwned;
 use serde_json::Value;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 use crate::model::{
-    AlertSnapshot, ContextCatalogRow, CustomResourceDef, NamespaceScope, OverviewMetrics,
-    PodContainerInfo, ResourceTab, RowData, TableData,
+    AlertSnapshot, ContextCatalogRow, CustomResourceDef, DeletePropagation, LogWindow,
+    NamespaceCounts, NamespaceScope, OverviewMetrics, PodContainerInfo, ResourceTab, RowData,
+    SecretEntry, TableData, TableSort, UsageSort, VOLUME_SNAPSHOT_GROUP,
 };
 
+pub const DEFAULT_PAGE_SIZE: u32 = 500;
+
 #[derive(Clone)]
 pub struct KubeGateway {
     client: Client,
     context: String,
     cluster: String,
+    /// `--cluster` override the gateway was built with; credential reloads keep it,
+    /// while `:ctx`/`:cluster`/`:usr` switches build a fresh gateway without one.
+    cluster_selection: Option<String>,
     user: String,
     default_namespace: String,
     kube_targets: Vec<KubeTarget>,
     available_clusters: Vec<String>,
     available_users: Vec<String>,
+    /// Keyed by (context, cluster): a `--cluster` override reuses the context name
+    /// while pointing at a different API server.
+    server_versions: HashMap<(String, String), ServerVersion>,
+    page_size: u32,
+}
+
+/// Kubeconfig selection that external `kubectl`/`oc`/`helm` calls are pinned to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KubectlTarget<'a> {
+    pub context: Option<&'a str>,
+    /// `--cluster` override; helm has no equivalent flag.
+    pub cluster: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerVersion {
+    pub git_version: String,
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl ServerVersion {
+    pub fn at_least(&self, major: u32, minor: u32) -> bool {
+        (self.major, self.minor) >= (major, minor)
+    }
+
+    pub fn label(&self) -> String {
+        let git_version = self.git_version.trim();
+        if git_version.is_empty() {
+            format!("v{}.{}", self.major, self.minor)
+        } else {
+            git_version.to_string()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RolloutState {
+    Progressing,
+    Complete,
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RolloutStatus {
+    pub state: RolloutState,
+    pub message: String,
+}
+
+impl RolloutStatus {
+    fn new(state: RolloutState, message: impl Into<String>) -> Self {
+        Self {
+            state,
+            message: message.into(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -54,8 +125,8 @@ struct KubeTarget {
 }
 
 impl KubeGateway {
-    pub async fn new() -> Result<Self> {
-        Self::from_kube_selection(None, None).await
+    pub async fn new(context: Option<String>, cluster: Option<String>) -> Result<Self> {
+        Self::from_kube_selection(context, cluster).await
     }
 
     pub fn available_contexts(&self) -> Vec<String> {
@@ -94,7 +165,7 @@ impl KubeGateway {
 
     pub async fn switch_context(&mut self, context: &str) -> Result<()> {
         let switched = Self::from_kube_selection(Some(context.to_string()), None).await?;
-        *self = switched;
+        self.replace_keeping_cache(switched);
         Ok(())
     }
 
@@ -124,7 +195,7 @@ impl KubeGateway {
         };
 
         let switched = Self::from_kube_selection(Some(target_context.clone()), None).await?;
-        *self = switched;
+        self.replace_keeping_cache(switched);
         Ok(target_context)
     }
 
@@ -149,7 +220,7 @@ impl KubeGateway {
         };
 
         let switched = Self::from_kube_selection(Some(target_context.clone()), None).await?;
-        *self = switched;
+        self.replace_keeping_cache(switched);
         Ok(target_context)
     }
 
@@ -161,6 +232,22 @@ impl KubeGateway {
         &self.context
     }
 
+    /// Context and cluster to pin `kubectl`/`helm` to; empty when running without a kubeconfig.
+    pub fn kubectl_target(&self) -> KubectlTarget<'_> {
+        if self.kube_targets.is_empty() {
+            return KubectlTarget::default();
+        }
+        KubectlTarget {
+            context: Some(self.context.as_str()),
+            cluster: self.cluster_override(),
+        }
+    }
+
+    /// Cluster passed with `--cluster`, overriding the one the context names.
+    pub fn cluster_override(&self) -> Option<&str> {
+        self.cluster_selection.as_deref()
+    }
+
     pub fn user(&self) -> &str {
         &self.user
     }
@@ -177,6 +264,7 @@ impl KubeGateway {
         let kubeconfig = Kubeconfig::read().ok();
 
         let config = if let Some(kubeconfig_value) = kubeconfig.clone() {
+            validate_kube_selection(&kubeconfig_value, context.as_deref(), cluster.as_deref())?;
             let options = KubeConfigOptions {
                 context: context.clone(),
                 cluster: cluster.clone(),
@@ -241,73 +329,152 @@ impl KubeGateway {
             client,
             context: active_context,
             cluster: cluster_url,
+            cluster_selection: cluster,
             user: active_user,
             default_namespace,
             kube_targets,
             available_clusters,
             available_users,
+            server_versions: HashMap::new(),
+            page_size: DEFAULT_PAGE_SIZE,
         })
     }
 
+    pub async fn reauthenticate(&mut self) -> Result<bool> {
+        let context = (!self.kube_targets.is_empty()).then(|| self.context.clone());
+        let refreshed = Self::from_kube_selection(context, self.cluster_selection.clone()).await?;
+        let refreshable = Kubeconfig::read()
+            .map(|kubeconfig| kubeconfig_user_refreshes(&kubeconfig, &refreshed.user))
+            .unwrap_or(true);
+        self.replace_keeping_cache(refreshed);
+        Ok(refreshable)
+    }
+
+    fn replace_keeping_cache(&mut self, switched: Self) {
+        let server_versions = std::mem::take(&mut self.server_versions);
+        let page_size = self.page_size;
+        *self = switched;
+        self.server_versions = server_versions;
+        self.page_size = page_size;
+    }
+
+    pub fn set_page_size(&mut self, page_size: u32) {
+        self.page_size = page_size;
+    }
+
+    pub async fn server_version(&mut self) -> Result<ServerVersion> {
+        if let Some(version) = self.cached_server_version() {
+            return Ok(version.clone());
+        }
+        let version = fetch_server_version(&self.client).await?;
+        self.server_versions
+            .insert(self.server_version_key(), version.clone());
+        Ok(version)
+    }
+
+    fn cached_server_version(&self) -> Option<&ServerVersion> {
+        self.server_versions.get(&self.server_version_key())
+    }
+
+    fn server_version_key(&self) -> (String, String) {
+        (self.context.clone(), self.cluster.clone())
+    }
+
     pub async fn fetch_table(
         &self,
         tab: ResourceTab,
         scope: &NamespaceScope,
         selected_custom: Option<&CustomResourceDef>,
+        label_selector: Option<&str>,
+        field_selector: Option<&str>,
+        pages: usize,
     ) -> Result<TableData> {
         let refreshed_at = Local::now();
-        let (headers, mut rows) = match tab {
-            ResourceTab::Orca
-            | ResourceTab::ArgoCdApps
-            | ResourceTab::ArgoCdResources
-            | ResourceTab::ArgoCdProjects
-            | ResourceTab::ArgoCdRepos
-            | ResourceTab::ArgoCdClusters
-            | ResourceTab::ArgoCdAccounts
-            | ResourceTab::ArgoCdCerts
-            | ResourceTab::ArgoCdGpgKeys => {
-                anyhow::bail!(
-                    "{} is fetched from Argo CD API, not Kubernetes API",
-                    tab.title()
-                )
-            }
-            ResourceTab::Pods => self.fetch_pods(scope).await?,
-            ResourceTab::CronJobs => self.fetch_cronjobs(scope).await?,
-            ResourceTab::DaemonSets => self.fetch_daemonsets(scope).await?,
-            ResourceTab::Deployments => self.fetch_deployments(scope).await?,
-            ResourceTab::ReplicaSets => self.fetch_replicasets(scope).await?,
-            ResourceTab::ReplicationControllers => {
-                self.fetch_replication_controllers(scope).await?
-            }
-            ResourceTab::StatefulSets => self.fetch_statefulsets(scope).await?,
-            ResourceTab::Jobs => self.fetch_jobs(scope).await?,
-            ResourceTab::Services => self.fetch_services(scope).await?,
-            ResourceTab::Ingresses => self.fetch_ingresses(scope).await?,
-            ResourceTab::IngressClasses => self.fetch_ingress_classes().await?,
-            ResourceTab::ConfigMaps => self.fetch_configmaps(scope).await?,
-            ResourceTab::PersistentVolumeClaims => {
-                self.fetch_persistent_volume_claims(scope).await?
-            }
-            ResourceTab::Secrets => self.fetch_secrets(scope).await?,
-            ResourceTab::StorageClasses => self.fetch_storage_classes().await?,
-            ResourceTab::PersistentVolumes => self.fetch_persistent_volumes().await?,
-            ResourceTab::ServiceAccounts => self.fetch_service_accounts(scope).await?,
-            ResourceTab::Roles => self.fetch_roles(scope).await?,
-            ResourceTab::RoleBindings => self.fetch_role_bindings(scope).await?,
-            ResourceTab::ClusterRoles => self.fetch_cluster_roles().await?,
-            ResourceTab::ClusterRoleBindings => self.fetch_cluster_role_bindings().await?,
-            ResourceTab::NetworkPolicies => self.fetch_network_policies(scope).await?,
-            ResourceTab::Nodes => self.fetch_nodes().await?,
-            ResourceTab::Events => self.fetch_events(scope).await?,
-            ResourceTab::Namespaces => self.fetch_namespaces().await?,
-            ResourceTab::CustomResources => {
-                if let Some(custom) = selected_custom {
-                    self.fetch_custom_resources(custom, scope).await?
-                } else {
-                    self.fetch_custom_resource_definitions().await?
+        let mut params = list_params();
+        if let Some(selector) = label_selector {
+            params = params.labels(selector);
+        }
+        if let Some(selector) = field_selector {
+            params = params.fields(selector);
+        }
+        let mut has_more = false;
+        let fetched: Result<(Vec<String>, Vec<RowData>)> = async {
+            Ok(match tab {
+                ResourceTab::Orca
+                | ResourceTab::ArgoCdApps
+                | ResourceTab::ArgoCdResources
+                | ResourceTab::ArgoCdProjects
+                | ResourceTab::ArgoCdRepos
+                | ResourceTab::ArgoCdClusters
+                | ResourceTab::ArgoCdAccounts
+                | ResourceTab::ArgoCdCerts
+                | ResourceTab::ArgoCdGpgKeys => {
+                    anyhow::bail!(
+                        "{} is fetched from Argo CD API, not Kubernetes API",
+                        tab.title()
+                    )
                 }
-            }
-        };
+                ResourceTab::Pods => {
+                    let (headers, rows, more) = self.fetch_pods(scope, &params, pages).await?;
+                    has_more = more;
+                    (headers, rows)
+                }
+                ResourceTab::CronJobs => self.fetch_cronjobs(scope, &params).await?,
+                ResourceTab::DaemonSets => self.fetch_daemonsets(scope, &params).await?,
+                ResourceTab::Deployments => self.fetch_deployments(scope, &params).await?,
+                ResourceTab::ReplicaSets => self.fetch_replicasets(scope, &params).await?,
+                ResourceTab::ReplicationControllers => {
+                    self.fetch_replication_controllers(scope, &params).await?
+                }
+                ResourceTab::StatefulSets => self.fetch_statefulsets(scope, &params).await?,
+                ResourceTab::Jobs => self.fetch_jobs(scope, &params).await?,
+                ResourceTab::Services => self.fetch_services(scope, &params).await?,
+                ResourceTab::EndpointSlices => {
+                    if let Some(version) = self.cached_server_version()
+                        && !version.at_least(1, 21)
+                    {
+                        anyhow::bail!(
+                            "EndpointSlices need discovery.k8s.io/v1 (Kubernetes 1.21+), server is {}",
+                            version.label()
+                        );
+                    }
+                    self.fetch_endpoint_slices(scope, &params).await?
+                }
+                ResourceTab::Ingresses => self.fetch_ingresses(scope, &params).await?,
+                ResourceTab::IngressClasses => self.fetch_ingress_classes(&params).await?,
+                ResourceTab::ConfigMaps => self.fetch_configmaps(scope, &params).await?,
+                ResourceTab::PersistentVolumeClaims => {
+                    self.fetch_persistent_volume_claims(scope, &params).await?
+                }
+                ResourceTab::Secrets => self.fetch_secrets(scope, &params).await?,
+                ResourceTab::StorageClasses => self.fetch_storage_classes(&params).await?,
+                ResourceTab::PersistentVolumes => self.fetch_persistent_volumes(&params).await?,
+                ResourceTab::VolumeSnapshots => self.fetch_volume_snapshots(scope, &params).await?,
+                ResourceTab::ServiceAccounts => self.fetch_service_accounts(scope, &params).await?,
+                ResourceTab::Roles => self.fetch_roles(scope, &params).await?,
+                ResourceTab::RoleBindings => self.fetch_role_bindings(scope, &params).await?,
+                ResourceTab::ClusterRoles => self.fetch_cluster_roles(&params).await?,
+                ResourceTab::ClusterRoleBindings => self.fetch_cluster_role_bindings(&params).await?,
+                ResourceTab::PriorityClasses => self.fetch_priority_classes(&params).await?,
+                ResourceTab::NetworkPolicies => self.fetch_network_policies(scope, &params).await?,
+                ResourceTab::Leases => self.fetch_leases(scope, &params).await?,
+                ResourceTab::MutatingWebhooks => self.fetch_mutating_webhooks(&params).await?,
+                ResourceTab::ValidatingWebhooks => self.fetch_validating_webhooks(&params).await?,
+                ResourceTab::Nodes => self.fetch_nodes(&params).await?,
+                ResourceTab::Events => self.fetch_events(scope, &params).await?,
+                ResourceTab::Namespaces => self.fetch_namespaces(&params).await?,
+                ResourceTab::CustomResources => {
+                    if let Some(custom) = selected_custom {
+                        self.fetch_custom_resources(custom, scope, &params).await?
+                    } else {
+                        self.fetch_custom_resource_definitions(&params).await?
+                    }
+                }
+            })
+        }
+        .await;
+        let (headers, mut rows) =
+            fetched.map_err(|error| selector_error(error, label_selector, field_selector))?;
 
         rows.sort_by(|left, right| {
             left.namespace
@@ -317,6 +484,13 @@ impl KubeGateway {
 
         let mut table = TableData::default();
         table.set_rows(headers, rows, refreshed_at);
+        table.has_more = has_more;
+        if tab == ResourceTab::PriorityClasses {
+            table.apply_sort(Some(TableSort {
+                column: 1,
+                descending: true,
+            }));
+        }
         Ok(table)
     }
 
@@ -326,12 +500,15 @@ impl KubeGateway {
         pod_name: &str,
         container: Option<&str>,
         previous: bool,
+        tail: usize,
+        window: Option<LogWindow>,
     ) -> Result<String> {
         let pods: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
         let params = LogParams {
             container: container.map(str::to_string),
             previous,
-            tail_lines: Some(500),
+            tail_lines: (tail > 0 && window.is_none()).then_some(tail as i64),
+            since_seconds: window.map(LogWindow::since_seconds),
             timestamps: true,
             ..LogParams::default()
         };
@@ -341,7 +518,41 @@ impl KubeGateway {
             .await
             .with_context(|| format!("failed to load logs for {namespace}/{pod_name}"))?;
 
-        Ok(logs)
+        Ok(match window {
+            Some(window) if window.offset > 0 => {
+                let now = k8s_openapi::jiff::Timestamp::now().as_second();
+                let cutoff = now.saturating_sub_unsigned(window.until_seconds_ago());
+                logs_before(&logs, cutoff)
+            }
+            _ => logs,
+        })
+    }
+
+    pub async fn fetch_secret_entries(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Vec<SecretEntry>> {
+        let secrets: Api<Secret> = Api::namespaced(self.client.clone(), namespace);
+        let secret = secrets
+            .get(name)
+            .await
+            .with_context(|| format!("failed to get secret {namespace}/{name}"))?;
+
+        let entries = secret
+            .data
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, value)| {
+                let bytes = value.0.len();
+                SecretEntry {
+                    key,
+                    value: String::from_utf8(value.0).ok(),
+                    bytes,
+                }
+            })
+            .collect();
+        Ok(entries)
     }
 
     pub async fn pod_containers(
@@ -393,6 +604,10 @@ impl KubeGateway {
             }
         }
 
+        let usage = self
+            .pod_container_usage(namespace, pod_name)
+            .await
+            .unwrap_or_default();
         let mut rows = Vec::new();
         for (name, image) in ordered {
             let mut info = statuses.remove(&name).unwrap_or_default();
@@ -403,6 +618,10 @@ impl KubeGateway {
             if info.age.is_empty() {
                 info.age = pod_age.clone();
             }
+            if let Some((cpu, memory)) = usage.get(&info.name) {
+                info.cpu = format_cpu_millicores(*cpu);
+                info.memory = format_bytes(*memory);
+            }
             rows.push(info);
         }
 
@@ -415,6 +634,38 @@ impl KubeGateway {
         Ok(rows)
     }
 
+    pub async fn pod_container_usage(
+        &self,
+        namespace: &str,
+        pod_name: &str,
+    ) -> Result<HashMap<String, (u64, u64)>> {
+        let gvk = GroupVersionKind::gvk("metrics.k8s.io", "v1beta1", "PodMetrics");
+        let resource = ApiResource::from_gvk_with_plural(&gvk, "pods");
+        let api: Api<DynamicObject> =
+            Api::namespaced_with(self.client.clone(), namespace, &resource);
+        let metrics = api
+            .get(pod_name)
+            .await
+            .with_context(|| format!("failed to fetch metrics for pod {namespace}/{pod_name}"))?;
+        Ok(parse_container_metrics_usage(&metrics.data))
+    }
+
+    pub async fn resolve_service_port(
+        &self,
+        namespace: &str,
+        service: &str,
+        port_name: &str,
+    ) -> Result<u16> {
+        let api: Api<Service> = Api::namespaced(self.client.clone(), namespace);
+        let svc = api
+            .get(service)
+            .await
+            .with_context(|| format!("failed to fetch service {namespace}/{service}"))?;
+        let ports = svc.spec.and_then(|spec| spec.ports).unwrap_or_default();
+        service_port_by_name(&ports, port_name)
+            .with_context(|| format!("service {namespace}/{service}"))
+    }
+
     pub async fn resolve_log_target(
         &self,
         tab: ResourceTab,
@@ -558,6 +809,34 @@ impl KubeGateway {
         Ok(snapshot)
     }
 
+    pub async fn fetch_node_metrics_table(&self) -> Result<TableData> {
+        let gvk = GroupVersionKind::gvk("metrics.k8s.io", "v1beta1", "NodeMetrics");
+        let resource = ApiResource::from_gvk_with_plural(&gvk, "nodes");
+        let metrics_api: Api<DynamicObject> = Api::all_with(self.client.clone(), &resource);
+        let usage = metrics_api
+            .list(&list_params())
+            .await?
+            .into_iter()
+            .map(|metric| {
+                (
+                    metric.name_any(),
+                    parse_usage_from_value(&metric.data["usage"]),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        let nodes: Api<Node> = Api::all(self.client.clone());
+        let nodes = nodes.list(&list_params()).await?.items;
+        let (headers, rows) = node_usage_rows(&nodes, &usage);
+        let mut table = TableData::default();
+        table.set_rows(headers, rows, Local::now());
+        table.apply_sort(Some(TableSort {
+            column: 3,
+            descending: true,
+        }));
+        Ok(table)
+    }
+
     pub async fn discover_custom_resources(&self) -> Result<Vec<CustomResourceDef>> {
         let crd_api: Api<CustomResourceDefinition> = Api::all(self.client.clone());
         let list = crd_api.list(&list_params()).await?;
@@ -579,6 +858,10 @@ impl KubeGateway {
                     kind: spec.names.kind.clone(),
                     plural: spec.names.plural,
                     namespaced: spec.scope == "Namespaced",
+                    scalable: storage_version
+                        .subresources
+                        .as_ref()
+                        .is_some_and(|subresources| subresources.scale.is_some()),
                 })
             })
             .collect::<Vec<_>>();
@@ -588,13 +871,120 @@ impl KubeGateway {
         Ok(resources)
     }
 
+    pub async fn fetch_resource_events(
+        &self,
+        kind: &str,
+        namespace: Option<&str>,
+        name: &str,
+    ) -> Result<(usize, String)> {
+        let events_api: Api<Event> = match namespace {
+            Some(namespace) => Api::namespaced(self.client.clone(), namespace),
+            None => Api::all(self.client.clone()),
+        };
+        let params = list_params().fields(&format!(
+            "involvedObject.kind={kind},involvedObject.name={name}"
+        ));
+        let events = events_api.list(&params).await?.items;
+        Ok((events.len(), resource_event_lines(events).join("\n")))
+    }
+
+    pub async fn unserved_tabs(&self) -> Result<HashSet<ResourceTab>> {
+        let mut group_versions = self
+            .client
+            .list_api_groups()
+            .await?
+            .groups
+            .into_iter()
+            .flat_map(|group| {
+                group
+                    .versions
+                    .into_iter()
+                    .map(|version| version.group_version)
+            })
+            .collect::<HashSet<_>>();
+        group_versions.extend(self.client.list_core_api_versions().await?.versions);
+
+        let mut served = HashMap::<String, HashSet<String>>::new();
+        for tab in ResourceTab::ALL {
+            let Some((group_version, _)) = tab.api_resource() else {
+                continue;
+            };
+            if served.contains_key(group_version) || !group_versions.contains(group_version) {
+                continue;
+            }
+            let resources = if group_version.contains('/') {
+                self.client.list_api_group_resources(group_version).await?
+            } else {
+                self.client.list_core_api_resources(group_version).await?
+            };
+            served.insert(
+                group_version.to_string(),
+                resources
+                    .resources
+                    .into_iter()
+                    .map(|resource| resource.name)
+                    .collect(),
+            );
+        }
+        Ok(unserved_tabs_from(&served))
+    }
+
+    /// Replaces the object opened in the inline editor; the edited manifest must still
+    /// name that same kind, namespace and name.
+    pub async fn apply_yaml(
+        &self,
+        kind: &str,
+        namespace: Option<&str>,
+        name: &str,
+        yaml: &str,
+        resource_version: Option<&str>,
+    ) -> Result<()> {
+        let mut object: DynamicObject =
+            serde_yaml::from_str(yaml).context("edited manifest is not a valid object")?;
+        let types = object
+            .types
+            .clone()
+            .context("edited manifest is missing apiVersion/kind")?;
+        ensure_apply_target(&object, kind, namespace, name)?;
+        let gvk = GroupVersionKind::try_from(&types).map_err(|error| {
+            anyhow::anyhow!("invalid apiVersion '{}': {error}", types.api_version)
+        })?;
+        let (resource, capabilities) = kube::discovery::pinned_kind(&self.client, &gvk)
+            .await
+            .with_context(|| format!("failed to discover {}", types.kind))?;
+        let api: Api<DynamicObject> = match capabilities.scope {
+            kube::discovery::Scope::Namespaced => {
+                let namespace = object
+                    .metadata
+                    .namespace
+                    .clone()
+                    .or_else(|| namespace.map(str::to_string))
+                    .context("namespace is required for this resource")?;
+                Api::namespaced_with(self.client.clone(), &namespace, &resource)
+            }
+            kube::discovery::Scope::Cluster => Api::all_with(self.client.clone(), &resource),
+        };
+        let name = object.name_any();
+        if object.metadata.resource_version.is_none() {
+            object.metadata.resource_version = match resource_version {
+                Some(version) => Some(version.to_string()),
+                None => api.get(&name).await?.metadata.resource_version,
+            };
+        }
+        api.replace(&name, &Default::default(), &object)
+            .await
+            .with_context(|| format!("failed to apply {} '{name}'", types.kind))?;
+        Ok(())
+    }
+
     pub async fn delete_resource(
         &self,
         tab: ResourceTab,
         namespace: Option<&str>,
         name: &str,
+        propagation: DeletePropagation,
     ) -> Result<()> {
-        let params = DeleteParams::default();
+        let params = delete_params(propagation);
         match tab {
             ResourceTab::Pods => {
                 let namespace = namespace.context("namespace is required for pod delete")?;
@@ -644,6 +1034,12 @@ impl KubeGateway {
                 let api: Api<Service> = Api::namespaced(self.client.clone(), namespace);
                 let _ = api.delete(name, &params).await?;
             }
+            ResourceTab::EndpointSlices => {
+                let namespace =
+                    namespace.context("namespace is required for endpoint slice delete")?;
+                let api: Api<EndpointSlice> = Api::namespaced(self.client.clone(), namespace);
+                let _ = api.delete(name, &params).await?;
+            }
             ResourceTab::Ingresses => {
                 let namespace = namespace.context("namespace is required for ingress delete")?;
                 let api: Api<Ingress> = Api::namespaced(self.client.clone(), namespace);
@@ -678,6 +1074,16 @@ impl KubeGateway {
                 let api: Api<PersistentVolume> = Api::all(self.client.clone());
                 let _ = api.delete(name, &params).await?;
             }
+            ResourceTab::VolumeSnapshots => {
+                let namespace =
+                    namespace.context("namespace is required for volumesnapshot delete")?;
+                let api: Api<DynamicObject> = Api::namespaced_with(
+                    self.client.clone(),
+                    namespace,
+                    &volume_snapshot_api_resource(),
+                );
+                let _ = api.delete(name, &params).await?;
+            }
             ResourceTab::ServiceAccounts => {
                 let namespace =
                     namespace.context("namespace is required for serviceaccount delete")?;
@@ -703,12 +1109,29 @@ impl KubeGateway {
                 let api: Api<ClusterRoleBinding> = Api::all(self.client.clone());
                 let _ = api.delete(name, &params).await?;
             }
+            ResourceTab::PriorityClasses => {
+                let api: Api<PriorityClass> = Api::all(self.client.clone());
+                let _ = api.delete(name, &params).await?;
+            }
             ResourceTab::NetworkPolicies => {
                 let namespace =
                     namespace.context("namespace is required for networkpolicy delete")?;
                 let api: Api<NetworkPolicy> = Api::namespaced(self.client.clone(), namespace);
                 let _ = api.delete(name, &params).await?;
             }
+            ResourceTab::Leases => {
+                let namespace = namespace.context("namespace is required for lease delete")?;
+                let api: Api<Lease> = Api::namespaced(self.client.clone(), namespace);
+                let _ = api.delete(name, &params).await?;
+            }
+            ResourceTab::MutatingWebhooks => {
+                let api: Api<MutatingWebhookConfiguration> = Api::all(self.client.clone());
+                let _ = api.delete(name, &params).await?;
+            }
+            ResourceTab::ValidatingWebhooks => {
+                let api: Api<ValidatingWebhookConfiguration> = Api::all(self.client.clone());
+                let _ = api.delete(name, &params).await?;
+            }
             ResourceTab::Nodes => {
                 let api: Api<Node> = Api::all(self.client.clone());
                 let _ = api.delete(name, &params).await?;
@@ -735,23 +1158,69 @@ impl KubeGateway {
         Ok(())
     }
 
+    pub async fn force_delete_pod(&self, namespace: &str, name: &str) -> Result<()> {
+        let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+        let _ = api.delete(name, &force_delete_params()).await?;
+        Ok(())
+    }
+
+    pub async fn delete_custom_resource(
+        &self,
+        custom: &CustomResourceDef,
+        namespace: Option<&str>,
+        name: &str,
+        propagation: DeletePropagation,
+    ) -> Result<()> {
+        let api = self.custom_resource_api(custom, namespace)?;
+        let _ = api.delete(name, &delete_params(propagation)).await?;
+        Ok(())
+    }
+
+    pub async fn scale_custom_resource(
+        &self,
+        custom: &CustomResourceDef,
+        namespace: Option<&str>,
+        name: &str,
+        replicas: i32,
+    ) -> Result<()> {
+        if !custom.scalable {
+            anyhow::bail!("{} does not expose a /scale subresource", custom.kind);
+        }
+        let api = self.custom_resource_api(custom, namespace)?;
+        let patch = serde_json::json!({ "spec": { "replicas": replicas } });
+        let _ = api
+            .patch_scale(name, &PatchParams::default(), &Patch::Merge(&patch))
+            .await?;
+        Ok(())
+    }
+
+    fn custom_resource_api(
+        &self,
+        custom: &CustomResourceDef,
+        namespace: Option<&str>,
+    ) -> Result<Api<DynamicObject>> {
+        let gvk = GroupVersionKind::gvk(&custom.group, &custom.version, &custom.kind);
+        let api_resource = ApiResource::from_gvk_with_plural(&gvk, &custom.plural);
+        if custom.namespaced {
+            let namespace =
+                namespace.with_context(|| format!("namespace is required for {}", custom.kind))?;
+            Ok(Api::namespaced_with(
+                self.client.clone(),
+                namespace,
+                &api_resource,
+            ))
+        } else {
+            Ok(Api::all_with(self.client.clone(), &api_resource))
+        }
+    }
+
     pub async fn restart_workload(
         &self,
         tab: ResourceTab,
         namespace: &str,
         name: &str,
     ) -> Result<()> {
-        let patch = serde_json::json!({
-            "spec": {
-                "template": {
-                    "metadata": {
-                        "annotations": {
-                            "kubectl.kubernetes.io/restartedAt": Utc::now().to_rfc3339()
-                        }
-                    }
-                }
-            }
-        });
+        let patch = restart_patch(&Utc::now().to_rfc3339());
         let params = PatchParams::default();
 
         match tab {
@@ -763,12 +1232,54 @@ impl KubeGateway {
                 let api: Api<StatefulSet> = Api::namespaced(self.client.clone(), namespace);
                 let _ = api.patch(name, &params, &Patch::Merge(&patch)).await?;
             }
+            ResourceTab::DaemonSets => {
+                let api: Api<DaemonSet> = Api::namespaced(self.client.clone(), namespace);
+                let _ = api.patch(name, &params, &Patch::Merge(&patch)).await?;
+            }
             _ => anyhow::bail!("restart is not supported for {}", tab.title()),
         }
 
         Ok(())
     }
 
+    pub async fn rerun_job(&self, namespace: &str, name: &str) -> Result<()> {
+        let api: Api<Job> = Api::namespaced(self.client.clone(), namespace);
+        let job = api
+            .get(name)
+            .await
+            .with_context(|| format!("failed to fetch job {namespace}/{name}"))?;
+        let fresh = rerun_job_manifest(&job);
+        api.delete(name, &delete_params(DeletePropagation::Background))
+            .await
+            .with_context(|| format!("failed to delete job {namespace}/{name}"))?;
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(30);
+        while api.get_opt(name).await?.is_some() {
+            if std::time::Instant::now() >= deadline {
+                anyhow::bail!("job {namespace}/{name} is still terminating after 30s");
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        }
+        api.create(&PostParams::default(), &fresh)
+            .await
+            .with_context(|| format!("failed to recreate job {namespace}/{name}"))?;
+        Ok(())
+    }
+
+    pub async fn set_cronjob_suspend(
+        &self,
+        namespace: &str,
+        name: &str,
+        suspend: bool,
+    ) -> Result<()> {
+        let api: Api<CronJob> = Api::namespaced(self.client.clone(), namespace);
+        let patch = serde_json::json!({ "spec": { "suspend": suspend } });
+        let _ = api
+            .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+            .await
+            .with_context(|| format!("failed to patch cronjob {namespace}/{name}"))?;
+        Ok(())
+    }
+
     pub async fn scale_workload(
         &self,
         tab: ResourceTab,
@@ -794,30 +1305,53 @@ impl KubeGateway {
         Ok(())
     }
 
-    async fn fetch_pods(&self, scope: &NamespaceScope) -> Result<(Vec<String>, Vec<RowData>)> {
+    pub async fn rollout_status(
+        &self,
+        tab: ResourceTab,
+        namespace: &str,
+        name: &str,
+    ) -> Result<RolloutStatus> {
+        match tab {
+            ResourceTab::Deployments => {
+                let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+                Ok(deployment_rollout_status(&api.get(name).await?))
+            }
+            ResourceTab::StatefulSets => {
+                let api: Api<StatefulSet> = Api::namespaced(self.client.clone(), namespace);
+                Ok(statefulset_rollout_status(&api.get(name).await?))
+            }
+            ResourceTab::DaemonSets => {
+                let api: Api<DaemonSet> = Api::namespaced(self.client.clone(), namespace);
+                Ok(daemonset_rollout_status(&api.get(name).await?))
+            }
+            _ => anyhow::bail!("rollout status is not supported for {}", tab.title()),
+        }
+    }
+
+    async fn fetch_pods(
+        &self,
+        scope: &NamespaceScope,
+        params: &ListParams,
+        pages: usize,
+    ) -> Result<(Vec<String>, Vec<RowData>, bool)> {
         let pods: Api<Pod> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = pods.list(&list_params()).await?;
+        let (list, has_more) = list_pages(&pods, params, self.page_size, pages).await?;
         let rows = list
             .into_iter()
             .map(|pod| {
                 let name = pod.name_any();
                 let namespace = pod.namespace();
-                let status = pod
-                    .status
-                    .as_ref()
-                    .and_then(|value| value.phase.clone())
-                    .unwrap_or_else(|| "Unknown".to_string());
+                let status = pod_display_status(&pod);
                 let node = pod
                     .spec
                     .as_ref()
                     .and_then(|spec| spec.node_name.clone())
                     .unwrap_or_else(|| "-".to_string());
-                let (ready, total, restarts) =
-                    pod.status.as_ref().map(pod_readiness).unwrap_or((0, 0, 0));
+                let (ready, total, restarts) = pod_readiness(&pod);
                 let age = human_age(pod.metadata.creation_timestamp.as_ref());
 
                 RowData {
@@ -832,7 +1366,11 @@ impl KubeGateway {
                         restarts.to_string(),
                         age,
                     ],
+                    labels: pod.metadata.labels.clone().unwrap_or_default(),
+                    annotations: pod.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&pod),
+                    created: created_at(pod.metadata.creation_timestamp.as_ref()),
+                    phase: pod.status.as_ref().and_then(|status| status.phase.clone()),
                 }
             })
             .collect::<Vec<_>>();
@@ -848,16 +1386,21 @@ impl KubeGateway {
                 "Age".to_string(),
             ],
             rows,
+            has_more,
         ))
     }
 
-    async fn fetch_cronjobs(&self, scope: &NamespaceScope) -> Result<(Vec<String>, Vec<RowData>)> {
+    async fn fetch_cronjobs(
+        &self,
+        scope: &NamespaceScope,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
         let cronjobs: Api<CronJob> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = cronjobs.list(&list_params()).await?;
+        let list = cronjobs.list(params).await?;
         let rows = list
             .into_iter()
             .map(|cronjob| {
@@ -898,7 +1441,11 @@ impl KubeGateway {
                         last,
                         age,
                     ],
+                    labels: cronjob.metadata.labels.clone().unwrap_or_default(),
+                    annotations: cronjob.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&cronjob),
+                    created: created_at(cronjob.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -920,13 +1467,14 @@ impl KubeGateway {
     async fn fetch_daemonsets(
         &self,
         scope: &NamespaceScope,
+        params: &ListParams,
     ) -> Result<(Vec<String>, Vec<RowData>)> {
         let daemonsets: Api<DaemonSet> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = daemonsets.list(&list_params()).await?;
+        let list = daemonsets.list(params).await?;
         let rows = list
             .into_iter()
             .map(|daemonset| {
@@ -965,7 +1513,11 @@ impl KubeGateway {
                         available.to_string(),
                         age,
                     ],
+                    labels: daemonset.metadata.labels.clone().unwrap_or_default(),
+                    annotations: daemonset.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&daemonset),
+                    created: created_at(daemonset.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -986,13 +1538,14 @@ impl KubeGateway {
     async fn fetch_deployments(
         &self,
         scope: &NamespaceScope,
+        params: &ListParams,
     ) -> Result<(Vec<String>, Vec<RowData>)> {
         let deployments: Api<Deployment> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = deployments.list(&list_params()).await?;
+        let list = deployments.list(params).await?;
         let rows = list
             .into_iter()
             .map(|deployment| {
@@ -1031,7 +1584,11 @@ impl KubeGateway {
                         available.to_string(),
                         age,
                     ],
+                    labels: deployment.metadata.labels.clone().unwrap_or_default(),
+                    annotations: deployment.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&deployment),
+                    created: created_at(deployment.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1052,13 +1609,14 @@ impl KubeGateway {
     async fn fetch_replicasets(
         &self,
         scope: &NamespaceScope,
+        params: &ListParams,
     ) -> Result<(Vec<String>, Vec<RowData>)> {
         let replicasets: Api<ReplicaSet> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = replicasets.list(&list_params()).await?;
+        let list = replicasets.list(params).await?;
         let rows = list
             .into_iter()
             .map(|replicaset| {
@@ -1091,7 +1649,11 @@ impl KubeGateway {
                         available.to_string(),
                         age,
                     ],
+                    labels: replicaset.metadata.labels.clone().unwrap_or_default(),
+                    annotations: replicaset.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&replicaset),
+                    created: created_at(replicaset.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1111,13 +1673,14 @@ impl KubeGateway {
     async fn fetch_replication_controllers(
         &self,
         scope: &NamespaceScope,
+        params: &ListParams,
     ) -> Result<(Vec<String>, Vec<RowData>)> {
         let controllers: Api<ReplicationController> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = controllers.list(&list_params()).await?;
+        let list = controllers.list(params).await?;
         let rows = list
             .into_iter()
             .map(|controller| {
@@ -1150,7 +1713,11 @@ impl KubeGateway {
                         current.to_string(),
                         age,
                     ],
+                    labels: controller.metadata.labels.clone().unwrap_or_default(),
+                    annotations: controller.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&controller),
+                    created: created_at(controller.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1170,13 +1737,14 @@ impl KubeGateway {
     async fn fetch_statefulsets(
         &self,
         scope: &NamespaceScope,
+        params: &ListParams,
     ) -> Result<(Vec<String>, Vec<RowData>)> {
         let statefulsets: Api<StatefulSet> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = statefulsets.list(&list_params()).await?;
+        let list = statefulsets.list(params).await?;
         let rows = list
             .into_iter()
             .map(|statefulset| {
@@ -1209,7 +1777,11 @@ impl KubeGateway {
                         current.to_string(),
                         age,
                     ],
+                    labels: statefulset.metadata.labels.clone().unwrap_or_default(),
+                    annotations: statefulset.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&statefulset),
+                    created: created_at(statefulset.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1226,13 +1798,17 @@ impl KubeGateway {
         ))
     }
 
-    async fn fetch_jobs(&self, scope: &NamespaceScope) -> Result<(Vec<String>, Vec<RowData>)> {
+    async fn fetch_jobs(
+        &self,
+        scope: &NamespaceScope,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
         let jobs: Api<Job> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = jobs.list(&list_params()).await?;
+        let list = jobs.list(params).await?;
         let rows = list
             .into_iter()
             .map(|job| {
@@ -1271,7 +1847,11 @@ impl KubeGateway {
                         failed.to_string(),
                         age,
                     ],
+                    labels: job.metadata.labels.clone().unwrap_or_default(),
+                    annotations: job.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&job),
+                    created: created_at(job.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1289,13 +1869,17 @@ impl KubeGateway {
         ))
     }
 
-    async fn fetch_services(&self, scope: &NamespaceScope) -> Result<(Vec<String>, Vec<RowData>)> {
-        let services: Api<Service> = match scope {
-            NamespaceScope::All => Api::all(self.client.clone()),
+    async fn fetch_services(
+        &self,
+        scope: &NamespaceScope,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
+        let services: Api<Service> = match scope {
+            NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = services.list(&list_params()).await?;
+        let list = services.list(params).await?;
         let rows = list
             .into_iter()
             .map(|service| {
@@ -1340,7 +1924,11 @@ impl KubeGateway {
                         },
                         age,
                     ],
+                    labels: service.metadata.labels.clone().unwrap_or_default(),
+                    annotations: service.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&service),
+                    created: created_at(service.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1358,13 +1946,102 @@ impl KubeGateway {
         ))
     }
 
-    async fn fetch_ingresses(&self, scope: &NamespaceScope) -> Result<(Vec<String>, Vec<RowData>)> {
+    async fn fetch_endpoint_slices(
+        &self,
+        scope: &NamespaceScope,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
+        let slices: Api<EndpointSlice> = match scope {
+            NamespaceScope::All => Api::all(self.client.clone()),
+            NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
+        };
+
+        let list = slices.list(params).await?;
+        let rows = list
+            .into_iter()
+            .map(|slice| {
+                let name = slice.name_any();
+                let namespace = slice.namespace();
+                let ready = slice
+                    .endpoints
+                    .iter()
+                    .filter(|endpoint| {
+                        endpoint
+                            .conditions
+                            .as_ref()
+                            .and_then(|conditions| conditions.ready)
+                            .unwrap_or(true)
+                    })
+                    .count();
+                let ports = slice
+                    .ports
+                    .as_ref()
+                    .map(|ports| {
+                        ports
+                            .iter()
+                            .map(|port| {
+                                let number = port
+                                    .port
+                                    .map_or_else(|| "*".to_string(), |port| port.to_string());
+                                let protocol = port.protocol.as_deref().unwrap_or("TCP");
+                                match port.name.as_deref().filter(|name| !name.is_empty()) {
+                                    Some(port_name) => format!("{port_name}:{number}/{protocol}"),
+                                    None => format!("{number}/{protocol}"),
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    })
+                    .filter(|ports| !ports.is_empty())
+                    .unwrap_or_else(|| "-".to_string());
+                let age = human_age(slice.metadata.creation_timestamp.as_ref());
+
+                RowData {
+                    name: name.clone(),
+                    namespace: namespace.clone(),
+                    columns: vec![
+                        name,
+                        namespace.unwrap_or_else(|| "-".to_string()),
+                        slice.address_type.clone(),
+                        slice.endpoints.len().to_string(),
+                        ports,
+                        ready.to_string(),
+                        age,
+                    ],
+                    labels: slice.metadata.labels.clone().unwrap_or_default(),
+                    annotations: slice.metadata.annotations.clone().unwrap_or_default(),
+                    detail: yaml_detail(&slice),
+                    created: created_at(slice.metadata.creation_timestamp.as_ref()),
+                    phase: None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Ok((
+            vec![
+                "Name".to_string(),
+                "Namespace".to_string(),
+                "AddressType".to_string(),
+                "Endpoints".to_string(),
+                "Ports".to_string(),
+                "Ready".to_string(),
+                "Age".to_string(),
+            ],
+            rows,
+        ))
+    }
+
+    async fn fetch_ingresses(
+        &self,
+        scope: &NamespaceScope,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
         let ingresses: Api<Ingress> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = ingresses.list(&list_params()).await?;
+        let list = ingresses.list(params).await?;
         let rows = list
             .into_iter()
             .map(|ingress| {
@@ -1426,7 +2103,11 @@ impl KubeGateway {
                         tls.to_string(),
                         age,
                     ],
+                    labels: ingress.metadata.labels.clone().unwrap_or_default(),
+                    annotations: ingress.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&ingress),
+                    created: created_at(ingress.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1445,9 +2126,12 @@ impl KubeGateway {
         ))
     }
 
-    async fn fetch_ingress_classes(&self) -> Result<(Vec<String>, Vec<RowData>)> {
+    async fn fetch_ingress_classes(
+        &self,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
         let classes: Api<IngressClass> = Api::all(self.client.clone());
-        let list = classes.list(&list_params()).await?;
+        let list = classes.list(params).await?;
         let rows = list
             .into_iter()
             .map(|class| {
@@ -1476,7 +2160,11 @@ impl KubeGateway {
                         if default { "Yes" } else { "No" }.to_string(),
                         age,
                     ],
+                    labels: class.metadata.labels.clone().unwrap_or_default(),
+                    annotations: class.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&class),
+                    created: created_at(class.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1495,13 +2183,14 @@ impl KubeGateway {
     async fn fetch_configmaps(
         &self,
         scope: &NamespaceScope,
+        params: &ListParams,
     ) -> Result<(Vec<String>, Vec<RowData>)> {
         let configmaps: Api<ConfigMap> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = configmaps.list(&list_params()).await?;
+        let list = configmaps.list(params).await?;
         let rows = list
             .into_iter()
             .map(|configmap| {
@@ -1529,7 +2218,11 @@ impl KubeGateway {
                         binary.to_string(),
                         age,
                     ],
+                    labels: configmap.metadata.labels.clone().unwrap_or_default(),
+                    annotations: configmap.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&configmap),
+                    created: created_at(configmap.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1549,13 +2242,14 @@ impl KubeGateway {
     async fn fetch_persistent_volume_claims(
         &self,
         scope: &NamespaceScope,
+        params: &ListParams,
     ) -> Result<(Vec<String>, Vec<RowData>)> {
         let pvcs: Api<PersistentVolumeClaim> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = pvcs.list(&list_params()).await?;
+        let list = pvcs.list(params).await?;
         let rows = list
             .into_iter()
             .map(|pvc| {
@@ -1599,7 +2293,11 @@ impl KubeGateway {
                         access,
                         age,
                     ],
+                    labels: pvc.metadata.labels.clone().unwrap_or_default(),
+                    annotations: pvc.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&pvc),
+                    created: created_at(pvc.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1618,13 +2316,17 @@ impl KubeGateway {
         ))
     }
 
-    async fn fetch_secrets(&self, scope: &NamespaceScope) -> Result<(Vec<String>, Vec<RowData>)> {
+    async fn fetch_secrets(
+        &self,
+        scope: &NamespaceScope,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
         let secrets: Api<Secret> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = secrets.list(&list_params()).await?;
+        let list = secrets.list(params).await?;
         let rows = list
             .into_iter()
             .map(|secret| {
@@ -1644,7 +2346,11 @@ impl KubeGateway {
                         data_count.to_string(),
                         age,
                     ],
+                    labels: secret.metadata.labels.clone().unwrap_or_default(),
+                    annotations: secret.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&secret),
+                    created: created_at(secret.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1661,9 +2367,12 @@ impl KubeGateway {
         ))
     }
 
-    async fn fetch_storage_classes(&self) -> Result<(Vec<String>, Vec<RowData>)> {
+    async fn fetch_storage_classes(
+        &self,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
         let classes: Api<StorageClass> = Api::all(self.client.clone());
-        let list = classes.list(&list_params()).await?;
+        let list = classes.list(params).await?;
         let rows = list
             .into_iter()
             .map(|class| {
@@ -1704,7 +2413,11 @@ impl KubeGateway {
                         if default { "Yes" } else { "No" }.to_string(),
                         age,
                     ],
+                    labels: class.metadata.labels.clone().unwrap_or_default(),
+                    annotations: class.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&class),
+                    created: created_at(class.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1723,9 +2436,66 @@ impl KubeGateway {
         ))
     }
 
-    async fn fetch_persistent_volumes(&self) -> Result<(Vec<String>, Vec<RowData>)> {
+    async fn fetch_volume_snapshots(
+        &self,
+        scope: &NamespaceScope,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
+        let api_resource = volume_snapshot_api_resource();
+        let snapshots: Api<DynamicObject> = match scope {
+            NamespaceScope::All => Api::all_with(self.client.clone(), &api_resource),
+            NamespaceScope::Named(namespace) => {
+                Api::namespaced_with(self.client.clone(), namespace, &api_resource)
+            }
+        };
+        let list = snapshots.list(params).await?;
+        let rows = list
+            .into_iter()
+            .map(|snapshot| {
+                let name = snapshot.name_any();
+                let namespace = snapshot.namespace();
+                let (ready, source, size) = volume_snapshot_columns(&snapshot.data);
+                let age = human_age(snapshot.metadata.creation_timestamp.as_ref());
+
+                RowData {
+                    name: name.clone(),
+                    namespace: namespace.clone(),
+                    columns: vec![
+                        name,
+                        namespace.unwrap_or_else(|| "-".to_string()),
+                        ready,
+                        truncate(&source, 32),
+                        size,
+                        age,
+                    ],
+                    labels: snapshot.metadata.labels.clone().unwrap_or_default(),
+                    annotations: snapshot.metadata.annotations.clone().unwrap_or_default(),
+                    detail: yaml_detail(&snapshot),
+                    created: created_at(snapshot.metadata.creation_timestamp.as_ref()),
+                    phase: None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Ok((
+            vec![
+                "Name".to_string(),
+                "Namespace".to_string(),
+                "ReadyToUse".to_string(),
+                "SourcePVC".to_string(),
+                "RestoreSize".to_string(),
+                "Age".to_string(),
+            ],
+            rows,
+        ))
+    }
+
+    async fn fetch_persistent_volumes(
+        &self,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
         let pvs: Api<PersistentVolume> = Api::all(self.client.clone());
-        let list = pvs.list(&list_params()).await?;
+        let list = pvs.list(params).await?;
         let rows = list
             .into_iter()
             .map(|pv| {
@@ -1784,7 +2554,11 @@ impl KubeGateway {
                         truncate(&class, 18),
                         age,
                     ],
+                    labels: pv.metadata.labels.clone().unwrap_or_default(),
+                    annotations: pv.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&pv),
+                    created: created_at(pv.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1807,13 +2581,14 @@ impl KubeGateway {
     async fn fetch_service_accounts(
         &self,
         scope: &NamespaceScope,
+        params: &ListParams,
     ) -> Result<(Vec<String>, Vec<RowData>)> {
         let accounts: Api<ServiceAccount> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = accounts.list(&list_params()).await?;
+        let list = accounts.list(params).await?;
         let rows = list
             .into_iter()
             .map(|account| {
@@ -1835,7 +2610,11 @@ impl KubeGateway {
                         secrets.to_string(),
                         age,
                     ],
+                    labels: account.metadata.labels.clone().unwrap_or_default(),
+                    annotations: account.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&account),
+                    created: created_at(account.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1851,13 +2630,17 @@ impl KubeGateway {
         ))
     }
 
-    async fn fetch_roles(&self, scope: &NamespaceScope) -> Result<(Vec<String>, Vec<RowData>)> {
+    async fn fetch_roles(
+        &self,
+        scope: &NamespaceScope,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
         let roles: Api<Role> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = roles.list(&list_params()).await?;
+        let list = roles.list(params).await?;
         let rows = list
             .into_iter()
             .map(|role| {
@@ -1875,7 +2658,11 @@ impl KubeGateway {
                         rules.to_string(),
                         age,
                     ],
+                    labels: role.metadata.labels.clone().unwrap_or_default(),
+                    annotations: role.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&role),
+                    created: created_at(role.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1894,13 +2681,14 @@ impl KubeGateway {
     async fn fetch_role_bindings(
         &self,
         scope: &NamespaceScope,
+        params: &ListParams,
     ) -> Result<(Vec<String>, Vec<RowData>)> {
         let role_bindings: Api<RoleBinding> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = role_bindings.list(&list_params()).await?;
+        let list = role_bindings.list(params).await?;
         let rows = list
             .into_iter()
             .map(|binding| {
@@ -1924,7 +2712,11 @@ impl KubeGateway {
                         subjects.to_string(),
                         age,
                     ],
+                    labels: binding.metadata.labels.clone().unwrap_or_default(),
+                    annotations: binding.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&binding),
+                    created: created_at(binding.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1941,9 +2733,12 @@ impl KubeGateway {
         ))
     }
 
-    async fn fetch_cluster_roles(&self) -> Result<(Vec<String>, Vec<RowData>)> {
+    async fn fetch_cluster_roles(
+        &self,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
         let roles: Api<ClusterRole> = Api::all(self.client.clone());
-        let list = roles.list(&list_params()).await?;
+        let list = roles.list(params).await?;
         let rows = list
             .into_iter()
             .map(|role| {
@@ -1961,7 +2756,11 @@ impl KubeGateway {
                     name: name.clone(),
                     namespace: None,
                     columns: vec![name, rules.to_string(), labels.to_string(), age],
+                    labels: role.metadata.labels.clone().unwrap_or_default(),
+                    annotations: role.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&role),
+                    created: created_at(role.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -1977,9 +2776,60 @@ impl KubeGateway {
         ))
     }
 
-    async fn fetch_cluster_role_bindings(&self) -> Result<(Vec<String>, Vec<RowData>)> {
+    async fn fetch_priority_classes(
+        &self,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
+        let classes: Api<PriorityClass> = Api::all(self.client.clone());
+        let list = classes.list(params).await?;
+        let rows = list
+            .into_iter()
+            .map(|class| {
+                let name = class.name_any();
+                let global_default = class.global_default.unwrap_or(false);
+                let preemption = class
+                    .preemption_policy
+                    .clone()
+                    .unwrap_or_else(|| "PreemptLowerPriority".to_string());
+                let age = human_age(class.metadata.creation_timestamp.as_ref());
+
+                RowData {
+                    name: name.clone(),
+                    namespace: None,
+                    columns: vec![
+                        name,
+                        class.value.to_string(),
+                        global_default.to_string(),
+                        preemption,
+                        age,
+                    ],
+                    labels: class.metadata.labels.clone().unwrap_or_default(),
+                    annotations: class.metadata.annotations.clone().unwrap_or_default(),
+                    detail: yaml_detail(&class),
+                    created: created_at(class.metadata.creation_timestamp.as_ref()),
+                    phase: None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Ok((
+            vec![
+                "Name".to_string(),
+                "Value".to_string(),
+                "GlobalDefault".to_string(),
+                "PreemptionPolicy".to_string(),
+                "Age".to_string(),
+            ],
+            rows,
+        ))
+    }
+
+    async fn fetch_cluster_role_bindings(
+        &self,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
         let bindings: Api<ClusterRoleBinding> = Api::all(self.client.clone());
-        let list = bindings.list(&list_params()).await?;
+        let list = bindings.list(params).await?;
         let rows = list
             .into_iter()
             .map(|binding| {
@@ -1996,7 +2846,11 @@ impl KubeGateway {
                     name: name.clone(),
                     namespace: None,
                     columns: vec![name, truncate(&role, 26), subjects.to_string(), age],
+                    labels: binding.metadata.labels.clone().unwrap_or_default(),
+                    annotations: binding.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&binding),
+                    created: created_at(binding.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -2012,16 +2866,159 @@ impl KubeGateway {
         ))
     }
 
+    async fn fetch_leases(
+        &self,
+        scope: &NamespaceScope,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
+        let leases: Api<Lease> = match scope {
+            NamespaceScope::All => Api::all(self.client.clone()),
+            NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
+        };
+
+        let list = leases.list(params).await?;
+        let rows = list
+            .into_iter()
+            .map(|lease| {
+                let name = lease.name_any();
+                let namespace = lease.namespace();
+                let spec = lease.spec.as_ref();
+                let holder = spec
+                    .and_then(|spec| spec.holder_identity.clone())
+                    .filter(|holder| !holder.is_empty())
+                    .unwrap_or_else(|| "-".to_string());
+                let duration = spec
+                    .and_then(|spec| spec.lease_duration_seconds)
+                    .map_or_else(|| "-".to_string(), |seconds| seconds.to_string());
+                let renewed = spec
+                    .and_then(|spec| spec.renew_time.as_ref())
+                    .map_or_else(|| "-".to_string(), |time| human_age_timestamp(time.0));
+                let age = human_age(lease.metadata.creation_timestamp.as_ref());
+
+                RowData {
+                    name: name.clone(),
+                    namespace: namespace.clone(),
+                    columns: vec![
+                        name,
+                        namespace.unwrap_or_else(|| "-".to_string()),
+                        truncate(&holder, 40),
+                        duration,
+                        renewed,
+                        age,
+                    ],
+                    labels: lease.metadata.labels.clone().unwrap_or_default(),
+                    annotations: lease.metadata.annotations.clone().unwrap_or_default(),
+                    detail: yaml_detail(&lease),
+                    created: created_at(lease.metadata.creation_timestamp.as_ref()),
+                    phase: None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Ok((
+            vec![
+                "Name".to_string(),
+                "Namespace".to_string(),
+                "Holder".to_string(),
+                "LeaseDurationSeconds".to_string(),
+                "RenewTime".to_string(),
+                "Age".to_string(),
+            ],
+            rows,
+        ))
+    }
+
+    async fn fetch_mutating_webhooks(
+        &self,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
+        let configs: Api<MutatingWebhookConfiguration> = Api::all(self.client.clone());
+        let list = configs.list(params).await?;
+        let rows = list
+            .into_iter()
+            .map(|config| {
+                let name = config.name_any();
+                let webhooks = config.webhooks.as_deref().unwrap_or_default();
+                let (policies, targets) = webhook_columns(
+                    webhooks
+                        .iter()
+                        .map(|hook| (hook.failure_policy.as_deref(), &hook.client_config)),
+                );
+                let age = human_age(config.metadata.creation_timestamp.as_ref());
+
+                RowData {
+                    name: name.clone(),
+                    namespace: None,
+                    columns: vec![
+                        name,
+                        webhooks.len().to_string(),
+                        policies,
+                        truncate(&targets, 48),
+                        age,
+                    ],
+                    labels: config.metadata.labels.clone().unwrap_or_default(),
+                    annotations: config.metadata.annotations.clone().unwrap_or_default(),
+                    detail: yaml_detail(&config),
+                    created: created_at(config.metadata.creation_timestamp.as_ref()),
+                    phase: None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Ok((webhook_headers(), rows))
+    }
+
+    async fn fetch_validating_webhooks(
+        &self,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
+        let configs: Api<ValidatingWebhookConfiguration> = Api::all(self.client.clone());
+        let list = configs.list(params).await?;
+        let rows = list
+            .into_iter()
+            .map(|config| {
+                let name = config.name_any();
+                let webhooks = config.webhooks.as_deref().unwrap_or_default();
+                let (policies, targets) = webhook_columns(
+                    webhooks
+                        .iter()
+                        .map(|hook| (hook.failure_policy.as_deref(), &hook.client_config)),
+                );
+                let age = human_age(config.metadata.creation_timestamp.as_ref());
+
+                RowData {
+                    name: name.clone(),
+                    namespace: None,
+                    columns: vec![
+                        name,
+                        webhooks.len().to_string(),
+                        policies,
+                        truncate(&targets, 48),
+                        age,
+                    ],
+                    labels: config.metadata.labels.clone().unwrap_or_default(),
+                    annotations: config.metadata.annotations.clone().unwrap_or_default(),
+                    detail: yaml_detail(&config),
+                    created: created_at(config.metadata.creation_timestamp.as_ref()),
+                    phase: None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Ok((webhook_headers(), rows))
+    }
+
     async fn fetch_network_policies(
         &self,
         scope: &NamespaceScope,
+        params: &ListParams,
     ) -> Result<(Vec<String>, Vec<RowData>)> {
         let policies: Api<NetworkPolicy> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = policies.list(&list_params()).await?;
+        let list = policies.list(params).await?;
         let rows = list
             .into_iter()
             .map(|policy| {
@@ -2072,7 +3069,11 @@ impl KubeGateway {
                         format!("{ingress_count}/{egress_count}"),
                         age,
                     ],
+                    labels: policy.metadata.labels.clone().unwrap_or_default(),
+                    annotations: policy.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&policy),
+                    created: created_at(policy.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -2090,9 +3091,9 @@ impl KubeGateway {
         ))
     }
 
-    async fn fetch_nodes(&self) -> Result<(Vec<String>, Vec<RowData>)> {
+    async fn fetch_nodes(&self, params: &ListParams) -> Result<(Vec<String>, Vec<RowData>)> {
         let nodes: Api<Node> = Api::all(self.client.clone());
-        let list = nodes.list(&list_params()).await?;
+        let list = nodes.list(params).await?;
         let rows = list
             .into_iter()
             .map(|node| {
@@ -2120,13 +3121,19 @@ impl KubeGateway {
                     .map(|info| info.kubelet_version.clone())
                     .unwrap_or_else(|| "-".to_string());
                 let roles = node_roles(&node);
+                let pressure = node_pressure(&node);
+                let taints = node_taints_summary(&node);
                 let age = human_age(node.metadata.creation_timestamp.as_ref());
 
                 RowData {
                     name: name.clone(),
                     namespace: None,
-                    columns: vec![name, ready, roles, version, age],
+                    columns: vec![name, ready, roles, version, pressure, taints, age],
+                    labels: node.metadata.labels.clone().unwrap_or_default(),
+                    annotations: node.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&node),
+                    created: created_at(node.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -2137,19 +3144,25 @@ impl KubeGateway {
                 "Ready".to_string(),
                 "Roles".to_string(),
                 "Version".to_string(),
+                "Pressure".to_string(),
+                "Taints".to_string(),
                 "Age".to_string(),
             ],
             rows,
         ))
     }
 
-    async fn fetch_events(&self, scope: &NamespaceScope) -> Result<(Vec<String>, Vec<RowData>)> {
+    async fn fetch_events(
+        &self,
+        scope: &NamespaceScope,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
         let events: Api<Event> = match scope {
             NamespaceScope::All => Api::all(self.client.clone()),
             NamespaceScope::Named(namespace) => Api::namespaced(self.client.clone(), namespace),
         };
 
-        let list = events.list(&list_params()).await?;
+        let list = events.list(params).await?;
         let rows = list
             .into_iter()
             .map(|event| {
@@ -2182,7 +3195,11 @@ impl KubeGateway {
                         truncate(&message, 72),
                         age,
                     ],
+                    labels: event.metadata.labels.clone().unwrap_or_default(),
+                    annotations: event.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&event),
+                    created: chrono::DateTime::from_timestamp(event_timestamp_seconds(&event), 0),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -2201,9 +3218,9 @@ impl KubeGateway {
         ))
     }
 
-    async fn fetch_namespaces(&self) -> Result<(Vec<String>, Vec<RowData>)> {
+    async fn fetch_namespaces(&self, params: &ListParams) -> Result<(Vec<String>, Vec<RowData>)> {
         let namespaces: Api<Namespace> = Api::all(self.client.clone());
-        let list = namespaces.list(&list_params()).await?;
+        let list = namespaces.list(params).await?;
         let rows = list
             .into_iter()
             .map(|namespace| {
@@ -2225,7 +3242,11 @@ impl KubeGateway {
                     name: name.clone(),
                     namespace: Some(name.clone()),
                     columns: vec![name, phase, labels.to_string(), age],
+                    labels: namespace.metadata.labels.clone().unwrap_or_default(),
+                    annotations: namespace.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&namespace),
+                    created: created_at(namespace.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -2245,6 +3266,7 @@ impl KubeGateway {
         &self,
         custom: &CustomResourceDef,
         scope: &NamespaceScope,
+        params: &ListParams,
     ) -> Result<(Vec<String>, Vec<RowData>)> {
         let gvk = GroupVersionKind::gvk(&custom.group, &custom.version, &custom.kind);
         let api_resource = ApiResource::from_gvk_with_plural(&gvk, &custom.plural);
@@ -2259,7 +3281,7 @@ impl KubeGateway {
             Api::all_with(self.client.clone(), &api_resource)
         };
 
-        let list = resources.list(&list_params()).await?;
+        let list = resources.list(params).await?;
         let rows = list
             .into_iter()
             .map(|resource| {
@@ -2282,7 +3304,11 @@ impl KubeGateway {
                         labels.to_string(),
                         age,
                     ],
+                    labels: resource.metadata.labels.clone().unwrap_or_default(),
+                    annotations: resource.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&resource),
+                    created: created_at(resource.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -2298,9 +3324,12 @@ impl KubeGateway {
         ))
     }
 
-    async fn fetch_custom_resource_definitions(&self) -> Result<(Vec<String>, Vec<RowData>)> {
+    async fn fetch_custom_resource_definitions(
+        &self,
+        params: &ListParams,
+    ) -> Result<(Vec<String>, Vec<RowData>)> {
         let crd_api: Api<CustomResourceDefinition> = Api::all(self.client.clone());
-        let list = crd_api.list(&list_params()).await?;
+        let list = crd_api.list(params).await?;
         let rows = list
             .into_iter()
             .map(|crd| {
@@ -2321,7 +3350,11 @@ impl KubeGateway {
                     name: name.clone(),
                     namespace: None,
                     columns: vec![name, kind, group, scope, versions, age],
+                    labels: crd.metadata.labels.clone().unwrap_or_default(),
+                    annotations: crd.metadata.annotations.clone().unwrap_or_default(),
                     detail: yaml_detail(&crd),
+                    created: created_at(crd.metadata.creation_timestamp.as_ref()),
+                    phase: None,
                 }
             })
             .collect::<Vec<_>>();
@@ -2457,7 +3490,7 @@ impl KubeGateway {
             }
 
             if let Some(status) = pod.status.as_ref() {
-                let (ready, total, _) = pod_readiness(status);
+                let (ready, total, _) = pod_readiness(pod);
                 if total > 0 && ready < total {
                     pod_not_ready = pod_not_ready.saturating_add(1);
                 }
@@ -2800,8 +3833,7 @@ impl KubeGateway {
                 .as_ref()
                 .and_then(|status| status.phase.clone())
                 .unwrap_or_else(|| "Unknown".to_string());
-            let (ready, total, restarts) =
-                pod.status.as_ref().map(pod_readiness).unwrap_or((0, 0, 0));
+            let (ready, total, restarts) = pod_readiness(pod);
 
             if phase == "Pending" {
                 pending_pods.push(format!("- {namespace}/{pod_name} ready:{ready}/{total}"));
@@ -3005,7 +4037,7 @@ impl KubeGateway {
                 failed_pods = failed_pods.saturating_add(1);
             }
 
-            let (_, _, restarts) = pod.status.as_ref().map(pod_readiness).unwrap_or((0, 0, 0));
+            let (_, _, restarts) = pod_readiness(pod);
             if restarts >= 5 {
                 restart_heavy_pods = restart_heavy_pods.saturating_add(1);
             }
@@ -3130,7 +4162,7 @@ impl KubeGateway {
             .and_then(|status| status.host_ip.clone())
             .unwrap_or_else(|| "-".to_string());
         let age = human_age(pod.metadata.creation_timestamp.as_ref());
-        let (ready, total, restarts) = pod.status.as_ref().map(pod_readiness).unwrap_or((0, 0, 0));
+        let (ready, total, restarts) = pod_readiness(&pod);
         let owner_line = pod
             .metadata
             .owner_references
@@ -3166,7 +4198,7 @@ impl KubeGateway {
             containers
                 .iter()
                 .map(|container| {
-                    format!(
+                    let mut line = format!(
                         "- {} image:{} ready:{} state:{} rst:{} age:{}",
                         container.name,
                         truncate(&container.image, 46),
@@ -3174,7 +4206,11 @@ impl KubeGateway {
                         container.state,
                         container.restarts,
                         container.age
-                    )
+                    );
+                    if !container.last_exit.is_empty() && container.last_exit != "-" {
+                        line.push_str(&format!(" last:{}", container.last_exit));
+                    }
+                    line
                 })
                 .collect::<Vec<_>>()
         };
@@ -3206,7 +4242,7 @@ impl KubeGateway {
                     && event.involved_object.name.as_deref() == Some(pod_name)
             })
             .collect::<Vec<_>>();
-        related_events.sort_by(|left, right| event_age(left).cmp(&event_age(right)));
+        related_events.sort_by_key(event_age);
         related_events.reverse();
         let event_lines = if related_events.is_empty() {
             vec!["-".to_string()]
@@ -3273,7 +4309,7 @@ impl KubeGateway {
             .map(|pod| (pod_relation_score(pod, workload_name, owner_kind), pod))
             .filter(|(score, _)| *score > 0)
             .collect::<Vec<_>>();
-        related_pods.sort_by(|left, right| right.0.cmp(&left.0));
+        related_pods.sort_by_key(|entry| std::cmp::Reverse(entry.0));
 
         let pod_lines = if related_pods.is_empty() {
             vec!["-".to_string()]
@@ -3287,8 +4323,7 @@ impl KubeGateway {
                         .as_ref()
                         .and_then(|status| status.phase.clone())
                         .unwrap_or_else(|| "Unknown".to_string());
-                    let (ready, total, restarts) =
-                        pod.status.as_ref().map(pod_readiness).unwrap_or((0, 0, 0));
+                    let (ready, total, restarts) = pod_readiness(pod);
                     let node = pod
                         .spec
                         .as_ref()
@@ -3342,7 +4377,7 @@ impl KubeGateway {
                         .is_none_or(|kind| event.involved_object.kind.as_deref() == Some(kind))
             })
             .collect::<Vec<_>>();
-        related_events.sort_by(|left, right| event_age(left).cmp(&event_age(right)));
+        related_events.sort_by_key(event_age);
         related_events.reverse();
         let event_lines = if related_events.is_empty() {
             vec!["-".to_string()]
@@ -3441,8 +4476,7 @@ impl KubeGateway {
                         .as_ref()
                         .and_then(|status| status.phase.clone())
                         .unwrap_or_else(|| "Unknown".to_string());
-                    let (ready, total, restarts) =
-                        pod.status.as_ref().map(pod_readiness).unwrap_or((0, 0, 0));
+                    let (ready, total, restarts) = pod_readiness(pod);
                     let node = pod
                         .spec
                         .as_ref()
@@ -3537,7 +4571,7 @@ impl KubeGateway {
         };
 
         let mut namespace_lines = namespace_counts.into_iter().collect::<Vec<_>>();
-        namespace_lines.sort_by(|left, right| right.1.cmp(&left.1));
+        namespace_lines.sort_by_key(|entry| std::cmp::Reverse(entry.1));
         let namespace_lines = if namespace_lines.is_empty() {
             vec!["-".to_string()]
         } else {
@@ -3551,10 +4585,21 @@ impl KubeGateway {
         let mut lines = vec![
             format!("󰒋 Node {node_name}"),
             format!("ready:{ready} roles:{roles} version:{version} age:{age}"),
+            format!(
+                "pressure:{} taints:{}",
+                node_pressure(&node),
+                node_taints_summary(&node)
+            ),
             format!("pods on node:{}", related_pods.len()),
-            String::new(),
-            "pod namespaces".to_string(),
         ];
+        let taints = node_taint_lines(&node);
+        if !taints.is_empty() {
+            lines.push(String::new());
+            lines.push("taints".to_string());
+            lines.extend(taints);
+        }
+        lines.push(String::new());
+        lines.push("pod namespaces".to_string());
         lines.extend(namespace_lines);
         lines.push(String::new());
         lines.push("pods".to_string());
@@ -3649,57 +4694,558 @@ impl KubeGateway {
         Ok(lines.join("\n"))
     }
 
-    async fn workload_scale_line(
+    pub async fn image_pull_report(
         &self,
         tab: ResourceTab,
         namespace: &str,
         name: &str,
     ) -> Result<String> {
-        match tab {
+        let (spec, pod_status) = match tab {
+            ResourceTab::Pods => {
+                let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+                let pod = api.get(name).await?;
+                (pod.spec, pod.status)
+            }
             ResourceTab::Deployments => {
                 let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
-                let deployment = api.get(name).await?;
-                let desired = deployment
+                let spec = api
+                    .get(name)
+                    .await?
                     .spec
-                    .as_ref()
-                    .and_then(|spec| spec.replicas)
-                    .unwrap_or(1);
-                let ready = deployment
-                    .status
-                    .as_ref()
-                    .and_then(|status| status.ready_replicas)
-                    .unwrap_or(0);
-                let available = deployment
-                    .status
-                    .as_ref()
-                    .and_then(|status| status.available_replicas)
-                    .unwrap_or(0);
-                Ok(format!("ready:{ready}/{desired} available:{available}"))
-            }
-            ResourceTab::DaemonSets => {
-                let api: Api<DaemonSet> = Api::namespaced(self.client.clone(), namespace);
-                let daemonset = api.get(name).await?;
-                let desired = daemonset
-                    .status
-                    .as_ref()
-                    .map(|status| status.desired_number_scheduled)
-                    .unwrap_or(0);
-                let ready = daemonset
-                    .status
-                    .as_ref()
-                    .map(|status| status.number_ready)
-                    .unwrap_or(0);
-                let available = daemonset
-                    .status
-                    .as_ref()
-                    .and_then(|status| status.number_available)
-                    .unwrap_or(0);
-                Ok(format!("ready:{ready}/{desired} available:{available}"))
+                    .and_then(|spec| spec.template.spec);
+                (spec, None)
             }
             ResourceTab::StatefulSets => {
                 let api: Api<StatefulSet> = Api::namespaced(self.client.clone(), namespace);
-                let statefulset = api.get(name).await?;
-                let desired = statefulset
+                let spec = api
+                    .get(name)
+                    .await?
+                    .spec
+                    .and_then(|spec| spec.template.spec);
+                (spec, None)
+            }
+            ResourceTab::DaemonSets => {
+                let api: Api<DaemonSet> = Api::namespaced(self.client.clone(), namespace);
+                let spec = api
+                    .get(name)
+                    .await?
+                    .spec
+                    .and_then(|spec| spec.template.spec);
+                (spec, None)
+            }
+            ResourceTab::ReplicaSets => {
+                let api: Api<ReplicaSet> = Api::namespaced(self.client.clone(), namespace);
+                let spec = api
+                    .get(name)
+                    .await?
+                    .spec
+                    .and_then(|spec| spec.template)
+                    .and_then(|template| template.spec);
+                (spec, None)
+            }
+            ResourceTab::Jobs => {
+                let api: Api<Job> = Api::namespaced(self.client.clone(), namespace);
+                let spec = api
+                    .get(name)
+                    .await?
+                    .spec
+                    .and_then(|spec| spec.template.spec);
+                (spec, None)
+            }
+            ResourceTab::CronJobs => {
+                let api: Api<CronJob> = Api::namespaced(self.client.clone(), namespace);
+                let spec = api
+                    .get(name)
+                    .await?
+                    .spec
+                    .and_then(|spec| spec.job_template.spec)
+                    .and_then(|spec| spec.template.spec);
+                (spec, None)
+            }
+            _ => anyhow::bail!("image summary is not supported for {}", tab.title()),
+        };
+        let spec = spec.with_context(|| format!("{namespace}/{name} has no pod template"))?;
+
+        let service_account = spec
+            .service_account_name
+            .clone()
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| "default".to_string());
+        let mut pull_secrets = spec
+            .image_pull_secrets
+            .iter()
+            .flatten()
+            .filter(|reference| !reference.name.is_empty())
+            .map(|reference| (reference.name.clone(), "pod spec"))
+            .collect::<Vec<_>>();
+        let service_accounts: Api<ServiceAccount> = Api::namespaced(self.client.clone(), namespace);
+        if let Ok(Some(account)) = service_accounts.get_opt(&service_account).await {
+            for reference in account.image_pull_secrets.iter().flatten() {
+                if !reference.name.is_empty()
+                    && !pull_secrets.iter().any(|(name, _)| *name == reference.name)
+                {
+                    pull_secrets.push((reference.name.clone(), "service account"));
+                }
+            }
+        }
+
+        let secrets: Api<Secret> = Api::namespaced(self.client.clone(), namespace);
+        let mut secret_lines = Vec::new();
+        let mut covered_registries = BTreeSet::new();
+        let mut findings = Vec::new();
+        for (secret_name, source) in &pull_secrets {
+            let state = match secrets.get_opt(secret_name).await {
+                Ok(Some(secret)) => {
+                    let secret_type = secret.type_.clone().unwrap_or_else(|| "Opaque".to_string());
+                    let registries = docker_config_registries(&secret);
+                    if registries.is_empty() {
+                        findings.push(format!(
+                            "pull secret '{secret_name}' ({secret_type}) has no registry credentials"
+                        ));
+                        format!("{secret_type}, no registries")
+                    } else {
+                        let listed = registries.iter().cloned().collect::<Vec<_>>().join(", ");
+                        covered_registries.extend(registries);
+                        format!("{secret_type}: {listed}")
+                    }
+                }
+                Ok(None) => {
+                    findings.push(format!(
+                        "pull secret '{secret_name}' referenced by {source} does not exist"
+                    ));
+                    "MISSING".to_string()
+                }
+                Err(error) => format!("unreadable ({error})"),
+            };
+            secret_lines.push(format!(
+                "{:<32} {:<16} {}",
+                truncate(secret_name, 32),
+                source,
+                state
+            ));
+        }
+
+        let containers = spec
+            .init_containers
+            .iter()
+            .flatten()
+            .map(|container| (container, "init"))
+            .chain(spec.containers.iter().map(|container| (container, "main")))
+            .collect::<Vec<_>>();
+        let mut image_lines = Vec::new();
+        let mut uncovered = BTreeSet::new();
+        for (container, kind) in containers {
+            let image = container.image.clone().unwrap_or_default();
+            let reference = parse_image_reference(&image);
+            let covered = covered_registries.contains(&reference.registry);
+            if !covered {
+                uncovered.insert(reference.registry.clone());
+            }
+            image_lines.push(format!(
+                "{:<24} {:<5} {:<28} {:<40} {:<20} {}",
+                truncate(&container.name, 24),
+                kind,
+                truncate(&reference.registry, 28),
+                truncate(&reference.repository, 40),
+                truncate(&reference.reference, 20),
+                if covered { "secret" } else { "-" }
+            ));
+        }
+        for registry in &uncovered {
+            findings.push(format!(
+                "no pull secret covers {registry} (fine for public images)"
+            ));
+        }
+
+        for status in pod_status.iter().flat_map(|status| {
+            status
+                .init_container_statuses
+                .iter()
+                .flatten()
+                .chain(status.container_statuses.iter().flatten())
+        }) {
+            let Some(waiting) = status
+                .state
+                .as_ref()
+                .and_then(|state| state.waiting.as_ref())
+            else {
+                continue;
+            };
+            let reason = waiting.reason.as_deref().unwrap_or_default();
+            if matches!(
+                reason,
+                "ImagePullBackOff" | "ErrImagePull" | "InvalidImageName" | "ErrImageNeverPull"
+            ) {
+                findings.push(format!(
+                    "container {} is {reason}: {}",
+                    status.name,
+                    waiting.message.as_deref().unwrap_or("-")
+                ));
+            }
+        }
+
+        let mut lines = vec![
+            format!("target: {} {namespace}/{name}", tab.title()),
+            format!("service account: {service_account}"),
+            String::new(),
+            format!(
+                "{:<24} {:<5} {:<28} {:<40} {:<20} {}",
+                "CONTAINER", "KIND", "REGISTRY", "REPOSITORY", "TAG/DIGEST", "CREDS"
+            ),
+        ];
+        lines.extend(image_lines);
+        lines.push(String::new());
+        lines.push(format!(
+            "{:<32} {:<16} {}",
+            "PULL SECRET", "SOURCE", "STATUS"
+        ));
+        if secret_lines.is_empty() {
+            lines.push("-".to_string());
+        } else {
+            lines.extend(secret_lines);
+        }
+        lines.push(String::new());
+        lines.push("findings".to_string());
+        if findings.is_empty() {
+            lines.push("- none".to_string());
+        } else {
+            lines.extend(findings.into_iter().map(|finding| format!("- {finding}")));
+        }
+
+        Ok(lines.join("\n"))
+    }
+
+    pub async fn fetch_node_neighbors(
+        &self,
+        node: &str,
+        pod_usage: &HashMap<String, (u64, u64)>,
+    ) -> Result<String> {
+        let pods: Api<Pod> = Api::all(self.client.clone());
+        let params = list_params().fields(&format!("spec.nodeName={node}"));
+        let list = pods
+            .list(&params)
+            .await
+            .with_context(|| format!("failed to list pods on node {node}"))?;
+
+        let mut entries = list
+            .items
+            .iter()
+            .map(|pod| {
+                let namespace = pod.namespace().unwrap_or_else(|| "-".to_string());
+                let name = pod.name_any();
+                let phase = pod
+                    .status
+                    .as_ref()
+                    .and_then(|status| status.phase.clone())
+                    .unwrap_or_else(|| "Unknown".to_string());
+                let (ready, total, restarts) = pod_readiness(pod);
+                let usage = pod_usage.get(&format!("{namespace}/{name}")).copied();
+                (
+                    format!("{namespace}/{name}"),
+                    phase,
+                    ready,
+                    total,
+                    restarts,
+                    usage,
+                )
+            })
+            .collect::<Vec<_>>();
+        entries.sort_by(|left, right| {
+            let left_cpu = left.5.map(|usage| usage.0).unwrap_or(0);
+            let right_cpu = right.5.map(|usage| usage.0).unwrap_or(0);
+            right_cpu.cmp(&left_cpu).then_with(|| left.0.cmp(&right.0))
+        });
+
+        let (cpu_total, memory_total) = entries
+            .iter()
+            .filter_map(|entry| entry.5)
+            .fold((0u64, 0u64), |acc, usage| {
+                (acc.0.saturating_add(usage.0), acc.1.saturating_add(usage.1))
+            });
+
+        let mut lines = vec![
+            format!("node: {node}"),
+            format!(
+                "pods: {}  usage: cpu {} mem {}",
+                entries.len(),
+                format_cpu_millicores(cpu_total),
+                format_bytes(memory_total)
+            ),
+            String::new(),
+            format!(
+                "{:<60} {:<10} {:<7} {:<8} {:>8} {:>10}",
+                "POD", "STATUS", "READY", "RESTARTS", "CPU", "MEM"
+            ),
+        ];
+        for (pod, phase, ready, total, restarts, usage) in entries {
+            let (cpu, memory) = match usage {
+                Some((cpu, memory)) => (format_cpu_millicores(cpu), format_bytes(memory)),
+                None => ("-".to_string(), "-".to_string()),
+            };
+            lines.push(format!(
+                "{:<60} {:<10} {:<7} {:<8} {:>8} {:>10}",
+                truncate(&pod, 60),
+                phase,
+                format!("{ready}/{total}"),
+                restarts,
+                cpu,
+                memory
+            ));
+        }
+
+        Ok(lines.join("\n"))
+    }
+
+    pub async fn scan_deprecated_apis(&self) -> Result<String> {
+        let version = match self.cached_server_version() {
+            Some(version) => version.clone(),
+            None => fetch_server_version(&self.client).await?,
+        };
+        let server = (version.major, version.minor);
+
+        let mut served = HashSet::new();
+        if let Ok(groups) = self.client.list_api_groups().await {
+            for group in groups.groups {
+                for version in group.versions {
+                    served.insert(version.group_version);
+                }
+            }
+        }
+
+        let mut findings = Vec::new();
+        let mut scanned = HashSet::new();
+        for entry in API_DEPRECATIONS {
+            let Some((group, version)) = entry.replacement.and_then(|gv| gv.split_once('/')) else {
+                if served.contains(entry.group_version) {
+                    let (group, version) = entry
+                        .group_version
+                        .split_once('/')
+                        .unwrap_or(("", entry.group_version));
+                    let gvk = GroupVersionKind::gvk(group, version, entry.kind);
+                    let resource = ApiResource::from_gvk_with_plural(&gvk, entry.plural);
+                    let api: Api<DynamicObject> = Api::all_with(self.client.clone(), &resource);
+                    if let Ok(list) = api.list(&list_params()).await {
+                        for object in list {
+                            findings.push(deprecation_finding(entry, server, &object, "served"));
+                        }
+                    }
+                }
+                continue;
+            };
+            if !scanned.insert((group, version, entry.kind)) {
+                continue;
+            }
+
+            let gvk = GroupVersionKind::gvk(group, version, entry.kind);
+            let resource = ApiResource::from_gvk_with_plural(&gvk, entry.plural);
+            let api: Api<DynamicObject> = Api::all_with(self.client.clone(), &resource);
+            let Ok(list) = api.list(&list_params()).await else {
+                continue;
+            };
+            for object in list {
+                for declared in declared_api_versions(&object) {
+                    if let Some(deprecated) = API_DEPRECATIONS.iter().find(|candidate| {
+                        candidate.kind == entry.kind && candidate.group_version == declared.0
+                    }) {
+                        findings.push(deprecation_finding(deprecated, server, &object, declared.1));
+                    }
+                }
+            }
+        }
+        findings.sort();
+        findings.dedup();
+
+        let served_deprecated = API_DEPRECATIONS
+            .iter()
+            .filter(|entry| served.contains(entry.group_version))
+            .map(|entry| {
+                format!(
+                    "  {} {} ({})",
+                    entry.group_version,
+                    entry.kind,
+                    deprecation_status(entry, server)
+                )
+            })
+            .collect::<BTreeSet<_>>();
+
+        let mut lines = vec![
+            format!("Deprecated API scan for server {}", version.label()),
+            format!("Checked {} known deprecations", API_DEPRECATIONS.len()),
+            String::new(),
+            format!("Objects declared with deprecated APIs: {}", findings.len()),
+        ];
+        if findings.is_empty() {
+            lines.push("  none found (managedFields and last-applied-configuration)".to_string());
+        } else {
+            lines.extend(findings);
+        }
+        lines.push(String::new());
+        lines.push(format!(
+            "Deprecated group versions still served: {}",
+            served_deprecated.len()
+        ));
+        if served_deprecated.is_empty() {
+            lines.push("  none".to_string());
+        } else {
+            lines.extend(served_deprecated);
+        }
+        Ok(lines.join("\n"))
+    }
+
+    pub async fn namespace_counts(&self, namespace: &str) -> Result<NamespaceCounts> {
+        let pods: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+        let deployments: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+        let services: Api<Service> = Api::namespaced(self.client.clone(), namespace);
+
+        let pods = pods
+            .list(&list_params())
+            .await
+            .with_context(|| format!("failed to list pods in {namespace}"))?;
+        let deployments = deployments
+            .list(&list_params())
+            .await
+            .with_context(|| format!("failed to list deployments in {namespace}"))?;
+        let services = services
+            .list(&list_params())
+            .await
+            .with_context(|| format!("failed to list services in {namespace}"))?;
+
+        let running_pods = pods
+            .items
+            .iter()
+            .filter(|pod| {
+                pod.status
+                    .as_ref()
+                    .and_then(|status| status.phase.as_deref())
+                    .is_some_and(|phase| phase == "Running")
+            })
+            .count();
+        let ready_deployments = deployments
+            .items
+            .iter()
+            .filter(|deployment| {
+                let desired = deployment
+                    .spec
+                    .as_ref()
+                    .and_then(|spec| spec.replicas)
+                    .unwrap_or(1);
+                let ready = deployment
+                    .status
+                    .as_ref()
+                    .and_then(|status| status.ready_replicas)
+                    .unwrap_or(0);
+                ready >= desired
+            })
+            .count();
+
+        Ok(NamespaceCounts {
+            pods: pods.items.len(),
+            running_pods,
+            deployments: deployments.items.len(),
+            ready_deployments,
+            services: services.items.len(),
+        })
+    }
+
+    pub async fn namespace_summary(
+        &self,
+        namespace: &str,
+        counts: NamespaceCounts,
+        usage: Option<(u64, u64)>,
+    ) -> String {
+        let quotas: Api<ResourceQuota> = Api::namespaced(self.client.clone(), namespace);
+        let mut lines = vec![
+            "# summary".to_string(),
+            format!("pods: {} (running {})", counts.pods, counts.running_pods),
+            format!(
+                "deployments: {} (ready {})",
+                counts.deployments, counts.ready_deployments
+            ),
+            format!("services: {}", counts.services),
+            match usage {
+                Some((cpu, memory)) => format!(
+                    "usage: cpu {} mem {}",
+                    format_cpu_millicores(cpu),
+                    format_bytes(memory)
+                ),
+                None => "usage: n/a (no metrics sampled for this namespace)".to_string(),
+            },
+        ];
+
+        match quotas.list(&list_params()).await {
+            Ok(list) if list.items.is_empty() => lines.push("quota: none".to_string()),
+            Ok(list) => {
+                for quota in list.items {
+                    lines.push(format!("quota {}:", quota.name_any()));
+                    let status = quota.status.unwrap_or_default();
+                    let hard = status.hard.unwrap_or_default();
+                    let used = status.used.unwrap_or_default();
+                    for (resource, limit) in hard {
+                        let consumed = used.get(&resource).map(|value| value.0.as_str());
+                        lines.push(format!(
+                            "  {resource}: {}",
+                            quota_utilization_line(&resource, consumed, &limit.0)
+                        ));
+                    }
+                }
+            }
+            Err(error) => lines.push(format!("quota: unavailable ({error})")),
+        }
+
+        lines.join("\n")
+    }
+
+    async fn workload_scale_line(
+        &self,
+        tab: ResourceTab,
+        namespace: &str,
+        name: &str,
+    ) -> Result<String> {
+        match tab {
+            ResourceTab::Deployments => {
+                let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+                let deployment = api.get(name).await?;
+                let desired = deployment
+                    .spec
+                    .as_ref()
+                    .and_then(|spec| spec.replicas)
+                    .unwrap_or(1);
+                let ready = deployment
+                    .status
+                    .as_ref()
+                    .and_then(|status| status.ready_replicas)
+                    .unwrap_or(0);
+                let available = deployment
+                    .status
+                    .as_ref()
+                    .and_then(|status| status.available_replicas)
+                    .unwrap_or(0);
+                Ok(format!("ready:{ready}/{desired} available:{available}"))
+            }
+            ResourceTab::DaemonSets => {
+                let api: Api<DaemonSet> = Api::namespaced(self.client.clone(), namespace);
+                let daemonset = api.get(name).await?;
+                let desired = daemonset
+                    .status
+                    .as_ref()
+                    .map(|status| status.desired_number_scheduled)
+                    .unwrap_or(0);
+                let ready = daemonset
+                    .status
+                    .as_ref()
+                    .map(|status| status.number_ready)
+                    .unwrap_or(0);
+                let available = daemonset
+                    .status
+                    .as_ref()
+                    .and_then(|status| status.number_available)
+                    .unwrap_or(0);
+                Ok(format!("ready:{ready}/{desired} available:{available}"))
+            }
+            ResourceTab::StatefulSets => {
+                let api: Api<StatefulSet> = Api::namespaced(self.client.clone(), namespace);
+                let statefulset = api.get(name).await?;
+                let desired = statefulset
                     .spec
                     .as_ref()
                     .and_then(|spec| spec.replicas)
@@ -3815,23 +5361,206 @@ impl KubeGateway {
     }
 }
 
-fn build_kube_targets(kubeconfig: &Kubeconfig) -> Vec<KubeTarget> {
-    let mut cluster_servers = HashMap::new();
-    for cluster in &kubeconfig.clusters {
-        let server = cluster
-            .cluster
-            .as_ref()
-            .and_then(|entry| entry.server.clone());
-        cluster_servers.insert(cluster.name.clone(), server);
+fn delete_params(propagation: DeletePropagation) -> DeleteParams {
+    let policy = match propagation {
+        DeletePropagation::Background => PropagationPolicy::Background,
+        DeletePropagation::Foreground => PropagationPolicy::Foreground,
+        DeletePropagation::Orphan => PropagationPolicy::Orphan,
+    };
+    DeleteParams {
+        propagation_policy: Some(policy),
+        ..DeleteParams::default()
     }
+}
 
-    let mut targets = kubeconfig
-        .contexts
-        .iter()
-        .filter_map(|named| {
-            let context = named.context.as_ref()?;
-            Some(KubeTarget {
-                context: named.name.clone(),
+fn unserved_tabs_from(served: &HashMap<String, HashSet<String>>) -> HashSet<ResourceTab> {
+    ResourceTab::ALL
+        .into_iter()
+        .filter(|tab| {
+            tab.api_resource().is_some_and(|(group_version, plural)| {
+                !served
+                    .get(group_version)
+                    .is_some_and(|resources| resources.contains(plural))
+            })
+        })
+        .collect()
+}
+
+fn webhook_headers() -> Vec<String> {
+    vec![
+        "Name".to_string(),
+        "Webhooks".to_string(),
+        "FailurePolicy".to_string(),
+        "Targets".to_string(),
+        "Age".to_string(),
+    ]
+}
+
+fn webhook_columns<'a>(
+    webhooks: impl Iterator<Item = (Option<&'a str>, &'a WebhookClientConfig)>,
+) -> (String, String) {
+    let mut policies = BTreeMap::<&str, usize>::new();
+    let mut targets = Vec::<String>::new();
+    for (policy, client) in webhooks {
+        *policies.entry(policy.unwrap_or("Fail")).or_default() += 1;
+        let target = match (client.service.as_ref(), client.url.as_deref()) {
+            (Some(service), _) => format!(
+                "{}/{}:{}",
+                service.namespace,
+                service.name,
+                service.port.unwrap_or(443)
+            ),
+            (None, Some(url)) => url.to_string(),
+            (None, None) => "-".to_string(),
+        };
+        if !targets.contains(&target) {
+            targets.push(target);
+        }
+    }
+    if policies.is_empty() {
+        return ("-".to_string(), "-".to_string());
+    }
+    let policies = policies
+        .into_iter()
+        .map(|(policy, count)| format!("{policy}:{count}"))
+        .collect::<Vec<_>>()
+        .join(",");
+    (policies, targets.join(","))
+}
+
+fn volume_snapshot_api_resource() -> ApiResource {
+    let gvk = GroupVersionKind::gvk(VOLUME_SNAPSHOT_GROUP, "v1", "VolumeSnapshot");
+    ApiResource::from_gvk_with_plural(&gvk, "volumesnapshots")
+}
+
+fn volume_snapshot_columns(data: &Value) -> (String, String, String) {
+    let ready = data
+        .pointer("/status/readyToUse")
+        .and_then(Value::as_bool)
+        .map(|ready| ready.to_string())
+        .unwrap_or_else(|| "-".to_string());
+    let source = data
+        .pointer("/spec/source/persistentVolumeClaimName")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .or_else(|| {
+            data.pointer("/spec/source/volumeSnapshotContentName")
+                .and_then(Value::as_str)
+                .map(|content| format!("content/{content}"))
+        })
+        .unwrap_or_else(|| "-".to_string());
+    let size = data
+        .pointer("/status/restoreSize")
+        .and_then(Value::as_str)
+        .unwrap_or("-")
+        .to_string();
+    (ready, source, size)
+}
+
+fn force_delete_params() -> DeleteParams {
+    DeleteParams {
+        grace_period_seconds: Some(0),
+        ..delete_params(DeletePropagation::Foreground)
+    }
+}
+
+fn ensure_apply_target(
+    object: &DynamicObject,
+    kind: &str,
+    namespace: Option<&str>,
+    name: &str,
+) -> Result<()> {
+    let edited_kind = object
+        .types
+        .as_ref()
+        .map_or("", |types| types.kind.as_str());
+    if edited_kind != kind {
+        anyhow::bail!("edited manifest changed kind from {kind} to {edited_kind}");
+    }
+    if object.metadata.name.as_deref() != Some(name) {
+        anyhow::bail!(
+            "edited manifest changed name from '{name}' to '{}'",
+            object.metadata.name.as_deref().unwrap_or("")
+        );
+    }
+    if let Some(edited) = object.metadata.namespace.as_deref()
+        && Some(edited) != namespace
+    {
+        anyhow::bail!(
+            "edited manifest changed namespace from '{}' to '{edited}'",
+            namespace.unwrap_or("")
+        );
+    }
+    Ok(())
+}
+
+fn validate_kube_selection(
+    kubeconfig: &Kubeconfig,
+    context: Option<&str>,
+    cluster: Option<&str>,
+) -> Result<()> {
+    if let Some(context) = context
+        && !kubeconfig
+            .contexts
+            .iter()
+            .any(|named| named.name == context)
+    {
+        let mut available = kubeconfig
+            .contexts
+            .iter()
+            .map(|named| named.name.as_str())
+            .collect::<Vec<_>>();
+        available.sort();
+        anyhow::bail!(
+            "Context '{context}' was not found in kubeconfig (available: {})",
+            if available.is_empty() {
+                "none".to_string()
+            } else {
+                available.join(", ")
+            }
+        );
+    }
+    if let Some(cluster) = cluster
+        && !kubeconfig
+            .clusters
+            .iter()
+            .any(|named| named.name == cluster)
+    {
+        let mut available = kubeconfig
+            .clusters
+            .iter()
+            .map(|named| named.name.as_str())
+            .collect::<Vec<_>>();
+        available.sort();
+        anyhow::bail!(
+            "Cluster '{cluster}' was not found in kubeconfig (available: {})",
+            if available.is_empty() {
+                "none".to_string()
+            } else {
+                available.join(", ")
+            }
+        );
+    }
+    Ok(())
+}
+
+fn build_kube_targets(kubeconfig: &Kubeconfig) -> Vec<KubeTarget> {
+    let mut cluster_servers = HashMap::new();
+    for cluster in &kubeconfig.clusters {
+        let server = cluster
+            .cluster
+            .as_ref()
+            .and_then(|entry| entry.server.clone());
+        cluster_servers.insert(cluster.name.clone(), server);
+    }
+
+    let mut targets = kubeconfig
+        .contexts
+        .iter()
+        .filter_map(|named| {
+            let context = named.context.as_ref()?;
+            Some(KubeTarget {
+                context: named.name.clone(),
                 cluster_name: context.cluster.clone(),
                 cluster_server: cluster_servers
                     .get(&context.cluster)
@@ -3864,13 +5593,54 @@ fn owner_kind_for_tab(tab: ResourceTab) -> Option<&'static str> {
     }
 }
 
+const JOB_GENERATED_LABELS: [&str; 4] = [
+    "controller-uid",
+    "batch.kubernetes.io/controller-uid",
+    "job-name",
+    "batch.kubernetes.io/job-name",
+];
+
+fn rerun_job_manifest(job: &Job) -> Job {
+    let strip = |labels: &Option<BTreeMap<String, String>>| {
+        labels.as_ref().map(|labels| {
+            labels
+                .iter()
+                .filter(|(key, _)| !JOB_GENERATED_LABELS.contains(&key.as_str()))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect::<BTreeMap<_, _>>()
+        })
+    };
+    let mut spec = job.spec.clone().unwrap_or_default();
+    spec.selector = None;
+    spec.manual_selector = None;
+    if let Some(metadata) = spec.template.metadata.as_mut() {
+        metadata.labels = strip(&metadata.labels);
+    }
+    Job {
+        metadata: ObjectMeta {
+            name: job.metadata.name.clone(),
+            namespace: job.metadata.namespace.clone(),
+            labels: strip(&job.metadata.labels),
+            annotations: job.metadata.annotations.clone(),
+            ..ObjectMeta::default()
+        },
+        spec: Some(spec),
+        status: None,
+    }
+}
+
 fn select_best_related_pod<'a>(
     pods: &'a [Pod],
     resource_name: &str,
     expected_owner_kind: Option<&str>,
 ) -> Option<&'a Pod> {
     pods.iter()
-        .max_by_key(|pod| pod_relation_score(pod, resource_name, expected_owner_kind))
+        .max_by_key(|pod| {
+            (
+                pod_relation_score(pod, resource_name, expected_owner_kind),
+                pod.metadata.creation_timestamp.clone(),
+            )
+        })
         .filter(|pod| pod_relation_score(pod, resource_name, expected_owner_kind) > 0)
 }
 
@@ -3954,7 +5724,32 @@ fn pod_container_from_status(
         ready: container.ready,
         state,
         restarts: container.restart_count as u32,
+        last_exit: container_last_exit(container),
         age,
+        ..PodContainerInfo::default()
+    }
+}
+
+fn container_last_exit(container: &k8s_openapi::api::core::v1::ContainerStatus) -> String {
+    let Some(terminated) = container
+        .last_state
+        .as_ref()
+        .and_then(|state| state.terminated.as_ref())
+    else {
+        return "-".to_string();
+    };
+    let reason = terminated
+        .reason
+        .as_deref()
+        .filter(|value| !value.is_empty())
+        .unwrap_or("Exit");
+    match terminated.finished_at.as_ref() {
+        Some(time) => format!(
+            "{reason}({}) {} ago",
+            terminated.exit_code,
+            human_age(Some(time))
+        ),
+        None => format!("{reason}({})", terminated.exit_code),
     }
 }
 
@@ -4032,6 +5827,159 @@ fn parse_pod_metrics_usage(data: &Value) -> (u64, u64) {
         })
 }
 
+fn node_usage_rows(
+    nodes: &[Node],
+    usage: &HashMap<String, (u64, u64)>,
+) -> (Vec<String>, Vec<RowData>) {
+    let percent = |used: Option<u64>, total: u64| match used {
+        Some(used) if total > 0 => format!("{}%", used.saturating_mul(100) / total),
+        _ => "-".to_string(),
+    };
+    let rows = nodes
+        .iter()
+        .map(|node| {
+            let name = node.name_any();
+            let allocatable = node
+                .status
+                .as_ref()
+                .and_then(|status| status.allocatable.as_ref());
+            let cpu_total = allocatable
+                .and_then(|values| values.get("cpu"))
+                .and_then(|quantity| parse_cpu_millicores(&quantity.0))
+                .unwrap_or(0);
+            let memory_total = allocatable
+                .and_then(|values| values.get("memory"))
+                .and_then(|quantity| parse_memory_bytes(&quantity.0))
+                .unwrap_or(0);
+            let (cpu_used, memory_used) = match usage.get(&name) {
+                Some((cpu, memory)) => (Some(*cpu), Some(*memory)),
+                None => (None, None),
+            };
+            RowData {
+                name: name.clone(),
+                namespace: None,
+                columns: vec![
+                    name,
+                    cpu_used.map_or_else(|| "-".to_string(), format_cpu_millicores),
+                    format_cpu_millicores(cpu_total),
+                    percent(cpu_used, cpu_total),
+                    memory_used.map_or_else(|| "-".to_string(), format_bytes),
+                    format_bytes(memory_total),
+                    percent(memory_used, memory_total),
+                ],
+                labels: node.metadata.labels.clone().unwrap_or_default(),
+                annotations: BTreeMap::new(),
+                detail: String::new(),
+                created: None,
+                phase: None,
+            }
+        })
+        .collect();
+    (
+        vec![
+            "Name".to_string(),
+            "CPU".to_string(),
+            "CPU Alloc".to_string(),
+            "CPU%".to_string(),
+            "Memory".to_string(),
+            "Mem Alloc".to_string(),
+            "Mem%".to_string(),
+        ],
+        rows,
+    )
+}
+
+fn logs_before(logs: &str, cutoff_second: i64) -> String {
+    let mut keep = true;
+    let mut kept = String::new();
+    for line in logs.split_inclusive('\n') {
+        if let Some(timestamp) = line
+            .split_whitespace()
+            .next()
+            .and_then(|token| token.parse::<k8s_openapi::jiff::Timestamp>().ok())
+        {
+            keep = timestamp.as_second() < cutoff_second;
+        }
+        if keep {
+            kept.push_str(line);
+        }
+    }
+    kept
+}
+
+fn selector_error(
+    error: anyhow::Error,
+    label_selector: Option<&str>,
+    field_selector: Option<&str>,
+) -> anyhow::Error {
+    let rejected = error.chain().any(|cause| {
+        matches!(
+            cause.downcast_ref::<kube::Error>(),
+            Some(kube::Error::Api(status)) if status.code == 400
+        )
+    });
+    let selectors = [("label", label_selector), ("field", field_selector)]
+        .into_iter()
+        .filter_map(|(kind, selector)| {
+            selector.map(|selector| format!("{kind} selector '{selector}'"))
+        })
+        .collect::<Vec<_>>();
+    if !rejected || selectors.is_empty() {
+        return error;
+    }
+    error.context(format!("API server rejected {}", selectors.join(" / ")))
+}
+
+pub fn is_auth_failure(error: &anyhow::Error) -> bool {
+    error
+        .chain()
+        .any(|cause| match cause.downcast_ref::<kube::Error>() {
+            Some(kube::Error::Api(status)) => {
+                status.code == 401
+                    || (status.code == 403
+                        && status.message.to_ascii_lowercase().contains("expired"))
+            }
+            Some(kube::Error::Auth(_)) => true,
+            _ => false,
+        })
+}
+
+fn kubeconfig_user_refreshes(kubeconfig: &Kubeconfig, user: &str) -> bool {
+    kubeconfig
+        .auth_infos
+        .iter()
+        .find(|entry| entry.name == user)
+        .and_then(|entry| entry.auth_info.as_ref())
+        .is_some_and(|auth| {
+            auth.exec.is_some() || auth.auth_provider.is_some() || auth.token_file.is_some()
+        })
+}
+
+pub fn is_metrics_api_unavailable(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        matches!(
+            cause.downcast_ref::<kube::Error>(),
+            Some(kube::Error::Api(status)) if matches!(status.code, 404 | 503)
+        )
+    })
+}
+
+fn parse_container_metrics_usage(data: &Value) -> HashMap<String, (u64, u64)> {
+    data.get("containers")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|container| {
+            let name = container.get("name")?.as_str()?.to_string();
+            let usage = container
+                .get("usage")
+                .map(parse_usage_from_value)
+                .unwrap_or((0, 0));
+            Some((name, usage))
+        })
+        .collect()
+}
+
 fn parse_usage_from_value(value: &Value) -> (u64, u64) {
     let cpu = value
         .get("cpu")
@@ -4132,7 +6080,33 @@ fn parse_memory_bytes(value: &str) -> Option<u64> {
 }
 
 fn list_params() -> ListParams {
-    ListParams::default().limit(500)
+    ListParams::default().limit(DEFAULT_PAGE_SIZE)
+}
+
+async fn list_pages<K>(
+    api: &Api<K>,
+    params: &ListParams,
+    page_size: u32,
+    pages: usize,
+) -> Result<(Vec<K>, bool)>
+where
+    K: Clone + DeserializeOwned + std::fmt::Debug,
+{
+    let mut params = params.clone();
+    params.limit = (page_size > 0).then_some(page_size);
+
+    let mut items = Vec::new();
+    for page in 1..=pages.max(1) {
+        let list = api.list(&params).await?;
+        items.extend(list.items);
+        match list.metadata.continue_.filter(|token| !token.is_empty()) {
+            Some(_) if page_size == 0 => break,
+            Some(_) if page == pages.max(1) => return Ok((items, true)),
+            Some(token) => params = params.continue_token(&token),
+            None => break,
+        }
+    }
+    Ok((items, false))
 }
 
 fn resolve_namespace_target(namespace: Option<&str>, fallback: &str) -> Result<String> {
@@ -4194,6 +6168,280 @@ fn service_ports_summary(service: &Service) -> String {
         .join(",")
 }
 
+struct ApiDeprecation {
+    group_version: &'static str,
+    kind: &'static str,
+    plural: &'static str,
+    deprecated_in: (u32, u32),
+    removed_in: (u32, u32),
+    replacement: Option<&'static str>,
+}
+
+const API_DEPRECATIONS: &[ApiDeprecation] = &[
+    ApiDeprecation {
+        group_version: "extensions/v1beta1",
+        kind: "Deployment",
+        plural: "deployments",
+        deprecated_in: (1, 9),
+        removed_in: (1, 16),
+        replacement: Some("apps/v1"),
+    },
+    ApiDeprecation {
+        group_version: "apps/v1beta1",
+        kind: "Deployment",
+        plural: "deployments",
+        deprecated_in: (1, 9),
+        removed_in: (1, 16),
+        replacement: Some("apps/v1"),
+    },
+    ApiDeprecation {
+        group_version: "apps/v1beta2",
+        kind: "Deployment",
+        plural: "deployments",
+        deprecated_in: (1, 9),
+        removed_in: (1, 16),
+        replacement: Some("apps/v1"),
+    },
+    ApiDeprecation {
+        group_version: "extensions/v1beta1",
+        kind: "DaemonSet",
+        plural: "daemonsets",
+        deprecated_in: (1, 9),
+        removed_in: (1, 16),
+        replacement: Some("apps/v1"),
+    },
+    ApiDeprecation {
+        group_version: "apps/v1beta2",
+        kind: "StatefulSet",
+        plural: "statefulsets",
+        deprecated_in: (1, 9),
+        removed_in: (1, 16),
+        replacement: Some("apps/v1"),
+    },
+    ApiDeprecation {
+        group_version: "extensions/v1beta1",
+        kind: "Ingress",
+        plural: "ingresses",
+        deprecated_in: (1, 14),
+        removed_in: (1, 22),
+        replacement: Some("networking.k8s.io/v1"),
+    },
+    ApiDeprecation {
+        group_version: "networking.k8s.io/v1beta1",
+        kind: "Ingress",
+        plural: "ingresses",
+        deprecated_in: (1, 19),
+        removed_in: (1, 22),
+        replacement: Some("networking.k8s.io/v1"),
+    },
+    ApiDeprecation {
+        group_version: "networking.k8s.io/v1beta1",
+        kind: "IngressClass",
+        plural: "ingressclasses",
+        deprecated_in: (1, 19),
+        removed_in: (1, 22),
+        replacement: Some("networking.k8s.io/v1"),
+    },
+    ApiDeprecation {
+        group_version: "rbac.authorization.k8s.io/v1beta1",
+        kind: "ClusterRole",
+        plural: "clusterroles",
+        deprecated_in: (1, 17),
+        removed_in: (1, 22),
+        replacement: Some("rbac.authorization.k8s.io/v1"),
+    },
+    ApiDeprecation {
+        group_version: "rbac.authorization.k8s.io/v1beta1",
+        kind: "Role",
+        plural: "roles",
+        deprecated_in: (1, 17),
+        removed_in: (1, 22),
+        replacement: Some("rbac.authorization.k8s.io/v1"),
+    },
+    ApiDeprecation {
+        group_version: "scheduling.k8s.io/v1beta1",
+        kind: "PriorityClass",
+        plural: "priorityclasses",
+        deprecated_in: (1, 14),
+        removed_in: (1, 22),
+        replacement: Some("scheduling.k8s.io/v1"),
+    },
+    ApiDeprecation {
+        group_version: "apiextensions.k8s.io/v1beta1",
+        kind: "CustomResourceDefinition",
+        plural: "customresourcedefinitions",
+        deprecated_in: (1, 16),
+        removed_in: (1, 22),
+        replacement: Some("apiextensions.k8s.io/v1"),
+    },
+    ApiDeprecation {
+        group_version: "batch/v1beta1",
+        kind: "CronJob",
+        plural: "cronjobs",
+        deprecated_in: (1, 21),
+        removed_in: (1, 25),
+        replacement: Some("batch/v1"),
+    },
+    ApiDeprecation {
+        group_version: "policy/v1beta1",
+        kind: "PodDisruptionBudget",
+        plural: "poddisruptionbudgets",
+        deprecated_in: (1, 21),
+        removed_in: (1, 25),
+        replacement: Some("policy/v1"),
+    },
+    ApiDeprecation {
+        group_version: "policy/v1beta1",
+        kind: "PodSecurityPolicy",
+        plural: "podsecuritypolicies",
+        deprecated_in: (1, 21),
+        removed_in: (1, 25),
+        replacement: None,
+    },
+    ApiDeprecation {
+        group_version: "discovery.k8s.io/v1beta1",
+        kind: "EndpointSlice",
+        plural: "endpointslices",
+        deprecated_in: (1, 21),
+        removed_in: (1, 25),
+        replacement: Some("discovery.k8s.io/v1"),
+    },
+    ApiDeprecation {
+        group_version: "autoscaling/v2beta1",
+        kind: "HorizontalPodAutoscaler",
+        plural: "horizontalpodautoscalers",
+        deprecated_in: (1, 22),
+        removed_in: (1, 25),
+        replacement: Some("autoscaling/v2"),
+    },
+    ApiDeprecation {
+        group_version: "autoscaling/v2beta2",
+        kind: "HorizontalPodAutoscaler",
+        plural: "horizontalpodautoscalers",
+        deprecated_in: (1, 23),
+        removed_in: (1, 26),
+        replacement: Some("autoscaling/v2"),
+    },
+    ApiDeprecation {
+        group_version: "flowcontrol.apiserver.k8s.io/v1beta2",
+        kind: "FlowSchema",
+        plural: "flowschemas",
+        deprecated_in: (1, 26),
+        removed_in: (1, 29),
+        replacement: Some("flowcontrol.apiserver.k8s.io/v1"),
+    },
+    ApiDeprecation {
+        group_version: "flowcontrol.apiserver.k8s.io/v1beta3",
+        kind: "FlowSchema",
+        plural: "flowschemas",
+        deprecated_in: (1, 29),
+        removed_in: (1, 32),
+        replacement: Some("flowcontrol.apiserver.k8s.io/v1"),
+    },
+    ApiDeprecation {
+        group_version: "storage.k8s.io/v1beta1",
+        kind: "CSIStorageCapacity",
+        plural: "csistoragecapacities",
+        deprecated_in: (1, 24),
+        removed_in: (1, 27),
+        replacement: Some("storage.k8s.io/v1"),
+    },
+];
+
+async fn fetch_server_version(client: &Client) -> Result<ServerVersion> {
+    let info = client.apiserver_version().await?;
+    let (major, minor) = parse_server_minor(&info.major, &info.minor);
+    Ok(ServerVersion {
+        git_version: info.git_version,
+        major,
+        minor,
+    })
+}
+
+fn parse_server_minor(major: &str, minor: &str) -> (u32, u32) {
+    let digits = |raw: &str| {
+        raw.chars()
+            .take_while(char::is_ascii_digit)
+            .collect::<String>()
+            .parse::<u32>()
+            .unwrap_or(0)
+    };
+    (digits(major), digits(minor))
+}
+
+fn deprecation_status(entry: &ApiDeprecation, server: (u32, u32)) -> String {
+    let (major, minor) = entry.removed_in;
+    if server >= entry.removed_in {
+        format!("REMOVED in {major}.{minor}")
+    } else if server >= entry.deprecated_in {
+        format!("deprecated, removal in {major}.{minor}")
+    } else {
+        format!(
+            "deprecated in {}.{}, removal in {major}.{minor}",
+            entry.deprecated_in.0, entry.deprecated_in.1
+        )
+    }
+}
+
+fn declared_api_versions(object: &DynamicObject) -> Vec<(String, &'static str)> {
+    let mut declared = object
+        .metadata
+        .managed_fields
+        .iter()
+        .flatten()
+        .filter_map(|entry| entry.api_version.clone())
+        .map(|api_version| (api_version, "managedFields"))
+        .collect::<Vec<_>>();
+    if let Some(api_version) = object
+        .metadata
+        .annotations
+        .as_ref()
+        .and_then(|annotations| annotations.get("kubectl.kubernetes.io/last-applied-configuration"))
+        .and_then(|raw| serde_json::from_str::<Value>(raw).ok())
+        .and_then(|applied| applied.get("apiVersion")?.as_str().map(str::to_string))
+    {
+        declared.push((api_version, "last-applied"));
+    }
+    declared
+}
+
+fn deprecation_finding(
+    entry: &ApiDeprecation,
+    server: (u32, u32),
+    object: &DynamicObject,
+    source: &str,
+) -> String {
+    let target = match object.namespace() {
+        Some(namespace) => format!("{namespace}/{}", object.name_any()),
+        None => object.name_any(),
+    };
+    format!(
+        "  {} {} {target} [{}] via {source} -> {}",
+        entry.group_version,
+        entry.kind,
+        deprecation_status(entry, server),
+        entry.replacement.unwrap_or("no replacement (removed API)")
+    )
+}
+
+fn quota_utilization_line(resource: &str, used: Option<&str>, hard: &str) -> String {
+    let used_raw = used.unwrap_or("0");
+    let parse = |value: &str| {
+        if resource.contains("cpu") {
+            parse_cpu_millicores(value)
+        } else {
+            parse_memory_bytes(value)
+        }
+    };
+    match (parse(used_raw), parse(hard)) {
+        (Some(used_value), Some(hard_value)) if hard_value > 0 => format!(
+            "{used_raw}/{hard} ({}%)",
+            used_value.saturating_mul(100).saturating_div(hard_value)
+        ),
+        _ => format!("{used_raw}/{hard}"),
+    }
+}
+
 fn format_cpu_millicores(value: u64) -> String {
     if value >= 1_000 {
         let cores = value as f64 / 1_000.0;
@@ -4225,17 +6473,179 @@ fn format_bytes(value: u64) -> String {
     format!("{value}B")
 }
 
-fn pod_readiness(status: &k8s_openapi::api::core::v1::PodStatus) -> (usize, usize, i32) {
-    let container_statuses = status.container_statuses.as_deref().unwrap_or(&[]);
-    let total = container_statuses.len();
-    let ready = container_statuses
+// Mirrors the STATUS column of `kubectl get pods`: init progress, container
+// waiting/terminated reasons and Terminating win over the bare phase.
+fn pod_display_status(pod: &Pod) -> String {
+    let Some(status) = pod.status.as_ref() else {
+        return "Unknown".to_string();
+    };
+    let mut reason = status
+        .reason
+        .clone()
+        .filter(|value| !value.is_empty())
+        .or_else(|| status.phase.clone())
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let init_statuses = status
+        .init_container_statuses
+        .as_deref()
+        .unwrap_or_default();
+    let init_total = pod
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.init_containers.as_ref())
+        .map_or(init_statuses.len(), Vec::len);
+    let sidecars = pod_sidecar_names(pod);
+    let mut initializing = false;
+    for (index, container) in init_statuses.iter().enumerate() {
+        let state = container.state.as_ref();
+        if sidecars.contains(container.name.as_str())
+            && state.is_some_and(|state| state.running.is_some())
+        {
+            continue;
+        }
+        if let Some(terminated) = state.and_then(|state| state.terminated.as_ref()) {
+            if terminated.exit_code == 0 {
+                continue;
+            }
+            reason = match terminated
+                .reason
+                .as_deref()
+                .filter(|value| !value.is_empty())
+            {
+                Some(value) => format!("Init:{value}"),
+                None => format!("Init:ExitCode:{}", terminated.exit_code),
+            };
+        } else {
+            reason = match state
+                .and_then(|state| state.waiting.as_ref())
+                .and_then(|waiting| waiting.reason.as_deref())
+                .filter(|value| !value.is_empty() && *value != "PodInitializing")
+            {
+                Some(value) => format!("Init:{value}"),
+                None => format!("Init:{index}/{init_total}"),
+            };
+        }
+        initializing = true;
+        break;
+    }
+
+    if !initializing {
+        let mut has_running = false;
+        for container in status
+            .container_statuses
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .rev()
+        {
+            let Some(state) = container.state.as_ref() else {
+                continue;
+            };
+            if let Some(waiting_reason) = state
+                .waiting
+                .as_ref()
+                .and_then(|waiting| waiting.reason.as_deref())
+                .filter(|value| !value.is_empty())
+            {
+                reason = waiting_reason.to_string();
+            } else if let Some(terminated) = state.terminated.as_ref() {
+                reason = match terminated
+                    .reason
+                    .as_deref()
+                    .filter(|value| !value.is_empty())
+                {
+                    Some(value) => value.to_string(),
+                    None => format!("ExitCode:{}", terminated.exit_code),
+                };
+            } else if state.running.is_some() && container.ready {
+                has_running = true;
+            }
+        }
+        if reason == "Completed" && has_running {
+            let ready = status
+                .conditions
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .any(|condition| condition.type_ == "Ready" && condition.status == "True");
+            reason = if ready { "Running" } else { "NotReady" }.to_string();
+        }
+    }
+
+    if pod.metadata.deletion_timestamp.is_some() {
+        if status.reason.as_deref() == Some("NodeLost") {
+            return "Unknown".to_string();
+        }
+        return "Terminating".to_string();
+    }
+    reason
+}
+
+fn pod_sidecar_names(pod: &Pod) -> HashSet<&str> {
+    pod.spec
+        .as_ref()
+        .and_then(|spec| spec.init_containers.as_ref())
+        .into_iter()
+        .flatten()
+        .filter(|container| container.restart_policy.as_deref() == Some("Always"))
+        .map(|container| container.name.as_str())
+        .collect()
+}
+
+// READY follows kubectl: regular containers plus restartable (sidecar) init
+// containers; restarts count init containers only while they are still running.
+fn pod_readiness(pod: &Pod) -> (usize, usize, i32) {
+    let sidecars = pod_sidecar_names(pod);
+    let Some(status) = pod.status.as_ref() else {
+        let total = pod.spec.as_ref().map_or(0, |spec| spec.containers.len());
+        return (0, total + sidecars.len(), 0);
+    };
+    let container_statuses = status.container_statuses.as_deref().unwrap_or_default();
+    let init_statuses = status
+        .init_container_statuses
+        .as_deref()
+        .unwrap_or_default();
+    let total = pod
+        .spec
+        .as_ref()
+        .map_or(container_statuses.len(), |spec| spec.containers.len())
+        + sidecars.len();
+
+    let ready_sidecars = init_statuses
         .iter()
-        .filter(|container| container.ready)
+        .filter(|container| sidecars.contains(container.name.as_str()) && container.ready)
         .count();
-    let restarts = container_statuses
+    let ready = container_statuses
         .iter()
-        .map(|container| container.restart_count)
-        .sum();
+        .filter(|container| container.ready)
+        .count()
+        + ready_sidecars;
+
+    let initializing = init_statuses.iter().any(|container| {
+        !sidecars.contains(container.name.as_str())
+            && container
+                .state
+                .as_ref()
+                .and_then(|state| state.terminated.as_ref())
+                .is_none_or(|terminated| terminated.exit_code != 0)
+    });
+    let restarts = if initializing {
+        init_statuses
+            .iter()
+            .map(|container| container.restart_count)
+            .sum()
+    } else {
+        container_statuses
+            .iter()
+            .chain(
+                init_statuses
+                    .iter()
+                    .filter(|container| sidecars.contains(container.name.as_str())),
+            )
+            .map(|container| container.restart_count)
+            .sum()
+    };
 
     (ready, total, restarts)
 }
@@ -4273,6 +6683,67 @@ fn node_roles(node: &Node) -> String {
     }
 }
 
+fn node_pressure(node: &Node) -> String {
+    let active = node
+        .status
+        .as_ref()
+        .and_then(|status| status.conditions.as_ref())
+        .map(|conditions| {
+            conditions
+                .iter()
+                .filter(|condition| {
+                    matches!(
+                        condition.type_.as_str(),
+                        "MemoryPressure" | "DiskPressure" | "PIDPressure" | "NetworkUnavailable"
+                    ) && condition.status == "True"
+                })
+                .map(|condition| condition.type_.clone())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if active.is_empty() {
+        "-".to_string()
+    } else {
+        active.join(",")
+    }
+}
+
+fn node_taints_summary(node: &Node) -> String {
+    let taints = node
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.taints.as_deref())
+        .unwrap_or_default();
+    if taints.is_empty() {
+        return "-".to_string();
+    }
+    let mut effects = taints
+        .iter()
+        .map(|taint| taint.effect.as_str())
+        .filter(|effect| matches!(*effect, "NoSchedule" | "NoExecute"))
+        .collect::<Vec<_>>();
+    effects.sort();
+    effects.dedup();
+    if effects.is_empty() {
+        taints.len().to_string()
+    } else {
+        format!("{} ({})", taints.len(), effects.join(","))
+    }
+}
+
+fn node_taint_lines(node: &Node) -> Vec<String> {
+    node.spec
+        .as_ref()
+        .and_then(|spec| spec.taints.as_deref())
+        .unwrap_or_default()
+        .iter()
+        .map(|taint| match taint.value.as_deref() {
+            Some(value) => format!("- {}={value}:{}", taint.key, taint.effect),
+            None => format!("- {}:{}", taint.key, taint.effect),
+        })
+        .collect()
+}
+
 fn event_age(event: &Event) -> String {
     if let Some(event_time) = event.event_time.as_ref() {
         return human_age_timestamp(event_time.0);
@@ -4289,6 +6760,78 @@ fn event_age(event: &Event) -> String {
     human_age(event.metadata.creation_timestamp.as_ref())
 }
 
+fn resource_event_lines(mut events: Vec<Event>) -> Vec<String> {
+    if events.is_empty() {
+        return vec!["No events recorded for this object".to_string()];
+    }
+    events.sort_by_key(event_timestamp_seconds);
+    let mut lines = vec![format!(
+        "{:<8} {:<8} {:<24} {:>5}  MESSAGE",
+        "LAST", "TYPE", "REASON", "COUNT"
+    )];
+    lines.extend(events.iter().map(|event| {
+        format!(
+            "{:<8} {:<8} {:<24} {:>5}  {}",
+            event_age(event),
+            event.type_.as_deref().unwrap_or("-"),
+            truncate(event.reason.as_deref().unwrap_or("-"), 24),
+            event.count.unwrap_or(1),
+            event.message.as_deref().unwrap_or("-").trim()
+        )
+    }));
+    lines
+}
+
+pub fn top_pod_usage_lines(
+    pod_usage: &HashMap<String, (u64, u64)>,
+    sort: UsageSort,
+    limit: usize,
+) -> String {
+    let ranked = |key: UsageSort| {
+        let mut pods = pod_usage
+            .iter()
+            .map(|(pod, (cpu, memory))| {
+                let value = match key {
+                    UsageSort::Cpu => *cpu,
+                    UsageSort::Memory => *memory,
+                };
+                (pod.as_str(), value)
+            })
+            .collect::<Vec<_>>();
+        pods.sort_by(|left, right| right.1.cmp(&left.1).then_with(|| left.0.cmp(right.0)));
+        pods.truncate(limit);
+        pods.into_iter()
+            .enumerate()
+            .map(|(index, (pod, value))| {
+                let value = match key {
+                    UsageSort::Cpu => format_cpu_millicores(value),
+                    UsageSort::Memory => format_bytes(value),
+                };
+                format!("{:>3}. {:<40} {:>9}", index + 1, truncate(pod, 40), value)
+            })
+            .collect::<Vec<_>>()
+    };
+    let primary = ranked(sort);
+    let secondary = ranked(sort.flip());
+    let mut lines = vec![format!(
+        "{:<56}  TOP {}",
+        format!("TOP {}", sort.label().to_ascii_uppercase()),
+        sort.flip().label().to_ascii_uppercase()
+    )];
+    for index in 0..primary.len().max(secondary.len()) {
+        lines.push(format!(
+            "{:<56}  {}",
+            primary.get(index).map(String::as_str).unwrap_or(""),
+            secondary.get(index).map(String::as_str).unwrap_or("")
+        ));
+    }
+    lines
+        .iter()
+        .map(|line| line.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn event_timestamp_seconds(event: &Event) -> i64 {
     event
         .event_time
@@ -4324,6 +6867,10 @@ fn truncate(value: &str, max: usize) -> String {
     out
 }
 
+fn created_at(timestamp: Option<&Time>) -> Option<chrono::DateTime<Utc>> {
+    timestamp.and_then(|time| chrono::DateTime::from_timestamp(time.0.as_second(), 0))
+}
+
 fn human_age(timestamp: Option<&Time>) -> String {
     let Some(timestamp) = timestamp else {
         return "-".to_string();
@@ -4353,9 +6900,1030 @@ fn format_elapsed_seconds(seconds: i64) -> String {
     format!("{seconds}s")
 }
 
+fn service_port_by_name(ports: &[ServicePort], port_name: &str) -> Result<u16> {
+    if let Some(port) = ports
+        .iter()
+        .find(|port| port.name.as_deref() == Some(port_name))
+    {
+        return u16::try_from(port.port)
+            .with_context(|| format!("port '{port_name}' has invalid number {}", port.port));
+    }
+
+    let names = ports
+        .iter()
+        .filter_map(|port| port.name.as_deref())
+        .filter(|name| !name.is_empty())
+        .collect::<Vec<_>>();
+    if names.is_empty() {
+        anyhow::bail!("no port named '{port_name}' (service ports are unnamed)");
+    }
+    anyhow::bail!(
+        "no port named '{port_name}' (available: {})",
+        names.join(", ")
+    )
+}
+
+fn restart_patch(restarted_at: &str) -> Value {
+    serde_json::json!({
+        "spec": {
+            "template": {
+                "metadata": {
+                    "annotations": {
+                        "kubectl.kubernetes.io/restartedAt": restarted_at
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ImageReference {
+    registry: String,
+    repository: String,
+    reference: String,
+}
+
+fn parse_image_reference(image: &str) -> ImageReference {
+    let image = image.trim();
+    let (remainder, digest) = match image.split_once('@') {
+        Some((remainder, digest)) => (remainder, Some(digest)),
+        None => (image, None),
+    };
+    let (registry, path) = match remainder.split_once('/') {
+        Some((first, rest))
+            if first.contains('.') || first.contains(':') || first == "localhost" =>
+        {
+            (first.to_string(), rest.to_string())
+        }
+        _ => ("docker.io".to_string(), remainder.to_string()),
+    };
+    let (repository, tag) = match path.rsplit_once(':') {
+        Some((repository, tag)) if !tag.contains('/') => (repository.to_string(), Some(tag)),
+        _ => (path.clone(), None),
+    };
+    let repository = if registry == "docker.io" && !repository.contains('/') {
+        format!("library/{repository}")
+    } else {
+        repository
+    };
+    let reference = match (tag, digest) {
+        (_, Some(digest)) => digest.to_string(),
+        (Some(tag), None) => tag.to_string(),
+        (None, None) => "latest".to_string(),
+    };
+
+    ImageReference {
+        registry: normalize_registry_host(&registry),
+        repository,
+        reference,
+    }
+}
+
+fn normalize_registry_host(raw: &str) -> String {
+    let host = raw
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .split('/')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    match host.as_str() {
+        "index.docker.io" | "registry-1.docker.io" | "registry.hub.docker.com" => {
+            "docker.io".to_string()
+        }
+        _ => host,
+    }
+}
+
+fn docker_config_registries(secret: &Secret) -> BTreeSet<String> {
+    let Some(data) = secret.data.as_ref() else {
+        return BTreeSet::new();
+    };
+    let parsed = data
+        .get(".dockerconfigjson")
+        .and_then(|raw| serde_json::from_slice::<Value>(&raw.0).ok())
+        .and_then(|config| config.get("auths").cloned())
+        .or_else(|| {
+            data.get(".dockercfg")
+                .and_then(|raw| serde_json::from_slice::<Value>(&raw.0).ok())
+        });
+    parsed
+        .as_ref()
+        .and_then(Value::as_object)
+        .map(|auths| {
+            auths
+                .keys()
+                .map(|host| normalize_registry_host(host))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn deployment_rollout_status(deployment: &Deployment) -> RolloutStatus {
+    let generation = deployment.metadata.generation.unwrap_or_default();
+    let Some(status) = deployment.status.as_ref() else {
+        return RolloutStatus::new(RolloutState::Progressing, "Waiting for deployment status");
+    };
+    if generation > status.observed_generation.unwrap_or_default() {
+        return RolloutStatus::new(
+            RolloutState::Progressing,
+            "Waiting for deployment spec update to be observed",
+        );
+    }
+
+    let deadline_exceeded = status.conditions.as_ref().is_some_and(|conditions| {
+        conditions.iter().any(|condition| {
+            condition.type_ == "Progressing"
+                && condition.reason.as_deref() == Some("ProgressDeadlineExceeded")
+        })
+    });
+    if deadline_exceeded {
+        return RolloutStatus::new(RolloutState::Failed, "Progress deadline exceeded");
+    }
+
+    let desired = deployment
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.replicas)
+        .unwrap_or(1);
+    let updated = status.updated_replicas.unwrap_or_default();
+    let total = status.replicas.unwrap_or_default();
+    let available = status.available_replicas.unwrap_or_default();
+
+    if updated < desired {
+        RolloutStatus::new(
+            RolloutState::Progressing,
+            format!("{updated} of {desired} new replicas updated"),
+        )
+    } else if total > updated {
+        RolloutStatus::new(
+            RolloutState::Progressing,
+            format!("{} old replicas pending termination", total - updated),
+        )
+    } else if available < updated {
+        RolloutStatus::new(
+            RolloutState::Progressing,
+            format!("{available} of {updated} updated replicas available"),
+        )
+    } else {
+        RolloutStatus::new(RolloutState::Complete, "successfully rolled out")
+    }
+}
+
+fn statefulset_rollout_status(statefulset: &StatefulSet) -> RolloutStatus {
+    let generation = statefulset.metadata.generation.unwrap_or_default();
+    let Some(status) = statefulset.status.as_ref() else {
+        return RolloutStatus::new(RolloutState::Progressing, "Waiting for statefulset status");
+    };
+    if generation > status.observed_generation.unwrap_or_default() {
+        return RolloutStatus::new(
+            RolloutState::Progressing,
+            "Waiting for statefulset spec update to be observed",
+        );
+    }
+
+    let desired = statefulset
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.replicas)
+        .unwrap_or(1);
+    let ready = status.ready_replicas.unwrap_or_default();
+    let updated = status.updated_replicas.unwrap_or_default();
+
+    if ready < desired {
+        RolloutStatus::new(
+            RolloutState::Progressing,
+            format!("{ready} of {desired} pods ready"),
+        )
+    } else if status.update_revision.is_some() && status.update_revision != status.current_revision
+    {
+        RolloutStatus::new(
+            RolloutState::Progressing,
+            format!("{updated} of {desired} pods at the update revision"),
+        )
+    } else {
+        RolloutStatus::new(RolloutState::Complete, "successfully rolled out")
+    }
+}
+
+fn daemonset_rollout_status(daemonset: &DaemonSet) -> RolloutStatus {
+    let generation = daemonset.metadata.generation.unwrap_or_default();
+    let Some(status) = daemonset.status.as_ref() else {
+        return RolloutStatus::new(RolloutState::Progressing, "Waiting for daemonset status");
+    };
+    if generation > status.observed_generation.unwrap_or_default() {
+        return RolloutStatus::new(
+            RolloutState::Progressing,
+            "Waiting for daemonset spec update to be observed",
+        );
+    }
+
+    let desired = status.desired_number_scheduled;
+    let updated = status.updated_number_scheduled.unwrap_or_default();
+    let available = status.number_available.unwrap_or_default();
+
+    if updated < desired {
+        RolloutStatus::new(
+            RolloutState::Progressing,
+            format!("{updated} of {desired} updated pods scheduled"),
+        )
+    } else if available < desired {
+        RolloutStatus::new(
+            RolloutState::Progressing,
+            format!("{available} of {desired} updated pods available"),
+        )
+    } else {
+        RolloutStatus::new(RolloutState::Complete, "successfully rolled out")
+    }
+}
+
 fn yaml_detail<T>(value: &T) -> String
 where
-    T: Serialize,
+    T: Serialize + kube::Resource,
 {
-    serde_yaml::to_string(value).unwrap_or_else(|error| format!("failed to format detail: {error}"))
+    let yaml = serde_yaml::to_string(value)
+        .unwrap_or_else(|error| format!("failed to format detail: {error}"));
+    let managers = field_manager_lines(value.meta().managed_fields.as_deref().unwrap_or_default());
+    if managers.is_empty() {
+        return yaml;
+    }
+    format!("{}\n{yaml}", managers.join("\n"))
+}
+
+fn field_manager_lines(entries: &[ManagedFieldsEntry]) -> Vec<String> {
+    let mut latest: Vec<(String, String, Option<k8s_openapi::jiff::Timestamp>)> = Vec::new();
+    for entry in entries {
+        let Some(manager) = entry.manager.as_deref().filter(|name| !name.is_empty()) else {
+            continue;
+        };
+        let operation = match (entry.operation.as_deref(), entry.subresource.as_deref()) {
+            (Some(operation), Some(subresource)) if !subresource.is_empty() => {
+                format!("{operation}/{subresource}")
+            }
+            (Some(operation), _) => operation.to_string(),
+            (None, _) => "-".to_string(),
+        };
+        let time = entry.time.as_ref().map(|time| time.0);
+        match latest.iter_mut().find(|(name, _, _)| name == manager) {
+            Some(existing) if time > existing.2 => {
+                existing.1 = operation;
+                existing.2 = time;
+            }
+            Some(_) => {}
+            None => latest.push((manager.to_string(), operation, time)),
+        }
+    }
+    if latest.is_empty() {
+        return Vec::new();
+    }
+
+    latest.sort_by(|left, right| right.2.cmp(&left.2).then_with(|| left.0.cmp(&right.0)));
+    let manager_width = latest
+        .iter()
+        .map(|(name, _, _)| name.len())
+        .max()
+        .unwrap_or(0);
+    let operation_width = latest.iter().map(|(_, op, _)| op.len()).max().unwrap_or(0);
+    let mut lines = vec!["# field managers (most recent first):".to_string()];
+    for (manager, operation, time) in latest {
+        let when = time
+            .map(|time| format!("{time} ({} ago)", human_age_timestamp(time)))
+            .unwrap_or_else(|| "-".to_string());
+        lines.push(format!(
+            "#   {manager:<manager_width$}  {operation:<operation_width$}  {when}"
+        ));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        API_DEPRECATIONS, RolloutState, ServerVersion, container_last_exit, delete_params,
+        deployment_rollout_status, deprecation_status, ensure_apply_target, field_manager_lines,
+        force_delete_params, is_auth_failure, kubeconfig_user_refreshes, logs_before,
+        node_pressure, node_taint_lines, node_taints_summary, node_usage_rows,
+        parse_container_metrics_usage, parse_image_reference, parse_server_minor,
+        pod_display_status, pod_readiness, rerun_job_manifest, resource_event_lines, restart_patch,
+        selector_error, service_port_by_name, top_pod_usage_lines, unserved_tabs_from,
+        validate_kube_selection, volume_snapshot_columns, webhook_columns,
+    };
+    use crate::model::UsageSort;
+    use k8s_openapi::api::apps::v1::Deployment;
+    use k8s_openapi::api::batch::v1::Job;
+    use k8s_openapi::api::core::v1::{ContainerStatus, Node, Pod, ServicePort};
+    use kube::config::Kubeconfig;
+    use std::collections::HashMap;
+
+    #[test]
+    fn restart_patch_sets_pod_template_restarted_at_annotation() {
+        let patch = restart_patch("2026-01-02T03:04:05+00:00");
+        assert_eq!(
+            patch
+                .pointer("/spec/template/metadata/annotations/kubectl.kubernetes.io~1restartedAt")
+                .and_then(|value| value.as_str()),
+            Some("2026-01-02T03:04:05+00:00")
+        );
+        assert_eq!(patch.as_object().map(|root| root.len()), Some(1));
+    }
+
+    #[test]
+    fn deprecation_status_tracks_server_version() {
+        assert_eq!(parse_server_minor("1", "27+"), (1, 27));
+        let pdb = API_DEPRECATIONS
+            .iter()
+            .find(|entry| {
+                entry.group_version == "policy/v1beta1" && entry.plural == "poddisruptionbudgets"
+            })
+            .expect("pdb deprecation entry");
+        assert_eq!(
+            deprecation_status(pdb, (1, 20)),
+            "deprecated in 1.21, removal in 1.25"
+        );
+        assert_eq!(
+            deprecation_status(pdb, (1, 23)),
+            "deprecated, removal in 1.25"
+        );
+        assert_eq!(deprecation_status(pdb, (1, 25)), "REMOVED in 1.25");
+    }
+
+    #[test]
+    fn server_version_gates_by_major_minor() {
+        let version = ServerVersion {
+            git_version: "v1.24.17-gke.1".to_string(),
+            major: 1,
+            minor: 24,
+        };
+        assert!(version.at_least(1, 21));
+        assert!(version.at_least(1, 24));
+        assert!(!version.at_least(1, 25));
+        assert_eq!(version.label(), "v1.24.17-gke.1");
+    }
+
+    #[test]
+    fn deployment_rollout_status_tracks_replica_progress() {
+        let mut deployment: Deployment = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "api", "generation": 3 },
+            "spec": { "replicas": 3 },
+            "status": {
+                "observedGeneration": 3,
+                "replicas": 4,
+                "updatedReplicas": 3,
+                "availableReplicas": 2
+            }
+        }))
+        .expect("deployment");
+
+        let status = deployment_rollout_status(&deployment);
+        assert_eq!(status.state, RolloutState::Progressing);
+        assert_eq!(status.message, "1 old replicas pending termination");
+
+        let deployment_status = deployment.status.as_mut().expect("status");
+        deployment_status.replicas = Some(3);
+        deployment_status.available_replicas = Some(3);
+        assert_eq!(
+            deployment_rollout_status(&deployment).state,
+            RolloutState::Complete
+        );
+
+        deployment.metadata.generation = Some(4);
+        assert_eq!(
+            deployment_rollout_status(&deployment).message,
+            "Waiting for deployment spec update to be observed"
+        );
+    }
+
+    #[test]
+    fn service_port_by_name_resolves_or_lists_available_names() {
+        let port = |name: &str, number: i32| ServicePort {
+            name: Some(name.to_string()),
+            port: number,
+            ..ServicePort::default()
+        };
+        let ports = vec![port("http", 80), port("metrics", 9090)];
+
+        assert_eq!(service_port_by_name(&ports, "metrics").ok(), Some(9090));
+        let error = service_port_by_name(&ports, "grpc")
+            .expect_err("unknown port name")
+            .to_string();
+        assert_eq!(error, "no port named 'grpc' (available: http, metrics)");
+    }
+
+    #[test]
+    fn parse_image_reference_splits_registry_repository_and_tag() {
+        let parsed = |image: &str| {
+            let reference = parse_image_reference(image);
+            (
+                reference.registry,
+                reference.repository,
+                reference.reference,
+            )
+        };
+        let owned = |a: &str, b: &str, c: &str| (a.to_string(), b.to_string(), c.to_string());
+
+        assert_eq!(
+            parsed("nginx"),
+            owned("docker.io", "library/nginx", "latest")
+        );
+        assert_eq!(
+            parsed("bitnami/redis:7.2"),
+            owned("docker.io", "bitnami/redis", "7.2")
+        );
+        assert_eq!(
+            parsed("ghcr.io/org/app:v1@sha256:abc"),
+            owned("ghcr.io", "org/app", "sha256:abc")
+        );
+        assert_eq!(
+            parsed("localhost:5000/tools/debug"),
+            owned("localhost:5000", "tools/debug", "latest")
+        );
+    }
+
+    #[test]
+    fn container_metrics_usage_is_keyed_by_container() {
+        let data = serde_json::json!({
+            "containers": [
+                {"name": "app", "usage": {"cpu": "250m", "memory": "64Mi"}},
+                {"name": "istio-proxy", "usage": {"cpu": "12000000n", "memory": "512Mi"}},
+                {"usage": {"cpu": "1", "memory": "1Gi"}}
+            ]
+        });
+        let usage = parse_container_metrics_usage(&data);
+        assert_eq!(usage.len(), 2);
+        assert_eq!(usage["app"], (250, 64 * 1024 * 1024));
+        assert_eq!(usage["istio-proxy"], (12, 512 * 1024 * 1024));
+    }
+
+    #[test]
+    fn rerun_job_manifest_drops_generated_selector_and_status() {
+        let job: Job = serde_json::from_value(serde_json::json!({
+            "metadata": {
+                "name": "migrate",
+                "namespace": "batch",
+                "uid": "abc",
+                "resourceVersion": "42",
+                "labels": {"app": "db", "controller-uid": "abc", "job-name": "migrate"}
+            },
+            "spec": {
+                "backoffLimit": 2,
+                "selector": {"matchLabels": {"controller-uid": "abc"}},
+                "template": {
+                    "metadata": {"labels": {
+                        "app": "db",
+                        "batch.kubernetes.io/controller-uid": "abc",
+                        "batch.kubernetes.io/job-name": "migrate"
+                    }},
+                    "spec": {"restartPolicy": "Never", "containers": [{"name": "migrate"}]}
+                }
+            },
+            "status": {"failed": 3}
+        }))
+        .expect("job");
+        let fresh = rerun_job_manifest(&job);
+        assert_eq!(fresh.metadata.name.as_deref(), Some("migrate"));
+        assert!(fresh.metadata.uid.is_none());
+        assert!(fresh.metadata.resource_version.is_none());
+        assert_eq!(
+            fresh
+                .metadata
+                .labels
+                .unwrap_or_default()
+                .into_keys()
+                .collect::<Vec<_>>(),
+            vec!["app".to_string()]
+        );
+        let spec = fresh.spec.expect("spec");
+        assert!(spec.selector.is_none());
+        assert_eq!(spec.backoff_limit, Some(2));
+        assert_eq!(
+            spec.template
+                .metadata
+                .and_then(|metadata| metadata.labels)
+                .unwrap_or_default()
+                .into_keys()
+                .collect::<Vec<_>>(),
+            vec!["app".to_string()]
+        );
+        assert!(fresh.status.is_none());
+    }
+
+    #[test]
+    fn auth_failures_are_told_apart_from_other_api_errors() {
+        let api = |code: u16, message: &str| {
+            anyhow::Error::new(kube::Error::Api(
+                kube::core::Status::failure(message, "Failure")
+                    .with_code(code)
+                    .boxed(),
+            ))
+            .context("failed to list pods")
+        };
+        assert!(is_auth_failure(&api(401, "Unauthorized")));
+        assert!(is_auth_failure(&api(403, "token has expired")));
+        assert!(!is_auth_failure(&api(403, "pods is forbidden")));
+        assert!(!is_auth_failure(&api(404, "not found")));
+    }
+
+    #[test]
+    fn only_exec_and_provider_users_count_as_refreshable() {
+        let kubeconfig: Kubeconfig = serde_yaml::from_str(
+            r#"
+users:
+  - name: eks
+    user:
+      exec:
+        apiVersion: client.authentication.k8s.io/v1beta1
+        command: aws
+  - name: static
+    user:
+      token: abc
+"#,
+        )
+        .expect("kubeconfig");
+        assert!(kubeconfig_user_refreshes(&kubeconfig, "eks"));
+        assert!(!kubeconfig_user_refreshes(&kubeconfig, "static"));
+        assert!(!kubeconfig_user_refreshes(&kubeconfig, "missing"));
+    }
+
+    #[test]
+    fn pod_readiness_counts_regular_containers_and_sidecars() {
+        let pod = |spec: serde_json::Value, status: serde_json::Value| -> Pod {
+            serde_json::from_value(serde_json::json!({
+                "metadata": {"name": "api"},
+                "spec": spec,
+                "status": status
+            }))
+            .expect("pod")
+        };
+        let container = |name: &str, ready: bool, restarts: i32, state: serde_json::Value| {
+            serde_json::json!({
+                "name": name, "image": "img", "imageID": "", "ready": ready,
+                "restartCount": restarts, "state": state
+            })
+        };
+        let spec = serde_json::json!({
+            "initContainers": [
+                {"name": "migrate"},
+                {"name": "proxy", "restartPolicy": "Always"}
+            ],
+            "containers": [{"name": "app"}]
+        });
+
+        let initializing = pod(
+            spec.clone(),
+            serde_json::json!({
+                "phase": "Pending",
+                "initContainerStatuses": [
+                    container("migrate", false, 2, serde_json::json!({"running": {}})),
+                    container("proxy", false, 0, serde_json::json!({"waiting": {}}))
+                ],
+                "containerStatuses": [
+                    container("app", false, 0, serde_json::json!({"waiting": {"reason": "PodInitializing"}}))
+                ]
+            }),
+        );
+        assert_eq!(pod_readiness(&initializing), (0, 2, 2));
+        assert_eq!(pod_display_status(&initializing), "Init:0/2");
+
+        let running = pod(
+            spec.clone(),
+            serde_json::json!({
+                "phase": "Running",
+                "initContainerStatuses": [
+                    container("migrate", false, 2, serde_json::json!({"terminated": {"exitCode": 0}})),
+                    container("proxy", true, 1, serde_json::json!({"running": {}}))
+                ],
+                "containerStatuses": [
+                    container("app", true, 3, serde_json::json!({"running": {}}))
+                ]
+            }),
+        );
+        assert_eq!(pod_readiness(&running), (2, 2, 4));
+        assert_eq!(pod_display_status(&running), "Running");
+
+        let completed = pod(
+            serde_json::json!({"containers": [{"name": "job"}]}),
+            serde_json::json!({
+                "phase": "Succeeded",
+                "containerStatuses": [
+                    container("job", false, 0, serde_json::json!({"terminated": {"exitCode": 0, "reason": "Completed"}}))
+                ]
+            }),
+        );
+        assert_eq!(pod_readiness(&completed), (0, 1, 0));
+        assert_eq!(pod_display_status(&completed), "Completed");
+
+        let unscheduled = pod(
+            serde_json::json!({"containers": [{"name": "app"}]}),
+            serde_json::json!({}),
+        );
+        assert_eq!(pod_readiness(&unscheduled), (0, 1, 0));
+    }
+
+    #[test]
+    fn pod_display_status_prefers_container_reasons_over_phase() {
+        let pod = |status: serde_json::Value| -> Pod {
+            serde_json::from_value(serde_json::json!({
+                "metadata": {"name": "api"},
+                "status": status
+            }))
+            .expect("pod")
+        };
+        let container = |state: serde_json::Value| {
+            serde_json::json!({
+                "name": "app", "image": "app:v1", "imageID": "", "ready": false,
+                "restartCount": 4, "state": state
+            })
+        };
+
+        let crashing = pod(serde_json::json!({
+            "phase": "Running",
+            "containerStatuses": [container(serde_json::json!({
+                "waiting": {"reason": "CrashLoopBackOff"}
+            }))]
+        }));
+        assert_eq!(pod_display_status(&crashing), "CrashLoopBackOff");
+
+        let init = pod(serde_json::json!({
+            "phase": "Pending",
+            "initContainerStatuses": [container(serde_json::json!({
+                "waiting": {"reason": "ImagePullBackOff"}
+            }))]
+        }));
+        assert_eq!(pod_display_status(&init), "Init:ImagePullBackOff");
+
+        let oom = pod(serde_json::json!({
+            "phase": "Running",
+            "containerStatuses": [container(serde_json::json!({
+                "terminated": {"exitCode": 137, "reason": "OOMKilled"}
+            }))]
+        }));
+        assert_eq!(pod_display_status(&oom), "OOMKilled");
+
+        let healthy = pod(serde_json::json!({
+            "phase": "Running",
+            "containerStatuses": [container(serde_json::json!({"running": {}}))]
+        }));
+        assert_eq!(pod_display_status(&healthy), "Running");
+    }
+
+    #[test]
+    fn container_last_exit_reports_oom_kill_reason_and_code() {
+        let status: ContainerStatus = serde_json::from_value(serde_json::json!({
+            "name": "app",
+            "image": "app:v1",
+            "imageID": "",
+            "ready": true,
+            "restartCount": 3,
+            "lastState": {"terminated": {"exitCode": 137, "reason": "OOMKilled"}}
+        }))
+        .expect("status");
+        assert_eq!(container_last_exit(&status), "OOMKilled(137)");
+
+        let fresh: ContainerStatus = serde_json::from_value(serde_json::json!({
+            "name": "app",
+            "image": "app:v1",
+            "imageID": "",
+            "ready": true,
+            "restartCount": 0
+        }))
+        .expect("status");
+        assert_eq!(container_last_exit(&fresh), "-");
+    }
+
+    #[test]
+    fn node_pressure_and_taints_surface_scheduling_blockers() {
+        let node: Node = serde_json::from_value(serde_json::json!({
+            "metadata": {"name": "worker-1"},
+            "spec": {"taints": [
+                {"key": "node.kubernetes.io/disk-pressure", "effect": "NoSchedule"},
+                {"key": "dedicated", "value": "gpu", "effect": "PreferNoSchedule"}
+            ]},
+            "status": {"conditions": [
+                {"type": "Ready", "status": "True"},
+                {"type": "MemoryPressure", "status": "False"},
+                {"type": "DiskPressure", "status": "True"}
+            ]}
+        }))
+        .expect("node");
+        assert_eq!(node_pressure(&node), "DiskPressure");
+        assert_eq!(node_taints_summary(&node), "2 (NoSchedule)");
+        assert_eq!(
+            node_taint_lines(&node),
+            vec![
+                "- node.kubernetes.io/disk-pressure:NoSchedule".to_string(),
+                "- dedicated=gpu:PreferNoSchedule".to_string(),
+            ]
+        );
+
+        let clean: Node = serde_json::from_value(serde_json::json!({
+            "metadata": {"name": "worker-2"}
+        }))
+        .expect("node");
+        assert_eq!(node_pressure(&clean), "-");
+        assert_eq!(node_taints_summary(&clean), "-");
+    }
+
+    #[test]
+    fn node_usage_rows_join_metrics_with_allocatable() {
+        let node = |name: &str| -> Node {
+            serde_json::from_value(serde_json::json!({
+                "metadata": {"name": name},
+                "status": {"allocatable": {"cpu": "4", "memory": "8Gi"}}
+            }))
+            .expect("node")
+        };
+        let usage = HashMap::from([("hot".to_string(), (3_600, 2 * 1024 * 1024 * 1024))]);
+        let (headers, rows) = node_usage_rows(&[node("hot"), node("new")], &usage);
+
+        assert_eq!(headers.len(), rows[0].columns.len());
+        assert_eq!(
+            rows[0].columns,
+            vec!["hot", "3.60c", "4.00c", "90%", "2.0Gi", "8.0Gi", "25%"]
+        );
+        assert_eq!(rows[1].columns[1], "-");
+        assert_eq!(rows[1].columns[3], "-");
+    }
+
+    #[test]
+    fn field_manager_lines_keep_latest_entry_per_manager() {
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ManagedFieldsEntry, Time};
+
+        let entry = |manager: &str, operation: &str, subresource: Option<&str>, time: &str| {
+            ManagedFieldsEntry {
+                manager: Some(manager.to_string()),
+                operation: Some(operation.to_string()),
+                subresource: subresource.map(str::to_string),
+                time: Some(Time(time.parse().expect("timestamp"))),
+                ..ManagedFieldsEntry::default()
+            }
+        };
+        let lines = field_manager_lines(&[
+            entry("kubectl-edit", "Update", None, "2026-01-01T00:00:00Z"),
+            entry("argocd-controller", "Apply", None, "2026-01-03T00:00:00Z"),
+            entry(
+                "kube-controller-manager",
+                "Update",
+                Some("status"),
+                "2026-01-02T00:00:00Z",
+            ),
+            entry("kubectl-edit", "Update", None, "2026-01-04T00:00:00Z"),
+        ]);
+
+        assert_eq!(lines[0], "# field managers (most recent first):");
+        assert_eq!(lines.len(), 4);
+        assert!(
+            lines[1]
+                .starts_with("#   kubectl-edit             Update         2026-01-04T00:00:00Z")
+        );
+        assert!(lines[2].contains("argocd-controller        Apply"));
+        assert!(lines[3].contains("Update/status  2026-01-02T00:00:00Z"));
+        assert!(field_manager_lines(&[]).is_empty());
+    }
+
+    #[test]
+    fn selector_error_names_rejected_selectors() {
+        let status =
+            kube::core::Status::failure("field label not supported: spec.bogus", "BadRequest")
+                .with_code(400);
+        let error = anyhow::Error::from(kube::Error::Api(Box::new(status)));
+        let message = format!("{:#}", selector_error(error, None, Some("spec.bogus=1")));
+        assert!(message.starts_with("API server rejected field selector 'spec.bogus=1'"));
+        assert!(message.contains("field label not supported"));
+
+        let other = selector_error(anyhow::anyhow!("timeout"), None, Some("spec.nodeName=a"));
+        assert_eq!(other.to_string(), "timeout");
+    }
+
+    #[test]
+    fn logs_before_drops_lines_at_or_after_cutoff() {
+        let logs = "2026-01-01T00:00:00Z first\n  continued\n2026-01-01T00:10:00Z second\n2026-01-01T00:20:00Z third\n  trace\n";
+        let cutoff = "2026-01-01T00:20:00Z"
+            .parse::<k8s_openapi::jiff::Timestamp>()
+            .expect("timestamp")
+            .as_second();
+        assert_eq!(
+            logs_before(logs, cutoff),
+            "2026-01-01T00:00:00Z first\n  continued\n2026-01-01T00:10:00Z second\n"
+        );
+    }
+
+    #[test]
+    fn kube_selection_rejects_unknown_context_or_cluster() {
+        let kubeconfig = kube::config::Kubeconfig {
+            contexts: vec![kube::config::NamedContext {
+                name: "dev".to_string(),
+                context: None,
+            }],
+            clusters: vec![kube::config::NamedCluster {
+                name: "dev-cluster".to_string(),
+                cluster: None,
+            }],
+            ..kube::config::Kubeconfig::default()
+        };
+
+        assert!(validate_kube_selection(&kubeconfig, None, None).is_ok());
+        assert!(validate_kube_selection(&kubeconfig, Some("dev"), Some("dev-cluster")).is_ok());
+        let error = validate_kube_selection(&kubeconfig, Some("prod"), None)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "Context 'prod' was not found in kubeconfig (available: dev)"
+        );
+        assert!(validate_kube_selection(&kubeconfig, None, Some("prod-cluster")).is_err());
+    }
+
+    #[test]
+    fn delete_params_map_each_propagation_policy() {
+        use crate::model::DeletePropagation;
+        use kube::api::PropagationPolicy;
+
+        let cases = [
+            (DeletePropagation::Background, PropagationPolicy::Background),
+            (DeletePropagation::Foreground, PropagationPolicy::Foreground),
+            (DeletePropagation::Orphan, PropagationPolicy::Orphan),
+        ];
+        for (propagation, expected) in cases {
+            assert_eq!(
+                delete_params(propagation).propagation_policy,
+                Some(expected)
+            );
+        }
+        assert_eq!(DeletePropagation::default(), DeletePropagation::Background);
+    }
+
+    #[test]
+    fn force_delete_params_skip_grace_period() {
+        use kube::api::PropagationPolicy;
+
+        let params = force_delete_params();
+        assert_eq!(params.grace_period_seconds, Some(0));
+        assert_eq!(
+            params.propagation_policy,
+            Some(PropagationPolicy::Foreground)
+        );
+    }
+
+    #[test]
+    fn volume_snapshot_columns_read_source_and_status() {
+        let bound = serde_json::json!({
+            "spec": {"source": {"persistentVolumeClaimName": "data-db-0"}},
+            "status": {"readyToUse": true, "restoreSize": "10Gi"}
+        });
+        assert_eq!(
+            volume_snapshot_columns(&bound),
+            (
+                "true".to_string(),
+                "data-db-0".to_string(),
+                "10Gi".to_string()
+            )
+        );
+
+        let pending = serde_json::json!({
+            "spec": {"source": {"volumeSnapshotContentName": "snapcontent-1"}}
+        });
+        assert_eq!(
+            volume_snapshot_columns(&pending),
+            (
+                "-".to_string(),
+                "content/snapcontent-1".to_string(),
+                "-".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn unserved_tabs_cover_missing_groups_and_resources() {
+        use crate::model::ResourceTab;
+        use std::collections::HashSet;
+
+        let served = HashMap::from([
+            (
+                "v1".to_string(),
+                HashSet::from(["pods".to_string(), "pods/log".to_string()]),
+            ),
+            (
+                "networking.k8s.io/v1".to_string(),
+                HashSet::from(["ingresses".to_string()]),
+            ),
+        ]);
+        let unserved = unserved_tabs_from(&served);
+        assert!(!unserved.contains(&ResourceTab::Pods));
+        assert!(!unserved.contains(&ResourceTab::Ingresses));
+        assert!(unserved.contains(&ResourceTab::NetworkPolicies));
+        assert!(unserved.contains(&ResourceTab::Deployments));
+        assert!(unserved.contains(&ResourceTab::Secrets));
+        assert!(!unserved.contains(&ResourceTab::ArgoCdApps));
+        assert!(!unserved.contains(&ResourceTab::Orca));
+    }
+
+    #[test]
+    fn top_pod_usage_lines_rank_both_columns() {
+        let usage = HashMap::from([
+            ("shop/web-1".to_string(), (250, 64 * 1_048_576)),
+            ("shop/db-0".to_string(), (1_500, 16 * 1_048_576)),
+            ("ops/agent".to_string(), (10, 512 * 1_048_576)),
+        ]);
+        let output = top_pod_usage_lines(&usage, UsageSort::Cpu, 2);
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("TOP CPU") && lines[0].ends_with("TOP MEMORY"));
+        assert!(lines[1].contains("1. shop/db-0") && lines[1].contains("1.50c"));
+        assert!(lines[1].contains("1. ops/agent") && lines[1].contains("512.0Mi"));
+        assert!(lines[2].contains("shop/web-1"));
+
+        let flipped = top_pod_usage_lines(&usage, UsageSort::Memory, 1);
+        assert!(flipped.starts_with("TOP MEMORY"));
+        assert!(
+            flipped
+                .lines()
+                .nth(1)
+                .unwrap()
+                .starts_with("  1. ops/agent")
+        );
+    }
+
+    #[test]
+    fn resource_event_lines_sort_oldest_first() {
+        use k8s_openapi::api::core::v1::Event;
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+        use k8s_openapi::jiff::Timestamp;
+
+        let event = |second: i64, reason: &str| Event {
+            reason: Some(reason.to_string()),
+            type_: Some("Normal".to_string()),
+            message: Some(format!("{reason} happened")),
+            last_timestamp: Some(Time(Timestamp::from_second(second).unwrap())),
+            ..Event::default()
+        };
+        let lines = resource_event_lines(vec![event(200, "Started"), event(100, "Pulled")]);
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("LAST"));
+        assert!(lines[1].contains("Pulled happened"));
+        assert!(lines[2].contains("Started happened"));
+        assert_eq!(
+            resource_event_lines(Vec::new()),
+            vec!["No events recorded for this object".to_string()]
+        );
+    }
+
+    #[test]
+    fn webhook_columns_summarize_policies_and_targets() {
+        use k8s_openapi::api::admissionregistration::v1::{ServiceReference, WebhookClientConfig};
+
+        let service = WebhookClientConfig {
+            service: Some(ServiceReference {
+                name: "gatekeeper-webhook".to_string(),
+                namespace: "gatekeeper".to_string(),
+                port: None,
+                path: None,
+            }),
+            ..WebhookClientConfig::default()
+        };
+        let url = WebhookClientConfig {
+            url: Some("https://hooks.example.com/validate".to_string()),
+            ..WebhookClientConfig::default()
+        };
+        let hooks = [
+            (None, &service),
+            (Some("Ignore"), &url),
+            (Some("Fail"), &service),
+        ];
+        assert_eq!(
+            webhook_columns(hooks.into_iter()),
+            (
+                "Fail:2,Ignore:1".to_string(),
+                "gatekeeper/gatekeeper-webhook:443,https://hooks.example.com/validate".to_string()
+            )
+        );
+        assert_eq!(
+            webhook_columns(std::iter::empty()),
+            ("-".to_string(), "-".to_string())
+        );
+    }
+
+    #[test]
+    fn apply_target_must_match_the_opened_object() {
+        let object = |yaml: &str| -> kube::core::DynamicObject {
+            serde_yaml::from_str(yaml).expect("object")
+        };
+        let same = object("apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: settings\n");
+        assert!(ensure_apply_target(&same, "ConfigMap", Some("shop"), "settings").is_ok());
+
+        let renamed = object("apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: other\n");
+        assert!(ensure_apply_target(&renamed, "ConfigMap", Some("shop"), "settings").is_err());
+
+        let moved = object(
+            "apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: settings\n  namespace: prod\n",
+        );
+        assert!(ensure_apply_target(&moved, "ConfigMap", Some("shop"), "settings").is_err());
+
+        let retyped = object("apiVersion: v1\nkind: Secret\nmetadata:\n  name: settings\n");
+        let error = ensure_apply_target(&retyped, "ConfigMap", Some("shop"), "settings")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("from ConfigMap to Secret"));
+    }
 }
@@ -1,16 +1,21 @@
 mod app;
 mod cli;
+mod clipboard;
 mod config;
 mod input;
 mod k8s;
 mod model;
+mod state;
 mod ui;
 
 use anyhow::{Context, Result};
-use app::{App, AppCommand, ArgoResourcePanelSection, OpsInspectTarget, PluginRun};
+use app::{
+    App, AppCommand, ArgoResourcePanelSection, HelmReleaseSection, OpsInspectTarget, PluginRun,
+    log_range_label,
+};
 use chrono::Local;
 use clap::Parser;
-use cli::CliArgs;
+use cli::{CliArgs, DumpFormat};
 use crossterm::event::{
     Event, EventStream, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, KeyboardEnhancementFlags,
     PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
@@ -22,35 +27,43 @@ use crossterm::terminal::{
 };
 use futures::{StreamExt, TryStreamExt};
 use input::key_event_signature;
-use k8s::KubeGateway;
+use k8s::{KubeGateway, KubectlTarget};
+use k8s_openapi::api::admissionregistration::v1::{
+    MutatingWebhookConfiguration, ValidatingWebhookConfiguration,
+};
 use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
 use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::coordination::v1::Lease;
 use k8s_openapi::api::core::v1::{
     ConfigMap, Event as KubeEvent, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod,
     ReplicationController, Secret, Service, ServiceAccount,
 };
+use k8s_openapi::api::discovery::v1::EndpointSlice;
 use k8s_openapi::api::networking::v1::{Ingress, IngressClass, NetworkPolicy};
 use k8s_openapi::api::rbac::v1::{ClusterRole, ClusterRoleBinding, Role, RoleBinding};
+use k8s_openapi::api::scheduling::v1::PriorityClass;
 use k8s_openapi::api::storage::v1::StorageClass;
-use kube::runtime::watcher::{Config as WatchConfig, watcher};
+use kube::runtime::watcher::{self, Config as WatchConfig, metadata_watcher};
 use kube::{Api, Client};
-use model::{NamespaceScope, ResourceTab};
+use model::{ArgoNamespaceLive, NamespaceScope, ResourceTab, WatchActivity, WatchChange};
 use model::{RowData, TableData};
 use portable_pty::{CommandBuilder as PtyCommandBuilder, PtySize, native_pty_system};
 use ratatui::Terminal;
 use ratatui::backend::CrosstermBackend;
 use serde_json::Value;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::io::{self, Read, Stdout, Write};
 use std::net::UdpSocket;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
+use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command as TokioCommand;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, oneshot};
 use tokio::task::JoinHandle;
-use tokio::time::{Duration, MissedTickBehavior, interval, timeout};
+use tokio::time::{Duration, MissedTickBehavior, interval, interval_at, timeout};
 use tracing::{debug, warn};
 use tracing_subscriber::EnvFilter;
 
@@ -58,10 +71,16 @@ type TuiTerminal = Terminal<CrosstermBackend<Stdout>>;
 const TABLE_REFRESH_TIMEOUT: Duration = Duration::from_secs(4);
 const METRICS_REFRESH_TIMEOUT: Duration = Duration::from_secs(2);
 const CRD_DISCOVERY_TIMEOUT: Duration = Duration::from_secs(5);
+const ROLLOUT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+const ROLLOUT_STATUS_TIMEOUT_SECS: u64 = 20;
+const WATCH_RETRY_BASE: Duration = Duration::from_millis(900);
+const WATCH_RETRY_MAX: Duration = Duration::from_secs(30);
+const SHELL_SCROLLBACK_LINES: usize = 4_000;
 
 enum LoopEffect {
     None,
     RestartWatchers,
+    RewatchRequested,
 }
 
 #[derive(Debug, Clone)]
@@ -75,17 +94,75 @@ struct PortForwardExitEvent {
     result: std::result::Result<std::process::ExitStatus, String>,
 }
 
+#[derive(Debug, Clone)]
+enum RolloutUpdate {
+    Progress(String),
+    Complete(String),
+    Failed(String),
+    TimedOut(String),
+    Error(String),
+}
+
+#[derive(Debug, Clone)]
+struct RolloutWatchEvent {
+    tab: ResourceTab,
+    namespace: String,
+    name: String,
+    elapsed: Duration,
+    update: RolloutUpdate,
+}
+
+#[derive(Debug, Clone)]
+struct JobRerunEvent {
+    namespace: String,
+    name: String,
+    result: std::result::Result<(), String>,
+}
+
+#[derive(Debug, Clone)]
+enum WatchEvent {
+    Changed {
+        tab: ResourceTab,
+        activity: Option<WatchActivity>,
+    },
+    Failed {
+        tab: ResourceTab,
+        error: String,
+        retry_in: Duration,
+    },
+}
+
 #[derive(Debug, Clone)]
 struct ShellOutputEvent {
     snapshot: String,
     application_cursor: bool,
 }
 
+#[derive(Debug, Clone)]
+struct PluginOutputEvent {
+    title: String,
+    snapshot: String,
+    finished: Option<String>,
+}
+
+struct EventSenders {
+    port_forward: mpsc::UnboundedSender<PortForwardExitEvent>,
+    /// Stop signals for running port-forwards, keyed by kubectl pid. The task that owns
+    /// the child kills it, so a pid reused after exit is never signalled.
+    port_forward_stops: Mutex<HashMap<u32, oneshot::Sender<()>>>,
+    rollout: mpsc::UnboundedSender<RolloutWatchEvent>,
+    job_rerun: mpsc::UnboundedSender<JobRerunEvent>,
+    shell_output: mpsc::UnboundedSender<ShellOutputEvent>,
+    plugin_output: mpsc::UnboundedSender<PluginOutputEvent>,
+}
+
 #[derive(Default)]
 struct EmbeddedShellState {
     child: Option<Box<dyn portable_pty::Child + Send + Sync>>,
     writer: Option<Box<dyn Write + Send>>,
+    parser: Option<Arc<Mutex<vt100::Parser>>>,
     application_cursor: bool,
+    scroll_offset: usize,
 }
 
 #[tokio::main]
@@ -93,8 +170,13 @@ async fn main() -> Result<()> {
     let args = CliArgs::parse();
     init_tracing(&args.log_filter)?;
 
-    let mut gateway = KubeGateway::new().await?;
-    let namespace_scope = resolve_namespace_scope(&args, &gateway);
+    let state = state::OrcaState::load();
+    let mut gateway = connect_gateway(&args, &state).await?;
+    gateway.set_page_size(args.page_size);
+    let namespace_scope = resolve_namespace_scope(&args, &gateway, &state);
+    if let Some(resource) = args.dump.as_deref() {
+        return dump_table(&gateway, resource, &namespace_scope, args.output).await;
+    }
 
     let mut app = App::new(
         gateway.cluster().to_string(),
@@ -107,6 +189,13 @@ async fn main() -> Result<()> {
     {
         app.set_read_only(true);
     }
+    app.set_log_tail(args.log_tail);
+    app.set_default_refresh_ms(args.refresh_ms.max(500));
+    app.set_watch_disabled(args.no_watch);
+    app.set_idle_refresh(
+        (args.idle_after_secs > 0).then(|| Duration::from_secs(args.idle_after_secs)),
+        args.idle_refresh_ms,
+    );
     app.set_user(gateway.user().to_string());
     let (host_user, host_name, host_ip) = resolve_host_identity();
     app.set_host_identity(host_user, host_name, host_ip);
@@ -124,6 +213,46 @@ async fn main() -> Result<()> {
     run(&mut app, &mut gateway, args.refresh_ms.max(500)).await
 }
 
+async fn dump_table(
+    gateway: &KubeGateway,
+    resource: &str,
+    scope: &NamespaceScope,
+    format: DumpFormat,
+) -> Result<()> {
+    let tab = ResourceTab::from_token(resource)
+        .with_context(|| format!("unknown resource '{resource}' for --dump"))?;
+    let table = gateway
+        .fetch_table(tab, scope, None, None, None, usize::MAX)
+        .await
+        .with_context(|| format!("failed to fetch {}", tab.title()))?;
+    let output = match format {
+        DumpFormat::Json => serde_json::to_string_pretty(&table.dump_value())?,
+        DumpFormat::Yaml => serde_yaml::to_string(&table.dump_value())?,
+        DumpFormat::Wide => app::render_table_text(&table.with_namespace_column()),
+    };
+    let mut stdout = io::stdout().lock();
+    writeln!(stdout, "{}", output.trim_end())?;
+    Ok(())
+}
+
+fn announce_version_change(app: &mut App, show_whats_new: bool) {
+    let version = env!("CARGO_PKG_VERSION");
+    let mut state = state::OrcaState::load();
+    if state.last_version.as_deref() == Some(version) {
+        return;
+    }
+    if show_whats_new && let Some(notes) = state::release_notes(version) {
+        app.set_output_overlay(
+            format!("What's new in orca {version} (Esc to dismiss)"),
+            notes.to_string(),
+        );
+    }
+    state.last_version = Some(version.to_string());
+    if let Err(error) = state.save() {
+        warn!("failed to save orca state: {error:#}");
+    }
+}
+
 fn init_tracing(level_filter: &str) -> Result<()> {
     let filter = EnvFilter::try_new(level_filter)
         .or_else(|_| EnvFilter::try_new("info"))
@@ -139,16 +268,53 @@ fn init_tracing(level_filter: &str) -> Result<()> {
     Ok(())
 }
 
-fn resolve_namespace_scope(args: &CliArgs, gateway: &KubeGateway) -> NamespaceScope {
+async fn connect_gateway(args: &CliArgs, state: &state::OrcaState) -> Result<KubeGateway> {
+    if args.context.is_some() || args.cluster.is_some() || args.dump.is_some() {
+        return KubeGateway::new(args.context.clone(), args.cluster.clone()).await;
+    }
+    let Some(context) = state.last_context.clone() else {
+        return KubeGateway::new(None, None).await;
+    };
+    match KubeGateway::new(Some(context.clone()), None).await {
+        Ok(gateway) => Ok(gateway),
+        Err(error) => {
+            warn!("failed to restore last context '{context}': {error:#}");
+            KubeGateway::new(None, None).await
+        }
+    }
+}
+
+fn resolve_namespace_scope(
+    args: &CliArgs,
+    gateway: &KubeGateway,
+    state: &state::OrcaState,
+) -> NamespaceScope {
     if args.all_namespaces {
         NamespaceScope::All
     } else if let Some(namespace) = &args.namespace {
         NamespaceScope::Named(namespace.clone())
+    } else if let Some(scope) = (args.dump.is_none() && args.context.is_none())
+        .then(|| state.last_scope_for(gateway.context()))
+        .flatten()
+    {
+        scope
     } else {
         NamespaceScope::Named(gateway.default_namespace().to_string())
     }
 }
 
+fn persist_session_state(app: &App, persisted: &mut (String, NamespaceScope)) {
+    if persisted.0 == app.context() && persisted.1 == *app.namespace_scope() {
+        return;
+    }
+    *persisted = (app.context().to_string(), app.namespace_scope().clone());
+    let mut state = state::OrcaState::load();
+    state.remember_session(&persisted.0, &persisted.1);
+    if let Err(error) = state.save() {
+        warn!("failed to save orca state: {error:#}");
+    }
+}
+
 fn parse_truthy_env(value: &str) -> bool {
     matches!(
         value.trim().to_ascii_lowercase().as_str(),
@@ -250,14 +416,23 @@ async fn run_loop(
 ) -> Result<()> {
     app.set_status("Bootstrapping Kubernetes data…");
     let mut config_watcher = config::RuntimeConfigWatcher::discover();
+    let mut show_whats_new = true;
     match config_watcher.load_current() {
         Ok(snapshot) => {
+            show_whats_new = snapshot.whats_new;
             app.set_runtime_config(
                 snapshot.aliases,
                 snapshot.plugins,
                 snapshot.hotkeys,
                 snapshot.source.clone(),
             );
+            app.set_confirm_skip(snapshot.skip_confirm);
+            app.set_dangerous_commands(snapshot.dangerous_commands);
+            app.set_adopt_context_namespace(snapshot.adopt_context_namespace);
+            app.set_export_dir(snapshot.export_dir);
+            app.set_strip_name_prefixes(snapshot.strip_name_prefixes);
+            app.set_tab_refresh_intervals(snapshot.refresh_intervals);
+            app.set_terraform_plan_timeout(snapshot.terraform_plan_timeout_secs);
         }
         Err(error) => {
             app.set_runtime_config(HashMap::new(), Vec::new(), Vec::new(), None);
@@ -268,6 +443,7 @@ async fn run_loop(
         }
     }
 
+    refresh_server_version(app, gateway).await;
     refresh_custom_resource_catalog(app, gateway).await;
     refresh_tab(app, gateway, ResourceTab::Namespaces).await;
     refresh_tab(app, gateway, ResourceTab::Nodes).await;
@@ -275,21 +451,40 @@ async fn run_loop(
     refresh_tab(app, gateway, ResourceTab::ArgoCdApps).await;
     refresh_tab(app, gateway, app.active_tab()).await;
     refresh_tab(app, gateway, ResourceTab::CustomResources).await;
+    announce_version_change(app, show_whats_new);
 
     let mut reader = EventStream::new();
-    let mut ticker = interval(Duration::from_millis(refresh_ms));
+    let mut ticker_ms = app.refresh_tick_ms(refresh_ms);
+    let mut ticker = interval(Duration::from_millis(ticker_ms));
     ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
-    let (watch_tx, mut watch_rx) = mpsc::unbounded_channel::<ResourceTab>();
-    let mut watch_tasks = start_resource_watchers(gateway.client(), watch_tx.clone());
+    let (watch_tx, mut watch_rx) = mpsc::unbounded_channel::<WatchEvent>();
+    let mut watch_tasks = if app.watch_disabled() {
+        Vec::new()
+    } else {
+        start_resource_watchers(gateway.client(), watch_tx.clone())
+    };
     let mut watch_throttle = HashMap::<ResourceTab, Instant>::new();
     let (pf_tx, mut pf_rx) = mpsc::unbounded_channel::<PortForwardExitEvent>();
     let (shell_output_tx, mut shell_output_rx) = mpsc::unbounded_channel::<ShellOutputEvent>();
+    let (rollout_tx, mut rollout_rx) = mpsc::unbounded_channel::<RolloutWatchEvent>();
+    let (job_rerun_tx, mut job_rerun_rx) = mpsc::unbounded_channel::<JobRerunEvent>();
+    let (plugin_output_tx, mut plugin_output_rx) = mpsc::unbounded_channel::<PluginOutputEvent>();
+    let senders = EventSenders {
+        port_forward: pf_tx,
+        port_forward_stops: Mutex::new(HashMap::new()),
+        rollout: rollout_tx,
+        job_rerun: job_rerun_tx,
+        shell_output: shell_output_tx,
+        plugin_output: plugin_output_tx,
+    };
     let mut embedded_shell = EmbeddedShellState::default();
+    let mut persisted_session = (String::new(), app.namespace_scope().clone());
 
     loop {
         terminal
             .draw(|frame| ui::render(frame, app))
             .context("failed to render terminal frame")?;
+        persist_session_state(app, &mut persisted_session);
 
         if !app.running() {
             break;
@@ -299,10 +494,28 @@ async fn run_loop(
             maybe_event = reader.next() => {
                 match maybe_event {
                     Some(Ok(Event::Key(key))) if key.kind == KeyEventKind::Press => {
+                        app.mark_input();
+                        let active_tick_ms = app.refresh_tick_ms(refresh_ms);
+                        if ticker_ms != active_tick_ms {
+                            ticker_ms = active_tick_ms;
+                            ticker = interval(Duration::from_millis(ticker_ms));
+                            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                            app.set_status(format!("Activity resumed: refresh every {ticker_ms}ms"));
+                        }
+
                         if app.shell_overlay_active()
                             && app.mode() == app::InputMode::Normal
                             && key.code != KeyCode::Esc
                         {
+                            if matches!(key.code, KeyCode::PageUp | KeyCode::PageDown) {
+                                scroll_embedded_shell(
+                                    &mut embedded_shell,
+                                    app,
+                                    key.code == KeyCode::PageUp,
+                                );
+                                continue;
+                            }
+                            reset_embedded_shell_scroll(&mut embedded_shell, app);
                             let _ = forward_key_to_embedded_shell(
                                 key,
                                 &mut embedded_shell.writer,
@@ -312,7 +525,7 @@ async fn run_loop(
                         }
 
                         if app.mode() == app::InputMode::Normal
-                            && let Some(signature) = key_event_signature(key.clone())
+                            && let Some(signature) = key_event_signature(key)
                             && let Some(command) = app.execute_hotkey_signature(&signature)
                         {
                             let was_shell_open = app.shell_overlay_active();
@@ -325,26 +538,25 @@ async fn run_loop(
                                     app,
                                     gateway,
                                     command,
-                                    &pf_tx,
-                                    &shell_output_tx,
+                                    &senders,
                                     &mut embedded_shell,
                                 )
                                 .await;
                             if was_shell_open && !app.shell_overlay_active() {
                                 stop_embedded_shell(&mut embedded_shell).await;
                             }
-                            if matches!(effect, LoopEffect::RestartWatchers) {
-                                restart_watchers(
-                                    &mut watch_tasks,
-                                    gateway.client(),
-                                    watch_tx.clone(),
-                                );
-                                watch_throttle.clear();
-                            }
+                            apply_loop_effect(
+                                effect,
+                                app,
+                                &mut watch_tasks,
+                                gateway.client(),
+                                &watch_tx,
+                                &mut watch_throttle,
+                            );
                             continue;
                         }
 
-                        if let Some(action) = input::map_key(app.mode(), key) {
+                        if let Some(action) = input::map_key(app.key_mode(), key) {
                             debug!("action={action:?}");
                             let was_shell_open = app.shell_overlay_active();
                             let command = app.apply_action(action);
@@ -357,17 +569,20 @@ async fn run_loop(
                                     app,
                                     gateway,
                                     command,
-                                    &pf_tx,
-                                    &shell_output_tx,
+                                    &senders,
                                     &mut embedded_shell,
                                 ).await;
                             if was_shell_open && !app.shell_overlay_active() {
                                 stop_embedded_shell(&mut embedded_shell).await;
                             }
-                            if matches!(effect, LoopEffect::RestartWatchers) {
-                                restart_watchers(&mut watch_tasks, gateway.client(), watch_tx.clone());
-                                watch_throttle.clear();
-                            }
+                            apply_loop_effect(
+                                effect,
+                                app,
+                                &mut watch_tasks,
+                                gateway.client(),
+                                &watch_tx,
+                                &mut watch_throttle,
+                            );
                         }
                     }
                     Some(Ok(Event::Resize(_, _))) => {}
@@ -390,6 +605,13 @@ async fn run_loop(
                             snapshot.hotkeys,
                             snapshot.source.clone(),
                         );
+                        app.set_confirm_skip(snapshot.skip_confirm);
+                        app.set_dangerous_commands(snapshot.dangerous_commands);
+                        app.set_adopt_context_namespace(snapshot.adopt_context_namespace);
+                        app.set_export_dir(snapshot.export_dir);
+                        app.set_strip_name_prefixes(snapshot.strip_name_prefixes);
+                        app.set_tab_refresh_intervals(snapshot.refresh_intervals);
+                        app.set_terraform_plan_timeout(snapshot.terraform_plan_timeout_secs);
                         let source = snapshot.source.unwrap_or_else(|| "(none)".to_string());
                         app.set_status(format!(
                             "Runtime config reloaded from {} (aliases:{} plugins:{} hotkeys:{})",
@@ -409,7 +631,33 @@ async fn run_loop(
                 }
 
                 let active = app.active_tab();
-                refresh_tab(app, gateway, active).await;
+                if app.tab_refresh_due(active, refresh_ms, Instant::now()) {
+                    refresh_tab(app, gateway, active).await;
+                }
+                if app.reauth_due(Instant::now()) {
+                    let effect = reauthenticate(app, gateway).await;
+                    apply_loop_effect(
+                        effect,
+                        app,
+                        &mut watch_tasks,
+                        gateway.client(),
+                        &watch_tx,
+                        &mut watch_throttle,
+                    );
+                }
+
+                let desired_ms = app.refresh_tick_ms(refresh_ms);
+                if desired_ms != ticker_ms {
+                    ticker_ms = desired_ms;
+                    ticker = interval_at(
+                        tokio::time::Instant::now() + Duration::from_millis(ticker_ms),
+                        Duration::from_millis(ticker_ms),
+                    );
+                    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                    if app.is_idle() {
+                        app.set_status(format!("Idle: refresh slowed to every {ticker_ms}ms"));
+                    }
+                }
 
                 let mut should_reset_shell = false;
                 if let Some(child) = embedded_shell.child.as_mut() {
@@ -431,19 +679,35 @@ async fn run_loop(
                 if should_reset_shell {
                     embedded_shell.child = None;
                     embedded_shell.writer = None;
+                    embedded_shell.parser = None;
                     embedded_shell.application_cursor = false;
+                    embedded_shell.scroll_offset = 0;
                 }
             }
-            maybe_tab = watch_rx.recv() => {
-                if let Some(tab) = maybe_tab
-                    && should_process_watch_event(tab, &mut watch_throttle)
-                    && (tab == app.active_tab() || tab == ResourceTab::Namespaces) {
-                    refresh_tab(app, gateway, tab).await;
+            maybe_event = watch_rx.recv() => match maybe_event {
+                Some(WatchEvent::Changed { tab, activity }) => {
+                    app.mark_watch_healthy(tab);
+                    if let Some(activity) = activity {
+                        app.record_watch_activity(activity);
+                    }
+                    if !app.is_idle()
+                        && should_process_watch_event(tab, &mut watch_throttle)
+                        && (tab == app.active_tab() || tab == ResourceTab::Namespaces) {
+                        refresh_tab(app, gateway, tab).await;
+                    }
                 }
-            }
+                Some(WatchEvent::Failed { tab, error, retry_in }) => {
+                    app.mark_watch_degraded(tab, error, retry_in.as_secs());
+                }
+                None => {}
+            },
             maybe_event = pf_rx.recv() => {
                 if let Some(event) = maybe_event {
+                    if let Ok(mut stops) = senders.port_forward_stops.lock() {
+                        stops.remove(&event.pid);
+                    }
                     let removed = app.remove_port_forward_by_pid(event.pid);
+                    app.refresh_port_forward_overlay();
                     let target = format!(
                         "{} {}/{} {}:{}",
                         event.tab.title(),
@@ -459,9 +723,11 @@ async fn run_loop(
                             }
                         }
                         Ok(status) => {
-                            app.set_status(format!(
-                                "Port-forward exited ({status}) for {target}"
-                            ));
+                            if removed.is_some() {
+                                app.set_status(format!(
+                                    "Port-forward exited ({status}) for {target}"
+                                ));
+                            }
                         }
                         Err(error) => {
                             app.set_status(format!("Port-forward failed for {target}: {error}"));
@@ -469,6 +735,53 @@ async fn run_loop(
                     }
                 }
             }
+            maybe_event = rollout_rx.recv() => {
+                if let Some(event) = maybe_event {
+                    let target = format!("{} {}/{}", event.tab.title(), event.namespace, event.name);
+                    let elapsed = event.elapsed.as_secs();
+                    match event.update {
+                        RolloutUpdate::Progress(message) => {
+                            app.set_status(format!("Rollout {target}: {message} ({elapsed}s)"));
+                        }
+                        RolloutUpdate::Complete(message) => {
+                            app.set_status(format!(
+                                "Rollout complete: {target} {message} ({elapsed}s)"
+                            ));
+                            let active = app.active_tab();
+                            refresh_tab(app, gateway, active).await;
+                        }
+                        RolloutUpdate::Failed(message) => {
+                            app.set_status(format!(
+                                "Rollout failed: {target}: {message} ({elapsed}s)"
+                            ));
+                        }
+                        RolloutUpdate::TimedOut(message) => {
+                            app.set_status(format!(
+                                "Rollout watch timed out after {elapsed}s: {target}: {message}"
+                            ));
+                        }
+                        RolloutUpdate::Error(error) => {
+                            app.set_status(format!("Rollout watch failed for {target}: {error}"));
+                        }
+                    }
+                }
+            }
+            maybe_event = job_rerun_rx.recv() => {
+                if let Some(event) = maybe_event {
+                    let (namespace, name) = (event.namespace, event.name);
+                    match event.result {
+                        Ok(()) => {
+                            app.set_status(format!(
+                                "Job {namespace}/{name} recreated from its spec (l tails the new pod)"
+                            ));
+                            refresh_tab(app, gateway, ResourceTab::Jobs).await;
+                        }
+                        Err(error) => app.set_status(format!(
+                            "Rerun failed for Job {namespace}/{name}: {error}"
+                        )),
+                    }
+                }
+            }
             maybe_shell_output = shell_output_rx.recv() => {
                 if let Some(event) = maybe_shell_output
                     && app.shell_overlay_active() {
@@ -476,6 +789,14 @@ async fn run_loop(
                     app.replace_shell_output(event.snapshot);
                 }
             }
+            maybe_plugin_output = plugin_output_rx.recv() => {
+                if let Some(event) = maybe_plugin_output {
+                    app.replace_stream_output(&event.title, event.snapshot);
+                    if let Some(status) = event.finished {
+                        app.set_status(status);
+                    }
+                }
+            }
         }
     }
 
@@ -488,10 +809,12 @@ async fn execute_app_command(
     app: &mut App,
     gateway: &mut KubeGateway,
     command: AppCommand,
-    pf_tx: &mpsc::UnboundedSender<PortForwardExitEvent>,
-    shell_output_tx: &mpsc::UnboundedSender<ShellOutputEvent>,
+    senders: &EventSenders,
     embedded_shell: &mut EmbeddedShellState,
 ) -> LoopEffect {
+    if !app.allow_command(&command) {
+        return LoopEffect::None;
+    }
     match command {
         AppCommand::None => {}
         AppCommand::RefreshActive => {
@@ -499,11 +822,14 @@ async fn execute_app_command(
             refresh_tab(app, gateway, tab).await;
         }
         AppCommand::RefreshAll => {
-            let tabs = app.tabs().to_vec();
+            let tabs = app.visible_tabs();
             for tab in tabs {
                 refresh_tab(app, gateway, tab).await;
             }
         }
+        AppCommand::RefreshTab { tab } => {
+            refresh_tab(app, gateway, tab).await;
+        }
         AppCommand::RefreshCustomResourceCatalog => {
             refresh_custom_resource_catalog(app, gateway).await;
             if app.active_tab() == ResourceTab::CustomResources {
@@ -515,6 +841,8 @@ async fn execute_app_command(
             pod_name,
             container,
             previous,
+            tail,
+            window,
         } => {
             let mut resolved_container = container.clone();
             if resolved_container.is_none()
@@ -529,6 +857,8 @@ async fn execute_app_command(
                     &pod_name,
                     resolved_container.as_deref(),
                     previous,
+                    tail,
+                    window,
                 )
                 .await
             {
@@ -543,6 +873,7 @@ async fn execute_app_command(
                         (None, true) => format!("Pod Logs (previous) {namespace}/{pod_name}"),
                         (None, false) => format!("Pod Logs {namespace}/{pod_name}"),
                     };
+                    let title = format!("{title} ({})", log_range_label(tail, window));
                     app.set_pod_logs_overlay(title, logs);
                     app.set_status(match resolved_container.as_deref() {
                         Some(container) => {
@@ -563,6 +894,8 @@ async fn execute_app_command(
             namespace,
             name,
             previous,
+            tail,
+            window,
         } => match gateway
             .resolve_log_target(tab, namespace.as_deref(), &name)
             .await
@@ -573,6 +906,8 @@ async fn execute_app_command(
                     &target.pod_name,
                     target.container.as_deref(),
                     previous,
+                    tail,
+                    window,
                 )
                 .await
             {
@@ -593,6 +928,7 @@ async fn execute_app_command(
                         }
                         (None, false) => format!("Logs {}/{}", target.namespace, target.pod_name),
                     };
+                    let title = format!("{title} ({})", log_range_label(tail, window));
                     app.set_related_logs_overlay(title, logs);
                     app.set_status(format!(
                         "Loaded related logs via {} for {}/{}",
@@ -630,7 +966,14 @@ async fn execute_app_command(
             kind,
             namespace,
             name,
-        } => match fetch_argocd_resource_panel(&kind, namespace.as_deref(), &name).await {
+        } => match fetch_argocd_resource_panel(
+            gateway.kubectl_target(),
+            &kind,
+            namespace.as_deref(),
+            &name,
+        )
+        .await
+        {
             Ok((title, panel)) => {
                 app.set_detail_overlay(title, panel);
                 app.set_status(match namespace.as_deref() {
@@ -652,55 +995,62 @@ async fn execute_app_command(
             namespace,
             name,
             section,
-        } => match fetch_argocd_resource_panel_sections(&kind, namespace.as_deref(), &name).await {
-            Ok((title, sections)) => {
-                let (panel_title, panel_text) = match section {
-                    ArgoResourcePanelSection::Events => (
-                        format!("{title} Events"),
-                        format!("EVENTS\n{}", sections.events),
-                    ),
-                    ArgoResourcePanelSection::Manifest => (
-                        format!("{title} Manifest"),
-                        format!("LIVE MANIFEST\n{}", sections.manifest),
-                    ),
-                };
-                app.set_output_overlay(panel_title, panel_text);
-                app.set_status(match section {
-                    ArgoResourcePanelSection::Events => format!(
-                        "Loaded Argo events for {} {}/{}",
-                        kind,
-                        namespace.as_deref().unwrap_or("-"),
-                        name
-                    ),
-                    ArgoResourcePanelSection::Manifest => format!(
-                        "Loaded Argo manifest for {} {}/{}",
-                        kind,
-                        namespace.as_deref().unwrap_or("-"),
-                        name
+        } => {
+            let loaded = match section {
+                ArgoResourcePanelSection::Diff => match app.argocd_selected_app() {
+                    Some(argo_app) => {
+                        let argo_app = argo_app.to_string();
+                        run_argocd_diff(&argo_app).await.map(|output| {
+                            argocd_resource_diff_section(&argo_app, &output, &kind, &name)
+                        })
+                    }
+                    None => Err(
+                        "Argo diff needs an application; open one with :argocd <app>".to_string(),
                     ),
-                });
-            }
-            Err(error) => {
-                let title = match namespace.as_deref() {
-                    Some(namespace) => format!("Argo {kind} {namespace}/{name}"),
-                    None => format!("Argo {kind} {name}"),
-                };
-                app.set_output_overlay(
-                    match section {
-                        ArgoResourcePanelSection::Events => format!("{title} Events"),
-                        ArgoResourcePanelSection::Manifest => format!("{title} Manifest"),
-                    },
-                    error.clone(),
-                );
-                app.set_status(format!("Argo section load failed: {error}"));
+                },
+                _ => fetch_argocd_resource_panel_sections(
+                    gateway.kubectl_target(),
+                    &kind,
+                    namespace.as_deref(),
+                    &name,
+                )
+                .await
+                .map(|(_, sections)| match section {
+                    ArgoResourcePanelSection::Events => format!("EVENTS\n{}", sections.events),
+                    _ => format!("LIVE MANIFEST\n{}", sections.manifest),
+                }),
+            };
+            let target = format!("{} {}/{}", kind, namespace.as_deref().unwrap_or("-"), name);
+            match loaded {
+                Ok(text) => {
+                    app.set_argo_panel_section_overlay(
+                        &kind,
+                        namespace.as_deref(),
+                        &name,
+                        section,
+                        text,
+                    );
+                    app.set_status(format!("Loaded Argo {} for {target}", section.label()));
+                }
+                Err(error) => {
+                    app.set_argo_panel_section_overlay(
+                        &kind,
+                        namespace.as_deref(),
+                        &name,
+                        section,
+                        error.clone(),
+                    );
+                    app.set_status(format!("Argo section load failed: {error}"));
+                }
             }
-        },
+        }
         AppCommand::DeleteSelected {
             tab,
             namespace,
             name,
+            propagation,
         } => match gateway
-            .delete_resource(tab, namespace.as_deref(), &name)
+            .delete_resource(tab, namespace.as_deref(), &name, propagation)
             .await
         {
             Ok(()) => {
@@ -718,6 +1068,101 @@ async fn execute_app_command(
                 name
             )),
         },
+        AppCommand::BulkDelete {
+            tab,
+            custom,
+            targets,
+            propagation,
+        } => {
+            let kind = custom
+                .as_ref()
+                .map_or_else(|| tab.title().to_string(), |custom| custom.kind.clone());
+            let mut deleted = 0;
+            let mut failed = Vec::new();
+            let mut first_error = None;
+            for (namespace, name) in targets {
+                let result = match custom.as_ref() {
+                    Some(custom) => {
+                        gateway
+                            .delete_custom_resource(
+                                custom,
+                                namespace.as_deref(),
+                                &name,
+                                propagation,
+                            )
+                            .await
+                    }
+                    None => {
+                        gateway
+                            .delete_resource(tab, namespace.as_deref(), &name, propagation)
+                            .await
+                    }
+                };
+                match result {
+                    Ok(()) => deleted += 1,
+                    Err(error) => {
+                        first_error.get_or_insert_with(|| format!("{name}: {error:#}"));
+                        failed.push((namespace, name));
+                    }
+                }
+            }
+            let summary = bulk_summary("deleted", deleted, failed.len(), first_error);
+            app.set_row_marks(tab, failed);
+            app.set_status(format!("{kind}: {summary}"));
+            refresh_tab(app, gateway, tab).await;
+        }
+        AppCommand::BulkRestart { tab, targets } => {
+            let mut restarted = 0;
+            let mut failed = Vec::new();
+            let mut first_error = None;
+            for (namespace, name) in targets {
+                match gateway.restart_workload(tab, &namespace, &name).await {
+                    Ok(()) => restarted += 1,
+                    Err(error) => {
+                        first_error.get_or_insert_with(|| format!("{name}: {error:#}"));
+                        failed.push((Some(namespace), name));
+                    }
+                }
+            }
+            let summary = bulk_summary("restarted", restarted, failed.len(), first_error);
+            app.set_row_marks(tab, failed);
+            app.set_status(format!("{}: {summary}", tab.title()));
+            refresh_tab(app, gateway, tab).await;
+        }
+        AppCommand::ForceDeletePod { namespace, name } => {
+            match gateway.force_delete_pod(&namespace, &name).await {
+                Ok(()) => {
+                    app.set_status(format!("Force deleted Pods {namespace}/{name}"));
+                    refresh_tab(app, gateway, ResourceTab::Pods).await;
+                }
+                Err(error) => app.set_status(format!(
+                    "Force delete failed for Pods {namespace}/{name}: {error:#}"
+                )),
+            }
+        }
+        AppCommand::DeleteCustomResource {
+            custom,
+            namespace,
+            name,
+            propagation,
+        } => match gateway
+            .delete_custom_resource(&custom, namespace.as_deref(), &name, propagation)
+            .await
+        {
+            Ok(()) => {
+                match namespace {
+                    Some(namespace) => {
+                        app.set_status(format!("Deleted {} {}/{}", custom.kind, namespace, name))
+                    }
+                    None => app.set_status(format!("Deleted {} {}", custom.kind, name)),
+                }
+                refresh_tab(app, gateway, ResourceTab::CustomResources).await;
+            }
+            Err(error) => app.set_status(format!(
+                "Delete failed for {} {}: {error:#}",
+                custom.kind, name
+            )),
+        },
         AppCommand::RestartWorkload {
             tab,
             namespace,
@@ -739,6 +1184,44 @@ async fn execute_app_command(
                 name
             )),
         },
+        AppCommand::RerunJob { namespace, name } => {
+            app.set_status(format!("Rerunning Job {namespace}/{name}…"));
+            spawn_job_rerun(gateway.clone(), namespace, name, senders.job_rerun.clone());
+        }
+        AppCommand::CreateJobFromCronJob { namespace, cronjob } => {
+            let job = manual_job_name(&cronjob, Local::now().timestamp());
+            let args =
+                create_job_from_cronjob_args(gateway.kubectl_target(), &namespace, &cronjob, &job);
+            match run_external_readonly("kubectl", &args, 20).await {
+                Ok(_) => {
+                    app.focus_row_in_tab(ResourceTab::Jobs, Some(namespace.clone()), job.clone());
+                    refresh_tab(app, gateway, ResourceTab::Jobs).await;
+                    app.set_status(format!(
+                        "Created Job {namespace}/{job} from CronJob {cronjob} (Esc returns to CronJobs)"
+                    ));
+                }
+                Err(error) => app.set_status(format!(
+                    "Creating a Job from CronJob {namespace}/{cronjob} failed: {error}"
+                )),
+            }
+        }
+        AppCommand::ToggleCronJobSuspend {
+            namespace,
+            name,
+            suspend,
+        } => match gateway
+            .set_cronjob_suspend(&namespace, &name, suspend)
+            .await
+        {
+            Ok(()) => {
+                let state = if suspend { "suspended" } else { "resumed" };
+                app.set_status(format!("CronJob {namespace}/{name} {state}"));
+                refresh_tab(app, gateway, ResourceTab::CronJobs).await;
+            }
+            Err(error) => app.set_status(format!(
+                "Suspend toggle failed for CronJob {namespace}/{name}: {error:#}"
+            )),
+        },
         AppCommand::ScaleWorkload {
             tab,
             namespace,
@@ -765,61 +1248,169 @@ async fn execute_app_command(
                 name
             )),
         },
-        AppCommand::ExecInPod {
+        AppCommand::ScaleCustomResource {
+            custom,
             namespace,
-            pod_name,
-            command,
-        } => match run_kubectl_exec(&namespace, &pod_name, &command).await {
-            Ok(output) => {
-                app.set_detail_overlay("Exec Output", output);
-                app.set_status(format!("Exec completed for {namespace}/{pod_name}"));
-            }
-            Err(error) => {
-                app.set_status(format!("Exec failed for {namespace}/{pod_name}: {error:#}"))
+            name,
+            replicas,
+        } => match gateway
+            .scale_custom_resource(&custom, namespace.as_deref(), &name, replicas)
+            .await
+        {
+            Ok(()) => {
+                app.set_status(format!(
+                    "Scaled {} {} to {} replicas",
+                    custom.kind, name, replicas
+                ));
+                refresh_tab(app, gateway, ResourceTab::CustomResources).await;
             }
+            Err(error) => app.set_status(format!(
+                "Scale failed for {} {}: {error:#}",
+                custom.kind, name
+            )),
         },
-        AppCommand::OpenPodShell {
+        AppCommand::ExecInPod {
             namespace,
             pod_name,
-            container,
-            shell,
+            command,
+            tty: true,
         } => {
             stop_embedded_shell(embedded_shell).await;
-            match start_embedded_kubectl_shell(&namespace, &pod_name, container.as_deref(), &shell)
-            {
+            let size = terminal
+                .size()
+                .map(exec_pty_size)
+                .unwrap_or(DEFAULT_SHELL_PTY_SIZE);
+            match start_embedded_kubectl_exec(
+                gateway.kubectl_target(),
+                &namespace,
+                &pod_name,
+                &command,
+                size,
+            ) {
                 Ok(started) => {
-                    let title = match container.as_deref() {
-                        Some(container) => {
-                            format!("Shell {namespace}/{pod_name}:{container} ({shell})")
-                        }
-                        None => format!("Shell {namespace}/{pod_name} ({shell})"),
-                    };
                     app.set_shell_overlay(
-                        title,
-                        "[orca] embedded shell started (Esc to close)\n".to_string(),
+                        format!("Exec {namespace}/{pod_name}: {}", command.join(" ")),
+                        "[orca] tty exec started (Esc to close)\n".to_string(),
                     );
-
-                    spawn_shell_reader(started.reader, shell_output_tx.clone());
-                    embedded_shell.child = Some(started.child);
-                    embedded_shell.writer = Some(started.writer);
-                    embedded_shell.application_cursor = false;
+                    attach_embedded_shell(embedded_shell, started, senders.shell_output.clone());
                     app.set_status(format!(
-                        "Embedded shell opened for {namespace}/{pod_name} (Esc to close)"
+                        "TTY exec running in {namespace}/{pod_name} (Esc to close)"
                     ));
                 }
-                Err(error) => app.set_status(format!(
-                    "Shell failed for {namespace}/{pod_name}: {error:#}"
-                )),
+                Err(error) => {
+                    app.set_status(format!("Exec failed for {namespace}/{pod_name}: {error:#}"))
+                }
             }
         }
-        AppCommand::EditSelected {
-            resource,
+        AppCommand::ExecInPod {
             namespace,
-            name,
-        } => match run_kubectl_edit(terminal, &resource, namespace.as_deref(), &name).await {
-            Ok(()) => {
-                app.set_status(match namespace {
-                    Some(namespace) => format!("Edited {resource} {namespace}/{name}"),
+            pod_name,
+            command,
+            tty: false,
+        } => {
+            match run_kubectl_exec(gateway.kubectl_target(), &namespace, &pod_name, &command).await
+            {
+                Ok(output) => {
+                    app.set_detail_overlay("Exec Output", output);
+                    app.set_status(format!("Exec completed for {namespace}/{pod_name}"));
+                }
+                Err(error) => {
+                    app.set_status(format!("Exec failed for {namespace}/{pod_name}: {error:#}"))
+                }
+            }
+        }
+        AppCommand::DebugPod {
+            namespace,
+            pod_name,
+            image,
+            target_container,
+        } => {
+            stop_embedded_shell(embedded_shell).await;
+            let size = terminal
+                .size()
+                .map(exec_pty_size)
+                .unwrap_or(DEFAULT_SHELL_PTY_SIZE);
+            match start_embedded_kubectl_debug(
+                gateway.kubectl_target(),
+                &namespace,
+                &pod_name,
+                &image,
+                target_container.as_deref(),
+                size,
+            ) {
+                Ok(started) => {
+                    let title = match target_container.as_deref() {
+                        Some(container) => {
+                            format!("Debug {namespace}/{pod_name} -> {container} ({image})")
+                        }
+                        None => format!("Debug {namespace}/{pod_name} ({image})"),
+                    };
+                    app.set_shell_overlay(
+                        title,
+                        "[orca] ephemeral debug container starting (Esc to close)\n".to_string(),
+                    );
+                    attach_embedded_shell(embedded_shell, started, senders.shell_output.clone());
+                    app.set_status(format!(
+                        "Debug container attached to {namespace}/{pod_name} (Esc to close)"
+                    ));
+                }
+                Err(error) => app.set_status(format!(
+                    "Debug failed for {namespace}/{pod_name}: {error:#}"
+                )),
+            }
+        }
+        AppCommand::OpenPodShell {
+            namespace,
+            pod_name,
+            container,
+            shell,
+        } => {
+            stop_embedded_shell(embedded_shell).await;
+            match start_embedded_kubectl_shell(
+                gateway.kubectl_target(),
+                &namespace,
+                &pod_name,
+                container.as_deref(),
+                &shell,
+            ) {
+                Ok(started) => {
+                    let title = match container.as_deref() {
+                        Some(container) => {
+                            format!("Shell {namespace}/{pod_name}:{container} ({shell})")
+                        }
+                        None => format!("Shell {namespace}/{pod_name} ({shell})"),
+                    };
+                    app.set_shell_overlay(
+                        title,
+                        "[orca] embedded shell started (Esc to close)\n".to_string(),
+                    );
+
+                    attach_embedded_shell(embedded_shell, started, senders.shell_output.clone());
+                    app.set_status(format!(
+                        "Embedded shell opened for {namespace}/{pod_name} (Esc to close)"
+                    ));
+                }
+                Err(error) => app.set_status(format!(
+                    "Shell failed for {namespace}/{pod_name}: {error:#}"
+                )),
+            }
+        }
+        AppCommand::EditSelected {
+            resource,
+            namespace,
+            name,
+        } => match run_kubectl_edit(
+            terminal,
+            gateway.kubectl_target(),
+            &resource,
+            namespace.as_deref(),
+            &name,
+        )
+        .await
+        {
+            Ok(()) => {
+                app.set_status(match namespace {
+                    Some(namespace) => format!("Edited {resource} {namespace}/{name}"),
                     None => format!("Edited {resource} {name}"),
                 });
                 refresh_tab(app, gateway, app.active_tab()).await;
@@ -833,50 +1424,43 @@ async fn execute_app_command(
             local_port,
             remote_port,
         } => {
-            match run_kubectl_port_forward(tab, &namespace, &name, local_port, remote_port).await {
-                Ok((pid, mut child)) => {
-                    app.register_port_forward(
-                        tab,
-                        namespace.clone(),
-                        name.clone(),
-                        local_port,
-                        remote_port,
-                        pid,
-                    );
-                    let target = match tab {
-                        ResourceTab::Pods => format!("pod/{name}"),
-                        ResourceTab::Services => format!("service/{name}"),
-                        _ => name.clone(),
-                    };
-                    app.set_status(format!(
-                        "Port-forward started ({target}) {local_port}:{remote_port} pid={pid}"
-                    ));
-
-                    let tx = pf_tx.clone();
-                    tokio::spawn(async move {
-                        let result = child
-                            .wait()
-                            .await
-                            .map_err(|error| format!("wait failed: {error}"));
-                        let _ = tx.send(PortForwardExitEvent {
-                            pid,
-                            tab,
-                            namespace,
-                            name,
-                            local_port,
-                            remote_port,
-                            result,
-                        });
-                    });
-                }
-                Err(error) => app.set_status(format!(
-                    "Port-forward failed for {} {}/{}: {error:#}",
-                    tab.title(),
+            let kube_target = gateway.kubectl_target();
+            start_port_forward(
+                app,
+                senders,
+                kube_target,
+                tab,
+                namespace,
+                name,
+                local_port,
+                remote_port,
+            )
+            .await
+        }
+        AppCommand::StartNamedPortForward {
+            namespace,
+            name,
+            local_port,
+            port_name,
+        } => match gateway
+            .resolve_service_port(&namespace, &name, &port_name)
+            .await
+        {
+            Ok(remote_port) => {
+                start_port_forward(
+                    app,
+                    senders,
+                    gateway.kubectl_target(),
+                    ResourceTab::Services,
                     namespace,
-                    name
-                )),
+                    name,
+                    local_port,
+                    remote_port,
+                )
+                .await
             }
-        }
+            Err(error) => app.set_status(format!("Port-forward failed: {error:#}")),
+        },
         AppCommand::InspectTooling => {
             let report = inspect_toolchain().await;
             app.set_output_overlay("Toolchain Inventory", report);
@@ -904,15 +1488,41 @@ async fn execute_app_command(
         },
         AppCommand::InspectOps { target } => {
             let refresh_target = target.clone();
-            let (title, report, status) = inspect_ops_target(target, app.namespace_scope()).await;
-            app.set_output_overlay(title, report);
+            let (title, report, status) = match &target {
+                OpsInspectTarget::TerraformPlan { dir, .. } => {
+                    let label = format!("terraform plan in {dir}");
+                    let scope = app.namespace_scope().clone();
+                    let kube_target = gateway.kubectl_target();
+                    with_spinner(
+                        terminal,
+                        app,
+                        &label,
+                        inspect_ops_target(target, &scope, kube_target),
+                    )
+                    .await
+                }
+                _ => {
+                    inspect_ops_target(target, app.namespace_scope(), gateway.kubectl_target())
+                        .await
+                }
+            };
+            match &refresh_target {
+                OpsInspectTarget::HelmRelease { name, section } => {
+                    app.set_helm_release_overlay(name, *section, report)
+                }
+                OpsInspectTarget::HelmDiff { .. } | OpsInspectTarget::GitDiff { .. } => {
+                    app.set_diff_overlay(title, report)
+                }
+                _ => app.set_output_overlay(title, report),
+            }
             app.set_status(status);
             if matches!(
                 refresh_target,
-                OpsInspectTarget::ArgoCdSync { .. }
+                OpsInspectTarget::ArgoCdSync { dry_run: false, .. }
                     | OpsInspectTarget::ArgoCdRefresh { .. }
                     | OpsInspectTarget::ArgoCdRollback { .. }
                     | OpsInspectTarget::ArgoCdDelete { .. }
+                    | OpsInspectTarget::ArgoCdCreate { .. }
             ) {
                 refresh_tab(app, gateway, ResourceTab::ArgoCdApps).await;
                 if matches!(
@@ -955,81 +1565,458 @@ async fn execute_app_command(
                 ));
             }
         },
-        AppCommand::RunPlugin { run } => match run_plugin_command(&run).await {
-            Ok(output) => {
-                app.set_output_overlay(format!("Plugin {}", run.name), output);
-                app.set_status(format!("Plugin '{}' finished", run.name));
+        AppCommand::DecodeSecret { namespace, name } => {
+            match gateway.fetch_secret_entries(&namespace, &name).await {
+                Ok(entries) => {
+                    let count = entries.len();
+                    app.set_secret_overlay(format!("Secret {namespace}/{name}"), entries);
+                    app.set_status(format!(
+                        "Decoded {count} keys from {namespace}/{name} (x to reveal values)"
+                    ));
+                }
+                Err(error) => {
+                    app.set_status(format!(
+                        "Secret decode failed for {namespace}/{name}: {error:#}"
+                    ));
+                }
+            }
+        }
+        AppCommand::OpenArgoSource {
+            app: app_name,
+            repo,
+            path,
+            revision,
+        } => match ensure_repo_checkout(&repo, revision.as_deref()).await {
+            Ok(summary) => {
+                let target = resolve_source_edit_target(&summary.path.join(path.trim_matches('/')));
+                match run_local_editor(terminal, &target).await {
+                    Ok(()) => app.set_status(format!(
+                        "Edited {} for Argo CD app {app_name} (local checkout only)",
+                        target.display()
+                    )),
+                    Err(error) => {
+                        app.set_status(format!("Editor failed for {}: {error:#}", target.display()))
+                    }
+                }
             }
             Err(error) => {
-                app.set_output_overlay(format!("Plugin {}", run.name), format!("{error:#}"));
-                app.set_status(format!("Plugin '{}' failed", run.name));
+                app.set_status(format!("Source checkout failed for {app_name}: {error}"));
             }
         },
-        AppCommand::SwitchContext { context } => match gateway.switch_context(&context).await {
+        AppCommand::OpenScratchYaml {
+            file_stem,
+            manifest,
+        } => match write_scratch_manifest(&file_stem, &manifest) {
+            Ok(path) => match run_local_editor(terminal, &path).await {
+                Ok(()) => app.set_status(format!(
+                    "Scratch copy saved at {} (not applied)",
+                    path.display()
+                )),
+                Err(error) => app.set_status(format!(
+                    "Editor failed for scratch copy {}: {error:#}",
+                    path.display()
+                )),
+            },
+            Err(error) => app.set_status(format!("Scratch copy failed: {error:#}")),
+        },
+        AppCommand::ApplyYaml {
+            tab,
+            kind,
+            namespace,
+            name,
+            yaml,
+            resource_version,
+        } => match gateway
+            .apply_yaml(
+                &kind,
+                namespace.as_deref(),
+                &name,
+                &yaml,
+                resource_version.as_deref(),
+            )
+            .await
+        {
             Ok(()) => {
-                app.set_kube_target(
-                    gateway.cluster().to_string(),
-                    gateway.context().to_string(),
-                    gateway.user().to_string(),
-                    gateway.default_namespace().to_string(),
-                    true,
-                );
-                app.set_kube_catalog(
-                    gateway.available_contexts(),
-                    gateway.available_clusters(),
-                    gateway.available_users(),
-                    gateway.context_catalog(),
-                );
-                refresh_custom_resource_catalog(app, gateway).await;
-                let tabs = app.tabs().to_vec();
-                for tab in tabs {
-                    refresh_tab(app, gateway, tab).await;
+                app.finish_yaml_apply(Ok(()));
+                refresh_tab(app, gateway, tab).await;
+            }
+            Err(error) => app.finish_yaml_apply(Err(format!("{error:#}"))),
+        },
+        AppCommand::RestartWatchers => return LoopEffect::RewatchRequested,
+        AppCommand::StopPortForward { pid } => {
+            let stop = senders
+                .port_forward_stops
+                .lock()
+                .ok()
+                .and_then(|mut stops| stops.remove(&pid));
+            let signalled = stop.is_some_and(|stop| stop.send(()).is_ok());
+            let stopped = app.remove_port_forward_by_pid(pid);
+            app.refresh_port_forward_overlay();
+            app.set_status(match stopped {
+                Some(session) if signalled => format!(
+                    "Stopped port-forward {}/{} {}:{} (pid {pid})",
+                    session.namespace, session.name, session.local_port, session.remote_port
+                ),
+                _ if signalled => format!("Stopped port-forward pid {pid}"),
+                _ => format!("Port-forward pid {pid} had already exited"),
+            });
+        }
+        AppCommand::WatchRollout {
+            tab,
+            namespace,
+            name,
+            timeout_secs,
+        } => spawn_rollout_watch(
+            gateway.clone(),
+            tab,
+            namespace,
+            name,
+            Duration::from_secs(timeout_secs),
+            senders.rollout.clone(),
+        ),
+        AppCommand::RolloutStatus {
+            tab,
+            namespace,
+            name,
+        } => {
+            let target = format!("{}/{name}", rollout_kind(tab));
+            let args = vec![
+                "rollout".to_string(),
+                "status".to_string(),
+                target.clone(),
+                "-n".to_string(),
+                namespace.clone(),
+                format!("--timeout={ROLLOUT_STATUS_TIMEOUT_SECS}s"),
+            ];
+            let title = format!("Rollout status {} {namespace}/{name}", tab.title());
+            match run_external_readonly(
+                "kubectl",
+                &kubectl_args(gateway.kubectl_target(), &args),
+                ROLLOUT_STATUS_TIMEOUT_SECS + 5,
+            )
+            .await
+            {
+                Ok(output) => {
+                    app.set_output_overlay(title, output);
+                    app.set_status(format!("Rollout status loaded for {namespace}/{target}"));
+                }
+                Err(error) => {
+                    app.set_output_overlay(title, error);
+                    app.set_status(format!(
+                        "Rollout of {namespace}/{target} not complete within {ROLLOUT_STATUS_TIMEOUT_SECS}s (:rollout watch follows it)"
+                    ));
                 }
-                app.set_status(format!(
-                    "Switched context to '{}' ({})",
-                    gateway.context(),
-                    gateway.cluster()
-                ));
-                return LoopEffect::RestartWatchers;
             }
-            Err(error) => {
-                app.set_status(format!("Context switch failed for '{context}': {error:#}"))
+        }
+        AppCommand::RolloutHistory {
+            tab,
+            namespace,
+            name,
+        } => {
+            let target = format!("{}/{name}", rollout_kind(tab));
+            let args = vec![
+                "rollout".to_string(),
+                "history".to_string(),
+                target.clone(),
+                "-n".to_string(),
+                namespace.clone(),
+            ];
+            match run_external_readonly(
+                "kubectl",
+                &kubectl_args(gateway.kubectl_target(), &args),
+                20,
+            )
+            .await
+            {
+                Ok(output) => {
+                    app.set_output_overlay(
+                        format!("Rollout history {} {namespace}/{name}", tab.title()),
+                        output,
+                    );
+                    app.set_status(format!("Rollout history loaded for {namespace}/{target}"));
+                }
+                Err(error) => {
+                    app.set_status(format!(
+                        "Rollout history failed for {namespace}/{target}: {error}"
+                    ));
+                }
             }
-        },
-        AppCommand::SwitchCluster { cluster } => match gateway.switch_cluster(&cluster).await {
-            Ok(context) => {
-                app.set_kube_target(
-                    gateway.cluster().to_string(),
-                    gateway.context().to_string(),
-                    gateway.user().to_string(),
-                    gateway.default_namespace().to_string(),
-                    true,
-                );
-                app.set_kube_catalog(
-                    gateway.available_contexts(),
-                    gateway.available_clusters(),
-                    gateway.available_users(),
-                    gateway.context_catalog(),
-                );
-                refresh_custom_resource_catalog(app, gateway).await;
-                let tabs = app.tabs().to_vec();
-                for tab in tabs {
+        }
+        AppCommand::RolloutUndo {
+            tab,
+            namespace,
+            name,
+            to_revision,
+        } => {
+            let target = format!("{}/{name}", rollout_kind(tab));
+            let args = rollout_undo_args(
+                gateway.kubectl_target(),
+                tab,
+                &namespace,
+                &name,
+                to_revision,
+            );
+            match run_external_readonly("kubectl", &args, 30).await {
+                Ok(output) => {
+                    app.set_status(format!(
+                        "Rollback triggered for {namespace}/{target}: {}",
+                        output.lines().next().unwrap_or("done")
+                    ));
                     refresh_tab(app, gateway, tab).await;
                 }
-                app.set_status(format!(
-                    "Switched cluster '{}' via context '{}' ({})",
-                    cluster,
-                    context,
-                    gateway.cluster()
-                ));
-                return LoopEffect::RestartWatchers;
+                Err(error) => {
+                    app.set_status(format!("Rollback failed for {namespace}/{target}: {error}"));
+                }
             }
-            Err(error) => {
-                app.set_status(format!("Cluster switch failed for '{cluster}': {error:#}"))
+        }
+        AppCommand::ExportSelectedYaml {
+            tab,
+            namespace,
+            name,
+            path,
+        } => {
+            let Some(detail) = app.row_detail(tab, namespace.as_deref(), &name) else {
+                app.set_status(format!("{} '{name}' is no longer loaded", tab.title()));
+                return LoopEffect::None;
+            };
+            match write_export_file(&expand_home(&path), &detail) {
+                Ok(written) => app.set_status(format!(
+                    "Saved {} '{name}' YAML to {}",
+                    tab.title(),
+                    written.display()
+                )),
+                Err(error) => app.set_status(format!("YAML export failed: {error}")),
             }
-        },
-        AppCommand::SwitchUser { user } => match gateway.switch_user(&user).await {
+        }
+        AppCommand::ExportNamespace {
+            namespace,
+            dir,
+            include_secrets,
+        } => {
+            let root = expand_home(&dir);
+            match export_namespace_manifests(gateway, &namespace, &root, include_secrets).await {
+                Ok(summary) => app.set_status(summary),
+                Err(error) => {
+                    app.set_status(format!("Export of namespace {namespace} failed: {error:#}"))
+                }
+            }
+        }
+        AppCommand::InspectImages {
+            tab,
+            namespace,
+            name,
+        } => match gateway.image_pull_report(tab, &namespace, &name).await {
+            Ok(report) => {
+                app.set_output_overlay(format!("Images {namespace}/{name}"), report);
+                app.set_status(format!("Image summary loaded for {namespace}/{name}"));
+            }
+            Err(error) => app.set_status(format!(
+                "Image summary failed for {namespace}/{name}: {error:#}"
+            )),
+        },
+        AppCommand::ScanDeprecatedApis => match gateway.scan_deprecated_apis().await {
+            Ok(report) => {
+                app.set_output_overlay("Deprecated APIs", report);
+                app.set_status("Deprecated API scan complete");
+            }
+            Err(error) => app.set_status(format!("Deprecated API scan failed: {error:#}")),
+        },
+        AppCommand::LoadTerraformState { dir } => {
+            let args = vec![
+                format!("-chdir={dir}"),
+                "state".to_string(),
+                "list".to_string(),
+            ];
+            match run_external_readonly("terraform", &args, 20).await {
+                Ok(output) => {
+                    app.open_terraform_state_picker(dir, terraform_state_addresses(&output))
+                }
+                Err(error) => {
+                    app.set_output_overlay(format!("Terraform State {dir}"), error);
+                    app.set_status(format!("Terraform state list failed in {dir}"));
+                }
+            }
+        }
+        AppCommand::LoadNodeUsage => match gateway.fetch_node_metrics_table().await {
+            Ok(table) => {
+                let nodes = table.rows.len();
+                app.set_output_overlay("Node Usage", app::render_table_text(&table));
+                app.set_status(format!("Loaded CPU/memory usage for {nodes} node(s)"));
+            }
+            Err(error) if k8s::is_metrics_api_unavailable(&error) => {
+                app.mark_metrics_unavailable();
+            }
+            Err(error) => app.set_status(format!("Node usage failed: {error:#}")),
+        },
+        AppCommand::LoadNodeNeighbors { node, pod } => {
+            let pod_usage = app.overview_metrics().pod_usage.clone();
+            match gateway.fetch_node_neighbors(&node, &pod_usage).await {
+                Ok(report) => {
+                    app.set_output_overlay(format!("Node Neighbors {node} (from {pod})"), report);
+                    app.set_status(format!("Loaded pods scheduled on {node}"));
+                }
+                Err(error) => {
+                    app.set_status(format!("Node neighbors failed for {node}: {error:#}"));
+                }
+            }
+        }
+        AppCommand::DescribeSelected {
+            tab,
+            resource,
+            namespace,
+            name,
+        } => {
+            let mut args = vec!["describe".to_string(), resource.clone(), name.clone()];
+            if let Some(namespace) = namespace.as_deref() {
+                args.push("-n".to_string());
+                args.push(namespace.to_string());
+            }
+            let target = match namespace.as_deref() {
+                Some(namespace) => format!("{namespace}/{name}"),
+                None => name.clone(),
+            };
+            match run_external_readonly(
+                "kubectl",
+                &kubectl_args(gateway.kubectl_target(), &args),
+                20,
+            )
+            .await
+            {
+                Ok(output) => {
+                    app.set_output_overlay(format!("Describe {} {target}", tab.title()), output);
+                    app.set_status(format!("Described {resource} {target}"));
+                }
+                Err(error) => {
+                    app.set_status(format!("Describe failed for {resource} {target}: {error}"));
+                }
+            }
+        }
+        AppCommand::ShowResourceEvents {
+            kind,
+            namespace,
+            name,
+        } => {
+            let target = match namespace.as_deref() {
+                Some(namespace) => format!("{namespace}/{name}"),
+                None => name.clone(),
+            };
+            match gateway
+                .fetch_resource_events(&kind, namespace.as_deref(), &name)
+                .await
+            {
+                Ok((count, output)) => {
+                    app.set_output_overlay(format!("Events {kind} {target}"), output);
+                    app.set_status(format!("Loaded {count} events for {kind} {target}"));
+                }
+                Err(error) => {
+                    app.set_status(format!("Events failed for {kind} {target}: {error:#}"));
+                }
+            }
+        }
+        AppCommand::ShowTopPods { sort, limit } => {
+            let metrics = app.overview_metrics();
+            let sampled = metrics.pod_usage.len();
+            let output = k8s::top_pod_usage_lines(&metrics.pod_usage, sort, limit);
+            app.set_output_overlay(format!("Top {limit} pods by {}", sort.label()), output);
+            app.set_status(format!(
+                "Ranked {sampled} sampled pods by {}; :top flip or :top <N> to change",
+                sort.label()
+            ));
+        }
+        AppCommand::LoadNamespaceSummary { name } => {
+            let usage = app.overview_metrics().namespace_usage.get(&name).copied();
+            let now = Instant::now();
+            let counts = match app.namespace_counts(&name, now) {
+                Some(counts) => counts,
+                None => match gateway.namespace_counts(&name).await {
+                    Ok(counts) => {
+                        app.cache_namespace_counts(&name, counts, now);
+                        counts
+                    }
+                    Err(error) => {
+                        app.set_status(format!("Namespace summary failed for {name}: {error:#}"));
+                        return LoopEffect::None;
+                    }
+                },
+            };
+            let summary = gateway.namespace_summary(&name, counts, usage).await;
+            app.show_namespace_summary(&name, summary);
+            app.set_status(format!("Namespace summary loaded for {name}"));
+        }
+        AppCommand::CopyToClipboard { label, text } => {
+            let summary = match text.lines().count() {
+                0 | 1 => text.clone(),
+                lines => format!("{lines} lines"),
+            };
+            match clipboard::copy(&text) {
+                Ok(via) => app.set_status(format!("Copied {label} via {via}: {summary}")),
+                Err(error) => app.set_status(format!(
+                    "Clipboard unavailable ({error}); {label}: {summary}"
+                )),
+            }
+        }
+        AppCommand::RunPlugin { run } if run.stream => {
+            let title = format!("Plugin {}", run.name);
+            app.set_output_overlay(
+                title.clone(),
+                plugin_stream_snapshot(&plugin_run_header(&run, 1), &[]),
+            );
+            app.set_status(format!(
+                "Plugin '{}' streaming (Esc closes the overlay)",
+                run.name
+            ));
+            spawn_plugin_stream(run, title, senders.plugin_output.clone());
+        }
+        AppCommand::RunPlugin { run } => match run_plugin_command(&run).await {
+            Ok(output) => {
+                app.set_output_overlay(format!("Plugin {}", run.name), output);
+                app.set_status(format!("Plugin '{}' finished", run.name));
+            }
+            Err(error) => {
+                app.set_output_overlay(format!("Plugin {}", run.name), format!("{error:#}"));
+                app.set_status(format!("Plugin '{}' failed", run.name));
+            }
+        },
+        AppCommand::SwitchContext { context } => match gateway.switch_context(&context).await {
+            Ok(()) => {
+                app.set_kube_catalog(
+                    gateway.available_contexts(),
+                    gateway.available_clusters(),
+                    gateway.available_users(),
+                    gateway.context_catalog(),
+                );
+                app.set_kube_target(
+                    gateway.cluster().to_string(),
+                    gateway.context().to_string(),
+                    gateway.user().to_string(),
+                    gateway.default_namespace().to_string(),
+                    true,
+                );
+                refresh_server_version(app, gateway).await;
+                refresh_custom_resource_catalog(app, gateway).await;
+                let tabs = app.visible_tabs();
+                for tab in tabs {
+                    refresh_tab(app, gateway, tab).await;
+                }
+                app.set_status(format!(
+                    "Switched context to '{}' ({})",
+                    gateway.context(),
+                    gateway.cluster()
+                ));
+                return LoopEffect::RestartWatchers;
+            }
+            Err(error) => {
+                app.set_status(format!("Context switch failed for '{context}': {error:#}"))
+            }
+        },
+        AppCommand::SwitchCluster { cluster } => match gateway.switch_cluster(&cluster).await {
             Ok(context) => {
+                app.set_kube_catalog(
+                    gateway.available_contexts(),
+                    gateway.available_clusters(),
+                    gateway.available_users(),
+                    gateway.context_catalog(),
+                );
                 app.set_kube_target(
                     gateway.cluster().to_string(),
                     gateway.context().to_string(),
@@ -1037,14 +2024,42 @@ async fn execute_app_command(
                     gateway.default_namespace().to_string(),
                     true,
                 );
+                refresh_server_version(app, gateway).await;
+                refresh_custom_resource_catalog(app, gateway).await;
+                let tabs = app.visible_tabs();
+                for tab in tabs {
+                    refresh_tab(app, gateway, tab).await;
+                }
+                app.set_status(format!(
+                    "Switched cluster '{}' via context '{}' ({})",
+                    cluster,
+                    context,
+                    gateway.cluster()
+                ));
+                return LoopEffect::RestartWatchers;
+            }
+            Err(error) => {
+                app.set_status(format!("Cluster switch failed for '{cluster}': {error:#}"))
+            }
+        },
+        AppCommand::SwitchUser { user } => match gateway.switch_user(&user).await {
+            Ok(context) => {
                 app.set_kube_catalog(
                     gateway.available_contexts(),
                     gateway.available_clusters(),
                     gateway.available_users(),
                     gateway.context_catalog(),
                 );
+                app.set_kube_target(
+                    gateway.cluster().to_string(),
+                    gateway.context().to_string(),
+                    gateway.user().to_string(),
+                    gateway.default_namespace().to_string(),
+                    true,
+                );
+                refresh_server_version(app, gateway).await;
                 refresh_custom_resource_catalog(app, gateway).await;
-                let tabs = app.tabs().to_vec();
+                let tabs = app.visible_tabs();
                 for tab in tabs {
                     refresh_tab(app, gateway, tab).await;
                 }
@@ -1204,11 +2219,22 @@ fn fit_text(input: &str, max_chars: usize) -> String {
 async fn inspect_ops_target(
     target: OpsInspectTarget,
     namespace_scope: &NamespaceScope,
+    kube_target: KubectlTarget<'_>,
 ) -> (String, String, String) {
     match target {
-        OpsInspectTarget::ArgoCdSync { name } => {
-            let args = vec!["app".to_string(), "sync".to_string(), name.clone()];
+        OpsInspectTarget::ArgoCdSync {
+            name,
+            prune,
+            dry_run,
+            resource,
+        } => {
+            let args = argocd_sync_args(&name, prune, dry_run, resource.as_deref());
             match run_external_readonly("argocd", &args, 30).await {
+                Ok(output) if dry_run => (
+                    format!("Argo CD Sync (dry-run) {name}"),
+                    bounded_output(&output, 260, 220),
+                    format!("Argo CD sync dry-run completed: {name}"),
+                ),
                 Ok(output) => (
                     format!("Argo CD Sync {name}"),
                     bounded_output(&output, 260, 220),
@@ -1241,21 +2267,23 @@ async fn inspect_ops_target(
                 ),
             }
         }
-        OpsInspectTarget::ArgoCdDiff { name } => {
-            let args = vec!["app".to_string(), "diff".to_string(), name.clone()];
-            match run_external_readonly("argocd", &args, 20).await {
-                Ok(output) => (
-                    format!("Argo CD Diff {name}"),
-                    bounded_output(&output, 320, 220),
-                    format!("Argo CD diff loaded: {name}"),
-                ),
-                Err(error) => (
-                    format!("Argo CD Diff {name}"),
-                    error.clone(),
-                    format!("Argo CD diff failed: {error}"),
-                ),
-            }
-        }
+        OpsInspectTarget::ArgoCdDiff { name } => match run_argocd_diff(&name).await {
+            Ok(output) if output.is_empty() => (
+                format!("Argo CD Diff {name}"),
+                "(in sync, no differences)".to_string(),
+                format!("Argo CD diff: {name} is in sync"),
+            ),
+            Ok(output) => (
+                format!("Argo CD Diff {name}"),
+                bounded_output(&output, 320, 220),
+                format!("Argo CD diff loaded: {name}"),
+            ),
+            Err(error) => (
+                format!("Argo CD Diff {name}"),
+                error.clone(),
+                format!("Argo CD diff failed: {error}"),
+            ),
+        },
         OpsInspectTarget::ArgoCdHistory { name } => {
             let args = vec![
                 "app".to_string(),
@@ -1317,9 +2345,38 @@ async fn inspect_ops_target(
                 ),
             }
         }
+        OpsInspectTarget::ArgoCdCreate {
+            name,
+            repo,
+            path,
+            dest_namespace,
+            project,
+        } => {
+            let title = format!("Argo CD Create {name}");
+            let created = match argocd_repo_url(&repo).await {
+                Ok(repo_url) => {
+                    let args =
+                        argocd_create_args(&name, &repo_url, &path, &dest_namespace, &project);
+                    run_external_readonly("argocd", &args, 30).await
+                }
+                Err(error) => Err(error),
+            };
+            match created {
+                Ok(output) => (
+                    title,
+                    bounded_output(&output, 220, 220),
+                    format!("Argo CD app created: {name} (:argocd sync {name} to deploy)"),
+                ),
+                Err(error) => (
+                    title,
+                    error.clone(),
+                    format!("Argo CD create failed: {error}"),
+                ),
+            }
+        }
         OpsInspectTarget::HelmReleases => {
             let args = vec!["list".to_string(), "-A".to_string()];
-            match run_external_readonly("helm", &args, 6).await {
+            match run_helm(kube_target, &args, 6).await {
                 Ok(output) => (
                     "Helm Releases".to_string(),
                     bounded_output(&output, 220, 220),
@@ -1332,22 +2389,119 @@ async fn inspect_ops_target(
                 ),
             }
         }
-        OpsInspectTarget::HelmRelease { name } => {
-            let mut args = vec!["status".to_string(), name.clone()];
+        OpsInspectTarget::HelmRelease { name, section } => {
+            let mut args = helm_release_args(section, &name);
             if let NamespaceScope::Named(namespace) = namespace_scope {
                 args.push("-n".to_string());
                 args.push(namespace.clone());
             }
-            match run_external_readonly("helm", &args, 6).await {
+            let label = section.label();
+            let max_lines = match section {
+                HelmReleaseSection::Status => 280,
+                HelmReleaseSection::Values | HelmReleaseSection::Manifest => 2_000,
+            };
+            match run_helm(kube_target, &args, 6).await {
                 Ok(output) => (
                     format!("Helm Release {}", name),
-                    bounded_output(&output, 280, 220),
-                    format!("Helm release loaded: {name}"),
+                    bounded_output(&output, max_lines, 220),
+                    format!("Helm release {label} loaded: {name}"),
                 ),
                 Err(error) => (
                     format!("Helm Release {}", name),
                     error,
-                    format!("Helm release lookup failed: {name}"),
+                    format!("Helm release {label} lookup failed: {name}"),
+                ),
+            }
+        }
+        OpsInspectTarget::HelmHistory { name } => {
+            let mut args = vec![
+                "history".to_string(),
+                name.clone(),
+                "-o".to_string(),
+                "json".to_string(),
+            ];
+            if let NamespaceScope::Named(namespace) = namespace_scope {
+                args.push("-n".to_string());
+                args.push(namespace.clone());
+            }
+            match run_helm(kube_target, &args, 8)
+                .await
+                .and_then(|output| helm_history_table(&output))
+            {
+                Ok(table) => (
+                    format!("Helm History {name}"),
+                    app::render_table_text(&table),
+                    format!(
+                        "Helm history loaded: {name} ({} revisions; :helm rollback {name} <revision>)",
+                        table.rows.len()
+                    ),
+                ),
+                Err(error) => (
+                    format!("Helm History {name}"),
+                    error.clone(),
+                    format!("Helm history failed: {error}"),
+                ),
+            }
+        }
+        OpsInspectTarget::HelmRollback { name, revision } => {
+            let mut args = vec!["rollback".to_string(), name.clone(), revision.clone()];
+            if let NamespaceScope::Named(namespace) = namespace_scope {
+                args.push("-n".to_string());
+                args.push(namespace.clone());
+            }
+            match run_helm(kube_target, &args, 60).await {
+                Ok(output) => (
+                    format!("Helm Rollback {name}#{revision}"),
+                    bounded_output(&output, 220, 220),
+                    format!("Helm rollback completed: {name}#{revision}"),
+                ),
+                Err(error) => (
+                    format!("Helm Rollback {name}#{revision}"),
+                    error.clone(),
+                    format!("Helm rollback failed: {error}"),
+                ),
+            }
+        }
+        OpsInspectTarget::HelmDiff {
+            release,
+            chart,
+            values_file,
+        } => {
+            let mut args = vec![
+                "diff".to_string(),
+                "upgrade".to_string(),
+                release.clone(),
+                chart.clone(),
+                "--no-color".to_string(),
+            ];
+            if let Some(values_file) = values_file {
+                args.push("-f".to_string());
+                args.push(values_file);
+            }
+            if let NamespaceScope::Named(namespace) = namespace_scope {
+                args.push("-n".to_string());
+                args.push(namespace.clone());
+            }
+            match run_helm(kube_target, &args, 30).await {
+                Ok(output) if output.is_empty() => (
+                    format!("Helm Diff {release}"),
+                    "(no changes)".to_string(),
+                    format!("Helm diff: no changes for {release}"),
+                ),
+                Ok(output) => (
+                    format!("Helm Diff {release} <- {chart}"),
+                    bounded_output(&output, 320, 220),
+                    format!("Helm diff loaded: {release}"),
+                ),
+                Err(error) if helm_diff_plugin_missing(&error) => (
+                    format!("Helm Diff {release}"),
+                    format!("{HELM_DIFF_INSTALL_HINT}\n\n{error}"),
+                    "Helm diff needs the helm-diff plugin".to_string(),
+                ),
+                Err(error) => (
+                    format!("Helm Diff {release}"),
+                    error.clone(),
+                    format!("Helm diff failed: {error}"),
                 ),
             }
         }
@@ -1385,7 +2539,10 @@ async fn inspect_ops_target(
                 )
                 .await
                 {
-                    Ok(output) => format!("state(list)\n{}", bounded_output(&output, 140, 220)),
+                    Ok(output) => format!(
+                        "state(list)  (:tf state to inspect a resource)\n{}",
+                        bounded_output(&output, 140, 220)
+                    ),
                     Err(error) => format!("state(list)\n{error}"),
                 },
             );
@@ -1395,21 +2552,65 @@ async fn inspect_ops_target(
                 "Terraform overview loaded".to_string(),
             )
         }
-        OpsInspectTarget::AnsibleOverview => {
-            let version = match run_external_readonly(
-                "ansible-playbook",
-                &["--version".to_string()],
-                5,
-            )
-            .await
-            {
-                Ok(output) => format!("ansible-playbook\n{}", bounded_output(&output, 14, 220)),
-                Err(error) => format!("ansible-playbook\n{error}"),
-            };
-
-            let playbooks = discover_ansible_playbooks(".", 6, 220);
-            let playbook_lines = if playbooks.is_empty() {
-                "No playbook-like files found under current path".to_string()
+        OpsInspectTarget::TerraformPlan { dir, timeout_secs } => {
+            let args = vec![
+                format!("-chdir={dir}"),
+                "plan".to_string(),
+                "-no-color".to_string(),
+                "-input=false".to_string(),
+            ];
+            match run_external_readonly("terraform", &args, timeout_secs).await {
+                Ok(output) => (
+                    format!("Terraform Plan {dir}"),
+                    bounded_output(&output, 2_000, 220),
+                    format!(
+                        "Terraform plan finished: {}",
+                        terraform_plan_summary(&output)
+                    ),
+                ),
+                Err(error) => (
+                    format!("Terraform Plan {dir}"),
+                    error.clone(),
+                    format!("Terraform plan failed in {dir}"),
+                ),
+            }
+        }
+        OpsInspectTarget::TerraformStateShow { dir, address } => {
+            let args = vec![
+                format!("-chdir={dir}"),
+                "state".to_string(),
+                "show".to_string(),
+                "-no-color".to_string(),
+                address.clone(),
+            ];
+            match run_external_readonly("terraform", &args, 20).await {
+                Ok(output) => (
+                    format!("Terraform State {address}"),
+                    bounded_output(&output, 1_000, 220),
+                    format!("Terraform state loaded: {address} (:tf state to pick another)"),
+                ),
+                Err(error) => (
+                    format!("Terraform State {address}"),
+                    error.clone(),
+                    format!("Terraform state show failed: {address}"),
+                ),
+            }
+        }
+        OpsInspectTarget::AnsibleOverview => {
+            let version = match run_external_readonly(
+                "ansible-playbook",
+                &["--version".to_string()],
+                5,
+            )
+            .await
+            {
+                Ok(output) => format!("ansible-playbook\n{}", bounded_output(&output, 14, 220)),
+                Err(error) => format!("ansible-playbook\n{error}"),
+            };
+
+            let playbooks = discover_ansible_playbooks(".", 6, 220);
+            let playbook_lines = if playbooks.is_empty() {
+                "No playbook-like files found under current path".to_string()
             } else {
                 playbooks
                     .into_iter()
@@ -1481,7 +2682,7 @@ async fn inspect_ops_target(
                 None => "RBAC Matrix".to_string(),
             };
 
-            match run_external_readonly("kubectl", &args, 8).await {
+            match run_external_readonly("kubectl", &kubectl_args(kube_target, &args), 8).await {
                 Ok(output) => (
                     title,
                     bounded_output(&output, 260, 220),
@@ -1496,7 +2697,7 @@ async fn inspect_ops_target(
             namespace,
         } => {
             let mut args = vec![verb.clone(), resource.clone()];
-            if let Some(namespace) = namespace.as_ref().or_else(|| match namespace_scope {
+            if let Some(namespace) = namespace.as_ref().or(match namespace_scope {
                 NamespaceScope::Named(namespace) => Some(namespace),
                 NamespaceScope::All => None,
             }) {
@@ -1505,15 +2706,19 @@ async fn inspect_ops_target(
             }
 
             let title = format!("WhoCan {} {}", verb, resource);
-            match run_external_readonly("kubectl-who-can", &args, 12).await {
+            match run_external_readonly("kubectl-who-can", &kubectl_args(kube_target, &args), 12)
+                .await
+            {
                 Ok(output) => (
                     title,
                     bounded_output(&output, 260, 220),
                     "who-can lookup loaded".to_string(),
                 ),
                 Err(primary_error) => {
+                    // kubectl rejects global flags ahead of a plugin name, so the
+                    // context goes after `who-can` for the plugin to parse.
                     let mut fallback = vec!["who-can".to_string()];
-                    fallback.extend(args.clone());
+                    fallback.extend(kubectl_args(kube_target, &args));
                     match run_external_readonly("kubectl", &fallback, 12).await {
                         Ok(output) => (
                             title,
@@ -1533,12 +2738,24 @@ async fn inspect_ops_target(
             }
         }
         OpsInspectTarget::OpenShiftProjects => {
-            let current = match run_external_readonly("oc", &["project".to_string()], 6).await {
+            let current = match run_external_readonly(
+                "oc",
+                &kubectl_args(kube_target, &["project".to_string()]),
+                6,
+            )
+            .await
+            {
                 Ok(output) => format!("current\n{}", bounded_output(&output, 18, 220)),
                 Err(error) => format!("current\n{error}"),
             };
 
-            let projects = match run_external_readonly("oc", &["projects".to_string()], 6).await {
+            let projects = match run_external_readonly(
+                "oc",
+                &kubectl_args(kube_target, &["projects".to_string()]),
+                6,
+            )
+            .await
+            {
                 Ok(output) => format!("projects\n{}", bounded_output(&output, 160, 220)),
                 Err(error) => format!("projects\n{error}"),
             };
@@ -1564,6 +2781,28 @@ async fn inspect_ops_target(
                 ),
             }
         }
+        OpsInspectTarget::KustomizeDiff { path } => {
+            let title = format!("Kustomize Dry-Run {path}");
+            match kustomize_apply(&path, namespace_scope, kube_target, true).await {
+                Ok(output) => (
+                    title,
+                    bounded_output(&output, 240, 220),
+                    format!("Server-side dry-run passed: {path}"),
+                ),
+                Err(error) => (title, error, format!("Server-side dry-run failed: {path}")),
+            }
+        }
+        OpsInspectTarget::KustomizeApply { path } => {
+            let title = format!("Kustomize Apply {path}");
+            match kustomize_apply(&path, namespace_scope, kube_target, false).await {
+                Ok(output) => (
+                    title,
+                    bounded_output(&output, 240, 220),
+                    format!("Applied kustomize build of {path}"),
+                ),
+                Err(error) => (title, error, format!("Kustomize apply failed: {path}")),
+            }
+        }
         OpsInspectTarget::GitCatalog => {
             let root = repo_cache_root();
             let mut repos = discover_cached_repos(&root);
@@ -1574,7 +2813,9 @@ async fn inspect_ops_target(
                 format!("cache {}", root.display()),
                 String::new(),
                 "commands".to_string(),
-                "- :git fetch <url-or-repo> [ref]".to_string(),
+                "- :git refs <url-or-repo>".to_string(),
+                "- :git fetch [--full] <url-or-repo> [ref]".to_string(),
+                "- :git diff <url-or-repo> <from> [to]".to_string(),
                 "- :git files <url-or-repo> [path]".to_string(),
                 "- :git show <url-or-repo> <path>".to_string(),
                 "- :git export <url-or-repo> <source> [destination]".to_string(),
@@ -1594,8 +2835,13 @@ async fn inspect_ops_target(
                 "Git repo toolkit opened".to_string(),
             )
         }
-        OpsInspectTarget::GitFetch { repo, reference } => {
-            match ensure_repo_checkout(&repo, reference.as_deref()).await {
+        OpsInspectTarget::GitFetch {
+            repo,
+            reference,
+            full,
+        } => {
+            let depth = if full { None } else { repo_clone_depth() };
+            match ensure_repo_checkout_at_depth(&repo, reference.as_deref(), depth).await {
                 Ok(summary) => {
                     let title = format!("Git Fetch {}", summary.slug);
                     let mut lines = vec![
@@ -1619,7 +2865,56 @@ async fn inspect_ops_target(
                 Err(error) => (
                     "Git Fetch".to_string(),
                     error.clone(),
-                    format!("Repository sync failed: {error}"),
+                    match reference {
+                        Some(_) => format!(
+                            "Repository sync failed (:git refs {repo} lists branches/tags): {error}"
+                        ),
+                        None => format!("Repository sync failed: {error}"),
+                    },
+                ),
+            }
+        }
+        OpsInspectTarget::GitRefs { repo } => match list_repo_refs(&repo).await {
+            Ok((slug, refs)) => {
+                let mut lines = vec![format!("branches ({})", refs.branches.len())];
+                lines.extend(refs.branches.iter().map(|branch| format!("  {branch}")));
+                lines.push(String::new());
+                lines.push(format!("tags ({})", refs.tags.len()));
+                lines.extend(refs.tags.iter().map(|tag| format!("  {tag}")));
+                lines.push(String::new());
+                lines.push(format!("checkout: :git fetch {repo} <ref>"));
+                (
+                    format!("Git Refs {slug}"),
+                    bounded_output(&lines.join("\n"), 400, 220),
+                    format!(
+                        "{slug}: {} branches, {} tags",
+                        refs.branches.len(),
+                        refs.tags.len()
+                    ),
+                )
+            }
+            Err(error) => (
+                "Git Refs".to_string(),
+                error.clone(),
+                format!("Listing repository refs failed: {error}"),
+            ),
+        },
+        OpsInspectTarget::GitDiff { repo, from, to } => {
+            match git_repo_diff(&repo, &from, &to).await {
+                Ok((slug, output)) if output.is_empty() => (
+                    format!("Git Diff {slug} {from}..{to}"),
+                    "(no changes)".to_string(),
+                    format!("{slug}: no changes between {from} and {to}"),
+                ),
+                Ok((slug, output)) => (
+                    format!("Git Diff {slug} {from}..{to}"),
+                    bounded_output(&output, 400, 220),
+                    format!("Git diff loaded: {slug} {from}..{to}"),
+                ),
+                Err(error) => (
+                    "Git Diff".to_string(),
+                    error.clone(),
+                    format!("Git diff failed: {error}"),
                 ),
             }
         }
@@ -1750,7 +3045,13 @@ async fn inspect_ops_target(
                             args.push(namespace.clone());
                         }
 
-                        match run_external_readonly("kubectl", &args, 20).await {
+                        match run_external_readonly(
+                            "kubectl",
+                            &kubectl_args(kube_target, &args),
+                            20,
+                        )
+                        .await
+                        {
                             Ok(output) => (
                                 format!("Git Apply {}", summary.slug),
                                 bounded_output(&output, 240, 220),
@@ -1846,9 +3147,223 @@ fn repo_slug_from_locator(repo: &str) -> String {
     }
 }
 
+fn repo_checkout_path(repo: &str, root: &Path) -> (String, PathBuf) {
+    let direct_path = PathBuf::from(repo);
+    if !looks_like_repo_url(repo) && direct_path.exists() {
+        let slug = direct_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(repo_slug_from_locator)
+            .unwrap_or_else(|| "repo".to_string());
+        return (slug, direct_path);
+    }
+    let slug = repo_slug_from_locator(repo);
+    (slug.clone(), root.join(slug))
+}
+
+async fn list_repo_refs(repo: &str) -> std::result::Result<(String, GitRefListing), String> {
+    let repo = repo.trim();
+    if repo.is_empty() {
+        return Err("repository locator is empty".to_string());
+    }
+    if looks_like_repo_url(repo) {
+        let args = vec![
+            "ls-remote".to_string(),
+            "--heads".to_string(),
+            "--tags".to_string(),
+            repo.to_string(),
+        ];
+        let output = run_external_readonly("git", &args, 15).await?;
+        return Ok((repo_slug_from_locator(repo), parse_git_refs(&output)));
+    }
+
+    let (slug, path) = repo_checkout_path(repo, &repo_cache_root());
+    if !path.join(".git").exists() {
+        return Err(format!(
+            "repo '{}' is not cached. Run :git fetch <url> first",
+            repo
+        ));
+    }
+    let remote_args = vec![
+        "-C".to_string(),
+        path.display().to_string(),
+        "ls-remote".to_string(),
+        "--heads".to_string(),
+        "--tags".to_string(),
+        "origin".to_string(),
+    ];
+    let output = match run_external_readonly("git", &remote_args, 15).await {
+        Ok(output) => output,
+        Err(_) => {
+            let local_args = vec![
+                "-C".to_string(),
+                path.display().to_string(),
+                "for-each-ref".to_string(),
+                "--format=%(objectname) %(refname)".to_string(),
+                "refs/heads".to_string(),
+                "refs/remotes".to_string(),
+                "refs/tags".to_string(),
+            ];
+            run_external_readonly("git", &local_args, 8).await?
+        }
+    };
+    Ok((slug, parse_git_refs(&output)))
+}
+
+async fn git_repo_diff(
+    repo: &str,
+    from: &str,
+    to: &str,
+) -> std::result::Result<(String, String), String> {
+    let summary = ensure_repo_checkout(repo, None).await?;
+    let (from, to) = match (
+        resolve_repo_ref(&summary.path, from).await,
+        resolve_repo_ref(&summary.path, to).await,
+    ) {
+        (Ok(from), Ok(to)) => (from, to),
+        (Err(error), _) | (_, Err(error)) => {
+            if !repo_is_shallow(&summary.path).await {
+                return Err(error);
+            }
+            unshallow_repo(&summary.path).await?;
+            (
+                resolve_repo_ref(&summary.path, from).await?,
+                resolve_repo_ref(&summary.path, to).await?,
+            )
+        }
+    };
+    let args = vec![
+        "-C".to_string(),
+        summary.path.display().to_string(),
+        "diff".to_string(),
+        "--no-color".to_string(),
+        format!("{from}..{to}"),
+    ];
+    let output = run_external_readonly("git", &args, 15).await?;
+    Ok((summary.slug, output))
+}
+
+async fn resolve_repo_ref(path: &Path, reference: &str) -> std::result::Result<String, String> {
+    for candidate in git_ref_candidates(reference) {
+        let args = vec![
+            "-C".to_string(),
+            path.display().to_string(),
+            "rev-parse".to_string(),
+            "--verify".to_string(),
+            "--quiet".to_string(),
+            format!("{candidate}^{{commit}}"),
+        ];
+        if run_external_readonly("git", &args, 6).await.is_ok() {
+            return Ok(candidate);
+        }
+    }
+    Err(format!(
+        "unknown ref '{reference}' in {} (:git refs lists branches and tags)",
+        path.display()
+    ))
+}
+
+fn git_ref_candidates(reference: &str) -> Vec<String> {
+    let reference = reference.trim();
+    let mut candidates = vec![reference.to_string()];
+    if !reference.starts_with("origin/") && !reference.starts_with("refs/") {
+        candidates.push(format!("origin/{reference}"));
+    }
+    candidates
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct GitRefListing {
+    branches: Vec<String>,
+    tags: Vec<String>,
+}
+
+fn parse_git_refs(output: &str) -> GitRefListing {
+    let mut branches = BTreeSet::new();
+    let mut tags = BTreeSet::new();
+    for line in strip_stderr_block(output).lines() {
+        let Some(refname) = line.split_whitespace().nth(1) else {
+            continue;
+        };
+        if let Some(tag) = refname.strip_prefix("refs/tags/") {
+            tags.insert(tag.trim_end_matches("^{}").to_string());
+        } else if let Some(branch) = refname.strip_prefix("refs/heads/") {
+            branches.insert(branch.to_string());
+        } else if let Some(remote) = refname.strip_prefix("refs/remotes/") {
+            let branch = remote.split_once('/').map_or(remote, |(_, branch)| branch);
+            if branch != "HEAD" {
+                branches.insert(branch.to_string());
+            }
+        }
+    }
+    GitRefListing {
+        branches: branches.into_iter().collect(),
+        tags: tags.into_iter().collect(),
+    }
+}
+
+const DEFAULT_REPO_CLONE_DEPTH: u32 = 1;
+
+fn repo_clone_depth() -> Option<u32> {
+    parse_clone_depth(std::env::var("ORCA_REPO_CLONE_DEPTH").ok().as_deref())
+}
+
+fn parse_clone_depth(raw: Option<&str>) -> Option<u32> {
+    match raw.map(str::trim) {
+        None | Some("") => Some(DEFAULT_REPO_CLONE_DEPTH),
+        Some(value) if value.eq_ignore_ascii_case("full") => None,
+        Some(value) => match value.parse::<u32>() {
+            Ok(0) => None,
+            Ok(depth) => Some(depth),
+            Err(_) => Some(DEFAULT_REPO_CLONE_DEPTH),
+        },
+    }
+}
+
+async fn repo_is_shallow(path: &Path) -> bool {
+    let args = vec![
+        "-C".to_string(),
+        path.display().to_string(),
+        "rev-parse".to_string(),
+        "--is-shallow-repository".to_string(),
+    ];
+    run_external_readonly("git", &args, 6)
+        .await
+        .is_ok_and(|output| output.trim() == "true")
+}
+
+async fn unshallow_repo(path: &Path) -> std::result::Result<String, String> {
+    let set_branches_args = vec![
+        "-C".to_string(),
+        path.display().to_string(),
+        "remote".to_string(),
+        "set-branches".to_string(),
+        "origin".to_string(),
+        "*".to_string(),
+    ];
+    run_external_readonly("git", &set_branches_args, 6).await?;
+    let fetch_args = vec![
+        "-C".to_string(),
+        path.display().to_string(),
+        "fetch".to_string(),
+        "--unshallow".to_string(),
+        "--tags".to_string(),
+        "origin".to_string(),
+    ];
+    run_external_readonly("git", &fetch_args, 60).await
+}
+
 async fn ensure_repo_checkout(
     repo: &str,
     reference: Option<&str>,
+) -> std::result::Result<RepoCheckoutSummary, String> {
+    ensure_repo_checkout_at_depth(repo, reference, repo_clone_depth()).await
+}
+
+async fn ensure_repo_checkout_at_depth(
+    repo: &str,
+    reference: Option<&str>,
+    depth: Option<u32>,
 ) -> std::result::Result<RepoCheckoutSummary, String> {
     let repo = repo.trim();
     if repo.is_empty() {
@@ -1860,21 +3375,7 @@ async fn ensure_repo_checkout(
         .map_err(|error| format!("failed to create repo cache {}: {error}", root.display()))?;
 
     let is_url = looks_like_repo_url(repo);
-    let direct_path = PathBuf::from(repo);
-    let (slug, path) = if is_url {
-        let slug = repo_slug_from_locator(repo);
-        (slug.clone(), root.join(slug))
-    } else if direct_path.exists() {
-        let slug = direct_path
-            .file_name()
-            .and_then(|name| name.to_str())
-            .map(repo_slug_from_locator)
-            .unwrap_or_else(|| "repo".to_string());
-        (slug, direct_path)
-    } else {
-        let slug = repo_slug_from_locator(repo);
-        (slug.clone(), root.join(slug))
-    };
+    let (slug, path) = repo_checkout_path(repo, &root);
 
     let git_dir = path.join(".git");
     let (mut status, mut output_lines) = if is_url {
@@ -1900,14 +3401,22 @@ async fn ensure_repo_checkout(
             if !output.trim().is_empty() {
                 lines.push(output);
             }
-            ("updated".to_string(), lines)
+            if depth.is_none() && repo_is_shallow(&path).await {
+                let output = unshallow_repo(&path).await?;
+                if !output.trim().is_empty() {
+                    lines.push(output);
+                }
+                ("updated + unshallowed".to_string(), lines)
+            } else {
+                ("updated".to_string(), lines)
+            }
         } else {
-            let clone_args = vec![
-                "clone".to_string(),
-                "--depth=1".to_string(),
-                repo.to_string(),
-                path.display().to_string(),
-            ];
+            let mut clone_args = vec!["clone".to_string()];
+            if let Some(depth) = depth {
+                clone_args.push(format!("--depth={depth}"));
+            }
+            clone_args.push(repo.to_string());
+            clone_args.push(path.display().to_string());
             let output = run_external_readonly("git", &clone_args, 30).await?;
             let mut lines = Vec::new();
             if !output.trim().is_empty() {
@@ -1931,13 +3440,19 @@ async fn ensure_repo_checkout(
             "checkout".to_string(),
             reference.to_string(),
         ];
-        match run_external_readonly("git", &checkout_args, 12).await {
-            Ok(output) => {
-                if !output.trim().is_empty() {
-                    output_lines.push(output);
-                }
+        let output = match run_external_readonly("git", &checkout_args, 12).await {
+            Ok(output) => output,
+            Err(error) if repo_is_shallow(&path).await => {
+                unshallow_repo(&path).await.map_err(|unshallow_error| {
+                    format!("{error}\n\nunshallow failed:\n{unshallow_error}")
+                })?;
+                status = format!("{status} + unshallowed");
+                run_external_readonly("git", &checkout_args, 12).await?
             }
             Err(error) => return Err(error),
+        };
+        if !output.trim().is_empty() {
+            output_lines.push(output);
         }
         status = format!("{status} + ref");
     }
@@ -2020,6 +3535,79 @@ fn copy_repo_path(source: &Path, destination: &Path) -> std::result::Result<usiz
     Ok(copied)
 }
 
+fn rollout_kind(tab: ResourceTab) -> &'static str {
+    match tab {
+        ResourceTab::StatefulSets => "statefulset",
+        ResourceTab::DaemonSets => "daemonset",
+        _ => "deployment",
+    }
+}
+
+fn rollout_undo_args(
+    kube_target: KubectlTarget<'_>,
+    tab: ResourceTab,
+    namespace: &str,
+    name: &str,
+    to_revision: Option<u64>,
+) -> Vec<String> {
+    let mut args = vec![
+        "rollout".to_string(),
+        "undo".to_string(),
+        format!("{}/{name}", rollout_kind(tab)),
+        "-n".to_string(),
+        namespace.to_string(),
+    ];
+    if let Some(revision) = to_revision {
+        args.push(format!("--to-revision={revision}"));
+    }
+    kubectl_args(kube_target, &args)
+}
+
+/// Pins a kubectl (or oc) argv to orca's context and `--cluster` override. `:ctx` switches
+/// never touch kubeconfig, so kubectl's own current-context may point at a different cluster.
+fn kubectl_args(kube_target: KubectlTarget<'_>, args: &[String]) -> Vec<String> {
+    let mut scoped = Vec::with_capacity(args.len() + 4);
+    if let Some(context) = kube_target.context {
+        scoped.push("--context".to_string());
+        scoped.push(context.to_string());
+    }
+    if let Some(cluster) = kube_target.cluster {
+        scoped.push("--cluster".to_string());
+        scoped.push(cluster.to_string());
+    }
+    scoped.extend(args.iter().cloned());
+    scoped
+}
+
+/// helm only takes `--kube-context`; with a `--cluster` override it would quietly talk to
+/// the context's own cluster, so refuse instead.
+fn helm_args(
+    kube_target: KubectlTarget<'_>,
+    args: &[String],
+) -> std::result::Result<Vec<String>, String> {
+    if let Some(cluster) = kube_target.cluster {
+        return Err(format!(
+            "helm has no --cluster flag; restart orca without --cluster {cluster} to use helm"
+        ));
+    }
+    let mut scoped = Vec::with_capacity(args.len() + 2);
+    if let Some(context) = kube_target.context {
+        scoped.push("--kube-context".to_string());
+        scoped.push(context.to_string());
+    }
+    scoped.extend(args.iter().cloned());
+    Ok(scoped)
+}
+
+async fn run_helm(
+    kube_target: KubectlTarget<'_>,
+    args: &[String],
+    timeout_secs: u64,
+) -> std::result::Result<String, String> {
+    let args = helm_args(kube_target, args)?;
+    run_external_readonly("helm", &args, timeout_secs).await
+}
+
 async fn run_external_readonly(
     program: &str,
     args: &[String],
@@ -2104,6 +3692,7 @@ async fn run_plugin_command(run: &PluginRun) -> Result<String> {
     for attempt in 1..=attempts {
         let mut cmd = TokioCommand::new(&run.program);
         cmd.args(&run.args)
+            .envs(run.env.iter().cloned())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped());
 
@@ -2114,22 +3703,7 @@ async fn run_plugin_command(run: &PluginRun) -> Result<String> {
                 result.map_err(|error| format!("failed to run plugin '{}': {error}", run.name))
             });
 
-        let mut header = vec![
-            format!("plugin {}", run.name),
-            format!("command {}", run.program),
-            format!(
-                "args {}",
-                if run.args.is_empty() {
-                    "(none)".to_string()
-                } else {
-                    run.args.join(" ")
-                }
-            ),
-            format!("mutating {}", run.mutating),
-            format!("profile timeout:{}s retries:{}", timeout_secs, run.retries),
-            format!("attempt {attempt}/{attempts}"),
-            String::new(),
-        ];
+        let mut header = plugin_run_header(run, attempt);
 
         match output {
             Ok(output) => {
@@ -2168,35 +3742,192 @@ async fn run_plugin_command(run: &PluginRun) -> Result<String> {
     ))
 }
 
-fn bounded_output(input: &str, max_lines: usize, max_line_chars: usize) -> String {
-    let mut lines = input
-        .lines()
-        .map(|line| fit_text(line, max_line_chars))
-        .collect::<Vec<_>>();
+fn plugin_run_header(run: &PluginRun, attempt: usize) -> Vec<String> {
+    vec![
+        format!("plugin {}", run.name),
+        format!("command {}", run.program),
+        format!(
+            "args {}",
+            if run.args.is_empty() {
+                "(none)".to_string()
+            } else {
+                run.args.join(" ")
+            }
+        ),
+        format!("mutating {}", run.mutating),
+        format!(
+            "profile timeout:{}s retries:{}",
+            run.timeout_secs.max(1),
+            run.retries
+        ),
+        format!(
+            "attempt {attempt}/{}",
+            usize::from(run.retries).saturating_add(1)
+        ),
+        String::new(),
+    ]
+}
+
+const PLUGIN_STREAM_MAX_LINES: usize = 2000;
+const PLUGIN_STREAM_FLUSH: Duration = Duration::from_millis(100);
 
+fn plugin_stream_snapshot(header: &[String], lines: &[String]) -> String {
+    let mut snapshot = header.join("\n");
+    snapshot.push_str("\nstream\n");
     if lines.is_empty() {
-        return "(no output)".to_string();
+        snapshot.push_str("(waiting for output)");
+    } else {
+        snapshot.push_str(&lines.join("\n"));
     }
+    snapshot
+}
 
-    if lines.len() > max_lines {
-        lines.truncate(max_lines);
-        lines.push("…".to_string());
+fn push_stream_line(lines: &mut Vec<String>, line: String) {
+    if lines.len() >= PLUGIN_STREAM_MAX_LINES {
+        lines.remove(0);
     }
-
-    lines.join("\n")
+    lines.push(fit_text(&line, 220));
 }
 
-fn discover_ansible_playbooks(root: &str, max_depth: usize, max_files: usize) -> Vec<String> {
-    fn walk(
-        root: &std::path::Path,
-        current: &std::path::Path,
-        depth: usize,
-        max_depth: usize,
-        max_files: usize,
-        out: &mut Vec<String>,
-    ) {
-        if depth > max_depth || out.len() >= max_files {
-            return;
+fn spawn_plugin_stream(
+    run: PluginRun,
+    title: String,
+    tx: mpsc::UnboundedSender<PluginOutputEvent>,
+) {
+    tokio::spawn(async move {
+        let attempts = usize::from(run.retries).saturating_add(1);
+        let mut failures = Vec::new();
+        for attempt in 1..=attempts {
+            let mut header = plugin_run_header(&run, attempt);
+            if !failures.is_empty() {
+                header.insert(header.len() - 1, format!("earlier {}", failures.join("; ")));
+            }
+            let (result, lines) = stream_plugin_attempt(&run, &header, &title, &tx).await;
+            let outcome = match result {
+                Ok(status) if status.success() => {
+                    let _ = tx.send(PluginOutputEvent {
+                        title: title.clone(),
+                        snapshot: plugin_stream_snapshot(&header, &lines),
+                        finished: Some(format!("Plugin '{}' finished", run.name)),
+                    });
+                    return;
+                }
+                Ok(status) => format!("exit {status}"),
+                Err(error) => error,
+            };
+            let mut lines = lines;
+            lines.push(String::new());
+            lines.push(outcome.clone());
+            let last = attempt == attempts;
+            let _ = tx.send(PluginOutputEvent {
+                title: title.clone(),
+                snapshot: plugin_stream_snapshot(&header, &lines),
+                finished: last.then(|| format!("Plugin '{}' failed: {outcome}", run.name)),
+            });
+            failures.push(format!("attempt {attempt}: {outcome}"));
+        }
+    });
+}
+
+async fn stream_plugin_attempt(
+    run: &PluginRun,
+    header: &[String],
+    title: &str,
+    tx: &mpsc::UnboundedSender<PluginOutputEvent>,
+) -> (
+    std::result::Result<std::process::ExitStatus, String>,
+    Vec<String>,
+) {
+    let timeout_secs = run.timeout_secs.max(1);
+    let mut cmd = TokioCommand::new(&run.program);
+    cmd.args(&run.args)
+        .envs(run.env.iter().cloned())
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(error) => {
+            return (
+                Err(format!("failed to run plugin '{}': {error}", run.name)),
+                Vec::new(),
+            );
+        }
+    };
+    let (Some(stdout), Some(stderr)) = (child.stdout.take(), child.stderr.take()) else {
+        return (
+            Err(format!("plugin '{}' output pipes unavailable", run.name)),
+            Vec::new(),
+        );
+    };
+    let mut stdout = BufReader::new(stdout).lines();
+    let mut stderr = BufReader::new(stderr).lines();
+    let (mut stdout_open, mut stderr_open) = (true, true);
+    let mut lines = Vec::new();
+    let mut last_flush = Instant::now();
+    let deadline = tokio::time::sleep(Duration::from_secs(timeout_secs));
+    tokio::pin!(deadline);
+
+    let result = loop {
+        tokio::select! {
+            line = stdout.next_line(), if stdout_open => match line {
+                Ok(Some(line)) => push_stream_line(&mut lines, line),
+                _ => stdout_open = false,
+            },
+            line = stderr.next_line(), if stderr_open => match line {
+                Ok(Some(line)) => push_stream_line(&mut lines, format!("stderr: {line}")),
+                _ => stderr_open = false,
+            },
+            status = child.wait(), if !stdout_open && !stderr_open => {
+                break status.map_err(|error| format!("plugin '{}' wait failed: {error}", run.name));
+            }
+            _ = &mut deadline => {
+                let _ = child.kill().await;
+                break Err(format!("plugin '{}' timed out after {}s", run.name, timeout_secs));
+            }
+        }
+        if last_flush.elapsed() >= PLUGIN_STREAM_FLUSH {
+            last_flush = Instant::now();
+            let _ = tx.send(PluginOutputEvent {
+                title: title.to_string(),
+                snapshot: plugin_stream_snapshot(header, &lines),
+                finished: None,
+            });
+        }
+    };
+    (result, lines)
+}
+
+fn bounded_output(input: &str, max_lines: usize, max_line_chars: usize) -> String {
+    let mut lines = input
+        .lines()
+        .map(|line| fit_text(line, max_line_chars))
+        .collect::<Vec<_>>();
+
+    if lines.is_empty() {
+        return "(no output)".to_string();
+    }
+
+    if lines.len() > max_lines {
+        lines.truncate(max_lines);
+        lines.push("…".to_string());
+    }
+
+    lines.join("\n")
+}
+
+fn discover_ansible_playbooks(root: &str, max_depth: usize, max_files: usize) -> Vec<String> {
+    fn walk(
+        root: &std::path::Path,
+        current: &std::path::Path,
+        depth: usize,
+        max_depth: usize,
+        max_files: usize,
+        out: &mut Vec<String>,
+    ) {
+        if depth > max_depth || out.len() >= max_files {
+            return;
         }
 
         let entries = match std::fs::read_dir(current) {
@@ -2272,9 +4003,9 @@ async fn refresh_tab(app: &mut App, gateway: &KubeGateway, tab: ResourceTab) {
         refresh_kubernetes_tab(app, gateway, ResourceTab::Nodes).await;
         refresh_kubernetes_tab(app, gateway, ResourceTab::Pods).await;
         refresh_kubernetes_tab(app, gateway, ResourceTab::CustomResources).await;
-        refresh_argocd_tab(app, ResourceTab::ArgoCdApps).await;
+        refresh_argocd_tab(app, gateway.kubectl_target(), ResourceTab::ArgoCdApps).await;
         if app.argocd_selected_app().is_some() {
-            refresh_argocd_tab(app, ResourceTab::ArgoCdResources).await;
+            refresh_argocd_tab(app, gateway.kubectl_target(), ResourceTab::ArgoCdResources).await;
         }
         let table = build_orca_dashboard_table(app);
         app.set_active_table_data(tab, table);
@@ -2293,13 +4024,76 @@ async fn refresh_tab(app: &mut App, gateway: &KubeGateway, tab: ResourceTab) {
             | ResourceTab::ArgoCdCerts
             | ResourceTab::ArgoCdGpgKeys
     ) {
-        refresh_argocd_tab(app, tab).await;
+        refresh_argocd_tab(app, gateway.kubectl_target(), tab).await;
         return;
     }
 
     refresh_kubernetes_tab(app, gateway, tab).await;
 }
 
+fn manual_job_name(cronjob: &str, unix: i64) -> String {
+    let suffix = format!("-manual-{unix}");
+    let keep = 63usize.saturating_sub(suffix.len());
+    let base = cronjob
+        .get(..keep.min(cronjob.len()))
+        .unwrap_or(cronjob)
+        .trim_end_matches('-');
+    format!("{base}{suffix}")
+}
+
+fn create_job_from_cronjob_args(
+    kube_target: KubectlTarget<'_>,
+    namespace: &str,
+    cronjob: &str,
+    job: &str,
+) -> Vec<String> {
+    kubectl_args(
+        kube_target,
+        &[
+            "create".to_string(),
+            "job".to_string(),
+            format!("--from=cronjob/{cronjob}"),
+            job.to_string(),
+            "-n".to_string(),
+            namespace.to_string(),
+        ],
+    )
+}
+
+async fn reauthenticate(app: &mut App, gateway: &mut KubeGateway) -> LoopEffect {
+    app.note_reauth_attempt(Instant::now());
+    let refreshable = match gateway.reauthenticate().await {
+        Ok(refreshable) => refreshable,
+        Err(error) => {
+            app.set_status(format!(
+                "Authentication expired and reloading credentials failed: {}",
+                compact_error(&error)
+            ));
+            return LoopEffect::None;
+        }
+    };
+    let probe = match app.active_tab() {
+        tab if tab.kind().is_some() => tab,
+        _ => ResourceTab::Pods,
+    };
+    refresh_kubernetes_tab(app, gateway, probe).await;
+    if !app.auth_expired() {
+        return LoopEffect::RestartWatchers;
+    }
+    app.set_status(if refreshable {
+        format!(
+            "Authentication still failing for user '{}' after refreshing exec credentials; retrying in 30s",
+            gateway.user()
+        )
+    } else {
+        format!(
+            "Authentication expired for user '{}': its static token or certificate cannot refresh, re-login and orca picks up the new kubeconfig within 30s",
+            gateway.user()
+        )
+    });
+    LoopEffect::None
+}
+
 async fn refresh_kubernetes_tab(app: &mut App, gateway: &KubeGateway, tab: ResourceTab) {
     if matches!(tab, ResourceTab::Orca) {
         return;
@@ -2307,15 +4101,25 @@ async fn refresh_kubernetes_tab(app: &mut App, gateway: &KubeGateway, tab: Resou
 
     let scope = app.namespace_scope().clone();
     let selected_custom = app.selected_custom_resource().cloned();
+    let label_selector = app.label_selector_for(tab).map(str::to_string);
+    let field_selector = app.field_selector_for(tab).map(str::to_string);
     match timeout(
         TABLE_REFRESH_TIMEOUT,
-        gateway.fetch_table(tab, &scope, selected_custom.as_ref()),
+        gateway.fetch_table(
+            tab,
+            &scope,
+            selected_custom.as_ref(),
+            label_selector.as_deref(),
+            field_selector.as_deref(),
+            app.list_pages_for(tab),
+        ),
     )
     .await
     {
         Ok(Ok(table)) => {
             app.set_active_table_data(tab, table);
-            if tab == app.active_tab() {
+            app.mark_auth_recovered();
+            if tab == app.active_tab() && !app.metrics_unavailable() {
                 match timeout(
                     METRICS_REFRESH_TIMEOUT,
                     gateway.fetch_overview_metrics(&scope),
@@ -2323,6 +4127,9 @@ async fn refresh_kubernetes_tab(app: &mut App, gateway: &KubeGateway, tab: Resou
                 .await
                 {
                     Ok(Ok(metrics)) => app.set_overview_metrics(metrics),
+                    Ok(Err(error)) if k8s::is_metrics_api_unavailable(&error) => {
+                        app.mark_metrics_unavailable();
+                    }
                     Ok(Err(error)) => {
                         app.set_status(format!(
                             "Metrics refresh failed for {}: {}",
@@ -2354,6 +4161,13 @@ async fn refresh_kubernetes_tab(app: &mut App, gateway: &KubeGateway, tab: Resou
                 }
             }
         }
+        Ok(Err(error)) if k8s::is_auth_failure(&error) => {
+            app.set_active_tab_error(
+                tab,
+                format!("authentication expired: {}", compact_error(&error)),
+            );
+            app.mark_auth_expired();
+        }
         Ok(Err(error)) => app.set_active_tab_error(tab, compact_error(&error)),
         Err(_) => {
             app.set_status(format!(
@@ -2392,6 +4206,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 "online".to_string(),
             ],
             detail: "ORCA unified control plane".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "k8s".to_string(),
@@ -2407,6 +4222,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 },
             ],
             detail: "Kubernetes estates under ORCA control".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "k8s/clusters".to_string(),
@@ -2418,6 +4234,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 "ok".to_string(),
             ],
             detail: "Discovered Kubernetes clusters from kubeconfig".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "k8s/contexts".to_string(),
@@ -2429,6 +4246,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 "ok".to_string(),
             ],
             detail: "Discovered kube contexts".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "k8s/users".to_string(),
@@ -2440,6 +4258,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 "ok".to_string(),
             ],
             detail: "Discovered kube auth users".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "k8s/namespaces".to_string(),
@@ -2455,6 +4274,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 },
             ],
             detail: "Current namespace inventory".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "k8s/nodes".to_string(),
@@ -2470,6 +4290,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 },
             ],
             detail: "Current node inventory".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "k8s/pods".to_string(),
@@ -2485,6 +4306,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 },
             ],
             detail: "Current pod inventory".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "argocd".to_string(),
@@ -2496,6 +4318,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 argo_state.to_string(),
             ],
             detail: "Argo CD application delivery surface".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "argocd/apps".to_string(),
@@ -2511,6 +4334,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 },
             ],
             detail: "Argo CD app catalog".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "argocd/resources".to_string(),
@@ -2526,6 +4350,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 },
             ],
             detail: "Argo CD managed resource graph".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "services".to_string(),
@@ -2537,6 +4362,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 "mapped".to_string(),
             ],
             detail: "Operations services exposed in ORCA".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "service/helm".to_string(),
@@ -2548,6 +4374,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 "ready".to_string(),
             ],
             detail: "Helm release management".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "service/terraform".to_string(),
@@ -2559,6 +4386,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 "ready".to_string(),
             ],
             detail: "Terraform insights and plans".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "service/ansible".to_string(),
@@ -2570,6 +4398,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 "ready".to_string(),
             ],
             detail: "Ansible execution catalog".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "service/docker".to_string(),
@@ -2581,6 +4410,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 "ready".to_string(),
             ],
             detail: "Container runtime inspection".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "service/git".to_string(),
@@ -2592,6 +4422,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 "ready".to_string(),
             ],
             detail: "Repository catalog and apply workflow".to_string(),
+            ..RowData::default()
         },
         RowData {
             name: "service/crd".to_string(),
@@ -2603,6 +4434,7 @@ fn build_orca_dashboard_table(app: &App) -> TableData {
                 "ready".to_string(),
             ],
             detail: "Custom resources discovered in cluster".to_string(),
+            ..RowData::default()
         },
     ];
 
@@ -2638,7 +4470,7 @@ fn compact_label(value: &str, max_chars: usize) -> String {
     out
 }
 
-async fn refresh_argocd_tab(app: &mut App, tab: ResourceTab) {
+async fn refresh_argocd_tab(app: &mut App, kube_target: KubectlTarget<'_>, tab: ResourceTab) {
     if let Some(server) = fetch_argocd_server().await {
         app.set_argocd_server(server);
     }
@@ -2680,7 +4512,7 @@ async fn refresh_argocd_tab(app: &mut App, tab: ResourceTab) {
                 return;
             };
 
-            match fetch_argocd_resources_table(&app_name).await {
+            match fetch_argocd_resources_table(app, kube_target, &app_name).await {
                 Ok(table) => app.set_active_table_data(tab, table),
                 Err(error) => app.set_active_tab_error(tab, error),
             }
@@ -2806,7 +4638,11 @@ async fn fetch_argocd_apps_table() -> std::result::Result<TableData, String> {
             name: name.clone(),
             namespace: Some(app_namespace.clone()),
             columns: vec![name, project, dest_namespace, sync, health, repo, path],
+            labels: json_string_map(item.pointer("/metadata/labels")),
+            annotations: json_string_map(item.pointer("/metadata/annotations")),
             detail,
+            created: None,
+            phase: None,
         });
     }
 
@@ -2827,7 +4663,13 @@ async fn fetch_argocd_apps_table() -> std::result::Result<TableData, String> {
     Ok(table)
 }
 
-async fn fetch_argocd_resources_table(app_name: &str) -> std::result::Result<TableData, String> {
+const ARGOCD_LIVE_FETCH_CONCURRENCY: usize = 4;
+
+async fn fetch_argocd_resources_table(
+    app: &mut App,
+    kube_target: KubectlTarget<'_>,
+    app_name: &str,
+) -> std::result::Result<TableData, String> {
     let payload = run_external_json(
         "argocd",
         &[
@@ -2871,6 +4713,7 @@ async fn fetch_argocd_resources_table(app_name: &str) -> std::result::Result<Tab
     fn kind_rank(kind: &str) -> u8 {
         match kind.to_ascii_lowercase().as_str() {
             "service" => 1,
+            "endpointslice" | "endpoints" => 2,
             "configmap" => 2,
             "secret" => 3,
             "deployment" => 10,
@@ -2894,6 +4737,7 @@ async fn fetch_argocd_resources_table(app_name: &str) -> std::result::Result<Tab
             "job" => "󰁨",
             "cronjob" => "󰃰",
             "service" => "󰒓",
+            "endpointslice" | "endpoints" => "󰛳",
             "configmap" => "󰈙",
             "secret" => "󰌋",
             "namespace" => "󰉖",
@@ -2905,6 +4749,8 @@ async fn fetch_argocd_resources_table(app_name: &str) -> std::result::Result<Tab
     fn tree_kind_label(kind: &str) -> String {
         match kind.to_ascii_lowercase().as_str() {
             "service" => "svc".to_string(),
+            "endpointslice" => "eps".to_string(),
+            "endpoints" => "ep".to_string(),
             "deployment" => "dpl".to_string(),
             "replicaset" => "rs".to_string(),
             "replicationcontroller" => "rc".to_string(),
@@ -3000,6 +4846,7 @@ async fn fetch_argocd_resources_table(app_name: &str) -> std::result::Result<Tab
                 node.wave.clone(),
             ],
             detail: node.detail.clone(),
+            ..RowData::default()
         });
 
         let branch = children.get(key).cloned().unwrap_or_default();
@@ -3028,6 +4875,7 @@ async fn fetch_argocd_resources_table(app_name: &str) -> std::result::Result<Tab
     let mut tracked_daemonsets = HashSet::<String>::new();
     let mut tracked_jobs = HashSet::<String>::new();
     let mut tracked_replicasets = HashSet::<String>::new();
+    let mut tracked_services = HashSet::<String>::new();
 
     for item in resources {
         let kind = item
@@ -3106,6 +4954,9 @@ async fn fetch_argocd_resources_table(app_name: &str) -> std::result::Result<Tab
             "replicaset" => {
                 tracked_replicasets.insert(set_key);
             }
+            "service" => {
+                tracked_services.insert(set_key);
+            }
             _ => {}
         }
     }
@@ -3116,28 +4967,79 @@ async fn fetch_argocd_resources_table(app_name: &str) -> std::result::Result<Tab
         .filter(|namespace| !namespace.is_empty() && namespace != "-")
         .collect::<HashSet<_>>();
 
+    let now = Instant::now();
+    let mut live_by_namespace = Vec::new();
+    let mut stale = Vec::new();
     for namespace in namespaces {
-        let payload = run_external_json(
-            "kubectl",
-            &[
-                "get".to_string(),
-                "replicasets,pods".to_string(),
-                "-n".to_string(),
-                namespace.clone(),
-                "-o".to_string(),
-                "json".to_string(),
-            ],
-            10,
-        )
-        .await;
-        let Ok(payload) = payload else {
-            continue;
-        };
-        let items = payload
-            .get("items")
-            .and_then(Value::as_array)
-            .cloned()
-            .unwrap_or_default();
+        let want_endpoints = tracked_services
+            .iter()
+            .any(|key| key.split_once('|').is_some_and(|(ns, _)| ns == namespace));
+        match app.cached_argocd_live(&namespace, want_endpoints, now) {
+            Some(live) => live_by_namespace.push((namespace, live)),
+            None => stale.push((namespace, want_endpoints)),
+        }
+    }
+    let fetched = futures::stream::iter(stale.into_iter().map(
+        |(namespace, want_endpoints)| async move {
+            let (live, complete) =
+                fetch_argocd_namespace_live(kube_target, &namespace, want_endpoints).await;
+            (namespace, want_endpoints, live, complete)
+        },
+    ))
+    .buffer_unordered(ARGOCD_LIVE_FETCH_CONCURRENCY)
+    .collect::<Vec<_>>()
+    .await;
+    for (namespace, want_endpoints, live, complete) in fetched {
+        // A failed kubectl call leaves holes; retry it on the next refresh instead.
+        if complete {
+            app.cache_argocd_live(namespace.clone(), live.clone(), want_endpoints, now);
+        }
+        live_by_namespace.push((namespace, live));
+    }
+
+    for (namespace, live) in live_by_namespace {
+        for item in live.endpoints {
+            let Some(name) = item.pointer("/metadata/name").and_then(Value::as_str) else {
+                continue;
+            };
+            let kind = if item.get("endpoints").is_some() || item.get("addressType").is_some() {
+                "EndpointSlice"
+            } else {
+                "Endpoints"
+            };
+            let service = if kind == "EndpointSlice" {
+                item.pointer("/metadata/labels/kubernetes.io~1service-name")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+            } else {
+                name
+            };
+            if !tracked_services.contains(&format!("{}|{}", namespace, service)) {
+                continue;
+            }
+            let key = node_key(kind, &namespace, name);
+            if nodes.contains_key(&key) {
+                continue;
+            }
+            let (ready, total) = endpoint_readiness(&item);
+            let detail = serde_json::to_string_pretty(&item).unwrap_or_else(|_| item.to_string());
+            nodes.insert(
+                key,
+                ArgoTreeNode {
+                    kind: kind.to_string(),
+                    namespace: namespace.clone(),
+                    name: name.to_string(),
+                    sync: "Live".to_string(),
+                    health: format!("{ready}/{total} ready"),
+                    hook: "-".to_string(),
+                    wave: "-".to_string(),
+                    parent: Some(node_key("Service", &namespace, service)),
+                    detail,
+                },
+            );
+        }
+
+        let items = live.workloads;
 
         for item in &items {
             let kind = item
@@ -3348,6 +5250,98 @@ async fn fetch_argocd_resources_table(app_name: &str) -> std::result::Result<Tab
     Ok(table)
 }
 
+/// Live workloads (and endpoints when wanted) in one namespace, plus whether every kubectl
+/// call succeeded; failed lookups come back empty so the tree still renders.
+async fn fetch_argocd_namespace_live(
+    kube_target: KubectlTarget<'_>,
+    namespace: &str,
+    want_endpoints: bool,
+) -> (ArgoNamespaceLive, bool) {
+    let get_items = |resources: &str| {
+        let args = vec![
+            "get".to_string(),
+            resources.to_string(),
+            "-n".to_string(),
+            namespace.to_string(),
+            "-o".to_string(),
+            "json".to_string(),
+        ];
+        async move {
+            run_external_json("kubectl", &kubectl_args(kube_target, &args), 10)
+                .await
+                .map(|payload| {
+                    payload
+                        .get("items")
+                        .and_then(Value::as_array)
+                        .cloned()
+                        .unwrap_or_default()
+                })
+        }
+    };
+
+    if want_endpoints
+        && let Ok(items) = get_items("replicasets,pods,endpointslices.discovery.k8s.io").await
+    {
+        return (split_argocd_live_items(items), true);
+    }
+    let workloads = get_items("replicasets,pods").await;
+    let endpoints = if !want_endpoints {
+        Ok(Vec::new())
+    } else {
+        match get_items("endpointslices.discovery.k8s.io").await {
+            Ok(items) => Ok(items),
+            Err(_) => get_items("endpoints").await,
+        }
+    };
+    let complete = workloads.is_ok() && endpoints.is_ok();
+    let live = ArgoNamespaceLive {
+        workloads: workloads.unwrap_or_default(),
+        endpoints: endpoints.unwrap_or_default(),
+    };
+    (live, complete)
+}
+
+fn split_argocd_live_items(items: Vec<Value>) -> ArgoNamespaceLive {
+    let (endpoints, workloads) = items.into_iter().partition(|item| {
+        item.get("kind")
+            .and_then(Value::as_str)
+            .is_some_and(|kind| kind.eq_ignore_ascii_case("endpointslice"))
+    });
+    ArgoNamespaceLive {
+        workloads,
+        endpoints,
+    }
+}
+
+fn endpoint_readiness(item: &Value) -> (usize, usize) {
+    if let Some(endpoints) = item.get("endpoints").and_then(Value::as_array) {
+        let ready = endpoints
+            .iter()
+            .filter(|endpoint| {
+                endpoint
+                    .pointer("/conditions/ready")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(true)
+            })
+            .count();
+        return (ready, endpoints.len());
+    }
+    let subsets = item
+        .get("subsets")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let count = |field: &str| {
+        subsets
+            .iter()
+            .filter_map(|subset| subset.get(field).and_then(Value::as_array))
+            .map(Vec::len)
+            .sum::<usize>()
+    };
+    let ready = count("addresses");
+    (ready, ready + count("notReadyAddresses"))
+}
+
 fn kubectl_resource_token_for_kind(kind: &str) -> String {
     match kind.to_ascii_lowercase().as_str() {
         "pod" => "pod".to_string(),
@@ -3358,6 +5352,7 @@ fn kubectl_resource_token_for_kind(kind: &str) -> String {
         "job" => "job".to_string(),
         "cronjob" => "cronjob".to_string(),
         "service" => "service".to_string(),
+        "endpointslice" => "endpointslices.discovery.k8s.io".to_string(),
         "namespace" => "namespace".to_string(),
         "node" => "node".to_string(),
         other => other.to_string(),
@@ -3387,6 +5382,7 @@ struct ArgoResourcePanelSections {
 }
 
 async fn fetch_argocd_resource_panel_sections(
+    kube_target: KubectlTarget<'_>,
     kind: &str,
     namespace: Option<&str>,
     name: &str,
@@ -3404,7 +5400,8 @@ async fn fetch_argocd_resource_panel_sections(
     }
     get_json_args.push("-o".to_string());
     get_json_args.push("json".to_string());
-    let summary_json = run_external_json("kubectl", &get_json_args, 8).await;
+    let summary_json =
+        run_external_json("kubectl", &kubectl_args(kube_target, &get_json_args), 8).await;
 
     let summary_block = match summary_json.as_ref() {
         Ok(object) => {
@@ -3481,7 +5478,7 @@ async fn fetch_argocd_resource_panel_sections(
         event_args.insert(2, "-n".to_string());
         event_args.insert(3, namespace.to_string());
     }
-    let events_block = run_external_readonly("kubectl", &event_args, 8)
+    let events_block = run_external_readonly("kubectl", &kubectl_args(kube_target, &event_args), 8)
         .await
         .map(|output| bounded_output(&output, 40, 220))
         .unwrap_or_else(|error| format!("Events unavailable: {error}"));
@@ -3496,7 +5493,7 @@ async fn fetch_argocd_resource_panel_sections(
             ];
             logs_args.push("-n".to_string());
             logs_args.push(namespace.to_string());
-            run_external_readonly("kubectl", &logs_args, 10)
+            run_external_readonly("kubectl", &kubectl_args(kube_target, &logs_args), 10)
                 .await
                 .map(|output| bounded_output(&output, 80, 220))
                 .unwrap_or_else(|error| format!("Logs unavailable: {error}"))
@@ -3514,10 +5511,11 @@ async fn fetch_argocd_resource_panel_sections(
     }
     manifest_args.push("-o".to_string());
     manifest_args.push("yaml".to_string());
-    let manifest_block = run_external_readonly("kubectl", &manifest_args, 10)
-        .await
-        .map(|output| bounded_output(&output, 240, 220))
-        .unwrap_or_else(|error| format!("Manifest unavailable: {error}"));
+    let manifest_block =
+        run_external_readonly("kubectl", &kubectl_args(kube_target, &manifest_args), 10)
+            .await
+            .map(|output| bounded_output(&output, 240, 220))
+            .unwrap_or_else(|error| format!("Manifest unavailable: {error}"));
 
     let title = match namespace.as_deref() {
         Some(namespace) => format!("Argo {kind} {namespace}/{name}"),
@@ -3534,12 +5532,139 @@ async fn fetch_argocd_resource_panel_sections(
     ))
 }
 
+async fn argocd_repo_url(repo: &str) -> std::result::Result<String, String> {
+    let repo = repo.trim();
+    if looks_like_repo_url(repo) {
+        return Ok(repo.to_string());
+    }
+    let (slug, path) = repo_checkout_path(repo, &repo_cache_root());
+    if !path.join(".git").exists() {
+        return Err(format!(
+            "repo '{repo}' is not cached. Use its URL or run :git fetch <url> first"
+        ));
+    }
+    let args = vec![
+        "-C".to_string(),
+        path.display().to_string(),
+        "remote".to_string(),
+        "get-url".to_string(),
+        "origin".to_string(),
+    ];
+    run_external_readonly("git", &args, 6)
+        .await
+        .map(|url| url.trim().to_string())
+        .map_err(|error| format!("cached repo {slug} has no origin URL: {error}"))
+}
+
+fn argocd_create_args(
+    name: &str,
+    repo_url: &str,
+    path: &str,
+    dest_namespace: &str,
+    project: &str,
+) -> Vec<String> {
+    vec![
+        "app".to_string(),
+        "create".to_string(),
+        name.to_string(),
+        "--repo".to_string(),
+        repo_url.to_string(),
+        "--path".to_string(),
+        path.to_string(),
+        "--dest-server".to_string(),
+        "https://kubernetes.default.svc".to_string(),
+        "--dest-namespace".to_string(),
+        dest_namespace.to_string(),
+        "--project".to_string(),
+        project.to_string(),
+    ]
+}
+
+fn argocd_sync_args(name: &str, prune: bool, dry_run: bool, resource: Option<&str>) -> Vec<String> {
+    let mut args = vec!["app".to_string(), "sync".to_string(), name.to_string()];
+    if prune {
+        args.push("--prune".to_string());
+    }
+    if dry_run {
+        args.push("--dry-run".to_string());
+    }
+    if let Some(resource) = resource {
+        args.push("--resource".to_string());
+        args.push(resource.to_string());
+    }
+    args
+}
+
+async fn run_argocd_diff(app_name: &str) -> std::result::Result<String, String> {
+    let mut cmd = TokioCommand::new("argocd");
+    cmd.args(["app", "diff", app_name])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = timeout(Duration::from_secs(30), cmd.output())
+        .await
+        .map_err(|_| "argocd timed out after 30s".to_string())?
+        .map_err(|error| format!("argocd: {error}"))?;
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    // argocd app diff exits 1 when the live state differs from the target state.
+    match output.status.code() {
+        Some(0) => Ok(String::new()),
+        Some(1) if !stdout.is_empty() => Ok(stdout),
+        _ => {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            let rendered = if stderr.is_empty() { stdout } else { stderr };
+            Err(format!(
+                "argocd app diff failed:\n{}",
+                bounded_output(&rendered, 80, 220)
+            ))
+        }
+    }
+}
+
+fn argocd_resource_diff_section(app_name: &str, output: &str, kind: &str, name: &str) -> String {
+    if output.trim().is_empty() {
+        return format!("DIFF {app_name}\n(in sync, no differences)");
+    }
+    let mut blocks = Vec::new();
+    let mut current: Option<Vec<&str>> = None;
+    for line in output.lines() {
+        if line.starts_with("=====") {
+            if let Some(block) = current.take() {
+                blocks.push(block);
+            }
+            current = Some(vec![line]);
+        } else if let Some(block) = current.as_mut() {
+            block.push(line);
+        }
+    }
+    blocks.extend(current);
+
+    let selected = blocks.into_iter().find(|block| {
+        let mut fields = block[0]
+            .trim_matches(|ch| ch == '=' || ch == ' ')
+            .split_whitespace();
+        let header_kind = fields.next().unwrap_or_default();
+        let header_name = fields.next().unwrap_or_default();
+        let header_kind = header_kind.rsplit('/').next().unwrap_or(header_kind);
+        let header_name = header_name.rsplit('/').next().unwrap_or(header_name);
+        header_kind.eq_ignore_ascii_case(kind) && header_name == name
+    });
+    match selected {
+        Some(block) => format!("DIFF {app_name}\n{}", block.join("\n")),
+        None => format!(
+            "DIFF {app_name}\n({kind} {name} has no differences; full app diff below)\n\n{}",
+            bounded_output(output, 320, 220)
+        ),
+    }
+}
+
 async fn fetch_argocd_resource_panel(
+    kube_target: KubectlTarget<'_>,
     kind: &str,
     namespace: Option<&str>,
     name: &str,
 ) -> std::result::Result<(String, String), String> {
-    let (title, sections) = fetch_argocd_resource_panel_sections(kind, namespace, name).await?;
+    let (title, sections) =
+        fetch_argocd_resource_panel_sections(kube_target, kind, namespace, name).await?;
     let panel = format!(
         "SUMMARY\n{}\n\nEVENTS\n{}\n\nLOGS\n{}\n\nLIVE MANIFEST\n{}",
         sections.summary, sections.events, sections.logs, sections.manifest
@@ -3609,7 +5734,11 @@ async fn fetch_argocd_projects_table() -> std::result::Result<TableData, String>
                 cluster_whitelist,
                 namespace_whitelist,
             ],
+            labels: json_string_map(item.pointer("/metadata/labels")),
+            annotations: json_string_map(item.pointer("/metadata/annotations")),
             detail,
+            created: None,
+            phase: None,
         });
     }
 
@@ -3687,7 +5816,11 @@ async fn fetch_argocd_repos_table() -> std::result::Result<TableData, String> {
             name: short_repo_label(&repo),
             namespace: None,
             columns: vec![repo, typ, name, project, insecure, oci],
+            labels: json_string_map(item.pointer("/metadata/labels")),
+            annotations: json_string_map(item.pointer("/metadata/annotations")),
             detail,
+            created: None,
+            phase: None,
         });
     }
 
@@ -3762,7 +5895,11 @@ async fn fetch_argocd_clusters_table() -> std::result::Result<TableData, String>
             name: name.clone(),
             namespace: None,
             columns: vec![name, server, status, version, applications],
+            labels: json_string_map(item.pointer("/metadata/labels")),
+            annotations: json_string_map(item.pointer("/metadata/annotations")),
             detail,
+            created: None,
+            phase: None,
         });
     }
 
@@ -3826,7 +5963,11 @@ async fn fetch_argocd_accounts_table() -> std::result::Result<TableData, String>
             name: name.clone(),
             namespace: None,
             columns: vec![name, enabled, capabilities],
+            labels: json_string_map(item.pointer("/metadata/labels")),
+            annotations: json_string_map(item.pointer("/metadata/annotations")),
             detail,
+            created: None,
+            phase: None,
         });
     }
 
@@ -3906,7 +6047,11 @@ async fn fetch_argocd_certs_table() -> std::result::Result<TableData, String> {
             name: format!("{server}:{sub_type}"),
             namespace: None,
             columns: vec![server, cert_type, sub_type, fingerprint],
+            labels: json_string_map(item.pointer("/metadata/labels")),
+            annotations: json_string_map(item.pointer("/metadata/annotations")),
             detail,
+            created: None,
+            phase: None,
         });
     }
 
@@ -3985,7 +6130,11 @@ async fn fetch_argocd_gpg_table() -> std::result::Result<TableData, String> {
             name: key_id.clone(),
             namespace: None,
             columns: vec![key_id, fingerprint, users],
+            labels: json_string_map(item.pointer("/metadata/labels")),
+            annotations: json_string_map(item.pointer("/metadata/annotations")),
             detail,
+            created: None,
+            phase: None,
         });
     }
 
@@ -4002,6 +6151,17 @@ async fn fetch_argocd_gpg_table() -> std::result::Result<TableData, String> {
     Ok(table)
 }
 
+fn json_string_map(value: Option<&Value>) -> BTreeMap<String, String> {
+    value
+        .and_then(Value::as_object)
+        .map(|map| {
+            map.iter()
+                .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 fn short_repo_label(repo: &str) -> String {
     let trimmed = repo.trim().trim_end_matches('/');
     if trimmed.is_empty() {
@@ -4019,22 +6179,42 @@ fn short_repo_label(repo: &str) -> String {
     }
 }
 
+async fn refresh_server_version(app: &mut App, gateway: &mut KubeGateway) {
+    match timeout(METRICS_REFRESH_TIMEOUT, gateway.server_version()).await {
+        Ok(Ok(version)) => app.set_server_version(Some(version.label())),
+        _ => app.set_server_version(None),
+    }
+}
+
 async fn refresh_custom_resource_catalog(app: &mut App, gateway: &KubeGateway) {
     match timeout(CRD_DISCOVERY_TIMEOUT, gateway.discover_custom_resources()).await {
         Ok(Ok(crds)) => app.set_custom_resources(crds),
         Ok(Err(error)) => app.set_status(format!("CRD discovery failed: {error:#}")),
         Err(_) => app.set_status("CRD discovery timed out (using cached)"),
     }
+    match timeout(CRD_DISCOVERY_TIMEOUT, gateway.unserved_tabs()).await {
+        Ok(Ok(unserved)) => app.set_unserved_tabs(unserved),
+        Ok(Err(error)) => app.set_status(format!("API discovery failed: {error:#}")),
+        Err(_) => app.set_status("API discovery timed out (tab visibility unchanged)"),
+    }
 }
 
-async fn run_kubectl_exec(namespace: &str, pod_name: &str, command: &[String]) -> Result<String> {
+async fn run_kubectl_exec(
+    kube_target: KubectlTarget<'_>,
+    namespace: &str,
+    pod_name: &str,
+    command: &[String],
+) -> Result<String> {
+    let mut args = vec![
+        "exec".to_string(),
+        "-n".to_string(),
+        namespace.to_string(),
+        pod_name.to_string(),
+        "--".to_string(),
+    ];
+    args.extend(command.iter().cloned());
     let mut cmd = TokioCommand::new("kubectl");
-    cmd.arg("exec")
-        .arg("-n")
-        .arg(namespace)
-        .arg(pod_name)
-        .arg("--")
-        .args(command)
+    cmd.args(kubectl_args(kube_target, &args))
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
 
@@ -4067,9 +6247,11 @@ struct StartedEmbeddedShell {
     child: Box<dyn portable_pty::Child + Send + Sync>,
     writer: Box<dyn Write + Send>,
     reader: Box<dyn Read + Send>,
+    size: PtySize,
 }
 
 fn start_embedded_kubectl_shell(
+    kube_target: KubectlTarget<'_>,
     namespace: &str,
     pod_name: &str,
     container: Option<&str>,
@@ -4082,73 +6264,167 @@ elif command -v ash >/dev/null 2>&1; then exec ash -i; \
 elif command -v sh >/dev/null 2>&1; then exec sh -i; \
 else exec /bin/sh -i; fi";
 
-    let pty_system = native_pty_system();
-    let pty_pair = pty_system
-        .openpty(PtySize {
-            rows: 48,
-            cols: 180,
-            pixel_width: 0,
-            pixel_height: 0,
-        })
-        .context("failed to allocate pseudo-tty for embedded shell")?;
-
-    let mut cmd = PtyCommandBuilder::new("kubectl");
-    cmd.env("TERM", "xterm-256color");
-    cmd.arg("exec");
-    cmd.arg("-i");
-    cmd.arg("-t");
-    cmd.arg("-n");
-    cmd.arg(namespace);
-    cmd.arg(pod_name);
+    let mut args = vec![
+        "exec".to_string(),
+        "-i".to_string(),
+        "-t".to_string(),
+        "-n".to_string(),
+        namespace.to_string(),
+        pod_name.to_string(),
+    ];
     if let Some(container) = container {
-        cmd.arg("-c");
-        cmd.arg(container);
+        args.push("-c".to_string());
+        args.push(container.to_string());
     }
-    cmd.arg("--");
+    args.push("--".to_string());
     if shell.eq_ignore_ascii_case("auto") {
-        cmd.arg("sh");
-        cmd.arg("-lc");
-        cmd.arg(AUTO_SHELL_BOOTSTRAP);
+        args.push("sh".to_string());
+        args.push("-lc".to_string());
+        args.push(AUTO_SHELL_BOOTSTRAP.to_string());
     } else {
-        cmd.arg(shell);
-        cmd.arg("-i");
+        args.push(shell.to_string());
+        args.push("-i".to_string());
+    }
+
+    spawn_kubectl_pty(kube_target, &args, DEFAULT_SHELL_PTY_SIZE)
+        .with_context(|| format!("failed to start embedded shell for {namespace}/{pod_name}"))
+}
+
+const DEFAULT_SHELL_PTY_SIZE: PtySize = PtySize {
+    rows: 48,
+    cols: 180,
+    pixel_width: 0,
+    pixel_height: 0,
+};
+
+fn exec_pty_size(size: ratatui::layout::Size) -> PtySize {
+    // The shell overlay sits inside the header, footer and pane borders.
+    PtySize {
+        rows: size.height.saturating_sub(10).max(10),
+        cols: size.width.saturating_sub(4).max(40),
+        pixel_width: 0,
+        pixel_height: 0,
+    }
+}
+
+fn start_embedded_kubectl_exec(
+    kube_target: KubectlTarget<'_>,
+    namespace: &str,
+    pod_name: &str,
+    command: &[String],
+    size: PtySize,
+) -> Result<StartedEmbeddedShell> {
+    let mut args = vec![
+        "exec".to_string(),
+        "-i".to_string(),
+        "-t".to_string(),
+        "-n".to_string(),
+        namespace.to_string(),
+        pod_name.to_string(),
+        "--".to_string(),
+    ];
+    args.extend(command.iter().cloned());
+    spawn_kubectl_pty(kube_target, &args, size)
+        .with_context(|| format!("failed to start tty exec for {namespace}/{pod_name}"))
+}
+
+fn start_embedded_kubectl_debug(
+    kube_target: KubectlTarget<'_>,
+    namespace: &str,
+    pod_name: &str,
+    image: &str,
+    target_container: Option<&str>,
+    size: PtySize,
+) -> Result<StartedEmbeddedShell> {
+    let mut args = vec![
+        "debug".to_string(),
+        "-i".to_string(),
+        "-t".to_string(),
+        "-n".to_string(),
+        namespace.to_string(),
+        pod_name.to_string(),
+        format!("--image={image}"),
+    ];
+    if let Some(container) = target_container {
+        args.push(format!("--target={container}"));
     }
+    spawn_kubectl_pty(kube_target, &args, size)
+        .with_context(|| format!("failed to start debug container for {namespace}/{pod_name}"))
+}
+
+fn spawn_kubectl_pty(
+    kube_target: KubectlTarget<'_>,
+    args: &[String],
+    size: PtySize,
+) -> Result<StartedEmbeddedShell> {
+    let pty_system = native_pty_system();
+    let pty_pair = pty_system
+        .openpty(size)
+        .context("failed to allocate pseudo-tty")?;
+
+    let mut cmd = PtyCommandBuilder::new("kubectl");
+    cmd.env("TERM", "xterm-256color");
+    cmd.args(kubectl_args(kube_target, args));
 
     let child = pty_pair
         .slave
         .spawn_command(cmd)
-        .with_context(|| format!("failed to start embedded shell for {namespace}/{pod_name}"))?;
+        .context("failed to spawn kubectl in pseudo-tty")?;
 
     let reader = pty_pair
         .master
         .try_clone_reader()
-        .context("failed to capture embedded shell reader")?;
+        .context("failed to capture pseudo-tty reader")?;
     let writer = pty_pair
         .master
         .take_writer()
-        .context("failed to capture embedded shell writer")?;
+        .context("failed to capture pseudo-tty writer")?;
 
     Ok(StartedEmbeddedShell {
         child,
         writer,
         reader,
+        size,
     })
 }
 
+fn attach_embedded_shell(
+    shell: &mut EmbeddedShellState,
+    started: StartedEmbeddedShell,
+    tx: mpsc::UnboundedSender<ShellOutputEvent>,
+) {
+    shell.parser = Some(spawn_shell_reader(started.reader, started.size, tx));
+    shell.child = Some(started.child);
+    shell.writer = Some(started.writer);
+    shell.application_cursor = false;
+    shell.scroll_offset = 0;
+}
+
 fn spawn_shell_reader(
     mut reader: Box<dyn Read + Send>,
+    size: PtySize,
     tx: mpsc::UnboundedSender<ShellOutputEvent>,
-) {
+) -> Arc<Mutex<vt100::Parser>> {
+    // Match the pty geometry so lines that scroll off land in the scrollback buffer.
+    let parser = Arc::new(Mutex::new(vt100::Parser::new(
+        size.rows,
+        size.cols,
+        SHELL_SCROLLBACK_LINES,
+    )));
+    let shared = Arc::clone(&parser);
     std::thread::spawn(move || {
-        let mut parser = vt100::Parser::new(200, 240, 4_000);
         let mut buffer = vec![0u8; 4096];
         loop {
             match reader.read(&mut buffer) {
                 Ok(0) => break,
                 Ok(read) => {
+                    let Ok(mut parser) = shared.lock() else {
+                        break;
+                    };
                     parser.process(&buffer[..read]);
                     let snapshot = render_shell_snapshot(parser.screen());
                     let application_cursor = parser.screen().application_cursor();
+                    drop(parser);
                     let _ = tx.send(ShellOutputEvent {
                         snapshot,
                         application_cursor,
@@ -4164,9 +6440,50 @@ fn spawn_shell_reader(
             }
         }
     });
+    parser
 }
 
-fn render_shell_snapshot(screen: &vt100::Screen) -> String {
+fn scroll_embedded_shell(shell: &mut EmbeddedShellState, app: &mut App, page_up: bool) {
+    let Some(parser) = shell.parser.as_ref() else {
+        return;
+    };
+    let Ok(mut parser) = parser.lock() else {
+        return;
+    };
+    let page = usize::from(parser.screen().size().0 / 2).max(1);
+    let target = if page_up {
+        shell.scroll_offset.saturating_add(page)
+    } else {
+        shell.scroll_offset.saturating_sub(page)
+    };
+    parser.screen_mut().set_scrollback(target);
+    shell.scroll_offset = parser.screen().scrollback();
+    app.replace_shell_output(render_shell_snapshot(parser.screen()));
+    if shell.scroll_offset == 0 {
+        app.set_status("Shell scrollback: live");
+    } else {
+        app.set_status(format!(
+            "Shell scrollback: {} lines up (PageDown or any key returns)",
+            shell.scroll_offset
+        ));
+    }
+}
+
+fn reset_embedded_shell_scroll(shell: &mut EmbeddedShellState, app: &mut App) {
+    if shell.scroll_offset == 0 {
+        return;
+    }
+    shell.scroll_offset = 0;
+    let Some(parser) = shell.parser.as_ref() else {
+        return;
+    };
+    if let Ok(mut parser) = parser.lock() {
+        parser.screen_mut().set_scrollback(0);
+        app.replace_shell_output(render_shell_snapshot(parser.screen()));
+    }
+}
+
+fn render_shell_snapshot(screen: &vt100::Screen) -> String {
     let (rows, cols) = screen.size();
     let mut lines = screen
         .rows(0, cols)
@@ -4174,7 +6491,9 @@ fn render_shell_snapshot(screen: &vt100::Screen) -> String {
         .collect::<Vec<String>>();
 
     let (cursor_row, cursor_col) = screen.cursor_position();
-    if let Some(line) = lines.get_mut(cursor_row as usize) {
+    if screen.scrollback() == 0
+        && let Some(line) = lines.get_mut(cursor_row as usize)
+    {
         let mut chars = line.chars().collect::<Vec<char>>();
         let cursor_index = cursor_col as usize;
         if cursor_index >= chars.len() {
@@ -4193,31 +6512,6 @@ fn render_shell_snapshot(screen: &vt100::Screen) -> String {
     lines.join("\n")
 }
 
-#[cfg(test)]
-mod shell_snapshot_tests {
-    use super::render_shell_snapshot;
-
-    #[test]
-    fn renders_block_cursor_without_raw_escape_bytes() {
-        let mut parser = vt100::Parser::new(8, 40, 32);
-        parser.process(b"\x1b[32mhello\x1b[0m");
-        let rendered = render_shell_snapshot(parser.screen());
-        assert!(rendered.contains("hello"));
-        assert!(rendered.contains('█'));
-        assert!(!rendered.contains("\x1b"));
-    }
-
-    #[test]
-    fn trims_trailing_blank_lines() {
-        let mut parser = vt100::Parser::new(8, 40, 32);
-        parser.process(b"line1\nline2");
-        let rendered = render_shell_snapshot(parser.screen());
-        assert!(rendered.contains("line1"));
-        assert!(rendered.contains("line2"));
-        assert!(!rendered.ends_with('\n'));
-    }
-}
-
 fn write_embedded_shell_bytes(writer: &mut Option<Box<dyn Write + Send>>, bytes: &[u8]) -> bool {
     let Some(writer) = writer.as_mut() else {
         return false;
@@ -4325,26 +6619,259 @@ fn forward_key_to_embedded_shell(
 
 async fn stop_embedded_shell(shell: &mut EmbeddedShellState) {
     shell.writer = None;
+    shell.parser = None;
     shell.application_cursor = false;
+    shell.scroll_offset = 0;
     if let Some(mut child) = shell.child.take() {
         let _ = child.kill();
         let _ = child.wait();
     }
 }
 
+fn resolve_source_edit_target(path: &Path) -> PathBuf {
+    if !path.is_dir() {
+        return path.to_path_buf();
+    }
+    for candidate in [
+        "kustomization.yaml",
+        "kustomization.yml",
+        "Chart.yaml",
+        "values.yaml",
+    ] {
+        let file = path.join(candidate);
+        if file.is_file() {
+            return file;
+        }
+    }
+    let mut manifests = fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|entry| {
+                    entry.is_file()
+                        && entry
+                            .extension()
+                            .and_then(|ext| ext.to_str())
+                            .is_some_and(|ext| matches!(ext, "yaml" | "yml" | "json"))
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    manifests.sort();
+    manifests
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+const EXPORT_NAMESPACE_TABS: [ResourceTab; 13] = [
+    ResourceTab::Deployments,
+    ResourceTab::StatefulSets,
+    ResourceTab::DaemonSets,
+    ResourceTab::CronJobs,
+    ResourceTab::Jobs,
+    ResourceTab::Services,
+    ResourceTab::Ingresses,
+    ResourceTab::ConfigMaps,
+    ResourceTab::PersistentVolumeClaims,
+    ResourceTab::ServiceAccounts,
+    ResourceTab::Roles,
+    ResourceTab::RoleBindings,
+    ResourceTab::NetworkPolicies,
+];
+
+async fn export_namespace_manifests(
+    gateway: &KubeGateway,
+    namespace: &str,
+    root: &Path,
+    include_secrets: bool,
+) -> Result<String> {
+    let scope = NamespaceScope::Named(namespace.to_string());
+    let mut tabs = EXPORT_NAMESPACE_TABS.to_vec();
+    if include_secrets {
+        tabs.push(ResourceTab::Secrets);
+    }
+
+    let mut written = 0usize;
+    let mut skipped_owned = 0usize;
+    let mut failures = Vec::new();
+    for tab in tabs {
+        let table = match timeout(
+            TABLE_REFRESH_TIMEOUT,
+            gateway.fetch_table(tab, &scope, None, None, None, usize::MAX),
+        )
+        .await
+        {
+            Ok(Ok(table)) => table,
+            Ok(Err(error)) => {
+                failures.push(format!("{}: {}", tab.title(), compact_error(&error)));
+                continue;
+            }
+            Err(_) => {
+                failures.push(format!("{}: timed out", tab.title()));
+                continue;
+            }
+        };
+
+        for row in table.rows {
+            let Ok(raw) = serde_yaml::from_str::<serde_yaml::Value>(&row.detail) else {
+                failures.push(format!("{} {}: unreadable manifest", tab.title(), row.name));
+                continue;
+            };
+            // Controller-owned objects (Jobs of a CronJob, ...) are recreated by their owner.
+            if raw
+                .get("metadata")
+                .and_then(|metadata| metadata.get("ownerReferences"))
+                .is_some()
+            {
+                skipped_owned += 1;
+                continue;
+            }
+            let kind = raw
+                .get("kind")
+                .and_then(serde_yaml::Value::as_str)
+                .map(str::to_ascii_lowercase)
+                .unwrap_or_else(|| tab.short_token().to_string());
+            let manifest = app::clean_manifest_yaml(&row.detail)
+                .with_context(|| format!("failed to clean {kind} {}", row.name))?;
+
+            let dir = root.join(&kind);
+            fs::create_dir_all(&dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+            let path = dir.join(format!("{}.yaml", row.name));
+            fs::write(&path, manifest)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+            written += 1;
+        }
+    }
+
+    let mut summary = format!(
+        "Exported {written} manifests from {namespace} to {}",
+        root.display()
+    );
+    if skipped_owned > 0 {
+        summary.push_str(&format!(" (skipped {skipped_owned} owned)"));
+    }
+    if !failures.is_empty() {
+        summary.push_str(&format!("; failed: {}", failures.join(", ")));
+    }
+    Ok(summary)
+}
+
+fn write_export_file(path: &Path, contents: &str) -> std::result::Result<PathBuf, String> {
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    fs::create_dir_all(parent).map_err(|error| {
+        format!(
+            "failed to create export directory {}: {error}",
+            parent.display()
+        )
+    })?;
+
+    let mut target = path.to_path_buf();
+    if target.exists() {
+        let stem = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("export");
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or("yaml");
+        let stamp = Local::now().format("%Y%m%d-%H%M%S");
+        target = parent.join(format!("{stem}-{stamp}.{extension}"));
+        let mut attempt = 1;
+        while target.exists() {
+            attempt += 1;
+            target = parent.join(format!("{stem}-{stamp}-{attempt}.{extension}"));
+        }
+    }
+
+    fs::write(&target, contents)
+        .map_err(|error| format!("failed to write {}: {error}", target.display()))?;
+    Ok(target)
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match path.strip_prefix("~/") {
+        Some(rest) => std::env::var_os("HOME")
+            .map(|home| PathBuf::from(home).join(rest))
+            .unwrap_or_else(|| PathBuf::from(path)),
+        None => PathBuf::from(path),
+    }
+}
+
+fn write_scratch_manifest(file_stem: &str, manifest: &str) -> Result<PathBuf> {
+    let dir = std::env::temp_dir().join("orca-scratch");
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("failed to create scratch dir {}", dir.display()))?;
+    let path = dir.join(format!(
+        "{file_stem}-{}.yaml",
+        Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    fs::write(&path, manifest)
+        .with_context(|| format!("failed to write scratch file {}", path.display()))?;
+    Ok(path)
+}
+
+async fn run_local_editor(terminal: &mut TuiTerminal, path: &Path) -> Result<()> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi").to_string();
+
+    suspend_terminal_for_subprocess(terminal)?;
+    let mut cmd = TokioCommand::new(&program);
+    cmd.args(parts)
+        .arg(path)
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit());
+    let run_result = cmd
+        .status()
+        .await
+        .with_context(|| format!("failed to run {program} for {}", path.display()));
+    let restore_result = resume_terminal_after_subprocess(terminal);
+
+    let status = match (run_result, restore_result) {
+        (Err(run_error), Err(restore_error)) => {
+            return Err(anyhow::anyhow!(
+                "{run_error:#}\nterminal resume error: {restore_error:#}"
+            ));
+        }
+        (Err(error), _) => return Err(error),
+        (_, Err(error)) => return Err(error),
+        (Ok(status), Ok(())) => status,
+    };
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!("{program} exited with status {status}"))
+    }
+}
+
 async fn run_kubectl_edit(
     terminal: &mut TuiTerminal,
+    kube_target: KubectlTarget<'_>,
     resource: &str,
     namespace: Option<&str>,
     name: &str,
 ) -> Result<()> {
     suspend_terminal_for_subprocess(terminal)?;
 
-    let mut cmd = TokioCommand::new("kubectl");
-    cmd.arg("edit").arg(resource).arg(name);
+    let mut args = vec!["edit".to_string(), resource.to_string(), name.to_string()];
     if let Some(namespace) = namespace {
-        cmd.arg("-n").arg(namespace);
+        args.push("-n".to_string());
+        args.push(namespace.to_string());
     }
+    let mut cmd = TokioCommand::new("kubectl");
+    cmd.args(kubectl_args(kube_target, &args));
     if std::env::var_os("KUBE_EDITOR").is_none()
         && let Some(editor) = std::env::var_os("EDITOR")
     {
@@ -4398,25 +6925,131 @@ fn resume_terminal_after_subprocess(terminal: &mut TuiTerminal) -> Result<()> {
     Ok(())
 }
 
+const EPHEMERAL_PORT_ATTEMPTS: usize = 3;
+
+#[allow(clippy::too_many_arguments)]
+async fn start_port_forward(
+    app: &mut App,
+    senders: &EventSenders,
+    kube_target: KubectlTarget<'_>,
+    tab: ResourceTab,
+    namespace: String,
+    name: String,
+    local_port: u16,
+    remote_port: u16,
+) {
+    match run_kubectl_port_forward(kube_target, tab, &namespace, &name, local_port, remote_port)
+        .await
+    {
+        Ok((pid, mut child, local_port)) => {
+            app.register_port_forward(
+                tab,
+                namespace.clone(),
+                name.clone(),
+                local_port,
+                remote_port,
+                pid,
+            );
+            let target = match tab {
+                ResourceTab::Pods => format!("pod/{name}"),
+                ResourceTab::Services => format!("service/{name}"),
+                _ => name.clone(),
+            };
+            app.set_status(format!(
+                "Port-forward started ({target}) {local_port}:{remote_port} pid={pid}"
+            ));
+
+            let tx = senders.port_forward.clone();
+            let (stop_tx, stop_rx) = oneshot::channel();
+            if let Ok(mut stops) = senders.port_forward_stops.lock() {
+                stops.insert(pid, stop_tx);
+            }
+            tokio::spawn(async move {
+                let result = tokio::select! {
+                    status = child.wait() => status,
+                    Ok(()) = stop_rx => match child.start_kill() {
+                        Ok(()) => child.wait().await,
+                        Err(error) => Err(error),
+                    },
+                }
+                .map_err(|error| format!("wait failed: {error}"));
+                let _ = tx.send(PortForwardExitEvent {
+                    pid,
+                    tab,
+                    namespace,
+                    name,
+                    local_port,
+                    remote_port,
+                    result,
+                });
+            });
+        }
+        Err(error) => app.set_status(format!(
+            "Port-forward failed for {} {}/{}: {error:#}",
+            tab.title(),
+            namespace,
+            name
+        )),
+    }
+}
+
 async fn run_kubectl_port_forward(
+    kube_target: KubectlTarget<'_>,
     tab: ResourceTab,
     namespace: &str,
     name: &str,
     local_port: u16,
     remote_port: u16,
-) -> Result<(u32, tokio::process::Child)> {
+) -> Result<(u32, tokio::process::Child, u16)> {
     let target = match tab {
         ResourceTab::Pods => format!("pod/{name}"),
         ResourceTab::Services => format!("service/{name}"),
         _ => anyhow::bail!("port-forward only supports pods and services"),
     };
 
+    if local_port != 0 {
+        let (pid, child) =
+            spawn_kubectl_port_forward(kube_target, namespace, &target, local_port, remote_port)?;
+        return Ok((pid, child, local_port));
+    }
+
+    let mut last_error = None;
+    for _ in 0..EPHEMERAL_PORT_ATTEMPTS {
+        let local_port = pick_ephemeral_port()?;
+        let (pid, mut child) =
+            spawn_kubectl_port_forward(kube_target, namespace, &target, local_port, remote_port)?;
+        // Another process can grab the port between probing and kubectl binding it;
+        // kubectl exits right away in that case, so give it a moment before trusting it.
+        match timeout(Duration::from_millis(400), child.wait()).await {
+            Err(_) => return Ok((pid, child, local_port)),
+            Ok(Ok(status)) => {
+                last_error = Some(anyhow::anyhow!(
+                    "kubectl port-forward exited ({status}) on local port {local_port}"
+                ));
+            }
+            Ok(Err(error)) => return Err(error).context("failed to wait for kubectl port-forward"),
+        }
+    }
+
+    Err(last_error.unwrap_or_else(|| anyhow::anyhow!("no free local port found")))
+}
+
+fn spawn_kubectl_port_forward(
+    kube_target: KubectlTarget<'_>,
+    namespace: &str,
+    target: &str,
+    local_port: u16,
+    remote_port: u16,
+) -> Result<(u32, tokio::process::Child)> {
+    let args = vec![
+        "port-forward".to_string(),
+        "-n".to_string(),
+        namespace.to_string(),
+        target.to_string(),
+        format!("{local_port}:{remote_port}"),
+    ];
     let child = TokioCommand::new("kubectl")
-        .arg("port-forward")
-        .arg("-n")
-        .arg(namespace)
-        .arg(&target)
-        .arg(format!("{local_port}:{remote_port}"))
+        .args(kubectl_args(kube_target, &args))
         .stdout(Stdio::null())
         .stderr(Stdio::null())
         .spawn()
@@ -4429,6 +7062,17 @@ async fn run_kubectl_port_forward(
     Ok((pid, child))
 }
 
+fn pick_ephemeral_port() -> Result<u16> {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")
+        .context("failed to reserve an ephemeral local port")?;
+    let port = listener
+        .local_addr()
+        .context("failed to read ephemeral local port")?
+        .port();
+    drop(listener);
+    Ok(port)
+}
+
 fn should_process_watch_event(
     tab: ResourceTab,
     throttle: &mut HashMap<ResourceTab, Instant>,
@@ -4448,20 +7092,56 @@ fn should_process_watch_event(
     }
 }
 
-fn restart_watchers(
+fn apply_loop_effect(
+    effect: LoopEffect,
+    app: &mut App,
     watch_tasks: &mut Vec<JoinHandle<()>>,
     client: Client,
-    tx: mpsc::UnboundedSender<ResourceTab>,
+    tx: &mpsc::UnboundedSender<WatchEvent>,
+    throttle: &mut HashMap<ResourceTab, Instant>,
 ) {
+    match effect {
+        LoopEffect::None => {}
+        LoopEffect::RestartWatchers => {
+            restart_watchers(watch_tasks, client, tx.clone(), !app.watch_disabled());
+            throttle.clear();
+            app.clear_degraded_watches();
+        }
+        LoopEffect::RewatchRequested if app.watch_disabled() => {
+            app.set_status(
+                "Resource watchers are disabled (--no-watch); tables refresh by polling",
+            );
+        }
+        LoopEffect::RewatchRequested => {
+            let restarted = restart_watchers(watch_tasks, client, tx.clone(), true);
+            throttle.clear();
+            app.clear_degraded_watches();
+            app.set_status(format!(
+                "Restarted {restarted} resource watchers (watch throttle cleared)"
+            ));
+        }
+    }
+}
+
+fn restart_watchers(
+    watch_tasks: &mut Vec<JoinHandle<()>>,
+    client: Client,
+    tx: mpsc::UnboundedSender<WatchEvent>,
+    enabled: bool,
+) -> usize {
     for task in watch_tasks.drain(..) {
         task.abort();
     }
+    if !enabled {
+        return 0;
+    }
     *watch_tasks = start_resource_watchers(client, tx);
+    watch_tasks.len()
 }
 
 fn start_resource_watchers(
     client: Client,
-    tx: mpsc::UnboundedSender<ResourceTab>,
+    tx: mpsc::UnboundedSender<WatchEvent>,
 ) -> Vec<JoinHandle<()>> {
     vec![
         spawn_watch_task::<Pod>(client.clone(), ResourceTab::Pods, tx.clone()),
@@ -4477,6 +7157,7 @@ fn start_resource_watchers(
         spawn_watch_task::<StatefulSet>(client.clone(), ResourceTab::StatefulSets, tx.clone()),
         spawn_watch_task::<Job>(client.clone(), ResourceTab::Jobs, tx.clone()),
         spawn_watch_task::<Service>(client.clone(), ResourceTab::Services, tx.clone()),
+        spawn_watch_task::<EndpointSlice>(client.clone(), ResourceTab::EndpointSlices, tx.clone()),
         spawn_watch_task::<Ingress>(client.clone(), ResourceTab::Ingresses, tx.clone()),
         spawn_watch_task::<IngressClass>(client.clone(), ResourceTab::IngressClasses, tx.clone()),
         spawn_watch_task::<ConfigMap>(client.clone(), ResourceTab::ConfigMaps, tx.clone()),
@@ -4505,7 +7186,19 @@ fn start_resource_watchers(
             ResourceTab::ClusterRoleBindings,
             tx.clone(),
         ),
+        spawn_watch_task::<PriorityClass>(client.clone(), ResourceTab::PriorityClasses, tx.clone()),
         spawn_watch_task::<NetworkPolicy>(client.clone(), ResourceTab::NetworkPolicies, tx.clone()),
+        spawn_watch_task::<Lease>(client.clone(), ResourceTab::Leases, tx.clone()),
+        spawn_watch_task::<MutatingWebhookConfiguration>(
+            client.clone(),
+            ResourceTab::MutatingWebhooks,
+            tx.clone(),
+        ),
+        spawn_watch_task::<ValidatingWebhookConfiguration>(
+            client.clone(),
+            ResourceTab::ValidatingWebhooks,
+            tx.clone(),
+        ),
         spawn_watch_task::<Node>(client.clone(), ResourceTab::Nodes, tx.clone()),
         spawn_watch_task::<KubeEvent>(client.clone(), ResourceTab::Events, tx.clone()),
         spawn_watch_task::<Namespace>(client, ResourceTab::Namespaces, tx),
@@ -4515,33 +7208,346 @@ fn start_resource_watchers(
 fn spawn_watch_task<K>(
     client: Client,
     tab: ResourceTab,
-    tx: mpsc::UnboundedSender<ResourceTab>,
+    tx: mpsc::UnboundedSender<WatchEvent>,
 ) -> JoinHandle<()>
 where
     K: Clone + std::fmt::Debug + serde::de::DeserializeOwned + kube::Resource + Send + 'static,
     <K as kube::Resource>::DynamicType: Default + Eq + std::hash::Hash + Clone + Send,
 {
     tokio::spawn(async move {
+        let mut failures = 0u32;
+        let mut known = HashSet::<String>::new();
         loop {
             let api: Api<K> = Api::all(client.clone());
-            let mut events = watcher(api, WatchConfig::default()).boxed();
+            let mut events = metadata_watcher(api, WatchConfig::default()).boxed();
+            let mut retry_in = WATCH_RETRY_BASE;
             loop {
                 match events.try_next().await {
-                    Ok(Some(_)) => {
-                        let _ = tx.send(tab);
+                    Ok(Some(event)) => {
+                        failures = 0;
+                        let activity = watch_activity(tab, &event, &mut known);
+                        let _ = tx.send(WatchEvent::Changed { tab, activity });
                     }
                     Ok(None) => break,
                     Err(error) => {
-                        warn!("watch stream error for {}: {error}", tab.title());
+                        failures = failures.saturating_add(1);
+                        retry_in = watch_backoff_delay(failures, watch_jitter(tab, failures));
+                        warn!(
+                            "watch stream error for {} (retry in {}ms): {error}",
+                            tab.title(),
+                            retry_in.as_millis()
+                        );
+                        let _ = tx.send(WatchEvent::Failed {
+                            tab,
+                            error: error.to_string(),
+                            retry_in,
+                        });
                         break;
                     }
                 }
             }
-            tokio::time::sleep(Duration::from_millis(900)).await;
+            tokio::time::sleep(retry_in).await;
+        }
+    })
+}
+
+fn watch_activity<K: kube::Resource>(
+    tab: ResourceTab,
+    event: &watcher::Event<K>,
+    known: &mut HashSet<String>,
+) -> Option<WatchActivity> {
+    let key = |object: &K| {
+        let meta = object.meta();
+        format!(
+            "{}/{}",
+            meta.namespace.as_deref().unwrap_or_default(),
+            meta.name.as_deref().unwrap_or_default()
+        )
+    };
+    let (object, change) = match event {
+        watcher::Event::Init => {
+            known.clear();
+            return None;
+        }
+        watcher::Event::InitApply(object) => {
+            known.insert(key(object));
+            return None;
+        }
+        watcher::Event::InitDone => return None,
+        watcher::Event::Apply(object) if known.insert(key(object)) => {
+            (object, WatchChange::Created)
+        }
+        watcher::Event::Apply(object) => (object, WatchChange::Updated),
+        watcher::Event::Delete(object) => {
+            known.remove(&key(object));
+            (object, WatchChange::Deleted)
         }
+    };
+    let heartbeat =
+        matches!(tab, ResourceTab::Leases | ResourceTab::Nodes) && change == WatchChange::Updated;
+    if heartbeat || tab == ResourceTab::Events {
+        return None;
+    }
+    let meta = object.meta();
+    Some(WatchActivity {
+        at: Local::now(),
+        tab,
+        change,
+        namespace: meta.namespace.clone(),
+        name: meta.name.clone().unwrap_or_default(),
     })
 }
 
+fn watch_backoff_delay(failures: u32, jitter: f64) -> Duration {
+    let exponent = failures.saturating_sub(1).min(16);
+    let capped = WATCH_RETRY_BASE
+        .saturating_mul(1 << exponent)
+        .min(WATCH_RETRY_MAX);
+    capped.mul_f64(0.5 + jitter.clamp(0.0, 1.0) * 0.5)
+}
+
+fn watch_jitter(tab: ResourceTab, failures: u32) -> f64 {
+    use std::hash::{BuildHasher, Hasher};
+
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write(tab.title().as_bytes());
+    hasher.write_u32(failures);
+    (hasher.finish() % 1_000) as f64 / 1_000.0
+}
+
+fn spawn_rollout_watch(
+    gateway: KubeGateway,
+    tab: ResourceTab,
+    namespace: String,
+    name: String,
+    limit: Duration,
+    tx: mpsc::UnboundedSender<RolloutWatchEvent>,
+) {
+    tokio::spawn(async move {
+        let started = Instant::now();
+        loop {
+            let update = match gateway.rollout_status(tab, &namespace, &name).await {
+                Ok(status) => match status.state {
+                    k8s::RolloutState::Complete => RolloutUpdate::Complete(status.message),
+                    k8s::RolloutState::Failed => RolloutUpdate::Failed(status.message),
+                    k8s::RolloutState::Progressing if started.elapsed() >= limit => {
+                        RolloutUpdate::TimedOut(status.message)
+                    }
+                    k8s::RolloutState::Progressing => RolloutUpdate::Progress(status.message),
+                },
+                Err(error) => RolloutUpdate::Error(compact_error(&error)),
+            };
+            let finished = !matches!(update, RolloutUpdate::Progress(_));
+            let event = RolloutWatchEvent {
+                tab,
+                namespace: namespace.clone(),
+                name: name.clone(),
+                elapsed: started.elapsed(),
+                update,
+            };
+            if tx.send(event).is_err() || finished {
+                break;
+            }
+            tokio::time::sleep(ROLLOUT_POLL_INTERVAL).await;
+        }
+    });
+}
+
+/// Deleting a Job waits for its finalizers, which can take many seconds; run it off the
+/// event loop so the UI keeps drawing.
+fn spawn_job_rerun(
+    gateway: KubeGateway,
+    namespace: String,
+    name: String,
+    tx: mpsc::UnboundedSender<JobRerunEvent>,
+) {
+    tokio::spawn(async move {
+        let result = gateway
+            .rerun_job(&namespace, &name)
+            .await
+            .map_err(|error| format!("{error:#}"));
+        let _ = tx.send(JobRerunEvent {
+            namespace,
+            name,
+            result,
+        });
+    });
+}
+
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+async fn with_spinner<T>(
+    terminal: &mut TuiTerminal,
+    app: &mut App,
+    label: &str,
+    task: impl std::future::Future<Output = T>,
+) -> T {
+    tokio::pin!(task);
+    let started = Instant::now();
+    let mut ticker = interval(Duration::from_millis(120));
+    let mut frame = 0usize;
+    loop {
+        tokio::select! {
+            result = &mut task => return result,
+            _ = ticker.tick() => {
+                app.set_status(format!(
+                    "{} {label} ({}s)",
+                    SPINNER_FRAMES[frame % SPINNER_FRAMES.len()],
+                    started.elapsed().as_secs()
+                ));
+                frame += 1;
+                let _ = terminal.draw(|frame| ui::render(frame, app));
+            }
+        }
+    }
+}
+
+async fn kustomize_apply(
+    path: &str,
+    namespace_scope: &NamespaceScope,
+    kube_target: KubectlTarget<'_>,
+    dry_run: bool,
+) -> std::result::Result<String, String> {
+    let build_args = vec!["build".to_string(), path.to_string()];
+    let built = run_external_readonly("kustomize", &build_args, 20).await?;
+    let manifest_path = write_scratch_manifest("kustomize", strip_stderr_block(&built))
+        .map_err(|error| error.to_string())?;
+    let args = kustomize_apply_args(&manifest_path, namespace_scope, kube_target, dry_run);
+    let result = run_external_readonly("kubectl", &args, 30).await;
+    let _ = fs::remove_file(&manifest_path);
+    result
+}
+
+fn kustomize_apply_args(
+    manifest_path: &Path,
+    namespace_scope: &NamespaceScope,
+    kube_target: KubectlTarget<'_>,
+    dry_run: bool,
+) -> Vec<String> {
+    let mut args = vec!["apply".to_string()];
+    if dry_run {
+        args.push("--dry-run=server".to_string());
+    }
+    args.push("-f".to_string());
+    args.push(manifest_path.display().to_string());
+    if let NamespaceScope::Named(namespace) = namespace_scope {
+        args.push("-n".to_string());
+        args.push(namespace.clone());
+    }
+    kubectl_args(kube_target, &args)
+}
+
+fn strip_stderr_block(output: &str) -> &str {
+    output
+        .split_once("\n\nstderr:\n")
+        .map_or(output, |(stdout, _)| stdout)
+}
+
+fn terraform_state_addresses(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .take_while(|line| *line != "stderr:")
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn terraform_plan_summary(output: &str) -> String {
+    output
+        .lines()
+        .map(str::trim)
+        .find(|line| line.starts_with("Plan:") || line.starts_with("No changes."))
+        .unwrap_or("see overlay")
+        .to_string()
+}
+
+const HELM_DIFF_INSTALL_HINT: &str = "The helm-diff plugin is not installed. Install it with:\n  helm plugin install https://github.com/databus23/helm-diff";
+
+fn helm_diff_plugin_missing(error: &str) -> bool {
+    error.contains("unknown command \"diff\"") || error.contains("unknown command 'diff'")
+}
+
+fn helm_history_table(output: &str) -> std::result::Result<TableData, String> {
+    let revisions = serde_json::from_str::<Vec<Value>>(output)
+        .map_err(|error| format!("failed to parse helm history JSON: {error}"))?;
+    let text = |entry: &Value, key: &str| {
+        entry
+            .get(key)
+            .and_then(Value::as_str)
+            .filter(|value| !value.is_empty())
+            .unwrap_or("-")
+            .to_string()
+    };
+    let rows = revisions
+        .iter()
+        .rev()
+        .map(|entry| {
+            let revision = entry
+                .get("revision")
+                .and_then(Value::as_u64)
+                .map_or_else(|| "-".to_string(), |revision| revision.to_string());
+            let updated = text(entry, "updated");
+            let updated = updated.get(..19).unwrap_or(&updated).replace('T', " ");
+            RowData {
+                name: revision.clone(),
+                columns: vec![
+                    revision,
+                    text(entry, "status"),
+                    text(entry, "chart"),
+                    text(entry, "app_version"),
+                    updated,
+                    text(entry, "description"),
+                ],
+                ..RowData::default()
+            }
+        })
+        .collect();
+    let mut table = TableData::default();
+    table.set_rows(
+        [
+            "Revision",
+            "Status",
+            "Chart",
+            "AppVersion",
+            "Updated",
+            "Description",
+        ]
+        .iter()
+        .map(|header| header.to_string())
+        .collect(),
+        rows,
+        Local::now(),
+    );
+    Ok(table)
+}
+
+fn helm_release_args(section: HelmReleaseSection, name: &str) -> Vec<String> {
+    let verb: &[&str] = match section {
+        HelmReleaseSection::Status => &["status"],
+        HelmReleaseSection::Values => &["get", "values"],
+        HelmReleaseSection::Manifest => &["get", "manifest"],
+    };
+    verb.iter()
+        .map(|part| part.to_string())
+        .chain(std::iter::once(name.to_string()))
+        .collect()
+}
+
+fn bulk_summary(
+    verb: &str,
+    succeeded: usize,
+    failed: usize,
+    first_error: Option<String>,
+) -> String {
+    match first_error {
+        Some(error) if failed > 0 => {
+            format!("{verb} {succeeded}, failed {failed} (still marked; first error {error})")
+        }
+        _ => format!("{verb} {succeeded}, failed {failed}"),
+    }
+}
+
 fn compact_error(error: &anyhow::Error) -> String {
     let mut out = Vec::new();
     for (index, cause) in error.chain().enumerate() {
@@ -4556,3 +7562,603 @@ fn compact_error(error: &anyhow::Error) -> String {
 
     out.join("\n")
 }
+
+#[cfg(test)]
+mod shell_snapshot_tests {
+    use super::render_shell_snapshot;
+
+    #[test]
+    fn renders_block_cursor_without_raw_escape_bytes() {
+        let mut parser = vt100::Parser::new(8, 40, 32);
+        parser.process(b"\x1b[32mhello\x1b[0m");
+        let rendered = render_shell_snapshot(parser.screen());
+        assert!(rendered.contains("hello"));
+        assert!(rendered.contains('█'));
+        assert!(!rendered.contains("\x1b"));
+    }
+
+    #[test]
+    fn trims_trailing_blank_lines() {
+        let mut parser = vt100::Parser::new(8, 40, 32);
+        parser.process(b"line1\nline2");
+        let rendered = render_shell_snapshot(parser.screen());
+        assert!(rendered.contains("line1"));
+        assert!(rendered.contains("line2"));
+        assert!(!rendered.ends_with('\n'));
+    }
+}
+
+#[cfg(test)]
+mod no_watch_tests {
+    use super::{LoopEffect, WatchEvent, apply_loop_effect};
+    use crate::app::App;
+    use crate::model::NamespaceScope;
+    use kube::{Client, Config};
+    use std::collections::HashMap;
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn disabled_watchers_are_never_started_again() {
+        let mut app = App::new(
+            "cluster".to_string(),
+            "context".to_string(),
+            NamespaceScope::Named("default".to_string()),
+        );
+        app.set_watch_disabled(true);
+        let client = Client::try_from(Config::new("http://127.0.0.1:1".parse().unwrap())).unwrap();
+        let (tx, _rx) = mpsc::unbounded_channel::<WatchEvent>();
+        let mut watch_tasks = Vec::new();
+        let mut throttle = HashMap::new();
+
+        apply_loop_effect(
+            LoopEffect::RestartWatchers,
+            &mut app,
+            &mut watch_tasks,
+            client.clone(),
+            &tx,
+            &mut throttle,
+        );
+        assert!(watch_tasks.is_empty());
+
+        apply_loop_effect(
+            LoopEffect::RewatchRequested,
+            &mut app,
+            &mut watch_tasks,
+            client,
+            &tx,
+            &mut throttle,
+        );
+        assert!(watch_tasks.is_empty());
+        assert!(app.status().contains("--no-watch"));
+    }
+}
+
+#[cfg(test)]
+mod watch_backoff_tests {
+    use super::{WATCH_RETRY_BASE, WATCH_RETRY_MAX, watch_activity, watch_backoff_delay};
+    use crate::model::{ResourceTab, WatchChange};
+    use k8s_openapi::api::core::v1::Pod;
+    use kube::runtime::watcher::Event;
+    use std::collections::HashSet;
+    use std::time::Duration;
+
+    #[test]
+    fn backoff_doubles_per_failure_and_caps() {
+        assert_eq!(watch_backoff_delay(1, 1.0), WATCH_RETRY_BASE);
+        assert_eq!(watch_backoff_delay(2, 1.0), WATCH_RETRY_BASE * 2);
+        assert_eq!(watch_backoff_delay(3, 1.0), WATCH_RETRY_BASE * 4);
+        assert_eq!(watch_backoff_delay(12, 1.0), WATCH_RETRY_MAX);
+        assert_eq!(watch_backoff_delay(u32::MAX, 1.0), WATCH_RETRY_MAX);
+    }
+
+    #[test]
+    fn watch_activity_tells_created_from_updated_and_deleted() {
+        let pod = |name: &str| -> Pod {
+            serde_json::from_value(serde_json::json!({
+                "metadata": {"name": name, "namespace": "default"}
+            }))
+            .expect("pod")
+        };
+        let mut known = HashSet::new();
+        let change = |event: Event<Pod>, known: &mut HashSet<String>| {
+            watch_activity(ResourceTab::Pods, &event, known).map(|activity| activity.change)
+        };
+
+        assert_eq!(change(Event::Init, &mut known), None);
+        assert_eq!(change(Event::InitApply(pod("web-a")), &mut known), None);
+        assert_eq!(
+            change(Event::Apply(pod("web-a")), &mut known),
+            Some(WatchChange::Updated)
+        );
+        assert_eq!(
+            change(Event::Apply(pod("web-b")), &mut known),
+            Some(WatchChange::Created)
+        );
+        let deleted = watch_activity(ResourceTab::Pods, &Event::Delete(pod("web-b")), &mut known)
+            .expect("delete activity");
+        assert_eq!(deleted.change, WatchChange::Deleted);
+        assert!(deleted.line().ends_with("Pod default/web-b deleted"));
+        assert_eq!(
+            watch_activity(ResourceTab::Events, &Event::Apply(pod("x")), &mut known),
+            None
+        );
+    }
+
+    #[test]
+    fn backoff_jitter_stays_within_half_to_full_delay() {
+        assert_eq!(watch_backoff_delay(12, 0.0), Duration::from_secs(15));
+        let delay = watch_backoff_delay(4, 0.25);
+        assert!(delay >= (WATCH_RETRY_BASE * 8) / 2);
+        assert!(delay <= WATCH_RETRY_BASE * 8);
+    }
+}
+
+#[cfg(test)]
+mod plugin_stream_tests {
+    use super::{PLUGIN_STREAM_MAX_LINES, plugin_stream_snapshot, push_stream_line};
+
+    #[test]
+    fn stream_keeps_the_newest_lines_under_the_cap() {
+        let header = vec!["plugin tail".to_string(), String::new()];
+        assert_eq!(
+            plugin_stream_snapshot(&header, &[]),
+            "plugin tail\n\nstream\n(waiting for output)"
+        );
+
+        let mut lines = Vec::new();
+        for index in 0..PLUGIN_STREAM_MAX_LINES + 5 {
+            push_stream_line(&mut lines, format!("line {index}"));
+        }
+        assert_eq!(lines.len(), PLUGIN_STREAM_MAX_LINES);
+        assert_eq!(lines[0], "line 5");
+        assert!(
+            plugin_stream_snapshot(&header, &lines)
+                .ends_with(&format!("line {}", PLUGIN_STREAM_MAX_LINES + 4))
+        );
+    }
+}
+
+#[cfg(test)]
+mod argocd_tree_tests {
+    use super::{endpoint_readiness, split_argocd_live_items};
+    use serde_json::json;
+
+    #[test]
+    fn combined_namespace_fetch_splits_endpoint_slices_from_workloads() {
+        let live = split_argocd_live_items(vec![
+            json!({"kind": "ReplicaSet", "metadata": {"name": "web-7c9"}}),
+            json!({"kind": "EndpointSlice", "metadata": {"name": "web-abcde"}}),
+            json!({"kind": "Pod", "metadata": {"name": "web-7c9-x1"}}),
+        ]);
+        assert_eq!(live.workloads.len(), 2);
+        assert_eq!(live.endpoints.len(), 1);
+        assert_eq!(live.endpoints[0]["metadata"]["name"], "web-abcde");
+    }
+
+    #[test]
+    fn endpoint_slices_treat_missing_ready_condition_as_ready() {
+        let slice = json!({
+            "addressType": "IPv4",
+            "endpoints": [
+                {"addresses": ["10.0.0.1"], "conditions": {"ready": true}},
+                {"addresses": ["10.0.0.2"], "conditions": {"ready": false}},
+                {"addresses": ["10.0.0.3"]}
+            ]
+        });
+        assert_eq!(endpoint_readiness(&slice), (2, 3));
+        assert_eq!(
+            endpoint_readiness(&json!({"addressType": "IPv4", "endpoints": []})),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn legacy_endpoints_count_not_ready_addresses() {
+        let endpoints = json!({
+            "subsets": [
+                {"addresses": [{"ip": "10.0.0.1"}], "notReadyAddresses": [{"ip": "10.0.0.2"}]},
+                {"addresses": [{"ip": "10.0.1.1"}]}
+            ]
+        });
+        assert_eq!(endpoint_readiness(&endpoints), (2, 3));
+        assert_eq!(endpoint_readiness(&json!({})), (0, 0));
+    }
+}
+
+#[cfg(test)]
+mod argocd_diff_tests {
+    use super::{argocd_create_args, argocd_resource_diff_section, argocd_sync_args};
+
+    #[test]
+    fn create_args_target_in_cluster_destination() {
+        assert_eq!(
+            argocd_create_args(
+                "web",
+                "https://github.com/acme/platform.git",
+                "apps/web",
+                "shop",
+                "default"
+            ),
+            vec![
+                "app",
+                "create",
+                "web",
+                "--repo",
+                "https://github.com/acme/platform.git",
+                "--path",
+                "apps/web",
+                "--dest-server",
+                "https://kubernetes.default.svc",
+                "--dest-namespace",
+                "shop",
+                "--project",
+                "default"
+            ]
+        );
+    }
+
+    #[test]
+    fn sync_args_append_selected_options() {
+        assert_eq!(
+            argocd_sync_args("guestbook", false, false, None),
+            vec!["app", "sync", "guestbook"]
+        );
+        assert_eq!(
+            argocd_sync_args("guestbook", true, true, Some("apps:Deployment:web")),
+            vec![
+                "app",
+                "sync",
+                "guestbook",
+                "--prune",
+                "--dry-run",
+                "--resource",
+                "apps:Deployment:web"
+            ]
+        );
+    }
+
+    const DIFF: &str = "===== /Service argocd-demo/guestbook-ui ======\n12c12\n<   port: 80\n---\n>   port: 8080\n===== apps/Deployment argocd-demo/guestbook-ui ======\n20c20\n<   replicas: 1\n---\n>   replicas: 3";
+
+    #[test]
+    fn resource_diff_keeps_only_the_selected_block() {
+        let section = argocd_resource_diff_section("guestbook", DIFF, "Deployment", "guestbook-ui");
+        assert!(section.starts_with("DIFF guestbook\n===== apps/Deployment"));
+        assert!(section.contains("replicas: 3"));
+        assert!(!section.contains("port: 8080"));
+    }
+
+    #[test]
+    fn resource_diff_falls_back_to_full_app_diff() {
+        let section = argocd_resource_diff_section("guestbook", DIFF, "ConfigMap", "settings");
+        assert!(section.contains("ConfigMap settings has no differences"));
+        assert!(section.contains("port: 8080"));
+        assert_eq!(
+            argocd_resource_diff_section("guestbook", "", "Service", "guestbook-ui"),
+            "DIFF guestbook\n(in sync, no differences)"
+        );
+    }
+}
+
+#[cfg(test)]
+mod git_refs_tests {
+    use super::{GitRefListing, git_ref_candidates, parse_clone_depth, parse_git_refs};
+
+    #[test]
+    fn clone_depth_defaults_to_shallow_and_accepts_full() {
+        assert_eq!(parse_clone_depth(None), Some(1));
+        assert_eq!(parse_clone_depth(Some("50")), Some(50));
+        assert_eq!(parse_clone_depth(Some("full")), None);
+        assert_eq!(parse_clone_depth(Some("0")), None);
+        assert_eq!(parse_clone_depth(Some("deep")), Some(1));
+    }
+
+    #[test]
+    fn diff_refs_fall_back_to_remote_tracking_branches() {
+        assert_eq!(git_ref_candidates("main"), vec!["main", "origin/main"]);
+        assert_eq!(git_ref_candidates("origin/dev"), vec!["origin/dev"]);
+        assert_eq!(git_ref_candidates("refs/tags/v1"), vec!["refs/tags/v1"]);
+    }
+
+    #[test]
+    fn ls_remote_output_splits_branches_and_peeled_tags() {
+        let output = "a1\trefs/heads/main\nb2\trefs/heads/release/1.x\nc3\trefs/tags/v1.0.0\nd4\trefs/tags/v1.0.0^{}\ne5\trefs/pull/7/head";
+        assert_eq!(
+            parse_git_refs(output),
+            GitRefListing {
+                branches: vec!["main".to_string(), "release/1.x".to_string()],
+                tags: vec!["v1.0.0".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn for_each_ref_output_folds_remote_branches() {
+        let output = "a1 refs/heads/main\na1 refs/remotes/origin/HEAD\na1 refs/remotes/origin/main\nb2 refs/remotes/origin/dev";
+        assert_eq!(
+            parse_git_refs(output).branches,
+            vec!["dev".to_string(), "main".to_string()]
+        );
+    }
+}
+
+#[cfg(test)]
+mod kustomize_apply_tests {
+    use super::{kustomize_apply_args, strip_stderr_block};
+    use crate::k8s::KubectlTarget;
+    use crate::model::NamespaceScope;
+    use std::path::Path;
+
+    #[test]
+    fn dry_run_args_target_scratch_manifest_and_namespace() {
+        let args = kustomize_apply_args(
+            Path::new("/tmp/orca-scratch/kustomize.yaml"),
+            &NamespaceScope::Named("shop".to_string()),
+            KubectlTarget {
+                context: Some("prod-eu"),
+                cluster: None,
+            },
+            true,
+        );
+        assert_eq!(
+            args,
+            vec![
+                "--context",
+                "prod-eu",
+                "apply",
+                "--dry-run=server",
+                "-f",
+                "/tmp/orca-scratch/kustomize.yaml",
+                "-n",
+                "shop"
+            ]
+        );
+        let args = kustomize_apply_args(
+            Path::new("out.yaml"),
+            &NamespaceScope::All,
+            KubectlTarget::default(),
+            false,
+        );
+        assert_eq!(args, vec!["apply", "-f", "out.yaml"]);
+    }
+
+    #[test]
+    fn build_output_drops_stderr_warnings() {
+        let output =
+            "apiVersion: v1\nkind: ConfigMap\n\nstderr:\n# Warning: 'commonLabels' is deprecated";
+        assert_eq!(
+            strip_stderr_block(output),
+            "apiVersion: v1\nkind: ConfigMap"
+        );
+        assert_eq!(strip_stderr_block("kind: Service"), "kind: Service");
+    }
+}
+
+#[cfg(test)]
+mod terraform_plan_tests {
+    use super::{terraform_plan_summary, terraform_state_addresses};
+
+    #[test]
+    fn terraform_state_addresses_skip_stderr_block() {
+        let output = "aws_s3_bucket.logs\nmodule.vpc.aws_subnet.private[\"a\"]\n\nstderr:\nWarning: provider deprecated";
+        assert_eq!(
+            terraform_state_addresses(output),
+            vec!["aws_s3_bucket.logs", "module.vpc.aws_subnet.private[\"a\"]"]
+        );
+    }
+
+    #[test]
+    fn terraform_plan_summary_picks_plan_line() {
+        let output = "Terraform will perform the following actions:\n\n  # aws_s3_bucket.logs will be created\n\nPlan: 1 to add, 0 to change, 0 to destroy.";
+        assert_eq!(
+            terraform_plan_summary(output),
+            "Plan: 1 to add, 0 to change, 0 to destroy."
+        );
+        assert_eq!(
+            terraform_plan_summary("No changes. Your infrastructure matches the configuration."),
+            "No changes. Your infrastructure matches the configuration."
+        );
+        assert_eq!(
+            terraform_plan_summary("Error: no configuration"),
+            "see overlay"
+        );
+    }
+}
+
+#[cfg(test)]
+mod helm_release_tests {
+    use super::{
+        HelmReleaseSection, helm_args, helm_diff_plugin_missing, helm_history_table,
+        helm_release_args,
+    };
+    use crate::k8s::KubectlTarget;
+
+    #[test]
+    fn helm_diff_detects_missing_plugin() {
+        assert!(helm_diff_plugin_missing(
+            "helm failed:\nError: unknown command \"diff\" for \"helm\"\nRun 'helm --help' for usage."
+        ));
+        assert!(!helm_diff_plugin_missing(
+            "helm failed:\nError: release: not found"
+        ));
+    }
+
+    #[test]
+    fn helm_history_table_lists_newest_revision_first() {
+        let output = r#"[
+            {"revision":1,"updated":"2026-03-01T10:00:00.123+00:00","status":"superseded","chart":"web-1.0.0","app_version":"1.0","description":"Install complete"},
+            {"revision":2,"updated":"2026-03-02T11:30:00.456+00:00","status":"deployed","chart":"web-1.1.0","app_version":"1.1","description":"Upgrade complete"}
+        ]"#;
+        let table = helm_history_table(output).unwrap();
+        assert_eq!(
+            table.headers,
+            vec![
+                "Revision",
+                "Status",
+                "Chart",
+                "AppVersion",
+                "Updated",
+                "Description"
+            ]
+        );
+        assert_eq!(
+            table.rows[0].columns,
+            vec![
+                "2",
+                "deployed",
+                "web-1.1.0",
+                "1.1",
+                "2026-03-02 11:30:00",
+                "Upgrade complete"
+            ]
+        );
+        assert_eq!(table.rows[1].name, "1");
+        assert!(helm_history_table("Error: release not found").is_err());
+    }
+
+    #[test]
+    fn helm_release_args_map_sections_to_helm_subcommands() {
+        assert_eq!(
+            helm_release_args(HelmReleaseSection::Status, "web"),
+            vec!["status", "web"]
+        );
+        assert_eq!(
+            helm_release_args(HelmReleaseSection::Values, "web"),
+            vec!["get", "values", "web"]
+        );
+        assert_eq!(
+            helm_release_args(HelmReleaseSection::Manifest, "web"),
+            vec!["get", "manifest", "web"]
+        );
+        assert_eq!(
+            helm_args(
+                KubectlTarget {
+                    context: Some("prod-eu"),
+                    cluster: None,
+                },
+                &helm_release_args(HelmReleaseSection::Values, "web")
+            )
+            .unwrap(),
+            vec!["--kube-context", "prod-eu", "get", "values", "web"]
+        );
+    }
+}
+
+#[cfg(test)]
+mod bulk_summary_tests {
+    use super::bulk_summary;
+
+    #[test]
+    fn bulk_summary_reports_counts_and_first_error() {
+        assert_eq!(bulk_summary("deleted", 7, 0, None), "deleted 7, failed 0");
+        assert_eq!(
+            bulk_summary("deleted", 7, 1, Some("web-1: forbidden".to_string())),
+            "deleted 7, failed 1 (still marked; first error web-1: forbidden)"
+        );
+    }
+}
+
+#[cfg(test)]
+mod cronjob_job_tests {
+    use super::{create_job_from_cronjob_args, manual_job_name};
+    use crate::k8s::KubectlTarget;
+
+    #[test]
+    fn manual_job_names_fit_the_job_name_limit() {
+        assert_eq!(
+            manual_job_name("nightly-backup", 1_760_000_000),
+            "nightly-backup-manual-1760000000"
+        );
+        let long = manual_job_name(&"a-".repeat(40), 1_760_000_000);
+        assert!(long.len() <= 63);
+        assert!(long.ends_with("a-manual-1760000000"));
+        assert_eq!(
+            create_job_from_cronjob_args(
+                KubectlTarget {
+                    context: Some("prod-eu"),
+                    cluster: None,
+                },
+                "ops",
+                "nightly-backup",
+                "nightly-backup-manual-1"
+            ),
+            vec![
+                "--context",
+                "prod-eu",
+                "create",
+                "job",
+                "--from=cronjob/nightly-backup",
+                "nightly-backup-manual-1",
+                "-n",
+                "ops"
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod kube_context_args_tests {
+    use super::{helm_args, kubectl_args, rollout_undo_args};
+    use crate::k8s::KubectlTarget;
+    use crate::model::ResourceTab;
+
+    const PROD_EU: KubectlTarget<'static> = KubectlTarget {
+        context: Some("prod-eu"),
+        cluster: None,
+    };
+
+    #[test]
+    fn kubectl_args_prepend_the_context_when_known() {
+        let args = vec!["get".to_string(), "pods".to_string()];
+        assert_eq!(
+            kubectl_args(PROD_EU, &args),
+            vec!["--context", "prod-eu", "get", "pods"]
+        );
+        assert_eq!(
+            kubectl_args(KubectlTarget::default(), &args),
+            vec!["get", "pods"]
+        );
+    }
+
+    #[test]
+    fn cluster_override_reaches_kubectl_and_blocks_helm() {
+        let target = KubectlTarget {
+            context: Some("prod-eu"),
+            cluster: Some("prod-eu-dr"),
+        };
+        let args = vec!["get".to_string(), "pods".to_string()];
+        assert_eq!(
+            kubectl_args(target, &args),
+            vec![
+                "--context",
+                "prod-eu",
+                "--cluster",
+                "prod-eu-dr",
+                "get",
+                "pods"
+            ]
+        );
+        let error = helm_args(target, &["list".to_string()]).unwrap_err();
+        assert!(error.contains("--cluster prod-eu-dr"));
+        assert_eq!(
+            helm_args(PROD_EU, &["list".to_string()]).unwrap(),
+            vec!["--kube-context", "prod-eu", "list"]
+        );
+    }
+
+    #[test]
+    fn rollout_undo_targets_the_active_context() {
+        assert_eq!(
+            rollout_undo_args(PROD_EU, ResourceTab::StatefulSets, "shop", "db", Some(3)),
+            vec![
+                "--context",
+                "prod-eu",
+                "rollout",
+                "undo",
+                "statefulset/db",
+                "-n",
+                "shop",
+                "--to-revision=3"
+            ]
+        );
+    }
+}
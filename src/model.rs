@@ -1,5 +1,7 @@
-use chrono::{DateTime, Local};
-use std::collections::HashMap;
+use chrono::{DateTime, Local, Utc};
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
@@ -22,6 +24,7 @@ pub enum ResourceTab {
     StatefulSets,
     Jobs,
     Services,
+    EndpointSlices,
     Ingresses,
     IngressClasses,
     ConfigMaps,
@@ -29,12 +32,17 @@ pub enum ResourceTab {
     Secrets,
     StorageClasses,
     PersistentVolumes,
+    VolumeSnapshots,
     ServiceAccounts,
     Roles,
     RoleBindings,
     ClusterRoles,
     ClusterRoleBindings,
+    PriorityClasses,
     NetworkPolicies,
+    Leases,
+    MutatingWebhooks,
+    ValidatingWebhooks,
     Nodes,
     Events,
     Namespaces,
@@ -42,7 +50,7 @@ pub enum ResourceTab {
 }
 
 impl ResourceTab {
-    pub const ALL: [Self; 35] = [
+    pub const ALL: [Self; 41] = [
         Self::Orca,
         Self::ArgoCdApps,
         Self::ArgoCdResources,
@@ -61,6 +69,7 @@ impl ResourceTab {
         Self::StatefulSets,
         Self::Jobs,
         Self::Services,
+        Self::EndpointSlices,
         Self::Ingresses,
         Self::IngressClasses,
         Self::ConfigMaps,
@@ -68,12 +77,17 @@ impl ResourceTab {
         Self::Secrets,
         Self::StorageClasses,
         Self::PersistentVolumes,
+        Self::VolumeSnapshots,
         Self::ServiceAccounts,
         Self::Roles,
         Self::RoleBindings,
         Self::ClusterRoles,
         Self::ClusterRoleBindings,
+        Self::PriorityClasses,
         Self::NetworkPolicies,
+        Self::Leases,
+        Self::MutatingWebhooks,
+        Self::ValidatingWebhooks,
         Self::Nodes,
         Self::Events,
         Self::Namespaces,
@@ -100,6 +114,7 @@ impl ResourceTab {
             Self::StatefulSets => "StatefulSets",
             Self::Jobs => "Jobs",
             Self::Services => "Services",
+            Self::EndpointSlices => "EndpointSlices",
             Self::Ingresses => "Ingresses",
             Self::IngressClasses => "IngressClasses",
             Self::ConfigMaps => "ConfigMaps",
@@ -107,12 +122,17 @@ impl ResourceTab {
             Self::Secrets => "Secrets",
             Self::StorageClasses => "StorageClasses",
             Self::PersistentVolumes => "PersistentVolumes",
+            Self::VolumeSnapshots => "VolumeSnapshots",
             Self::ServiceAccounts => "ServiceAccounts",
             Self::Roles => "Roles",
             Self::RoleBindings => "RoleBindings",
             Self::ClusterRoles => "ClusterRoles",
             Self::ClusterRoleBindings => "ClusterRoleBindings",
+            Self::PriorityClasses => "PriorityClasses",
             Self::NetworkPolicies => "NetworkPolicies",
+            Self::Leases => "Leases",
+            Self::MutatingWebhooks => "MutatingWebhooks",
+            Self::ValidatingWebhooks => "ValidatingWebhooks",
             Self::Nodes => "Nodes",
             Self::Events => "Events",
             Self::Namespaces => "Namespaces",
@@ -154,6 +174,8 @@ impl ResourceTab {
             "sts" | "statefulset" | "statefulsets" => Some(Self::StatefulSets),
             "job" | "jobs" => Some(Self::Jobs),
             "svc" | "service" | "services" => Some(Self::Services),
+            "eps" | "endpointslice" | "endpointslices" | "endpoint-slice" | "endpoint-slices"
+            | "ep" | "endpoints" => Some(Self::EndpointSlices),
             "ing" | "ingress" | "ingresses" => Some(Self::Ingresses),
             "ingclass" | "ingressclass" | "ingressclasses" | "ingress-class"
             | "ingress-classes" | "ic" => Some(Self::IngressClasses),
@@ -171,6 +193,8 @@ impl ResourceTab {
             }
             "pv" | "persistentvolume" | "persistentvolumes" | "persistent-volume"
             | "persistent-volumes" => Some(Self::PersistentVolumes),
+            "vs" | "volumesnapshot" | "volumesnapshots" | "volume-snapshot"
+            | "volume-snapshots" => Some(Self::VolumeSnapshots),
             "sa" | "serviceaccount" | "serviceaccounts" | "service-account"
             | "service-accounts" => Some(Self::ServiceAccounts),
             "role" | "roles" => Some(Self::Roles),
@@ -185,9 +209,25 @@ impl ResourceTab {
             | "clusterrolebindings"
             | "cluster-role-binding"
             | "cluster-role-bindings" => Some(Self::ClusterRoleBindings),
+            "pc" | "priorityclass" | "priorityclasses" | "priority-class" | "priority-classes" => {
+                Some(Self::PriorityClasses)
+            }
             "np" | "networkpolicy" | "networkpolicies" | "network-policy" | "network-policies" => {
                 Some(Self::NetworkPolicies)
             }
+            "lease" | "leases" => Some(Self::Leases),
+            "mwc"
+            | "mutatingwebhook"
+            | "mutatingwebhooks"
+            | "mutatingwebhookconfiguration"
+            | "mutatingwebhookconfigurations"
+            | "mutating-webhooks" => Some(Self::MutatingWebhooks),
+            "vwc"
+            | "validatingwebhook"
+            | "validatingwebhooks"
+            | "validatingwebhookconfiguration"
+            | "validatingwebhookconfigurations"
+            | "validating-webhooks" => Some(Self::ValidatingWebhooks),
             "node" | "nodes" | "no" => Some(Self::Nodes),
             "event" | "events" | "ev" => Some(Self::Events),
             "ns" | "namespace" | "namespaces" => Some(Self::Namespaces),
@@ -222,6 +262,7 @@ impl ResourceTab {
             Self::StatefulSets => "sts",
             Self::Jobs => "job",
             Self::Services => "svc",
+            Self::EndpointSlices => "eps",
             Self::Ingresses => "ing",
             Self::IngressClasses => "ingclass",
             Self::ConfigMaps => "cm",
@@ -229,20 +270,163 @@ impl ResourceTab {
             Self::Secrets => "secret",
             Self::StorageClasses => "sc",
             Self::PersistentVolumes => "pv",
+            Self::VolumeSnapshots => "vs",
             Self::ServiceAccounts => "sa",
             Self::Roles => "role",
             Self::RoleBindings => "rb",
             Self::ClusterRoles => "crole",
             Self::ClusterRoleBindings => "crb",
+            Self::PriorityClasses => "pc",
             Self::NetworkPolicies => "np",
+            Self::Leases => "lease",
+            Self::MutatingWebhooks => "mwc",
+            Self::ValidatingWebhooks => "vwc",
             Self::Nodes => "node",
             Self::Events => "event",
             Self::Namespaces => "ns",
             Self::CustomResources => "crd",
         }
     }
+
+    pub fn kind(self) -> Option<&'static str> {
+        Some(match self {
+            Self::Orca
+            | Self::ArgoCdApps
+            | Self::ArgoCdResources
+            | Self::ArgoCdProjects
+            | Self::ArgoCdRepos
+            | Self::ArgoCdClusters
+            | Self::ArgoCdAccounts
+            | Self::ArgoCdCerts
+            | Self::ArgoCdGpgKeys
+            | Self::CustomResources => return None,
+            Self::Pods => "Pod",
+            Self::CronJobs => "CronJob",
+            Self::DaemonSets => "DaemonSet",
+            Self::Deployments => "Deployment",
+            Self::ReplicaSets => "ReplicaSet",
+            Self::ReplicationControllers => "ReplicationController",
+            Self::StatefulSets => "StatefulSet",
+            Self::Jobs => "Job",
+            Self::Services => "Service",
+            Self::EndpointSlices => "EndpointSlice",
+            Self::Ingresses => "Ingress",
+            Self::IngressClasses => "IngressClass",
+            Self::ConfigMaps => "ConfigMap",
+            Self::PersistentVolumeClaims => "PersistentVolumeClaim",
+            Self::Secrets => "Secret",
+            Self::StorageClasses => "StorageClass",
+            Self::PersistentVolumes => "PersistentVolume",
+            Self::VolumeSnapshots => "VolumeSnapshot",
+            Self::ServiceAccounts => "ServiceAccount",
+            Self::Roles => "Role",
+            Self::RoleBindings => "RoleBinding",
+            Self::ClusterRoles => "ClusterRole",
+            Self::ClusterRoleBindings => "ClusterRoleBinding",
+            Self::PriorityClasses => "PriorityClass",
+            Self::NetworkPolicies => "NetworkPolicy",
+            Self::Leases => "Lease",
+            Self::MutatingWebhooks => "MutatingWebhookConfiguration",
+            Self::ValidatingWebhooks => "ValidatingWebhookConfiguration",
+            Self::Nodes => "Node",
+            Self::Events => "Event",
+            Self::Namespaces => "Namespace",
+        })
+    }
+
+    pub fn api_resource(self) -> Option<(&'static str, &'static str)> {
+        Some(match self {
+            Self::Orca
+            | Self::ArgoCdApps
+            | Self::ArgoCdResources
+            | Self::ArgoCdProjects
+            | Self::ArgoCdRepos
+            | Self::ArgoCdClusters
+            | Self::ArgoCdAccounts
+            | Self::ArgoCdCerts
+            | Self::ArgoCdGpgKeys => return None,
+            Self::Pods => ("v1", "pods"),
+            Self::CronJobs => ("batch/v1", "cronjobs"),
+            Self::DaemonSets => ("apps/v1", "daemonsets"),
+            Self::Deployments => ("apps/v1", "deployments"),
+            Self::ReplicaSets => ("apps/v1", "replicasets"),
+            Self::ReplicationControllers => ("v1", "replicationcontrollers"),
+            Self::StatefulSets => ("apps/v1", "statefulsets"),
+            Self::Jobs => ("batch/v1", "jobs"),
+            Self::Services => ("v1", "services"),
+            Self::EndpointSlices => ("discovery.k8s.io/v1", "endpointslices"),
+            Self::Ingresses => ("networking.k8s.io/v1", "ingresses"),
+            Self::IngressClasses => ("networking.k8s.io/v1", "ingressclasses"),
+            Self::ConfigMaps => ("v1", "configmaps"),
+            Self::PersistentVolumeClaims => ("v1", "persistentvolumeclaims"),
+            Self::Secrets => ("v1", "secrets"),
+            Self::StorageClasses => ("storage.k8s.io/v1", "storageclasses"),
+            Self::PersistentVolumes => ("v1", "persistentvolumes"),
+            Self::VolumeSnapshots => ("snapshot.storage.k8s.io/v1", "volumesnapshots"),
+            Self::ServiceAccounts => ("v1", "serviceaccounts"),
+            Self::Roles => ("rbac.authorization.k8s.io/v1", "roles"),
+            Self::RoleBindings => ("rbac.authorization.k8s.io/v1", "rolebindings"),
+            Self::ClusterRoles => ("rbac.authorization.k8s.io/v1", "clusterroles"),
+            Self::ClusterRoleBindings => ("rbac.authorization.k8s.io/v1", "clusterrolebindings"),
+            Self::PriorityClasses => ("scheduling.k8s.io/v1", "priorityclasses"),
+            Self::NetworkPolicies => ("networking.k8s.io/v1", "networkpolicies"),
+            Self::Leases => ("coordination.k8s.io/v1", "leases"),
+            Self::MutatingWebhooks => (
+                "admissionregistration.k8s.io/v1",
+                "mutatingwebhookconfigurations",
+            ),
+            Self::ValidatingWebhooks => (
+                "admissionregistration.k8s.io/v1",
+                "validatingwebhookconfigurations",
+            ),
+            Self::Nodes => ("v1", "nodes"),
+            Self::Events => ("v1", "events"),
+            Self::Namespaces => ("v1", "namespaces"),
+            Self::CustomResources => ("apiextensions.k8s.io/v1", "customresourcedefinitions"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct LogWindow {
+    pub width_secs: u64,
+    pub offset: u32,
+}
+
+impl LogWindow {
+    pub fn since_seconds(self) -> i64 {
+        let seconds = self.width_secs.saturating_mul(u64::from(self.offset) + 1);
+        i64::try_from(seconds).unwrap_or(i64::MAX)
+    }
+
+    pub fn until_seconds_ago(self) -> u64 {
+        self.width_secs.saturating_mul(u64::from(self.offset))
+    }
+
+    pub fn label(self) -> String {
+        let start = format_window_duration(self.since_seconds().unsigned_abs());
+        if self.offset == 0 {
+            format!("last {start}")
+        } else {
+            let end = format_window_duration(self.until_seconds_ago());
+            format!("{start}-{end} ago")
+        }
+    }
 }
 
+pub fn format_window_duration(seconds: u64) -> String {
+    let (hours, minutes, secs) = (seconds / 3_600, (seconds % 3_600) / 60, seconds % 60);
+    match (hours, minutes, secs) {
+        (0, 0, secs) => format!("{secs}s"),
+        (0, minutes, 0) => format!("{minutes}m"),
+        (0, minutes, secs) => format!("{minutes}m{secs}s"),
+        (hours, 0, 0) => format!("{hours}h"),
+        (hours, minutes, _) => format!("{hours}h{minutes}m"),
+    }
+}
+
+pub const VOLUME_SNAPSHOT_GROUP: &str = "snapshot.storage.k8s.io";
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct CustomResourceDef {
     pub name: String,
@@ -251,6 +435,7 @@ pub struct CustomResourceDef {
     pub kind: String,
     pub plural: String,
     pub namespaced: bool,
+    pub scalable: bool,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -261,6 +446,54 @@ pub struct ContextCatalogRow {
     pub namespace: String,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct SecretEntry {
+    pub key: String,
+    pub value: Option<String>,
+    pub bytes: usize,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum WatchChange {
+    Created,
+    Updated,
+    Deleted,
+}
+
+impl WatchChange {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Deleted => "deleted",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct WatchActivity {
+    pub at: DateTime<Local>,
+    pub tab: ResourceTab,
+    pub change: WatchChange,
+    pub namespace: Option<String>,
+    pub name: String,
+}
+
+impl WatchActivity {
+    pub fn line(&self) -> String {
+        let kind = self.tab.kind().unwrap_or_else(|| self.tab.title());
+        let target = match self.namespace.as_deref() {
+            Some(namespace) => format!("{namespace}/{}", self.name),
+            None => self.name.clone(),
+        };
+        format!(
+            "{}  {kind} {target} {}",
+            self.at.format("%H:%M:%S"),
+            self.change.label()
+        )
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
 pub struct PodContainerInfo {
     pub name: String,
@@ -268,7 +501,45 @@ pub struct PodContainerInfo {
     pub ready: bool,
     pub state: String,
     pub restarts: u32,
+    pub last_exit: String,
     pub age: String,
+    pub cpu: String,
+    pub memory: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum DeletePropagation {
+    #[default]
+    Background,
+    Foreground,
+    Orphan,
+}
+
+impl DeletePropagation {
+    pub fn from_token(token: &str) -> Option<Self> {
+        match token.trim().to_ascii_lowercase().as_str() {
+            "background" | "bg" => Some(Self::Background),
+            "foreground" | "fg" => Some(Self::Foreground),
+            "orphan" => Some(Self::Orphan),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Background => "background",
+            Self::Foreground => "foreground",
+            Self::Orphan => "orphan",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Background => Self::Foreground,
+            Self::Foreground => Self::Orphan,
+            Self::Orphan => Self::Background,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -295,12 +566,17 @@ impl Display for NamespaceScope {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct RowData {
     pub name: String,
     pub namespace: Option<String>,
     pub columns: Vec<String>,
+    pub labels: BTreeMap<String, String>,
+    pub annotations: BTreeMap<String, String>,
     pub detail: String,
+    pub created: Option<DateTime<Utc>>,
+    /// `status.phase` for pods; the Status column holds the derived reason instead.
+    pub phase: Option<String>,
 }
 
 impl RowData {
@@ -310,6 +586,16 @@ impl RowData {
             return true;
         }
 
+        if let Some(expr) = query.strip_prefix("label:") {
+            return metadata_matches(&self.labels, expr);
+        }
+        if let Some(expr) = query
+            .strip_prefix("annotation:")
+            .or_else(|| query.strip_prefix("anno:"))
+        {
+            return metadata_matches(&self.annotations, expr);
+        }
+
         let query_lower = query.to_ascii_lowercase();
 
         if self.name.to_ascii_lowercase().contains(&query_lower) {
@@ -322,9 +608,59 @@ impl RowData {
             return true;
         }
 
-        self.columns
+        if self
+            .columns
             .iter()
             .any(|column| column.to_ascii_lowercase().contains(&query_lower))
+        {
+            return true;
+        }
+
+        fuzzy_matches(&self.name.to_ascii_lowercase(), &query_lower)
+    }
+}
+
+const RESERVED_FILTER_PREFIXES: [&str; 3] = ["label", "annotation", "anno"];
+
+pub fn filter_column_key(header: &str) -> String {
+    header
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_lowercase()
+}
+
+fn metadata_matches(map: &BTreeMap<String, String>, expr: &str) -> bool {
+    match expr.trim().split_once('=') {
+        Some((key, value)) => map.get(key.trim()).is_some_and(|v| v == value.trim()),
+        None => map.contains_key(expr.trim()),
+    }
+}
+
+fn fuzzy_matches(candidate: &str, query: &str) -> bool {
+    let mut candidate = candidate.chars();
+    query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .all(|needle| candidate.any(|c| c == needle))
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct TableSort {
+    pub column: usize,
+    pub descending: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct RowChanges {
+    pub added: usize,
+    pub changed: usize,
+    pub removed: usize,
+}
+
+impl RowChanges {
+    pub fn is_empty(&self) -> bool {
+        self.added == 0 && self.changed == 0 && self.removed == 0
     }
 }
 
@@ -335,9 +671,71 @@ pub struct TableData {
     pub selected: usize,
     pub last_refreshed: Option<DateTime<Local>>,
     pub error: Option<String>,
+    pub sort: Option<TableSort>,
+    pub has_more: bool,
 }
 
 impl TableData {
+    pub fn dump_value(&self) -> serde_json::Value {
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                let columns = self
+                    .headers
+                    .iter()
+                    .zip(&row.columns)
+                    .map(|(header, value)| (header.clone(), serde_json::Value::from(value.clone())))
+                    .collect::<serde_json::Map<_, _>>();
+                serde_json::json!({
+                    "name": row.name,
+                    "namespace": row.namespace,
+                    "columns": columns,
+                    "labels": row.labels,
+                    "annotations": row.annotations,
+                })
+            })
+            .collect();
+        serde_json::Value::Array(rows)
+    }
+
+    pub fn with_namespace_column(&self) -> TableData {
+        if self.rows.iter().all(|row| row.namespace.is_none()) {
+            return self.clone();
+        }
+        let mut table = self.clone();
+        table.headers.insert(0, "Namespace".to_string());
+        for row in &mut table.rows {
+            row.columns
+                .insert(0, row.namespace.clone().unwrap_or_else(|| "-".to_string()));
+        }
+        table
+    }
+
+    pub fn row_matches_filter(&self, row: &RowData, query: &str) -> bool {
+        match self.column_filter(query) {
+            Some((index, value)) => row.columns.get(index).is_some_and(|cell| {
+                let cell = cell.to_ascii_lowercase();
+                let value = value.to_ascii_lowercase();
+                cell.contains(&value) || fuzzy_matches(&cell, &value)
+            }),
+            None => row.matches_filter(query),
+        }
+    }
+
+    pub fn column_filter<'a>(&self, query: &'a str) -> Option<(usize, &'a str)> {
+        let (key, value) = query.trim().split_once(':')?;
+        let key = key.trim().to_ascii_lowercase();
+        if RESERVED_FILTER_PREFIXES.contains(&key.as_str()) {
+            return None;
+        }
+        let index = self
+            .headers
+            .iter()
+            .position(|header| filter_column_key(header) == key)?;
+        Some((index, value.trim()))
+    }
+
     pub fn set_rows(
         &mut self,
         headers: Vec<String>,
@@ -349,6 +747,75 @@ impl TableData {
         self.last_refreshed = Some(refreshed_at);
         self.error = None;
         self.selected = self.selected.min(self.rows.len().saturating_sub(1));
+        self.apply_sort(self.sort);
+    }
+
+    pub fn merge_rows(&mut self, incoming: TableData) -> RowChanges {
+        let mut previous = self
+            .rows
+            .iter()
+            .map(|row| ((row.namespace.as_deref(), row.name.as_str()), row))
+            .collect::<HashMap<_, _>>();
+        let mut changes = RowChanges::default();
+        for row in &incoming.rows {
+            match previous.remove(&(row.namespace.as_deref(), row.name.as_str())) {
+                Some(old) if old == row => {}
+                Some(_) => changes.changed += 1,
+                None => changes.added += 1,
+            }
+        }
+        changes.removed = previous.len();
+
+        let same_order = self.rows.len() == incoming.rows.len()
+            && self
+                .rows
+                .iter()
+                .zip(&incoming.rows)
+                .all(|(old, new)| old.namespace == new.namespace && old.name == new.name);
+        if same_order {
+            for (old, new) in self.rows.iter_mut().zip(incoming.rows) {
+                if *old != new {
+                    *old = new;
+                }
+            }
+        } else {
+            self.rows = incoming.rows;
+        }
+        self.headers = incoming.headers;
+        self.last_refreshed = incoming.last_refreshed;
+        self.error = incoming.error;
+        self.has_more = incoming.has_more;
+        changes
+    }
+
+    pub fn apply_sort(&mut self, sort: Option<TableSort>) {
+        self.sort = sort;
+        let Some(sort) = sort else {
+            return;
+        };
+        let ages = self
+            .headers
+            .get(sort.column)
+            .is_some_and(|header| matches!(header.as_str(), "Age" | "Last"));
+        self.rows.sort_by(|left, right| {
+            let ordering = compare_cells(
+                ages,
+                left.columns
+                    .get(sort.column)
+                    .map(String::as_str)
+                    .unwrap_or(""),
+                right
+                    .columns
+                    .get(sort.column)
+                    .map(String::as_str)
+                    .unwrap_or(""),
+            );
+            if sort.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
     }
 
     pub fn set_error(&mut self, error: impl Into<String>, refreshed_at: DateTime<Local>) {
@@ -359,6 +826,100 @@ impl TableData {
     }
 }
 
+fn compare_cells(ages: bool, left: &str, right: &str) -> Ordering {
+    match (numeric_cell_key(left, ages), numeric_cell_key(right, ages)) {
+        (Some(left_key), Some(right_key)) => {
+            left_key.partial_cmp(&right_key).unwrap_or(Ordering::Equal)
+        }
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => left
+            .to_ascii_lowercase()
+            .cmp(&right.to_ascii_lowercase())
+            .then_with(|| left.cmp(right)),
+    }
+}
+
+/// Sort key for numeric-looking cells. Age columns read `s/m/h/d/y` suffixes as time; every
+/// other column reads Kubernetes quantity suffixes, so `500m` CPU sorts below `1`.
+fn numeric_cell_key(cell: &str, ages: bool) -> Option<(f64, f64)> {
+    let cell = cell.trim();
+    if let Some((ready, total)) = cell.split_once('/') {
+        return Some((ready.trim().parse().ok()?, total.trim().parse().ok()?));
+    }
+    if let Ok(value) = cell.parse::<f64>() {
+        return Some((value, 0.0));
+    }
+    let leading = cell.split_whitespace().next()?;
+    if leading.len() != cell.len() {
+        return numeric_cell_key(leading, ages);
+    }
+    let unit_start = cell.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (amount, unit) = cell.split_at(unit_start);
+    let amount = amount.parse::<f64>().ok()?;
+    let scale = if ages {
+        match unit {
+            "s" => 1.0,
+            "m" => 60.0,
+            "h" => 3_600.0,
+            "d" => 86_400.0,
+            "y" => 31_536_000.0,
+            _ => return None,
+        }
+    } else {
+        match unit {
+            "n" => 1e-9,
+            "u" => 1e-6,
+            "m" => 1e-3,
+            "k" => 1e3,
+            "M" => 1e6,
+            "G" => 1e9,
+            "T" => 1e12,
+            "P" => 1e15,
+            "E" => 1e18,
+            "Ki" => 1_024_f64,
+            "Mi" => 1_024_f64.powi(2),
+            "Gi" => 1_024_f64.powi(3),
+            "Ti" => 1_024_f64.powi(4),
+            "Pi" => 1_024_f64.powi(5),
+            "Ei" => 1_024_f64.powi(6),
+            _ => return None,
+        }
+    };
+    Some((amount * scale, 0.0))
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum UsageSort {
+    #[default]
+    Cpu,
+    Memory,
+}
+
+impl UsageSort {
+    pub fn from_token(token: &str) -> Option<Self> {
+        match token.trim().to_ascii_lowercase().as_str() {
+            "cpu" | "c" => Some(Self::Cpu),
+            "mem" | "memory" | "m" => Some(Self::Memory),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Cpu => "CPU",
+            Self::Memory => "memory",
+        }
+    }
+
+    pub fn flip(self) -> Self {
+        match self {
+            Self::Cpu => Self::Memory,
+            Self::Memory => Self::Cpu,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct OverviewMetrics {
     pub cpu_usage_millicores: u64,
@@ -373,6 +934,22 @@ pub struct OverviewMetrics {
     pub namespace_usage: HashMap<String, (u64, u64)>,
 }
 
+/// Live kubectl objects backing the Argo CD resource tree for one namespace.
+#[derive(Debug, Clone, Default)]
+pub struct ArgoNamespaceLive {
+    pub workloads: Vec<Value>,
+    pub endpoints: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct NamespaceCounts {
+    pub pods: usize,
+    pub running_pods: usize,
+    pub deployments: usize,
+    pub ready_deployments: usize,
+    pub services: usize,
+}
+
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct AlertSnapshot {
     pub crash_loop_pods: usize,
@@ -385,7 +962,208 @@ pub struct AlertSnapshot {
 
 #[cfg(test)]
 mod tests {
-    use super::ResourceTab;
+    use super::{LogWindow, ResourceTab, RowChanges, RowData, TableData, TableSort};
+    use chrono::Local;
+
+    fn row(name: &str, restarts: &str, age: &str) -> RowData {
+        RowData {
+            name: name.to_string(),
+            namespace: None,
+            columns: vec![name.to_string(), restarts.to_string(), age.to_string()],
+            detail: String::new(),
+            ..RowData::default()
+        }
+    }
+
+    #[test]
+    fn filter_fuzzy_matches_name_subsequence() {
+        let pod = row("payments-api-7d9f", "0", "1d");
+        assert!(pod.matches_filter("api"));
+        assert!(pod.matches_filter("pyapi"));
+        assert!(pod.matches_filter("PAYAPI"));
+        assert!(!pod.matches_filter("apipay"));
+        assert!(!pod.matches_filter("worker"));
+    }
+
+    #[test]
+    fn filter_matches_label_and_annotation_expressions() {
+        let mut deploy = row("api", "0", "1d");
+        deploy.labels.insert(
+            "app.kubernetes.io/managed-by".to_string(),
+            "Helm".to_string(),
+        );
+        deploy
+            .annotations
+            .insert("argocd.argoproj.io/sync-wave".to_string(), "2".to_string());
+        assert!(deploy.matches_filter("label:app.kubernetes.io/managed-by"));
+        assert!(deploy.matches_filter("label:app.kubernetes.io/managed-by=Helm"));
+        assert!(!deploy.matches_filter("label:app.kubernetes.io/managed-by=helm"));
+        assert!(!deploy.matches_filter("label:app"));
+        assert!(deploy.matches_filter("annotation:argocd.argoproj.io/sync-wave=2"));
+        assert!(deploy.matches_filter("anno:argocd.argoproj.io/sync-wave"));
+        assert!(!deploy.matches_filter("annotation:app.kubernetes.io/managed-by"));
+    }
+
+    #[test]
+    fn dump_value_keys_columns_by_header() {
+        let mut table = TableData::default();
+        let mut pod = row("api", "2", "1d");
+        pod.namespace = Some("shop".to_string());
+        pod.labels.insert("app".to_string(), "api".to_string());
+        table.set_rows(
+            vec![
+                "Name".to_string(),
+                "Restarts".to_string(),
+                "Age".to_string(),
+            ],
+            vec![pod],
+            Local::now(),
+        );
+
+        assert_eq!(
+            table.dump_value(),
+            serde_json::json!([{
+                "name": "api",
+                "namespace": "shop",
+                "columns": {"Name": "api", "Restarts": "2", "Age": "1d"},
+                "labels": {"app": "api"},
+                "annotations": {},
+            }])
+        );
+        let wide = table.with_namespace_column();
+        assert_eq!(wide.headers[0], "Namespace");
+        assert_eq!(wide.rows[0].columns, vec!["shop", "api", "2", "1d"]);
+    }
+
+    #[test]
+    fn column_scoped_filter_matches_only_that_column() {
+        let mut table = TableData::default();
+        table.set_rows(
+            vec![
+                "Name".to_string(),
+                "Restarts".to_string(),
+                "Last Seen".to_string(),
+            ],
+            vec![row("crashloop-api", "0", "1d"), row("worker", "7", "2h")],
+            Local::now(),
+        );
+        let matching = |query: &str| {
+            table
+                .rows
+                .iter()
+                .filter(|row| table.row_matches_filter(row, query))
+                .map(|row| row.name.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(matching("restarts:7"), vec!["worker"]);
+        assert_eq!(matching("lastseen:1d"), vec!["crashloop-api"]);
+        assert_eq!(matching("Name:wkr"), vec!["worker"]);
+        assert_eq!(matching("restarts:"), vec!["crashloop-api", "worker"]);
+        assert_eq!(matching("7"), vec!["worker"]);
+        assert!(matching("status:x").is_empty());
+        assert!(matching("label:app").is_empty());
+    }
+
+    #[test]
+    fn table_sort_is_numeric_aware_for_counts_and_ages() {
+        let mut table = TableData::default();
+        table.set_rows(
+            vec![
+                "Name".to_string(),
+                "Restarts".to_string(),
+                "Age".to_string(),
+            ],
+            vec![
+                row("b", "10", "2d"),
+                row("a", "9", "45s"),
+                row("c", "-", "3h"),
+            ],
+            Local::now(),
+        );
+
+        table.apply_sort(Some(TableSort {
+            column: 1,
+            descending: false,
+        }));
+        let names = table
+            .rows
+            .iter()
+            .map(|row| row.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["a", "b", "c"]);
+
+        table.apply_sort(Some(TableSort {
+            column: 2,
+            descending: true,
+        }));
+        let names = table
+            .rows
+            .iter()
+            .map(|row| row.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["b", "c", "a"]);
+
+        table.set_rows(
+            table.headers.clone(),
+            vec![row("x", "0", "1m"), row("y", "0", "5d")],
+            Local::now(),
+        );
+        let names = table
+            .rows
+            .iter()
+            .map(|row| row.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["y", "x"]);
+    }
+
+    #[test]
+    fn table_sort_reads_quantity_suffixes_outside_age_columns() {
+        let sized = |name: &str, value: &str| RowData {
+            name: name.to_string(),
+            columns: vec![name.to_string(), value.to_string()],
+            ..RowData::default()
+        };
+        let mut table = TableData::default();
+        table.set_rows(
+            vec!["Name".to_string(), "Capacity".to_string()],
+            vec![
+                sized("logs", "1Ti"),
+                sized("cache", "500Mi"),
+                sized("data", "2Gi"),
+                sized("scratch", "1500M"),
+                sized("pending", "-"),
+            ],
+            Local::now(),
+        );
+
+        table.apply_sort(Some(TableSort {
+            column: 1,
+            descending: false,
+        }));
+        let names = table
+            .rows
+            .iter()
+            .map(|row| row.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["cache", "scratch", "data", "logs", "pending"]);
+
+        table.set_rows(
+            vec!["Name".to_string(), "CPU".to_string()],
+            vec![
+                sized("busy", "1"),
+                sized("idle", "250m"),
+                sized("warm", "1.5"),
+            ],
+            Local::now(),
+        );
+        let names = table
+            .rows
+            .iter()
+            .map(|row| row.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["idle", "busy", "warm"]);
+    }
 
     #[test]
     fn resource_aliases_map_to_expected_tabs() {
@@ -429,6 +1207,19 @@ mod tests {
             ResourceTab::from_token("clusterrolebindings"),
             Some(ResourceTab::ClusterRoleBindings)
         );
+        assert_eq!(
+            ResourceTab::from_token("eps"),
+            Some(ResourceTab::EndpointSlices)
+        );
+        assert_eq!(
+            ResourceTab::from_token("pc"),
+            Some(ResourceTab::PriorityClasses)
+        );
+        assert_eq!(
+            ResourceTab::from_token("vs"),
+            Some(ResourceTab::VolumeSnapshots)
+        );
+        assert_eq!(ResourceTab::from_token("leases"), Some(ResourceTab::Leases));
         assert_eq!(
             ResourceTab::from_token("np"),
             Some(ResourceTab::NetworkPolicies)
@@ -438,4 +1229,76 @@ mod tests {
             Some(ResourceTab::ServiceAccounts)
         );
     }
+
+    #[test]
+    fn log_window_labels_describe_the_time_range() {
+        let latest = LogWindow {
+            width_secs: 900,
+            offset: 0,
+        };
+        assert_eq!(latest.since_seconds(), 900);
+        assert_eq!(latest.label(), "last 15m");
+
+        let older = LogWindow {
+            width_secs: 900,
+            offset: 3,
+        };
+        assert_eq!(older.since_seconds(), 3_600);
+        assert_eq!(older.until_seconds_ago(), 2_700);
+        assert_eq!(older.label(), "1h-45m ago");
+    }
+
+    #[test]
+    fn merge_rows_counts_changes_and_updates_in_place() {
+        let headers = vec![
+            "Name".to_string(),
+            "Restarts".to_string(),
+            "Age".to_string(),
+        ];
+        let mut table = TableData::default();
+        table.set_rows(
+            headers.clone(),
+            vec![row("a", "0", "1m"), row("b", "0", "1m")],
+            Local::now(),
+        );
+
+        let mut incoming = TableData::default();
+        incoming.set_rows(
+            headers.clone(),
+            vec![row("a", "0", "1m"), row("b", "3", "1m")],
+            Local::now(),
+        );
+        let changes = table.merge_rows(incoming);
+        assert_eq!(
+            changes,
+            RowChanges {
+                added: 0,
+                changed: 1,
+                removed: 0
+            }
+        );
+        assert_eq!(table.rows[1].columns[1], "3");
+
+        let mut incoming = TableData::default();
+        incoming.set_rows(
+            headers,
+            vec![row("c", "0", "1s"), row("b", "3", "1m")],
+            Local::now(),
+        );
+        let changes = table.merge_rows(incoming);
+        assert_eq!(
+            changes,
+            RowChanges {
+                added: 1,
+                changed: 0,
+                removed: 1
+            }
+        );
+        let names = table
+            .rows
+            .iter()
+            .map(|row| row.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["c", "b"]);
+    }
 }
@@ -0,0 +1,148 @@
+use crate::model::NamespaceScope;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+const RELEASE_NOTES: &[(&str, &str)] = &[(
+    "0.1.0",
+    "Keys
+  a        toggle all namespaces / last named namespace
+  I        edit the selected YAML inline (Ctrl+S apply, Esc discard)
+  W        save the selected YAML to ./.manifests/exports
+  Enter    on Events jumps to the involved object
+  Tab      while filtering (/) scopes the search to one column
+
+Commands
+  :rollout watch [secs]     follow a rollout until it completes or fails
+  :debug [image] [target]   attach an ephemeral debug container
+  :images                   image registries and pull secret coverage
+  :node-top                 per-node CPU/memory against allocatable
+  :export ns <dir>          write cleaned namespace manifests to disk
+  :save [dir|file.yaml]     save the selected YAML
+  :pf <local>:<port-name>   port-forward a service port by name
+
+CLI
+  --dump <resource> -o json|yaml|wide   print a table without the TUI
+
+Config
+  adopt_context_namespace, export_dir, strip_name_prefixes, whats_new
+
+Set `whats_new: false` in the orca config to stop showing this on upgrade.",
+)];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrcaState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_context: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_namespace: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub last_all_namespaces: bool,
+}
+
+impl OrcaState {
+    pub fn load() -> Self {
+        state_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|raw| serde_yaml::from_str(&raw).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn last_scope_for(&self, context: &str) -> Option<NamespaceScope> {
+        if self.last_context.as_deref() != Some(context) {
+            return None;
+        }
+        if self.last_all_namespaces {
+            return Some(NamespaceScope::All);
+        }
+        self.last_namespace.clone().map(NamespaceScope::Named)
+    }
+
+    pub fn remember_session(&mut self, context: &str, scope: &NamespaceScope) {
+        self.last_context = Some(context.to_string());
+        match scope {
+            NamespaceScope::All => {
+                self.last_all_namespaces = true;
+            }
+            NamespaceScope::Named(namespace) => {
+                self.last_all_namespaces = false;
+                self.last_namespace = Some(namespace.clone());
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = state_path().context("no state directory (HOME is not set)")?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let raw = serde_yaml::to_string(self)?;
+        fs::write(&path, raw).with_context(|| format!("failed to write {}", path.display()))
+    }
+}
+
+pub fn release_notes(version: &str) -> Option<&'static str> {
+    RELEASE_NOTES
+        .iter()
+        .find(|(release, _)| *release == version)
+        .map(|(_, notes)| *notes)
+}
+
+fn state_path() -> Option<PathBuf> {
+    if let Ok(path) = std::env::var("ORCA_STATE")
+        && !path.trim().is_empty()
+    {
+        return Some(PathBuf::from(path));
+    }
+    if let Ok(dir) = std::env::var("XDG_STATE_HOME")
+        && !dir.trim().is_empty()
+    {
+        return Some(PathBuf::from(dir).join("orca/state.yaml"));
+    }
+    std::env::var("HOME")
+        .ok()
+        .map(|home| PathBuf::from(home).join(".local/state/orca/state.yaml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OrcaState, release_notes};
+    use crate::model::NamespaceScope;
+
+    #[test]
+    fn current_version_has_release_notes() {
+        assert!(release_notes(env!("CARGO_PKG_VERSION")).is_some());
+        assert!(release_notes("0.0.0-unknown").is_none());
+    }
+
+    #[test]
+    fn state_round_trips_without_empty_fields() {
+        assert_eq!(
+            serde_yaml::to_string(&OrcaState::default()).unwrap(),
+            "{}\n"
+        );
+        let state: OrcaState = serde_yaml::from_str("last_version: 0.1.0\n").unwrap();
+        assert_eq!(state.last_version.as_deref(), Some("0.1.0"));
+    }
+
+    #[test]
+    fn session_scope_restores_only_for_the_same_context() {
+        let mut state = OrcaState::default();
+        state.remember_session("prod", &NamespaceScope::Named("payments".to_string()));
+        assert_eq!(
+            state.last_scope_for("prod"),
+            Some(NamespaceScope::Named("payments".to_string()))
+        );
+        assert_eq!(state.last_scope_for("dev"), None);
+
+        state.remember_session("prod", &NamespaceScope::All);
+        let raw = serde_yaml::to_string(&state).unwrap();
+        let restored: OrcaState = serde_yaml::from_str(&raw).unwrap();
+        assert_eq!(restored.last_scope_for("prod"), Some(NamespaceScope::All));
+        assert_eq!(restored.last_namespace.as_deref(), Some("payments"));
+    }
+}
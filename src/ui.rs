@@ -3,10 +3,11 @@ use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Wrap};
+use regex::Regex;
 use serde_json::Value;
 
 use crate::app::{App, DetailPaneMode, InputMode, TableOverlayKind};
-use crate::model::{ResourceTab, RowData};
+use crate::model::{NamespaceScope, ResourceTab, RowData};
 
 const BG: Color = Color::Rgb(9, 15, 25);
 const PANEL: Color = Color::Rgb(16, 27, 44);
@@ -34,6 +35,18 @@ pub fn render(frame: &mut Frame, app: &mut App) {
     render_body(frame, root[1], app);
     render_footer(frame, root[2], app);
 
+    if app.reference_menu_active() {
+        render_reference_menu(frame, app);
+    }
+    if app.namespace_picker_active() {
+        render_namespace_picker(frame, app);
+    }
+    if let Some((dir, addresses, selected)) = app.terraform_state_picker() {
+        render_terraform_state_picker(frame, dir, addresses, selected);
+    }
+    if let Some(details) = app.pending_confirmation_details() {
+        render_confirmation_modal(frame, app, details);
+    }
     if app.show_help() {
         render_help_modal(frame, app);
     }
@@ -89,6 +102,8 @@ fn build_left_header_line(app: &App) -> Line<'static> {
             Some(TableOverlayKind::PodLogs) => ("󰍩", "logs"),
             Some(TableOverlayKind::RelatedLogs) => ("󰌨", "logs"),
             Some(TableOverlayKind::Shell) => ("", "shell"),
+            Some(TableOverlayKind::PortForwards) => ("󰕒", "port-forwards"),
+            Some(TableOverlayKind::Activity) => ("󰋚", "activity"),
             _ => (tab_icon(app.active_tab()), "output"),
         };
         format!(
@@ -217,7 +232,14 @@ fn build_left_header_line(app: &App) -> Line<'static> {
         );
         push_powerline_segment(
             &mut spans,
-            format!(" 󱃾 {} ", compact_text(app.context(), 14)),
+            match app.server_version() {
+                Some(version) => format!(
+                    " 󱃾 {} {} ",
+                    compact_text(app.context(), 14),
+                    compact_text(version, 12)
+                ),
+                None => format!(" 󱃾 {} ", compact_text(app.context(), 14)),
+            },
             Color::White,
             PL_C,
             PL_D,
@@ -307,9 +329,10 @@ fn render_body(frame: &mut Frame, area: Rect, app: &mut App) {
     let (detail_width, detail_height) = detail_viewport(area);
     app.set_detail_viewport(detail_width, detail_height);
 
-    if app.detail_mode() == DetailPaneMode::Details
-        && !app.table_overlay_active()
-        && !app.table_overview_active()
+    if app.yaml_editor_active()
+        || (app.detail_mode() == DetailPaneMode::Details
+            && !app.table_overlay_active()
+            && !app.table_overview_active())
     {
         render_detail(frame, area, app, true);
     } else {
@@ -324,12 +347,21 @@ fn render_table(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
     }
 
     if app.table_overlay_active() {
-        let title = app
+        let mut title = app
             .table_overlay_title()
             .map(str::to_string)
             .unwrap_or_else(|| "Output".to_string());
-        let text = app.table_overlay_text().unwrap_or("");
-        let paragraph = Paragraph::new(Text::from(text.to_string()))
+        if let Some(summary) = app.log_filter_summary() {
+            title = format!("{title} [{summary}]");
+        }
+        let text = match app.log_filter_regex() {
+            Some(pattern) => highlight_log_matches(&app.table_overlay_lines(), &pattern),
+            None if app.table_overlay_kind() == Some(TableOverlayKind::Diff) => {
+                highlight_diff_lines(&app.table_overlay_lines())
+            }
+            None => Text::from(app.table_overlay_text().unwrap_or("").to_string()),
+        };
+        let paragraph = Paragraph::new(text)
             .wrap(Wrap { trim: false })
             .scroll((app.table_scroll(), 0))
             .block(
@@ -379,32 +411,83 @@ fn render_table(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
         headers.push("PF".to_string());
     }
     let visible_rows = app.active_visible_rows();
+    let sort = app.active_sort();
 
-    let header_row = Row::new(headers.iter().map(|header| {
-        Cell::from(header.clone()).style(Style::default().add_modifier(Modifier::BOLD))
+    let header_row = Row::new(headers.iter().enumerate().map(|(index, header)| {
+        let label = match sort {
+            Some(sort) if sort.column == index => {
+                format!("{header} {}", if sort.descending { "▼" } else { "▲" })
+            }
+            _ => header.clone(),
+        };
+        Cell::from(label).style(Style::default().add_modifier(Modifier::BOLD))
     }))
     .height(1)
     .style(Style::default().fg(ACCENT));
 
+    let name_column = headers.iter().position(|header| header == "Name");
+    let age_column = headers.iter().position(|header| header == "Age");
     let rows = visible_rows.iter().map(|row| {
         let mut columns = row.columns.clone();
+        if let Some(cell) = name_column.and_then(|index| columns.get_mut(index))
+            && *cell == row.name
+        {
+            *cell = app.display_name(row);
+        }
+        if let Some(cell) = age_column.and_then(|index| columns.get_mut(index))
+            && let Some(age) = app.display_age(row)
+        {
+            *cell = age;
+        }
         if include_pf_column {
             columns.push(app.port_forward_cell_for_row(active_tab, row));
         }
+        let marked = app.row_marked(row);
+        if marked && let Some(first) = columns.first_mut() {
+            *first = format!("● {first}");
+        }
+        let color = if marked { WARN } else { Color::White };
 
         Row::new(
             columns
                 .into_iter()
-                .map(|column| Cell::from(column).style(Style::default().fg(Color::White))),
+                .map(|column| Cell::from(column).style(Style::default().fg(color))),
         )
     });
 
     let constraints = column_constraints(headers.len().max(1));
     let title = if app.active_tab() == ResourceTab::Orca {
         format!("Dashboard ({})", visible_rows.len())
+    } else if app.table_has_more_for(app.active_tab()) {
+        format!(
+            "{} ({}+, :more loads next page)",
+            app.active_tab().title(),
+            visible_rows.len()
+        )
     } else {
         format!("{} ({})", app.active_tab().title(), visible_rows.len())
     };
+    let title = match app.label_selector_for(app.active_tab()) {
+        Some(selector) => format!("{title} [-l {selector}]"),
+        None => title,
+    };
+    let title = match app.field_selector_for(app.active_tab()) {
+        Some(selector) => format!("{title} [--field-selector {selector}]"),
+        None => title,
+    };
+    let title = match app.marked_count() {
+        0 => title,
+        marked => format!("{title} [{marked} marked]"),
+    };
+    let title = if app.auth_expired() {
+        format!("{title} [auth expired]")
+    } else if app.watch_degraded(app.active_tab()) {
+        format!("{title} [watch degraded]")
+    } else if app.watch_disabled() && app.active_tab().kind().is_some() {
+        format!("{title} [polling]")
+    } else {
+        title
+    };
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
@@ -454,6 +537,17 @@ fn render_container_picker(frame: &mut Frame, area: Rect, app: &App, focused: bo
             Cell::from(item.ready.clone()).style(Style::default().fg(Color::White)),
             Cell::from(compact_text(&item.state, 16)).style(Style::default().fg(Color::White)),
             Cell::from(item.restarts.clone()).style(Style::default().fg(Color::White)),
+            Cell::from(compact_text(&item.last_exit, 22)).style(Style::default().fg(
+                if item.last_exit.starts_with("OOMKilled") {
+                    ERROR
+                } else if item.last_exit == "-" {
+                    Color::White
+                } else {
+                    WARN
+                },
+            )),
+            Cell::from(item.cpu.clone()).style(Style::default().fg(Color::White)),
+            Cell::from(item.memory.clone()).style(Style::default().fg(Color::White)),
             Cell::from(item.age.clone()).style(Style::default().fg(Color::White)),
             Cell::from(item.pf.clone()).style(Style::default().fg(Color::White)),
         ])
@@ -479,6 +573,9 @@ fn render_container_picker(frame: &mut Frame, area: Rect, app: &App, focused: bo
             Constraint::Length(7),
             Constraint::Length(14),
             Constraint::Length(9),
+            Constraint::Length(22),
+            Constraint::Length(7),
+            Constraint::Length(8),
             Constraint::Length(6),
             Constraint::Length(11),
         ],
@@ -499,6 +596,10 @@ fn render_container_picker(frame: &mut Frame, area: Rect, app: &App, focused: bo
 }
 
 fn render_detail(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
+    if app.yaml_editor_active() {
+        render_yaml_editor(frame, area, app);
+        return;
+    }
     let title = app.detail_title();
     let detail = app.detail_text();
     let text = if app.detail_overlay_active() {
@@ -524,6 +625,61 @@ fn render_detail(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
     frame.render_widget(paragraph, area);
 }
 
+fn render_yaml_editor(frame: &mut Frame, area: Rect, app: &App) {
+    let title = app
+        .yaml_editor_title()
+        .unwrap_or_else(|| "Edit".to_string());
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(WARN))
+        .style(Style::default().bg(PANEL));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let (editor_area, error_area) = match app.yaml_editor_error() {
+        Some(_) => {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(1), Constraint::Length(3)])
+                .split(inner);
+            (chunks[0], Some(chunks[1]))
+        }
+        None => (inner, None),
+    };
+
+    let scroll = app.yaml_editor_scroll();
+    let lines = app
+        .yaml_editor_lines()
+        .iter()
+        .skip(scroll)
+        .take(editor_area.height as usize)
+        .map(|line| highlight_yaml_line(line))
+        .collect::<Vec<_>>();
+    frame.render_widget(
+        Paragraph::new(lines).style(Style::default().fg(Color::White)),
+        editor_area,
+    );
+
+    let (row, col) = app.yaml_editor_cursor();
+    if row >= scroll && col < editor_area.width as usize {
+        frame.set_cursor_position((
+            editor_area.x + col as u16,
+            editor_area.y + (row - scroll) as u16,
+        ));
+    }
+
+    if let (Some(error), Some(error_area)) = (app.yaml_editor_error(), error_area) {
+        frame.render_widget(
+            Paragraph::new(error.to_string())
+                .wrap(Wrap { trim: false })
+                .block(Block::default().borders(Borders::TOP))
+                .style(Style::default().fg(ERROR)),
+            error_area,
+        );
+    }
+}
+
 fn render_dashboard(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
     let model = build_dashboard_model(app);
     let block = Block::default()
@@ -1033,6 +1189,23 @@ fn row_health_score(tab: ResourceTab, row: &RowData) -> u64 {
                 }
             })
             .unwrap_or(65),
+        ResourceTab::EndpointSlices => {
+            let total = row
+                .columns
+                .get(3)
+                .and_then(|value| value.parse::<u64>().ok())
+                .unwrap_or(0);
+            let ready = row
+                .columns
+                .get(5)
+                .and_then(|value| value.parse::<u64>().ok())
+                .unwrap_or(0);
+            if ready == 0 {
+                20
+            } else {
+                (ready * 100 / total.max(ready)).max(45)
+            }
+        }
         ResourceTab::Ingresses | ResourceTab::IngressClasses => row
             .columns
             .get(4)
@@ -1074,13 +1247,35 @@ fn row_health_score(tab: ResourceTab, row: &RowData) -> u64 {
                 }
             })
             .unwrap_or(50),
+        ResourceTab::VolumeSnapshots => row
+            .columns
+            .get(2)
+            .map(|value| match value.as_str() {
+                "true" => 95,
+                "false" => 45,
+                _ => 60,
+            })
+            .unwrap_or(60),
+        ResourceTab::Leases => match row.columns.get(2).map(String::as_str) {
+            Some("-") | None => 40,
+            Some(_) => 85,
+        },
+        ResourceTab::MutatingWebhooks | ResourceTab::ValidatingWebhooks => {
+            match row.columns.get(2) {
+                Some(policies) if policies.contains("Fail") => 60,
+                _ => 85,
+            }
+        }
         ResourceTab::Nodes => row
             .columns
             .get(1)
             .map(|value| value.to_ascii_lowercase())
             .map(|status| {
-                if status.contains("ready") && !status.contains("notready") {
+                let pressured = row.columns.get(4).is_some_and(|value| value != "-");
+                if status.contains("ready") && !status.contains("notready") && !pressured {
                     100
+                } else if status.contains("ready") && !status.contains("notready") {
+                    60
                 } else if status.contains("unknown") {
                     45
                 } else {
@@ -1116,6 +1311,7 @@ fn row_health_score(tab: ResourceTab, row: &RowData) -> u64 {
         | ResourceTab::RoleBindings
         | ResourceTab::ClusterRoles
         | ResourceTab::ClusterRoleBindings
+        | ResourceTab::PriorityClasses
         | ResourceTab::NetworkPolicies => {
             let score = row
                 .columns
@@ -1152,7 +1348,7 @@ fn selected_metric_line(tab: ResourceTab, row: &RowData) -> String {
         ),
         ResourceTab::ArgoCdResources => format!(
             "kind:{} sync:{} health:{}",
-            row.columns.get(0).map_or("-", String::as_str),
+            row.columns.first().map_or("-", String::as_str),
             row.columns.get(3).map_or("-", String::as_str),
             row.columns.get(4).map_or("-", String::as_str)
         ),
@@ -1250,6 +1446,12 @@ fn selected_metric_line(tab: ResourceTab, row: &RowData) -> String {
             row.columns.get(2).map_or("-", String::as_str),
             compact_text(row.columns.get(4).map_or("-", String::as_str), 20)
         ),
+        ResourceTab::EndpointSlices => format!(
+            "ready:{}/{} ports:{}",
+            row.columns.get(5).map_or("-", String::as_str),
+            row.columns.get(3).map_or("-", String::as_str),
+            compact_text(row.columns.get(4).map_or("-", String::as_str), 20)
+        ),
         ResourceTab::ConfigMaps => format!(
             "data:{} binary:{}",
             row.columns.get(2).map_or("-", String::as_str),
@@ -1276,6 +1478,12 @@ fn selected_metric_line(tab: ResourceTab, row: &RowData) -> String {
             row.columns.get(4).map_or("-", String::as_str),
             row.columns.get(6).map_or("-", String::as_str)
         ),
+        ResourceTab::VolumeSnapshots => format!(
+            "ready:{} source:{} size:{}",
+            row.columns.get(2).map_or("-", String::as_str),
+            compact_text(row.columns.get(3).map_or("-", String::as_str), 16),
+            row.columns.get(4).map_or("-", String::as_str)
+        ),
         ResourceTab::ServiceAccounts => format!(
             "namespace:{} secrets:{}",
             row.columns.get(1).map_or("-", String::as_str),
@@ -1301,15 +1509,32 @@ fn selected_metric_line(tab: ResourceTab, row: &RowData) -> String {
             compact_text(row.columns.get(1).map_or("-", String::as_str), 16),
             row.columns.get(2).map_or("-", String::as_str)
         ),
+        ResourceTab::PriorityClasses => format!(
+            "value:{} default:{} preempt:{}",
+            row.columns.get(1).map_or("-", String::as_str),
+            row.columns.get(2).map_or("-", String::as_str),
+            compact_text(row.columns.get(3).map_or("-", String::as_str), 20)
+        ),
         ResourceTab::NetworkPolicies => format!(
             "selector:{} types:{}",
             row.columns.get(2).map_or("-", String::as_str),
             compact_text(row.columns.get(3).map_or("-", String::as_str), 14)
         ),
+        ResourceTab::Leases => format!(
+            "holder:{} renewed:{}",
+            compact_text(row.columns.get(2).map_or("-", String::as_str), 20),
+            row.columns.get(4).map_or("-", String::as_str)
+        ),
+        ResourceTab::MutatingWebhooks | ResourceTab::ValidatingWebhooks => format!(
+            "hooks:{} policy:{}",
+            row.columns.get(1).map_or("-", String::as_str),
+            compact_text(row.columns.get(2).map_or("-", String::as_str), 18)
+        ),
         ResourceTab::Nodes => format!(
-            "state:{} role:{}",
+            "state:{} role:{} taints:{}",
             row.columns.get(1).map_or("-", String::as_str),
-            compact_text(row.columns.get(2).map_or("-", String::as_str), 20)
+            compact_text(row.columns.get(2).map_or("-", String::as_str), 20),
+            row.columns.get(5).map_or("-", String::as_str)
         ),
         ResourceTab::Events => format!(
             "type:{} reason:{}",
@@ -1346,8 +1571,7 @@ fn parse_u64(value: &str) -> Option<u64> {
 fn render_footer(frame: &mut Frame, area: Rect, app: &App) {
     if matches!(app.mode(), InputMode::Normal) {
         let status_text = app
-            .pending_confirmation_prompt()
-            .map(|pending| format!("{pending}? (y/n)"))
+            .pending_confirmation_text()
             .unwrap_or_else(|| app.status().to_string());
 
         let mut spans = Vec::new();
@@ -1439,6 +1663,12 @@ fn render_footer(frame: &mut Frame, area: Rect, app: &App) {
             Color::Rgb(125, 211, 252),
             Color::Black,
         ),
+        InputMode::Edit => (
+            " 󰏫 edit ",
+            "Ctrl+S apply  Esc discard  Enter newline  Tab indent".to_string(),
+            WARN,
+            Color::Black,
+        ),
         InputMode::Normal => unreachable!(),
     };
 
@@ -1734,6 +1964,59 @@ fn footer_status_icon(status_text: &str) -> &'static str {
     if has_failure { "󰅚" } else { "󰄬" }
 }
 
+fn highlight_diff_lines(lines: &[&str]) -> Text<'static> {
+    let rendered = lines
+        .iter()
+        .map(|line| {
+            let style = if line.starts_with('+') {
+                Style::default().fg(ACCENT)
+            } else if line.starts_with('-') {
+                Style::default().fg(ERROR)
+            } else if line.starts_with("@@")
+                || line.starts_with("diff --git ")
+                || ["has changed:", "has been added:", "has been removed:"]
+                    .iter()
+                    .any(|suffix| line.trim_end().ends_with(suffix))
+            {
+                Style::default().fg(WARN).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            Line::from(Span::styled(line.to_string(), style))
+        })
+        .collect::<Vec<_>>();
+    Text::from(rendered)
+}
+
+fn highlight_log_matches(lines: &[&str], pattern: &Regex) -> Text<'static> {
+    let match_style = Style::default()
+        .fg(Color::Black)
+        .bg(WARN)
+        .add_modifier(Modifier::BOLD);
+    let rendered = lines
+        .iter()
+        .map(|line| {
+            let mut spans = Vec::new();
+            let mut cursor = 0usize;
+            for found in pattern.find_iter(line) {
+                if found.start() == found.end() {
+                    continue;
+                }
+                if found.start() > cursor {
+                    spans.push(Span::raw(line[cursor..found.start()].to_string()));
+                }
+                spans.push(Span::styled(found.as_str().to_string(), match_style));
+                cursor = found.end();
+            }
+            if cursor < line.len() {
+                spans.push(Span::raw(line[cursor..].to_string()));
+            }
+            Line::from(spans)
+        })
+        .collect::<Vec<_>>();
+    Text::from(rendered)
+}
+
 fn highlight_structured_text(input: &str) -> Text<'static> {
     let trimmed = input.trim_start();
     if (trimmed.starts_with('{') || trimmed.starts_with('['))
@@ -1983,6 +2266,175 @@ fn spans_width(spans: &[Span<'_>]) -> usize {
     spans.iter().map(|span| span.content.chars().count()).sum()
 }
 
+fn render_reference_menu(frame: &mut Frame, app: &App) {
+    let area = centered_rect(60, 30, frame.area());
+    frame.render_widget(Clear, area);
+
+    let selected = app.reference_menu_selected();
+    let lines = app
+        .reference_menu_entries()
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let style = if index == selected {
+                Style::default().fg(Color::Black).bg(ACCENT)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            Line::styled(
+                format!("{} {:<9} {}", index + 1, entry.label, entry.value),
+                style,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let menu = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(
+                app.reference_menu_title()
+                    .unwrap_or("Copy reference")
+                    .to_string(),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(ACCENT))
+            .style(Style::default().bg(PANEL)),
+    );
+    frame.render_widget(menu, area);
+}
+
+fn render_confirmation_modal(frame: &mut Frame, app: &App, details: &[String]) {
+    let area = centered_rect(60, 40, frame.area());
+    frame.render_widget(Clear, area);
+
+    let mut lines = details
+        .iter()
+        .map(|line| {
+            if line.starts_with("WARNING") {
+                Line::styled(
+                    line.clone(),
+                    Style::default().fg(ERROR).add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Line::styled(line.clone(), Style::default().fg(Color::White))
+            }
+        })
+        .collect::<Vec<_>>();
+    lines.push(Line::from(""));
+    match app.pending_confirmation_typed() {
+        Some((expected, typed)) => {
+            lines.push(Line::styled(
+                format!("Type '{expected}' and press Enter to confirm, Esc cancels"),
+                Style::default().fg(WARN),
+            ));
+            lines.push(Line::styled(
+                format!("> {typed}█"),
+                Style::default().fg(Color::White),
+            ));
+        }
+        None => lines.push(Line::styled(
+            "Press y to confirm, n or Esc to cancel",
+            Style::default().fg(WARN),
+        )),
+    }
+
+    let modal = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(
+                app.pending_confirmation_prompt()
+                    .unwrap_or("Confirm")
+                    .to_string(),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(ERROR))
+            .style(Style::default().bg(PANEL)),
+    );
+    frame.render_widget(modal, area);
+}
+
+fn render_namespace_picker(frame: &mut Frame, app: &App) {
+    let area = centered_rect(50, 60, frame.area());
+    frame.render_widget(Clear, area);
+
+    let entries = app.namespace_picker_entries();
+    let selected = app
+        .namespace_picker_selected()
+        .min(entries.len().saturating_sub(1));
+    let visible = area.height.saturating_sub(2).max(1) as usize;
+    let start = selected.saturating_sub(visible.saturating_sub(1));
+    let lines = entries
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(visible)
+        .map(|(index, scope)| {
+            let style = if index == selected {
+                Style::default().fg(Color::Black).bg(ACCENT)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            let marker = if scope == app.namespace_scope() {
+                "*"
+            } else {
+                " "
+            };
+            let label = match scope {
+                NamespaceScope::All => "(all namespaces)".to_string(),
+                NamespaceScope::Named(namespace) => namespace.clone(),
+            };
+            Line::styled(format!("{marker} {label}"), style)
+        })
+        .collect::<Vec<_>>();
+
+    let picker = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!("Namespaces ({})", entries.len().saturating_sub(1)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(ACCENT))
+            .style(Style::default().bg(PANEL)),
+    );
+    frame.render_widget(picker, area);
+}
+
+fn render_terraform_state_picker(
+    frame: &mut Frame,
+    dir: &str,
+    addresses: &[String],
+    selected: usize,
+) {
+    let area = centered_rect(70, 70, frame.area());
+    frame.render_widget(Clear, area);
+
+    let selected = selected.min(addresses.len().saturating_sub(1));
+    let visible = area.height.saturating_sub(2).max(1) as usize;
+    let start = selected.saturating_sub(visible.saturating_sub(1));
+    let lines = addresses
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(visible)
+        .map(|(index, address)| {
+            let style = if index == selected {
+                Style::default().fg(Color::Black).bg(ACCENT)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            Line::styled(format!(" {address}"), style)
+        })
+        .collect::<Vec<_>>();
+
+    let picker = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(
+                "Terraform state {dir} ({})  Enter show  Esc close",
+                addresses.len()
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(ACCENT))
+            .style(Style::default().bg(PANEL)),
+    );
+    frame.render_widget(picker, area);
+}
+
 fn render_help_modal(frame: &mut Frame, app: &App) {
     let area = centered_rect(78, 72, frame.area());
     frame.render_widget(Clear, area);
@@ -2015,26 +2467,20 @@ fn render_help_modal(frame: &mut Frame, app: &App) {
 }
 
 fn contextual_help_lines(app: &App) -> Vec<String> {
-    let mut lines = Vec::new();
-
-    lines.push("Flow: Enter drill-down  Esc step-back  d details  o overview".to_string());
-    lines.push(
+    let mut lines = vec![
+        "Flow: Enter drill-down  Esc step-back  d details  D describe  o overview  f/F focus row ns / all ns  a toggle all/last ns  {/} cycle ns  h pod node neighbors  x decode secret (x again reveals)  S sort column  A sort direction  E Argo app source in $EDITOR  w scratch YAML in $EDITOR  W save YAML to file  I edit YAML inline  c copy reference  C copy name/YAML  v object events  t run CronJob now  z suspend/resume CronJob  T relative/absolute age".to_string(),
         "Views: Ctrl+1..9 switch/create  Ctrl+Shift+1..9 mirror  Ctrl+Alt+0..9 delete".to_string(),
-    );
-    lines.push("Hotkeys: runtime bindings from orca.yaml are active in normal mode".to_string());
-    lines.push(
+        "Hotkeys: runtime bindings from orca.yaml are active in normal mode".to_string(),
         "Catalog: :ctx list/switch  :cluster list/switch  :usr list/switch  :ns list/scope"
             .to_string(),
-    );
-    lines.push("Config: :config view runtime aliases/plugins (auto-reload)".to_string());
-    lines.push("Safety: :readonly on|off|toggle (blocks mutating actions)".to_string());
-    lines.push(
+        "Config: :config view runtime aliases/plugins (auto-reload)".to_string(),
+        "Safety: :readonly on|off|toggle (blocks mutating actions)".to_string(),
         "Ops: :tools  :alerts  :argocd  :helm  :tf  :ansible  :docker  :rbac  :who-can  :oc  :kustomize  :git  :plugin"
             .to_string(),
-    );
-    lines.push("SRE: :pulses fleet snapshot  :xray selected-resource relations".to_string());
-    lines.push("Input: : command  > jump  / filter  Tab autocomplete  Ctrl+u/d page".to_string());
-    lines.push(String::new());
+        "SRE: :pulses fleet snapshot  :xray selected-resource relations".to_string(),
+        "Input: : command  > jump  / filter  Tab autocomplete  Ctrl+u/d page".to_string(),
+        String::new(),
+    ];
 
     if app.shell_overlay_active() {
         lines.push("Shell pane active".to_string());
@@ -2054,6 +2500,11 @@ fn contextual_help_lines(app: &App) -> Vec<String> {
     if app.table_overlay_active() {
         lines.push("Output pane active".to_string());
         lines.push("Keys: j/k or Ctrl+u/d scroll  gg/G top/bottom  Esc close output".to_string());
+        if app.log_overlay_active() {
+            lines.push(
+                "Logs: / filter (substring or regex)  i toggle case  Esc clear filter  :tail <n> lines  [ / ] older/newer time window".to_string(),
+            );
+        }
         lines.push(
             "Use Enter from table rows to drill deeper, then l/Shift+L for logs.".to_string(),
         );
@@ -2144,6 +2595,9 @@ fn resource_tab_help(tab: ResourceTab) -> String {
         ResourceTab::Namespaces => {
             "Namespace flow: Enter namespace to switch scope and open Pods".to_string()
         }
+        ResourceTab::Events => {
+            "Event flow: Enter jumps to the involved object  d details".to_string()
+        }
         ResourceTab::CustomResources => {
             "CRD flow: :crd <name|kind|plural> choose resource, Enter to navigate rows".to_string()
         }
@@ -2175,7 +2629,10 @@ fn resource_commands_help(tab: ResourceTab) -> String {
         ResourceTab::Deployments | ResourceTab::StatefulSets => {
             "Commands: :scale <replicas>  :restart  :edit  :delete".to_string()
         }
-        ResourceTab::Services => "Commands: :port-forward <L:R>  :edit  :delete".to_string(),
+        ResourceTab::DaemonSets => "Commands: :restart  :edit  :delete".to_string(),
+        ResourceTab::Services => {
+            "Commands: :port-forward <L:R>  :slices  :edit  :delete".to_string()
+        }
         ResourceTab::Namespaces => {
             "Commands: :ns <name> set scope  :all-ns clear scope".to_string()
         }
@@ -2189,6 +2646,7 @@ fn help_mode_label(mode: InputMode) -> &'static str {
         InputMode::Filter => "filter",
         InputMode::Command => "command",
         InputMode::Jump => "jump",
+        InputMode::Edit => "edit",
     }
 }
 
@@ -2255,6 +2713,7 @@ fn tab_icon(tab: ResourceTab) -> &'static str {
         ResourceTab::StatefulSets => "󰛨",
         ResourceTab::Jobs => "󰁨",
         ResourceTab::Services => "󰒓",
+        ResourceTab::EndpointSlices => "󰛳",
         ResourceTab::Ingresses => "󰇚",
         ResourceTab::IngressClasses => "󰊠",
         ResourceTab::ConfigMaps => "󰈙",
@@ -2262,12 +2721,17 @@ fn tab_icon(tab: ResourceTab) -> &'static str {
         ResourceTab::Secrets => "󰌋",
         ResourceTab::StorageClasses => "󰆼",
         ResourceTab::PersistentVolumes => "󱃔",
+        ResourceTab::VolumeSnapshots => "󰄄",
         ResourceTab::ServiceAccounts => "󰯃",
         ResourceTab::Roles => "󰒃",
         ResourceTab::RoleBindings => "󰑖",
         ResourceTab::ClusterRoles => "󰒄",
         ResourceTab::ClusterRoleBindings => "󰑗",
+        ResourceTab::PriorityClasses => "󰁞",
         ResourceTab::NetworkPolicies => "󰅙",
+        ResourceTab::Leases => "󰌾",
+        ResourceTab::MutatingWebhooks => "󰛕",
+        ResourceTab::ValidatingWebhooks => "󰄬",
         ResourceTab::Nodes => "󰣇",
         ResourceTab::Events => "󱐋",
         ResourceTab::Namespaces => "󰉖",
@@ -2294,18 +2758,26 @@ fn tab_group_label(tab: ResourceTab) -> &'static str {
         | ResourceTab::ReplicationControllers
         | ResourceTab::StatefulSets
         | ResourceTab::Jobs => "workloads",
-        ResourceTab::Services | ResourceTab::Ingresses | ResourceTab::IngressClasses => "service",
+        ResourceTab::Services
+        | ResourceTab::EndpointSlices
+        | ResourceTab::Ingresses
+        | ResourceTab::IngressClasses => "service",
         ResourceTab::ConfigMaps
         | ResourceTab::PersistentVolumeClaims
         | ResourceTab::Secrets
         | ResourceTab::StorageClasses
-        | ResourceTab::PersistentVolumes => "config",
+        | ResourceTab::PersistentVolumes
+        | ResourceTab::VolumeSnapshots => "config",
         ResourceTab::ServiceAccounts
         | ResourceTab::Roles
         | ResourceTab::RoleBindings
         | ResourceTab::ClusterRoles
         | ResourceTab::ClusterRoleBindings
+        | ResourceTab::PriorityClasses
         | ResourceTab::NetworkPolicies
+        | ResourceTab::Leases
+        | ResourceTab::MutatingWebhooks
+        | ResourceTab::ValidatingWebhooks
         | ResourceTab::Nodes
         | ResourceTab::Events
         | ResourceTab::Namespaces => "cluster",